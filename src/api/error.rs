@@ -0,0 +1,112 @@
+use crate::api::handlers::error_response;  // Štandardná obálka chybovej odpovede
+use axum::{                          // Webový framework
+    extract::rejection::{JsonRejection, QueryRejection},  // Chyba pri parsovaní query parametrov a tela
+    http::StatusCode,                 // HTTP status kódy
+    response::{IntoResponse, Response},
+};
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+
+/// Chyby vrátené API handlermi
+/// Každá chyba sa konvertuje na JSON `{ "success": false, "error": "..." }`
+#[derive(Debug, thiserror::Error)]
+pub enum ApiError {
+    /// Neplatný parameter požiadavky - 400 Bad Request
+    #[error("{0}")]
+    BadRequest(String),
+
+    /// Požadovaný záznam neexistuje - 404 Not Found
+    #[error("{0}")]
+    NotFound(String),
+
+    /// Chyba databázy - 500 Internal Server Error
+    /// Detail sa loguje na serveri, klient dostane len všeobecnú správu
+    #[error("Database error")]
+    Database(#[from] sqlx::Error),
+
+    /// Blokujúca úloha zberu metrík sa nedokončila - 500 Internal Server Error
+    #[error("Metrics collection failed")]
+    Collection(#[from] tokio::task::JoinError),
+
+    /// Zber metrík spanikáril; monitor sa vytvoril nanovo - 500 Internal Server Error
+    #[error("Metrics collection failed")]
+    CollectionPanicked(String),
+}
+
+impl ApiError {
+    /// HTTP status kód zodpovedajúci chybe
+    pub fn status_code(&self) -> StatusCode {
+        match self {
+            ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::Database(_) | ApiError::Collection(_) | ApiError::CollectionPanicked(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        match &self {
+            ApiError::Database(e) => eprintln!("❌ [API] Database error: {}", e),  // Detail len do logu
+            ApiError::Collection(e) => eprintln!("❌ [API] Metrics collection failed: {}", e),
+            ApiError::CollectionPanicked(message) => eprintln!("❌ [API] Metrics collection panicked, monitor restarted: {}", message),
+            ApiError::BadRequest(_) | ApiError::NotFound(_) => {}
+        }
+
+        (self.status_code(), error_response(self.to_string())).into_response()
+    }
+}
+
+/// Nesprávne formátované query parametre (napr. `?limit=abc`)
+/// vrátia rovnakú JSON odpoveď ako ostatné chyby validácie
+impl From<QueryRejection> for ApiError {
+    fn from(rejection: QueryRejection) -> Self {
+        ApiError::BadRequest(rejection.body_text())
+    }
+}
+
+/// Neplatné JSON telo požiadavky vráti rovnakú JSON odpoveď ako ostatné chyby
+impl From<JsonRejection> for ApiError {
+    fn from(rejection: JsonRejection) -> Self {
+        ApiError::BadRequest(rejection.body_text())
+    }
+}
+
+/// Kontrola, že desatinný query parameter je konečné nezáporné číslo
+/// (`NaN` a `inf` sa dajú zapísať do query, preto nestačí porovnanie s nulou)
+pub fn validate_non_negative(name: &str, value: f64) -> Result<f64, ApiError> {
+    if value.is_finite() && value >= 0.0 {
+        Ok(value)
+    } else {
+        Err(ApiError::BadRequest(format!(
+            "Parameter '{}' must be a non-negative number, got {}",
+            name, value
+        )))
+    }
+}
+
+/// Kontrola, že hodnota query parametra je v povolenom rozsahu
+///
+/// # Argumenty
+/// * `name` - Názov parametra (pre chybovú správu)
+/// * `value` - Zadaná hodnota
+/// * `range` - Povolený rozsah vrátane hraníc
+///
+/// # Návratová hodnota
+/// Hodnota parametra alebo `ApiError::BadRequest` s popisom chyby
+pub fn validate_query<T>(name: &str, value: T, range: RangeInclusive<T>) -> Result<T, ApiError>
+where
+    T: PartialOrd + Display,
+{
+    if range.contains(&value) {
+        Ok(value)
+    } else {
+        Err(ApiError::BadRequest(format!(
+            "Parameter '{}' must be between {} and {}, got {}",
+            name,
+            range.start(),
+            range.end(),
+            value
+        )))
+    }
+}
@@ -1,19 +1,102 @@
+use crate::api::error::{validate_non_negative, validate_query, ApiError};  // Jednotné chybové odpovede
 use crate::api::state::AppState;  // Stav aplikácie
+use crate::api::stream;           // Stream metrík (SSE)
 use crate::db;                    // Databázové funkcie
+use crate::services::event_log::{EventLevel, EVENT_LOG_CAPACITY};  // Log udalostí
+use crate::modes::saver::{MIN_SAVE_INTERVAL_SECS, MAX_SAVE_INTERVAL_SECS};  // Rozsah intervalu ukladania
+use crate::models::{MetricsDelta, CompactMetrics, ProcessMinimums, ProcessSort, SystemMetrics, SYSTEM_METRICS_SCHEMA};  // Zmena metrík v čase, kompaktný formát, popis polí
 use axum::{                       // Webový framework
-    extract::{Query, State},      // Extrakcia parametrov z požiadaviek
-    http::StatusCode,             // HTTP status kódy
+    body::Body,                   // Telo odpovede (pretty JSON)
+    extract::{rejection::{JsonRejection, QueryRejection}, Path, Query, Request, State},  // Extrakcia parametrov z požiadaviek
+    http::{header, HeaderMap, StatusCode},  // Hlavičky (ETag, If-None-Match)
+    response::sse::{Event, KeepAlive, Sse},  // Server-Sent Events
+    middleware::Next,             // Ďalšia vrstva (pretty JSON)
+    response::{IntoResponse, Response},
     Json,                         // JSON serializácia
 };
+use futures_util::stream::Stream;  // Prúd SSE udalostí
 use serde::{Deserialize, Serialize};  // Serializácia/deserializácia
 use serde_json::{json, Value};        // Práca s JSON hodnotami
 
-/// Query parameter pre obmedzenie počtu výsledkov
-/// Používa sa napr. v `/api/metrics/latest?limit=10`
+// ==================== OBÁLKA ODPOVEDE ====================
+
+/// Úspešná odpoveď `{ "success": true, "data": ... }`
+pub fn success_response(data: impl Serialize) -> Json<Value> {
+    success_response_with(data, Value::Null)
+}
+
+/// Úspešná odpoveď s ďalšími poľami vedľa `data` (napr. `count`, `limit`)
+/// `meta` musí byť JSON objekt, iné hodnoty sa ignorujú
+pub fn success_response_with(data: impl Serialize, meta: Value) -> Json<Value> {
+    let mut body = json!({
+        "success": true,
+        "data": data
+    });
+    if let (Some(body), Value::Object(meta)) = (body.as_object_mut(), meta) {
+        body.extend(meta);
+    }
+    Json(body)
+}
+
+/// Neúspešná odpoveď `{ "success": false, "error": "..." }`
+/// Rovnaký tvar vracia aj `ApiError`
+pub fn error_response(message: impl Into<String>) -> Json<Value> {
+    Json(json!({
+        "success": false,
+        "error": message.into()
+    }))
+}
+
+/// Query parameter `?pretty=true` spoločný pre všetky endpointy
+#[derive(Debug, Deserialize)]
+struct PrettyQuery {
+    #[serde(default)]  // Predvolene kompaktný JSON
+    pretty: bool,
+}
+
+/// Middleware pre `?pretty=true` - JSON odpoveď s odsadením (ladenie cez curl)
+///
+/// Bez parametra sa odpoveď nemení. Odpovede iného typu ako `application/json`
+/// (SSE stream, `/metrics`) sa neprepisujú. Neplatná hodnota (`?pretty=yes`)
+/// vráti 400 ako ostatné query parametre.
+pub async fn pretty_json(request: Request, next: Next) -> Response {
+    let pretty = match Query::<PrettyQuery>::try_from_uri(request.uri()) {
+        Ok(Query(query)) => query.pretty,
+        Err(rejection) => return ApiError::from(rejection).into_response(),
+    };
+
+    let response = next.run(request).await;
+    let is_json = response.headers()
+        .get(header::CONTENT_TYPE)
+        .is_some_and(|value| value.as_bytes().starts_with(b"application/json"));
+    if !pretty || !is_json {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = axum::body::to_bytes(body, usize::MAX).await else {
+        return (StatusCode::INTERNAL_SERVER_ERROR, error_response("Failed to read response body")).into_response();
+    };
+
+    // Telo, ktoré nie je platný JSON, sa vráti bez zmeny
+    match serde_json::from_slice::<Value>(&bytes).ok().and_then(|value| serde_json::to_vec_pretty(&value).ok()) {
+        Some(indented) => {
+            parts.headers.remove(header::CONTENT_LENGTH);  // Dĺžka sa zmenila
+            Response::from_parts(parts, Body::from(indented))
+        }
+        None => Response::from_parts(parts, Body::from(bytes)),
+    }
+}
+
+/// Query parametre pre `/api/processes/top?limit=10&sort=disk&min_cpu=5&min_memory_mb=100`
 #[derive(Debug, Deserialize)]
 pub struct LimitQuery {
     #[serde(default = "default_limit")]  // Predvolená hodnota 10 ak nie je zadané
     pub limit: i64,
+    #[serde(default)]                    // Predvolene kombinované skóre
+    pub sort: ProcessSort,
+    pub min_cpu: Option<f64>,            // Len procesy s CPU >= min_cpu %
+    pub min_memory_mb: Option<f64>,      // Len procesy s pamäťou >= min_memory_mb MB
 }
 
 /// Predvolená hodnota pre limit výsledkov
@@ -21,6 +104,141 @@ fn default_limit() -> i64 {
     10
 }
 
+/// Povolený rozsah parametra `limit` (vrátane hraníc)
+const MIN_LIMIT: i64 = 1;
+const MAX_LIMIT: i64 = 1000;
+
+impl LimitQuery {
+    /// Limit orezaný na serverový strop namiesto odmietnutia
+    /// Hodnoty menšie ako 1 sa odmietnu skôr, než dôjde ku konverzii na `usize`
+    /// (záporné by pretiekli na obrovské číslo)
+    pub fn capped(&self, cap: usize) -> Result<usize, ApiError> {
+        if self.limit < MIN_LIMIT {
+            return Err(ApiError::BadRequest(format!(
+                "Parameter 'limit' must be >= {}, got {}",
+                MIN_LIMIT, self.limit
+            )));
+        }
+        Ok(usize::try_from(self.limit).unwrap_or(usize::MAX).min(cap))
+    }
+
+    /// Kontrola a prevod `min_cpu` / `min_memory_mb` (záporné hodnoty sa odmietnu)
+    pub fn minimums(&self) -> Result<ProcessMinimums, ApiError> {
+        let cpu = self.min_cpu.map(|value| validate_non_negative("min_cpu", value)).transpose()?;
+        let memory_mb = self.min_memory_mb.map(|value| validate_non_negative("min_memory_mb", value)).transpose()?;
+
+        Ok(ProcessMinimums {
+            cpu: cpu.map(|value| value as f32),
+            memory: memory_mb.map(|value| (value * 1024.0 * 1024.0) as u64),
+        })
+    }
+}
+
+/// Query parametre pre `/api/metrics/latest?limit=10&order=asc`
+#[derive(Debug, Deserialize)]
+pub struct LatestQuery {
+    #[serde(default = "default_limit")]  // Predvolená hodnota 10 ak nie je zadané
+    pub limit: i64,
+    #[serde(default)]                    // Predvolene od najnovšieho (desc)
+    pub order: db::SortOrder,
+}
+
+impl LatestQuery {
+    /// Kontrola, že limit je v rozsahu 1..=1000
+    /// Záporné hodnoty by v PostgreSQL zlyhali (`LIMIT -1`)
+    pub fn validate(&self) -> Result<i64, ApiError> {
+        validate_query("limit", self.limit, MIN_LIMIT..=MAX_LIMIT)
+    }
+}
+
+/// Formát odpovede s metrikami
+#[derive(Debug, Default, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricsFormat {
+    #[default]
+    Full,     // Všetky polia `SystemMetrics`
+    Compact,  // Len cpu, mem_pct, disk_pct, gpu, temp
+}
+
+/// Query parameter pre `/api/metrics/current?format=compact`
+#[derive(Debug, Deserialize)]
+pub struct FormatQuery {
+    #[serde(default)]  // Predvolene úplný formát
+    pub format: MetricsFormat,
+}
+
+/// ETag aktuálnych metrík - mení sa s novým riadkom v DB alebo iným formátom
+/// Tvar `"<id>-<timestamp v ms>-<format>"`
+pub(super) fn metrics_etag(metrics: &SystemMetrics, format: MetricsFormat) -> String {
+    let format = match format {
+        MetricsFormat::Full => "full",
+        MetricsFormat::Compact => "compact",
+    };
+    format!(
+        "\"{}-{}-{}\"",
+        metrics.id.unwrap_or_default(),
+        metrics.timestamp.timestamp_millis(),
+        format
+    )
+}
+
+/// Zhoduje sa `If-None-Match` od klienta s ETagom? (zoznam oddelený čiarkou,
+/// slabé `W/` porovnanie aj `*`)
+pub(super) fn etag_matches(headers: &HeaderMap, etag: &str) -> bool {
+    headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(|tag| tag.trim())
+        .any(|tag| tag == "*" || tag.trim_start_matches("W/") == etag)
+}
+
+/// Query parameter pre `/api/events/metrics?max_rate=0.5`
+#[derive(Debug, Deserialize)]
+pub struct StreamQuery {
+    #[serde(default = "default_max_rate")]  // Predvolene jedna snímka za sekundu
+    pub max_rate: f64,
+}
+
+/// Predvolený počet snímok za sekundu
+fn default_max_rate() -> f64 {
+    1.0
+}
+
+/// Povolený rozsah parametra `max_rate` (snímky za sekundu)
+const MIN_MAX_RATE: f64 = 0.01;
+const MAX_MAX_RATE: f64 = 10.0;
+
+impl StreamQuery {
+    /// Kontrola, že `max_rate` je v rozsahu 0.01..=10
+    /// Nula by znamenala nekonečný odstup snímok
+    pub fn validate(&self) -> Result<f64, ApiError> {
+        validate_query("max_rate", self.max_rate, MIN_MAX_RATE..=MAX_MAX_RATE)
+    }
+}
+
+/// Query parametre pre `/api/logs?level=warn&limit=50`
+#[derive(Debug, Deserialize)]
+pub struct LogsQuery {
+    #[serde(default)]                    // Predvolene všetky udalosti (info a vyššie)
+    pub level: EventLevel,
+    #[serde(default = "default_logs_limit")]
+    pub limit: i64,
+}
+
+/// Predvolený počet vrátených udalostí
+fn default_logs_limit() -> i64 {
+    100
+}
+
+impl LogsQuery {
+    /// Kontrola, že limit je v rozsahu 1..=EVENT_LOG_CAPACITY (viac sa v logu nedrží)
+    pub fn validate(&self) -> Result<i64, ApiError> {
+        validate_query("limit", self.limit, 1..=EVENT_LOG_CAPACITY as i64)
+    }
+}
+
 /// Query parameter pre časový rozsah v hodinách
 /// Používa sa napr. v `/api/metrics/history?hours=24`
 #[derive(Debug, Deserialize)]
@@ -34,59 +252,87 @@ fn default_hours() -> i64 {
     24
 }
 
+/// Povolený rozsah parametra `hours` (najviac jeden rok)
+const MIN_HOURS: i64 = 1;
+const MAX_HOURS: i64 = 24 * 365;
+
+impl HoursQuery {
+    /// Kontrola, že počet hodín je v rozsahu 1..=8760
+    pub fn validate(&self) -> Result<i64, ApiError> {
+        validate_query("hours", self.hours, MIN_HOURS..=MAX_HOURS)
+    }
+}
+
 // ==================== HANDLERE PRE METRIKY ====================
 
-/// GET /api/metrics/current
+/// GET /api/metrics/current?format=compact
 /// Vráti aktuálne metriky systému (posledne uložené v databáze)
+/// S `format=compact` len skrátený objekt pre klientov s malým prenosom
+/// Odpoveď nesie `ETag`; klient s rovnakým `If-None-Match` dostane `304` bez tela
 ///
 /// # Parametre
 /// - `state`: Globálny stav aplikácie
+/// - `headers`: Hlavičky požiadavky (`If-None-Match`)
+/// - `params`: Query parametre (format)
 ///
 /// # Návratová hodnota
-/// - `Ok(Json)`: JSON s aktuálnymi metrikami
-/// - `Err(StatusCode)`: 500 ak nastane chyba
+/// - `Ok(Response)`: JSON s aktuálnymi metrikami, alebo `304 Not Modified`
+/// - `Err(ApiError)`: 400 pri neznámom formáte, 404 ak v DB ešte nie je žiadna vzorka,
+///   500 ak nastane chyba
 pub async fn get_current_metrics(
     State(state): State<AppState>,
-) -> Result<Json<Value>, StatusCode> {
+    headers: HeaderMap,
+    params: Result<Query<FormatQuery>, QueryRejection>,
+) -> Result<Response, ApiError> {
+    let Query(params) = params?;
+
     // Načítanie aktuálnych metrík z databázy
-    let metrics = db::get_current_metrics(&state.db_pool)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;  // Konvertovanie chyby na 500
-
-    match metrics {
-        Some(m) => Ok(Json(json!({
-            "success": true,
-            "data": m
-        }))),
-        None => Ok(Json(json!({
-            "success": false,
-            "message": "No metrics available yet"  // Žiadne metriky ešte nie sú dostupné
-        }))),
+    let metrics = db::get_current_metrics(&state.db_pool.get()).await?;  // Chyba databázy -> 500
+
+    let Some(m) = metrics else {
+        // Žiadne metriky ešte nie sú uložené - 404 s chybovou obálkou, nie 200
+        return Err(ApiError::NotFound("No metrics available yet".to_string()));
+    };
+
+    // Klient má aktuálnu verziu - bez tela
+    let etag = metrics_etag(&m, params.format);
+    if etag_matches(&headers, &etag) {
+        return Ok((StatusCode::NOT_MODIFIED, [(header::ETAG, etag)]).into_response());
     }
+
+    let body = match params.format {
+        MetricsFormat::Compact => success_response(CompactMetrics::from(&m)),
+        MetricsFormat::Full => success_response(m),
+    };
+    Ok(([(header::ETAG, etag)], body).into_response())
 }
 
-/// GET /api/metrics/latest?limit=10
+/// GET /api/metrics/latest?limit=10&order=desc
 /// Vráti X najnovších metrík (podľa parametra limit)
+/// S `order=asc` sú zoradené chronologicky (vhodné pre grafy)
 ///
 /// # Parametre
 /// - `state`: Globálny stav aplikácie
-/// - `params`: Query parametre (limit)
+/// - `params`: Query parametre (limit, order)
 ///
 /// # Návratová hodnota
 /// - `Ok(Json)`: JSON so zoznamom metrík
+/// - `Err(ApiError)`: 400 ak limit chýba v správnom formáte alebo je mimo rozsahu 1..=1000,
+///   alebo ak `order` nie je `asc`/`desc`
 pub async fn get_latest_metrics(
     State(state): State<AppState>,
-    Query(params): Query<LimitQuery>,
-) -> Result<Json<Value>, StatusCode> {
+    params: Result<Query<LatestQuery>, QueryRejection>,
+) -> Result<Json<Value>, ApiError> {
+    let Query(params) = params?;     // Neplatný formát -> 400 s JSON telom
+    let limit = params.validate()?;  // Kontrola rozsahu limitu
+
     // Načítanie N najnovších metrík z databázy
-    let metrics = db::get_latest_metrics(&state.db_pool, params.limit)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let metrics = db::get_latest_metrics(&state.db_pool.get(), limit, params.order).await?;
 
-    Ok(Json(json!({
-        "success": true,
-        "count": metrics.len(),  // Skutočný počet vrátených záznamov
-        "data": metrics
+    Ok(success_response_with(&metrics, json!({
+        "limit": limit,          // Použitý limit (predvolený alebo zadaný)
+        "order": params.order.as_sql().to_lowercase(),  // Použité poradie
+        "count": metrics.len()   // Skutočný počet vrátených záznamov
     })))
 }
 
@@ -99,42 +345,61 @@ pub async fn get_latest_metrics(
 ///
 /// # Návratová hodnota
 /// - `Ok(Json)`: JSON s históriou metrík
+/// - `Err(ApiError)`: 400 ak je počet hodín mimo rozsahu 1..=8760
 pub async fn get_metrics_history(
     State(state): State<AppState>,
-    Query(params): Query<HoursQuery>,
-) -> Result<Json<Value>, StatusCode> {
+    params: Result<Query<HoursQuery>, QueryRejection>,
+) -> Result<Json<Value>, ApiError> {
+    let Query(params) = params?;
+    let hours = params.validate()?;  // Kontrola rozsahu hodín
+
     // Načítanie metrík za posledných N hodín
-    let metrics = db::get_metrics_since(&state.db_pool, params.hours)
-        .await
-        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    let metrics = db::get_metrics_since(&state.db_pool.get(), hours).await?;
 
-    Ok(Json(json!({
-        "success": true,
+    Ok(success_response_with(&metrics, json!({
         "count": metrics.len(),
-        "hours": params.hours,  // Vrátime späť počet požadovaných hodín
-        "data": metrics
+        "hours": hours   // Vrátime späť počet požadovaných hodín
     })))
 }
 
+/// GET /api/metrics/delta?hours=24
+/// Vráti zmenu použitého disku, RAM a počtu procesov medzi najstaršou
+/// a najnovšou vzorkou v okne, spolu so zmenou za hodinu
+///
+/// # Návratová hodnota
+/// - `Ok(Json)`: JSON so zmenami (pri menej ako dvoch vzorkách hodnoty `null`)
+/// - `Err(ApiError)`: 400 ak je počet hodín mimo rozsahu 1..=8760
+pub async fn get_metrics_delta(
+    State(state): State<AppState>,
+    params: Result<Query<HoursQuery>, QueryRejection>,
+) -> Result<Json<Value>, ApiError> {
+    let Query(params) = params?;
+    let hours = params.validate()?;  // Kontrola rozsahu hodín
+
+    let (oldest, newest) = db::get_window_endpoints(&state.db_pool.get(), hours).await?;
+    let delta = MetricsDelta::between(oldest.as_ref(), newest.as_ref());
+
+    Ok(success_response_with(delta, json!({ "hours": hours })))
+}
+
 // ==================== HANDLERE PRE SYSTÉMOVÉ INFORMÁCIE ====================
 
 /// GET /api/cpu
-/// Vráti informácie o procesore
+/// Vráti využitie a frekvenciu každého logického procesora
 ///
-/// # Poznámka
-/// Tento endpoint momentálne volá `get_gpu_info()` - pravdepodobne chyba v implementácii
-/// Malo by volať `get_cpu_info()` alebo podobnú metódu
+/// Logické procesory s rovnakým `physical_core` zdieľajú fyzické jadro (SMT).
+/// `physical_cores` je `null`, ak platforma počet fyzických jadier neposkytuje.
 pub async fn get_cpu_info(
     State(state): State<AppState>,
-) -> Result<Json<Value>, StatusCode> {
-    // Získanie zámku na monitor (aby sa predišlo súbežným prístupom)
-    let mut monitor = state.system_monitor.lock().await;
-    let cpu_info = monitor.get_gpu_info();  // TODO: Opraviť na get_cpu_info()
+) -> Result<Json<Value>, ApiError> {
+    // Zber v blokujúcom vlákne (zámok sa nedrží v async kontexte)
+    let (cpu_info, physical_cores) = state
+        .with_monitor(|monitor| (monitor.get_cpu_info(), monitor.physical_core_count()))
+        .await?;
 
-    Ok(Json(json!({
-        "success": true,
-        "cpu_count": cpu_info.as_ref().map(|c| c.name.len()).unwrap_or(0),  // Počet CPU jadier
-        "data": cpu_info
+    Ok(success_response_with(&cpu_info, json!({
+        "cpu_count": cpu_info.len(),        // Počet logických procesorov
+        "physical_cores": physical_cores,   // Počet fyzických jadier (ak je známy)
     })))
 }
 
@@ -145,14 +410,10 @@ pub async fn get_cpu_info(
 /// Tento endpoint tiež volá `get_gpu_info()` - potrebuje opraviť
 pub async fn get_memory_info(
     State(state): State<AppState>,
-) -> Result<Json<Value>, StatusCode> {
-    let mut monitor = state.system_monitor.lock().await;
-    let memory_info = monitor.get_gpu_info();  // TODO: Opraviť na get_memory_info()
+) -> Result<Json<Value>, ApiError> {
+    let memory_info = state.with_monitor(|monitor| monitor.get_gpu_info()).await?;  // TODO: Opraviť na get_memory_info()
 
-    Ok(Json(json!({
-        "success": true,
-        "data": memory_info
-    })))
+    Ok(success_response(memory_info))
 }
 
 /// GET /api/disk
@@ -162,34 +423,87 @@ pub async fn get_memory_info(
 /// Tento endpoint tiež volá `get_gpu_info()` - potrebuje opraviť
 pub async fn get_disk_info(
     State(state): State<AppState>,
-) -> Result<Json<Value>, StatusCode> {
-    let mut monitor = state.system_monitor.lock().await;
-    let disk_info = monitor.get_gpu_info();  // TODO: Opraviť na get_disk_info()
+) -> Result<Json<Value>, ApiError> {
+    let disk_info = state.with_monitor(|monitor| monitor.get_gpu_info()).await?;  // TODO: Opraviť na get_disk_info()
 
-    Ok(Json(json!({
-        "success": true,
-        "count": disk_info.as_ref().map(|d| d.name.len()).unwrap_or(0),  // Počet diskov
-        "data": disk_info
+    Ok(success_response_with(&disk_info, json!({
+        "count": disk_info.as_ref().map(|d| d.name.len()).unwrap_or(0)  // Počet diskov
     })))
 }
 
-/// GET /api/processes/top?limit=10
+/// GET /api/processes/top?limit=10&sort=disk
 /// Vráti X najnáročnejších procesov podľa využitia zdrojov
 ///
 /// # Parametre
 /// - `state`: Globálny stav aplikácie
-/// - `params`: Query parametre (limit)
+/// - `params`: Query parametre (limit, sort = combined/cpu/memory/network/disk/swap)
+///
+/// # Návratová hodnota
+/// - `Ok(Json)`: `limit` je skutočne použitý limit (najviac `max_process_limit`)
+/// - `Err(ApiError)`: 400 ak je limit menší ako 1
 pub async fn get_top_processes(
     State(state): State<AppState>,
-    Query(params): Query<LimitQuery>,
-) -> Result<Json<Value>, StatusCode> {
-    let mut monitor = state.system_monitor.lock().await;
-    let processes = monitor.get_top_processes(params.limit as usize);  // Konverzia na usize
+    params: Result<Query<LimitQuery>, QueryRejection>,
+) -> Result<Json<Value>, ApiError> {
+    let Query(params) = params?;
+    let limit = params.capped(state.max_process_limit)?;  // Orezanie na serverový strop
+    let minimums = params.minimums()?;                     // Záporné hranice -> 400
 
-    Ok(Json(json!({
-        "success": true,
-        "count": processes.len(),
-        "data": processes
+    // refresh_all() je pomalé - beží mimo vlákien async runtime
+    let sort = params.sort;
+    let processes = state.with_monitor(move |monitor| monitor.get_top_processes(limit, sort, minimums)).await?;
+
+    Ok(success_response_with(&processes, json!({
+        "limit": limit,                          // Skutočne použitý limit
+        "requested_limit": params.limit,         // Limit zadaný klientom
+        "max_limit": state.max_process_limit,    // Serverový strop
+        "min_cpu": params.min_cpu,               // Použité hranice (null = bez filtra)
+        "min_memory_mb": params.min_memory_mb,
+        "count": processes.len()
+    })))
+}
+
+/// GET /api/processes/{pid}
+/// Vráti jeden proces podľa PID vrátane príkazového riadku (`cmd`)
+///
+/// # Návratová hodnota
+/// - `Ok(Json)`: Proces (bez sieťových štatistík; `cmd` chýba, ak ho OS nevráti)
+/// - `Err(ApiError)`: 400 pri nečíselnom PID, 404 ak proces neexistuje
+pub async fn get_process(
+    State(state): State<AppState>,
+    Path(pid): Path<String>,
+) -> Result<Json<Value>, ApiError> {
+    let pid: u32 = pid.parse()
+        .map_err(|_| ApiError::BadRequest(format!("Invalid PID '{}'", pid)))?;
+
+    let process = state.with_monitor(move |monitor| monitor.get_process(pid)).await?
+        .ok_or_else(|| ApiError::NotFound(format!("Process {} not found", pid)))?;
+
+    Ok(success_response(process))
+}
+
+/// GET /api/processes/{name}/history?hours=24
+/// Vráti uložené vzorky procesu s daným názvom za posledných X hodín
+/// Vzorky ukladá len server spustený s `--save-processes` (top procesy podľa CPU),
+/// proces mimo top procesov v danom cykle nemá v tom čase vzorku
+///
+/// # Návratová hodnota
+/// - `Ok(Json)`: JSON so vzorkami (čas, PID, CPU, pamäť) zoradenými chronologicky
+/// - `Err(ApiError)`: 400 ak je počet hodín mimo rozsahu 1..=8760
+pub async fn get_process_history(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    params: Result<Query<HoursQuery>, QueryRejection>,
+) -> Result<Json<Value>, ApiError> {
+    let Query(params) = params?;
+    let hours = params.validate()?;  // Kontrola rozsahu hodín
+
+    let points = db::get_process_history(&state.db_pool.get(), &name, hours).await?;
+
+    Ok(success_response_with(&points, json!({
+        "name": name,
+        "count": points.len(),
+        "hours": hours
     })))
 }
 
@@ -204,34 +518,166 @@ pub async fn get_top_processes(
 /// - `average_cpu_24h`: Priemerné využitie CPU za posledných 24 hodín
 pub async fn get_stats(
     State(state): State<AppState>,
-) -> Result<Json<Value>, StatusCode> {
+) -> Result<Json<Value>, ApiError> {
     // Asynchrónne načítanie viacerých štatistík súčasne
-    let avg_cpu_1h = db::get_average_cpu(&state.db_pool, 1).await.unwrap_or(0.0);
-    let avg_cpu_24h = db::get_average_cpu(&state.db_pool, 24).await.unwrap_or(0.0);
-    let total_metrics = db::count_metrics(&state.db_pool).await.unwrap_or(0);
+    let avg_cpu_1h = db::get_average_cpu(&state.db_pool.get(), 1).await.unwrap_or(0.0);
+    let avg_cpu_24h = db::get_average_cpu(&state.db_pool.get(), 24).await.unwrap_or(0.0);
+    let total_metrics = db::count_metrics(&state.db_pool.get()).await.unwrap_or(0);
 
-    Ok(Json(json!({
-        "success": true,
-        "stats": {
-            "total_metrics": total_metrics,
-            "average_cpu_1h": avg_cpu_1h,
-            "average_cpu_24h": avg_cpu_24h
-        }
+    Ok(success_response(json!({
+        "total_metrics": total_metrics,
+        "average_cpu_1h": avg_cpu_1h,
+        "average_cpu_24h": avg_cpu_24h
+    })))
+}
+
+// ==================== STREAM ====================
+
+/// GET /api/events/metrics?max_rate=1
+/// Priebežné metriky ako Server-Sent Events (udalosti `metrics` a `stats`)
+/// v intervale `--stream-interval`, kým sa klient neodpojí
+/// Pomalý klient dostáva vždy najnovšiu vzorku, medziľahlé sa zahodia
+/// Počas ticha chodia komentáre `: heartbeat`, aby proxy nezavrela spojenie
+///
+/// # Parametre
+/// - `state`: Globálny stav aplikácie
+/// - `params`: Query parametre (max_rate - najviac snímok za sekundu)
+///
+/// # Návratová hodnota
+/// - `Ok(Sse)`: Stream udalostí, kým sa klient neodpojí
+/// - `Err(ApiError)`: 400 ak `max_rate` nie je v rozsahu 0.01..=10
+pub async fn stream_metrics(
+    State(state): State<AppState>,
+    params: Result<Query<StreamQuery>, QueryRejection>,
+) -> Result<Sse<impl Stream<Item = Result<Event, axum::Error>>>, ApiError> {
+    let Query(params) = params?;
+    let max_rate = params.validate()?;  // Validácia rozsahu
+
+    let receiver = state.metrics_feed.subscribe();
+    let keep_alive = KeepAlive::new()
+        .interval(stream::HEARTBEAT_INTERVAL)
+        .text("heartbeat");
+
+    Ok(Sse::new(stream::metrics_events(receiver, max_rate)).keep_alive(keep_alive))
+}
+
+// ==================== LOG UDALOSTÍ ====================
+
+/// GET /api/logs?level=warn&limit=50
+/// Posledné udalosti úloh na pozadí (ukladanie metrík), od najnovšej
+/// `level` je najnižšia vrátená závažnosť: `info`, `warn` alebo `error`
+///
+/// # Návratová hodnota
+/// - `Ok(Json)`: Zoznam udalostí (`timestamp`, `level`, `source`, `message`)
+/// - `Err(ApiError)`: 400 pri neznámej úrovni alebo limite mimo rozsahu
+pub async fn get_logs(
+    State(state): State<AppState>,
+    params: Result<Query<LogsQuery>, QueryRejection>,
+) -> Result<Json<Value>, ApiError> {
+    let Query(params) = params?;
+    let limit = params.validate()?;
+
+    let events = state.events.recent(params.level, limit as usize);
+
+    Ok(success_response_with(&events, json!({
+        "level": params.level,      // Najnižšia vrátená závažnosť
+        "limit": limit,
+        "count": events.len()
     })))
 }
 
+/// POST /api/metrics/save
+/// Okamžité meranie a uloženie metrík nezávisle od intervalu ukladania na pozadí
+/// Deduplikácia ukladania na pozadí sa neuplatní - vždy vznikne nový záznam
+///
+/// # Návratová hodnota
+/// - `Ok(Json)`: Uložené metriky vrátane `id`, ID aj na najvyššej úrovni
+/// - `Err(ApiError)`: 400 bez `--save-metrics`, 500 pri chybe zberu alebo databázy
+pub async fn post_save_metrics(
+    State(state): State<AppState>,
+) -> Result<Json<Value>, ApiError> {
+    if state.save_interval.is_none() {
+        return Err(ApiError::BadRequest(SAVING_DISABLED.to_string()));
+    }
+
+    let mut metrics = state.with_monitor(|monitor| monitor.get_metrics_for_db()).await?;
+    let id = db::save_metrics(&state.db_pool.get(), &metrics).await?;
+    metrics.id = Some(id);
+    state.events.record(EventLevel::Info, "manual-save", format!("Metrics saved on demand (ID: {})", id));
+
+    Ok(success_response_with(&metrics, json!({ "id": id })))
+}
+
+// ==================== KONFIGURÁCIA ====================
+
+/// Chyba endpointov, ktoré potrebujú ukladanie metrík (`--save-metrics`)
+const SAVING_DISABLED: &str = "Background metric saving is disabled (start the server with --save-metrics)";
+
+/// Telo požiadavky pre `PUT /api/config/save-interval`
+#[derive(Debug, Deserialize)]
+pub struct SaveIntervalRequest {
+    pub seconds: u64,  // Nový interval ukladania v sekundách
+}
+
+/// PUT /api/config/save-interval  `{"seconds": 30}`
+/// Zmena intervalu ukladania metrík na pozadí bez reštartu servera
+/// Nová hodnota platí od nasledujúceho uloženia
+///
+/// # Návratová hodnota
+/// - `Ok(Json)`: Predchádzajúci a nový interval v sekundách
+/// - `Err(ApiError)`: 400 bez `--save-metrics`, pri neplatnom tele
+///   alebo hodnote mimo rozsahu 5 - 86400
+pub async fn put_save_interval(
+    State(state): State<AppState>,
+    body: Result<Json<SaveIntervalRequest>, JsonRejection>,
+) -> Result<Json<Value>, ApiError> {
+    let Some(save_interval) = &state.save_interval else {
+        return Err(ApiError::BadRequest(SAVING_DISABLED.to_string()));
+    };
+    let Json(body) = body?;
+    let seconds = validate_query("seconds", body.seconds, MIN_SAVE_INTERVAL_SECS..=MAX_SAVE_INTERVAL_SECS)?;
+
+    let previous = save_interval.seconds();
+    save_interval.set_seconds(seconds);
+    state.events.record(
+        EventLevel::Info,
+        "config",
+        format!("Save interval changed from {}s to {}s", previous, seconds),
+    );
+
+    Ok(success_response(json!({
+        "previous_seconds": previous,
+        "seconds": seconds
+    })))
+}
+
+// ==================== SCHÉMA ====================
+
+/// GET /api/schema
+/// Popis polí `SystemMetrics` - typ, jednotka a či môže byť `null`
+/// Generickí klienti (dashboardy) podľa neho zobrazia hodnoty bez hardcodovania
+///
+/// # Návratová hodnota
+/// Vždy `200 OK` so zoznamom polí v poradí štruktúry
+pub async fn get_schema() -> Json<Value> {
+    success_response(SYSTEM_METRICS_SCHEMA)
+}
+
 // ==================== HEALTH CHECK ====================
 
 /// GET /health
 /// Health check endpoint pre monitorovanie stavu služby
 /// Používa sa napr. kubernetes, docker swarm, load balancermi
+/// Nepristupuje k databáze ani k monitoru, takže je lacný aj pri častom volaní
 ///
 /// # Návratová hodnota
-/// Vždy vráti `200 OK` so základnými informáciami o službe
-pub async fn health_check() -> Json<Value> {
+/// Vždy vráti `200 OK` so základnými informáciami o službe, verziou a dobou behu
+pub async fn health_check(State(state): State<AppState>) -> Json<Value> {
     Json(json!({
         "status": "ok",
         "service": "system-monitor",
+        "version": env!("CARGO_PKG_VERSION"),                          // Verzia z Cargo.toml
+        "uptime_seconds": state.started_at.elapsed().as_secs_f64(),  // Doba behu servera
         "timestamp": chrono::Utc::now().to_rfc3339()  // Časová pečiatka odpovede
     }))
 }
\ No newline at end of file
@@ -2,7 +2,13 @@
 pub mod state;      // Štruktúry pre správu stavu aplikácie
 pub mod routes;     // Definície API endpointov
 pub mod handlers;   // Obsluha HTTP požiadaviek
+pub mod error;      // Jednotné chybové odpovede API
+pub mod stream;     // Stream metrík (SSE) so zahadzovaním snímok
+pub mod request_metrics;  // Počítadlá požiadaviek pre Prometheus (/metrics)
+
+#[cfg(test)]
+mod tests;          // Testy handlerov proti PostgreSQL z `DATABASE_URL`
 
 /// Reexporty pre jednoduchší prístup z iných modulov
 pub use state::AppState;
-pub use routes::create_router;
\ No newline at end of file
+pub use routes::{create_router, nest_under_base_path, normalize_base_path};
\ No newline at end of file
@@ -0,0 +1,131 @@
+// request_metrics.rs
+
+use crate::api::state::AppState;  // Stav aplikácie
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::{header, Method},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Horné hranice košov histogramu latencie v sekundách (štandardné hodnoty Prometheus klientov)
+const DURATION_BUCKETS: [f64; 11] = [0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0];
+
+/// Content-Type textového formátu Prometheus
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4; charset=utf-8";
+
+/// Histogram latencie jednej cesty
+#[derive(Debug, Default, Clone)]
+struct DurationHistogram {
+    buckets: [u64; DURATION_BUCKETS.len()],  // Počet požiadaviek <= hranica (kumulatívne)
+    count: u64,                              // Počet všetkých požiadaviek
+    sum: f64,                                // Súčet trvaní v sekundách
+}
+
+impl DurationHistogram {
+    fn observe(&mut self, seconds: f64) {
+        for (bucket, bound) in self.buckets.iter_mut().zip(DURATION_BUCKETS) {
+            if seconds <= bound {
+                *bucket += 1;
+            }
+        }
+        self.count += 1;
+        self.sum += seconds;
+    }
+}
+
+/// Počítadlá požiadaviek podľa cesty (zdieľa ich celý router cez `AppState`)
+///
+/// Cesta je šablóna z routera (napr. `/api/metrics/latest`), nie skutočná URL,
+/// takže počet sérií ostáva obmedzený aj pri rôznych query parametroch.
+#[derive(Debug, Default)]
+pub struct RequestMetrics {
+    requests: Mutex<BTreeMap<(String, String, u16), u64>>,            // (metóda, cesta, status) -> počet
+    durations: Mutex<BTreeMap<(String, String), DurationHistogram>>,  // (metóda, cesta) -> latencia
+}
+
+impl RequestMetrics {
+    /// Započítanie jednej vybavenej požiadavky
+    pub fn record(&self, method: &Method, route: &str, status: u16, elapsed: Duration) {
+        let method = method.as_str().to_string();
+
+        if let Ok(mut requests) = self.requests.lock() {
+            *requests.entry((method.clone(), route.to_string(), status)).or_default() += 1;
+        }
+        if let Ok(mut durations) = self.durations.lock() {
+            durations.entry((method, route.to_string())).or_default().observe(elapsed.as_secs_f64());
+        }
+    }
+
+    /// Výpis v textovom formáte Prometheus
+    /// (`http_requests_total` a `http_request_duration_seconds`)
+    pub fn render_prometheus(&self) -> String {
+        let mut out = String::new();
+
+        out.push_str("# HELP http_requests_total Total number of HTTP requests by route and status.\n");
+        out.push_str("# TYPE http_requests_total counter\n");
+        if let Ok(requests) = self.requests.lock() {
+            for ((method, route, status), count) in requests.iter() {
+                let _ = writeln!(
+                    out,
+                    "http_requests_total{{method=\"{}\",route=\"{}\",status=\"{}\"}} {}",
+                    method, escape_label(route), status, count
+                );
+            }
+        }
+
+        out.push_str("# HELP http_request_duration_seconds HTTP request latency by route.\n");
+        out.push_str("# TYPE http_request_duration_seconds histogram\n");
+        if let Ok(durations) = self.durations.lock() {
+            for ((method, route), histogram) in durations.iter() {
+                let labels = format!("method=\"{}\",route=\"{}\"", method, escape_label(route));
+                for (bound, count) in DURATION_BUCKETS.iter().zip(histogram.buckets) {
+                    let _ = writeln!(out, "http_request_duration_seconds_bucket{{{},le=\"{}\"}} {}", labels, bound, count);
+                }
+                let _ = writeln!(out, "http_request_duration_seconds_bucket{{{},le=\"+Inf\"}} {}", labels, histogram.count);
+                let _ = writeln!(out, "http_request_duration_seconds_sum{{{}}} {}", labels, histogram.sum);
+                let _ = writeln!(out, "http_request_duration_seconds_count{{{}}} {}", labels, histogram.count);
+            }
+        }
+
+        out
+    }
+}
+
+/// Escapovanie hodnoty labelu podľa formátu Prometheus
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+/// Middleware, ktorý meria každú požiadavku na známu cestu
+/// Pri streame (SSE) sa meria len čas do odoslania hlavičiek
+pub async fn track_requests(
+    State(state): State<AppState>,
+    matched_path: Option<MatchedPath>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let method = request.method().clone();
+    let route = matched_path
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let started = Instant::now();
+    let response = next.run(request).await;
+    state.request_metrics.record(&method, &route, response.status().as_u16(), started.elapsed());
+
+    response
+}
+
+/// GET /metrics - Počítadlá a latencia požiadaviek vo formáte Prometheus
+pub async fn prometheus_metrics(State(state): State<AppState>) -> Response {
+    (
+        [(header::CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)],
+        state.request_metrics.render_prometheus(),
+    )
+        .into_response()
+}
@@ -1,7 +1,9 @@
 use crate::api::handlers;  // Obslužné funkcie endpointov
+use crate::api::request_metrics;  // Meranie požiadaviek
 use crate::api::state::AppState;  // Stav aplikácie
 use axum::{                // Webový framework
-    routing::get,          // GET metóda smerovania
+    middleware,            // Middleware pre meranie požiadaviek
+    routing::{get, post, put},  // GET, POST a PUT metódy smerovania
     Router,                // Hlavný router
 };
 
@@ -25,6 +27,17 @@ pub fn create_router(state: AppState) -> Router {
         .route("/api/metrics/latest", get(handlers::get_latest_metrics))
         // GET /api/metrics/history - Metriky za časové obdobie
         .route("/api/metrics/history", get(handlers::get_metrics_history))
+        // GET /api/metrics/delta - Zmena metrík za časové obdobie
+        .route("/api/metrics/delta", get(handlers::get_metrics_delta))
+        // POST /api/metrics/save - Okamžité uloženie metrík (len s --save-metrics)
+        .route("/api/metrics/save", post(handlers::post_save_metrics))
+        
+        // GET /api/schema - Popis polí metrík (typ, jednotka, voliteľnosť)
+        .route("/api/schema", get(handlers::get_schema))
+        
+        // ========== STREAM ==========
+        // GET /api/events/metrics - Priebežné metriky (Server-Sent Events)
+        .route("/api/events/metrics", get(handlers::stream_metrics))
         
         // ========== SYSTÉMOVÉ INFORMÁCIE ==========
         // GET /api/cpu - Informácie o procesore
@@ -35,12 +48,62 @@ pub fn create_router(state: AppState) -> Router {
         .route("/api/disk", get(handlers::get_disk_info))
         // GET /api/processes/top - Najnáročnejšie procesy
         .route("/api/processes/top", get(handlers::get_top_processes))
+        // GET /api/processes/:pid - Jeden proces vrátane príkazového riadku
+        .route("/api/processes/:pid", get(handlers::get_process))
+        // GET /api/processes/:name/history - Uložená história procesu (--save-processes)
+        .route("/api/processes/:name/history", get(handlers::get_process_history))
         
         // ========== ŠTATISTIKY ==========
         // GET /api/stats - Agregované štatistiky
         .route("/api/stats", get(handlers::get_stats))
         
+        // ========== LOG UDALOSTÍ ==========
+        // GET /api/logs - Posledné udalosti úloh na pozadí (?level=warn)
+        .route("/api/logs", get(handlers::get_logs))
+        
+        // ========== KONFIGURÁCIA ==========
+        // PUT /api/config/save-interval - Interval ukladania metrík za behu
+        .route("/api/config/save-interval", put(handlers::put_save_interval))
+        
+        // ========== PROMETHEUS ==========
+        // GET /metrics - Počty a latencia požiadaviek podľa cesty
+        .route("/metrics", get(request_metrics::prometheus_metrics))
+        
+        // Meranie všetkých známych ciest (route_layer pozná šablónu cesty)
+        .route_layer(middleware::from_fn_with_state(state.clone(), request_metrics::track_requests))
+        
+        // Odsadený JSON pri ?pretty=true (všetky JSON odpovede)
+        .layer(middleware::from_fn(handlers::pretty_json))
+        
         // Pripojenie globálneho stavu k routeru
         // Tento stav bude automaticky injektovaný do všetkých handlerov
         .with_state(state)
-}
\ No newline at end of file
+}
+
+/// Vnorenie routera pod prefix (`api --base-path /monitor`)
+/// Bez prefixu sa router nemení; cesty bez prefixu potom vrátia 404
+pub fn nest_under_base_path(router: Router, base_path: Option<&str>) -> Router {
+    match base_path {
+        Some(prefix) => Router::new().nest(prefix, router),
+        None => router,
+    }
+}
+
+/// Normalizácia prefixu z `--base-path`
+///
+/// Koncové lomky sa odstránia (`/monitor/` -> `/monitor`), samotné `/` znamená
+/// bez prefixu. Prefix musí začínať lomkou a nesmie obsahovať parametre ciest
+/// (`:`, `*`), medzery ani query (`?`, `#`).
+pub fn normalize_base_path(raw: &str) -> Result<Option<String>, String> {
+    let trimmed = raw.trim().trim_end_matches('/');
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    if !trimmed.starts_with('/') {
+        return Err(format!("base path '{}' must start with '/'", raw));
+    }
+    if trimmed.contains(|c: char| c.is_whitespace() || matches!(c, ':' | '*' | '?' | '#')) {
+        return Err(format!("base path '{}' contains an invalid character", raw));
+    }
+    Ok(Some(trimmed.to_string()))
+}
@@ -1,18 +1,43 @@
 use sqlx::PgPool;           // Pool spojení s PostgreSQL databázou
+use crate::db::SharedPool;  // Pool vymeniteľný po výpadku databázy
 use std::sync::Arc;         // Atomický reference counter pre bezpečné zdieľanie
-use tokio::sync::Mutex;     // Asynchrónny mutex pre vzájomné vylúčenie
-use crate::services::api_monitor::ApiSystemMonitor;  // Monitorovací servis
+use crate::services::api_monitor::{ApiSystemMonitor, SharedMonitor};  // Monitorovací servis
+use super::error::ApiError;  // Chyby API handlerov
+use super::stream::MetricsFeed;  // Stream metrík
+use super::request_metrics::RequestMetrics;  // Počítadlá požiadaviek pre /metrics
+use crate::services::event_log::EventLog;  // Log udalostí pre /api/logs
+use crate::modes::saver::SaveInterval;  // Interval ukladania na pozadí
+use std::time::{Duration, Instant};
 
 /// Globálny stav aplikácie zdieľaný medzi všetkými API endpointami
 /// Tento stav je bezpečný pre konkurentný prístup z viacerých vlákien
 #[derive(Clone)]
 pub struct AppState {
     /// Pool databázových spojení - zdieľaný medzi všetkými požiadavkami
-    pub db_pool: Arc<PgPool>,
+    /// a ukladaním na pozadí, ktoré ho po výpadku databázy vymení
+    pub db_pool: SharedPool,
     
-    /// Monitorovací servis chránený mutexom - umožňuje bezpečný prístup
-    /// z viacerých asynchrónnych úloh súčasne
-    pub system_monitor: Arc<Mutex<ApiSystemMonitor>>,
+    /// Monitorovací servis zdieľaný so streamom a ukladaním na pozadí
+    /// Handlery k nemu pristupujú len cez `with_monitor`
+    pub system_monitor: SharedMonitor,
+    
+    /// Serverový strop pre počet procesov v jednej odpovedi
+    pub max_process_limit: usize,
+
+    /// Zdroj vzoriek pre `/api/events/metrics`
+    pub metrics_feed: MetricsFeed,
+
+    /// Počty a latencia požiadaviek podľa cesty pre `/metrics`
+    pub request_metrics: Arc<RequestMetrics>,
+
+    /// Posledné udalosti úloh na pozadí pre `/api/logs`
+    pub events: EventLog,
+
+    /// Čas spustenia servera pre `uptime_seconds` v `/health`
+    pub started_at: Instant,
+
+    /// Interval ukladania metrík na pozadí (None bez `--save-metrics`)
+    pub save_interval: Option<SaveInterval>,
 }
 
 impl AppState {
@@ -21,13 +46,50 @@ impl AppState {
     /// # Argumenty
     /// * `pool` - Pool databázových spojení
     /// * `monitor` - Inštancia monitorovacieho servisu
+    /// * `max_process_limit` - Maximálny počet procesov v odpovedi
+    /// * `stream_interval` - Interval vzoriek pre `/api/events/metrics`
+    /// * `save_interval` - Interval ukladania zdieľaný s úlohou na pozadí
     ///
     /// # Návratová hodnota
     /// Nová inštancia `AppState` s obalom pre bezpečné zdieľanie
-    pub fn new(pool: PgPool, monitor: ApiSystemMonitor) -> Self {
+    ///
+    /// Musí sa volať v tokio runtime - spúšťa zber vzoriek pre stream
+    pub fn new(pool: PgPool, monitor: ApiSystemMonitor, max_process_limit: usize, stream_interval: Duration, save_interval: Option<SaveInterval>) -> Self {
+        let system_monitor = SharedMonitor::new(monitor);  // Obnova mimo zámku monitora
+        let metrics_feed = MetricsFeed::start(system_monitor.clone(), stream_interval);
+
         Self {
-            db_pool: Arc::new(pool),  // Zabalíme pool do Arc pre zdieľanie
-            system_monitor: Arc::new(Mutex::new(monitor)),  // Zabalíme monitor do Arc+Mutex
+            db_pool: SharedPool::new(pool),  // Zabalíme pool pre zdieľanie s ukladaním
+            system_monitor,
+            max_process_limit,
+            metrics_feed,
+            request_metrics: Arc::new(RequestMetrics::default()),
+            events: EventLog::default(),
+            started_at: Instant::now(),
+            save_interval,
         }
     }
-}
\ No newline at end of file
+    
+    /// Spustenie zberu dát nad monitorom v blokujúcom vlákne
+    ///
+    /// `refresh_all()` v sysinfo je synchrónne a trvá desiatky milisekúnd,
+    /// preto sa nesmie volať priamo v async handleri - zablokovalo by
+    /// vlákno runtime a tým aj ostatné požiadavky. Obnova beží mimo zámku
+    /// monitora (pozri `SharedMonitor`), zámok sa drží len počas zberu
+    /// z už obnovených dát.
+    ///
+    /// Panic počas zberu sa zachytí (`collect_isolated`) - požiadavka dostane
+    /// 500 a ďalšie požiadavky pracujú s novým monitorom.
+    ///
+    /// # Argumenty
+    /// * `f` - Funkcia, ktorá z monitora získa požadované dáta
+    pub async fn with_monitor<F, T>(&self, f: F) -> Result<T, ApiError>
+    where
+        F: FnOnce(&mut ApiSystemMonitor) -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let monitor = self.system_monitor.clone();
+        let result = tokio::task::spawn_blocking(move || monitor.collect_blocking(f)).await?;
+        result.map_err(ApiError::CollectionPanicked)
+    }
+}
@@ -0,0 +1,252 @@
+// stream.rs
+
+use crate::models::SystemMetrics;
+use crate::services::api_monitor::SharedMonitor;
+use axum::response::sse::Event;          // Udalosť Server-Sent Events
+use futures_util::stream::{self, Stream};
+use serde_json::json;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::watch;
+use tokio::time::Instant;
+
+/// Odporúčaný odstup opätovného pripojenia pre `EventSource` (pole `retry`)
+pub const RETRY_HINT: Duration = Duration::from_secs(3);
+
+/// Interval komentárov, ktoré udržia spojenie cez proxy aj bez nových dát
+pub const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Ako často klient dostane súhrn odoslaných a zahodených snímok
+pub const STATS_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Jedna vzorka streamu s rastúcim poradovým číslom
+#[derive(Debug, Clone)]
+pub struct MetricsFrame {
+    pub seq: u64,                       // Poradové číslo vzorky (od 1)
+    pub metrics: Arc<SystemMetrics>,    // Zdieľané medzi všetkými klientmi
+}
+
+/// Počítadlo snímok jedného klienta
+///
+/// Klient dostáva vždy len najnovšiu vzorku - ak nestíha, medziľahlé
+/// vzorky sa zahodia a prejavia sa ako medzera v poradových číslach.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FrameStats {
+    pub sent: u64,          // Počet odoslaných snímok
+    pub dropped: u64,       // Počet preskočených (zahodených) snímok
+    last_seq: Option<u64>,  // Poradové číslo poslednej odoslanej snímky
+}
+
+impl FrameStats {
+    /// Záznam odoslanej snímky
+    /// Prvá snímka sa počíta od okamihu pripojenia, nie od začiatku streamu
+    pub fn record(&mut self, seq: u64) {
+        if let Some(last) = self.last_seq {
+            self.dropped += seq.saturating_sub(last + 1);
+        }
+        self.last_seq = Some(seq);
+        self.sent += 1;
+    }
+}
+
+/// Zdroj vzoriek pre všetkých klientov streamu
+///
+/// Vzorky sa ukladajú do `watch` kanála, ktorý drží len poslednú hodnotu.
+/// Pomalý klient preto nikdy nehromadí frontu - pri ďalšom čítaní dostane
+/// najnovšiu vzorku a staršie sa preskočia.
+#[derive(Clone)]
+pub struct MetricsFeed {
+    sender: Arc<watch::Sender<Option<MetricsFrame>>>,
+}
+
+impl MetricsFeed {
+    /// Spustenie zberu vzoriek na pozadí
+    /// Kým nie je pripojený žiadny klient, metriky sa nezbierajú
+    ///
+    /// # Argumenty
+    /// * `monitor` - Zdieľaný monitor (ten istý ako pre handlery)
+    /// * `interval` - Interval medzi vzorkami
+    pub fn start(monitor: SharedMonitor, interval: Duration) -> Self {
+        let (sender, _) = watch::channel(None);
+        let sender = Arc::new(sender);
+        let task_sender = Arc::clone(&sender);
+
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            let mut seq = 0;
+
+            loop {
+                ticker.tick().await;
+                if task_sender.receiver_count() == 0 {
+                    continue;  // Nikto nepočúva
+                }
+
+                // refresh_all() je synchrónne - zber v blokujúcom vlákne
+                let monitor = monitor.clone();
+                // Panic pri zbere sa zachytí a monitor sa vytvorí nanovo
+                match tokio::task::spawn_blocking(move || {
+                    monitor.collect_blocking(|monitor| monitor.get_metrics_for_db())
+                }).await {
+                    Ok(Ok(metrics)) => {
+                        seq += 1;
+                        task_sender.send_replace(Some(MetricsFrame { seq, metrics: Arc::new(metrics) }));
+                    }
+                    Ok(Err(panic)) => eprintln!("❌ [Stream] Metrics collection panicked, monitor restarted: {}", panic),
+                    Err(e) => eprintln!("❌ [Stream] Metrics collection failed: {}", e),
+                }
+            }
+        });
+
+        Self { sender }
+    }
+
+    /// Nový odberateľ - dostane vzorky vytvorené po pripojení
+    pub fn subscribe(&self) -> watch::Receiver<Option<MetricsFrame>> {
+        self.sender.subscribe()
+    }
+}
+
+/// Stav streamu jedného klienta
+struct ClientStream {
+    receiver: watch::Receiver<Option<MetricsFrame>>,
+    min_gap: Duration,           // Minimálny odstup snímok podľa `max_rate`
+    stats: FrameStats,
+    retry_sent: bool,            // Bol už odoslaný `retry` hint?
+    last_sent: Option<Instant>,  // Čas poslednej odoslanej snímky
+    last_stats: Instant,         // Čas posledného súhrnu
+}
+
+/// SSE udalosti s metrikami pre jedného klienta
+///
+/// Prvá udalosť nesie len `retry` hint, potom udalosť `metrics` so `SystemMetrics`
+/// (id = poradové číslo vzorky) a každých `STATS_INTERVAL` udalosť `stats`
+/// s počtom odoslaných a zahodených snímok.
+/// Ďalšia vzorka sa číta až po odoslaní predchádzajúcej, takže pri pomalom
+/// spojení sa snímky zahadzujú namiesto hromadenia.
+///
+/// # Argumenty
+/// * `receiver` - Odberateľ z `MetricsFeed::subscribe`
+/// * `max_rate` - Najvyšší počet snímok za sekundu
+pub fn metrics_events(
+    receiver: watch::Receiver<Option<MetricsFrame>>,
+    max_rate: f64,
+) -> impl Stream<Item = Result<Event, axum::Error>> {
+    let state = ClientStream {
+        receiver,
+        min_gap: Duration::from_secs_f64(1.0 / max_rate),
+        stats: FrameStats::default(),
+        retry_sent: false,
+        last_sent: None,
+        last_stats: Instant::now(),
+    };
+
+    stream::unfold(state, |mut state| async move {
+        // Klient sa po výpadku znovu pripojí po `RETRY_HINT`
+        if !state.retry_sent {
+            state.retry_sent = true;
+            return Some((Ok(Event::default().retry(RETRY_HINT)), state));
+        }
+
+        // Periodický súhrn odoslaných a zahodených snímok
+        if state.last_stats.elapsed() >= STATS_INTERVAL {
+            state.last_stats = Instant::now();
+            let event = Event::default().event("stats").json_data(json!({
+                "sent_frames": state.stats.sent,
+                "dropped_frames": state.stats.dropped
+            }));
+            return Some((event, state));
+        }
+
+        // Obmedzenie rýchlosti - vzorky počas čakania sa prepíšu novšími
+        if let Some(last_sent) = state.last_sent {
+            tokio::time::sleep_until(last_sent + state.min_gap).await;
+        }
+
+        // Čakanie na novú vzorku (chyba = zdroj zanikol, stream končí)
+        state.receiver.changed().await.ok()?;
+        let frame = state.receiver.borrow_and_update().clone()?;
+
+        state.stats.record(frame.seq);
+        state.last_sent = Some(Instant::now());
+
+        let event = Event::default()
+            .event("metrics")
+            .id(frame.seq.to_string())
+            .json_data(&*frame.metrics);
+        Some((event, state))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_metrics;
+    use axum::response::{IntoResponse, Sse};
+    use chrono::Utc;
+    use futures_util::StreamExt;
+
+    fn frame(seq: u64) -> Option<MetricsFrame> {
+        Some(MetricsFrame { seq, metrics: Arc::new(sample_metrics(Utc::now(), seq as f64)) })
+    }
+
+    /// Text SSE odpovede, kým nepríde `count` udalostí `metrics`
+    async fn read_metrics_events(events: impl Stream<Item = Result<Event, axum::Error>> + Send + 'static, count: usize) -> String {
+        let mut body = Sse::new(events).into_response().into_body().into_data_stream();
+        let mut text = String::new();
+        while text.matches("event: metrics").count() < count {
+            let chunk = body.next().await.expect("stream ended early").unwrap();
+            text.push_str(std::str::from_utf8(&chunk).unwrap());
+        }
+        text
+    }
+
+    #[test]
+    fn skipped_sequence_numbers_count_as_dropped() {
+        let mut stats = FrameStats::default();
+        stats.record(3);  // Prvá snímka po pripojení
+        stats.record(4);
+        stats.record(8);
+
+        assert_eq!(stats.sent, 3);
+        assert_eq!(stats.dropped, 3);
+    }
+
+    #[tokio::test]
+    async fn backlog_collapses_to_the_latest_sample() {
+        let (sender, _) = watch::channel(None);
+        let receiver = sender.subscribe();
+        for seq in 1..=5 {
+            sender.send_replace(frame(seq));
+        }
+
+        let text = read_metrics_events(metrics_events(receiver, 100.0), 1).await;
+
+        assert!(text.contains("id: 5"), "{text}");
+        assert!(!text.contains("id: 1\n"), "{text}");
+    }
+
+    #[tokio::test]
+    async fn stream_yields_parseable_metrics_events() {
+        let (sender, _) = watch::channel(None);
+        let events = metrics_events(sender.subscribe(), 100.0);
+        sender.send_replace(frame(1));
+
+        // Druhá vzorka príde až po odoslaní prvej
+        let producer = tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            sender.send_replace(frame(2));
+            sender  // Zdroj ostáva otvorený do konca čítania
+        });
+        let text = read_metrics_events(events, 2).await;
+        drop(producer.await.unwrap());
+
+        assert!(text.starts_with("retry:3000"), "{text}");
+        let samples: Vec<SystemMetrics> = text
+            .lines()
+            .filter_map(|line| line.strip_prefix("data: "))
+            .map(|data| serde_json::from_str(data).unwrap())
+            .collect();
+        assert_eq!(samples.len(), 2);
+        assert_eq!((samples[0].cpu_usage, samples[1].cpu_usage), (1.0, 2.0));
+    }
+}
@@ -0,0 +1,457 @@
+// tests.rs
+
+//! Testy API handlerov
+//!
+//! Smerovanie a validácia bežia nad `connect_lazy` poolom bez databázy.
+//! Testy proti skutočnému PostgreSQL sa bez `DATABASE_URL` preskočia
+//! (pozri `test_support::test_db`).
+
+use super::error::ApiError;
+use super::handlers::{etag_matches, get_top_processes, health_check, metrics_etag, post_save_metrics, LatestQuery, LimitQuery, MetricsFormat};
+use super::routes::{create_router, nest_under_base_path};
+use super::state::AppState;
+use crate::db::{count_metrics, get_current_metrics, save_metrics, SortOrder};
+use crate::models::{ProcessInfo, TemperatureMode};
+use crate::modes::saver::{spawn_metrics_saver, wait_for_next_save, SaveInterval, SaverOptions};
+use crate::services::api_monitor::ApiSystemMonitor;
+use crate::services::event_log::EventLevel;
+use crate::test_support::{sample_metrics, sample_process, test_db};
+use axum::body::Body;
+use axum::extract::{Query, State};
+use axum::http::{header, HeaderMap, HeaderValue, Request, StatusCode};
+use axum::{Json, Router};
+use sqlx::PgPool;
+use std::time::Duration;
+use tower::Service;
+
+/// Stav API nad testovacou databázou (`save_metrics` = `--save-metrics`)
+fn test_state(pool: &PgPool, save_metrics: bool) -> AppState {
+    AppState::new(
+        pool.clone(),
+        ApiSystemMonitor::new(TemperatureMode::Off),
+        100,
+        Duration::from_secs(60),
+        save_metrics.then(SaveInterval::default),
+    )
+}
+
+/// Stav API bez databázy - `connect_lazy` sa nepripája, kým pool niekto nepoužije
+fn lazy_state() -> AppState {
+    test_state(&PgPool::connect_lazy("postgres://localhost/unused").unwrap(), false)
+}
+
+/// Odpoveď routera na GET požiadavku (router je vždy pripravený, stačí `call`)
+async fn get(app: &Router, uri: &str) -> axum::response::Response {
+    app.clone().call(Request::get(uri).body(Body::empty()).unwrap()).await.unwrap()
+}
+
+#[tokio::test]
+async fn save_endpoint_inserts_one_row_and_returns_its_id() {
+    let Some(db) = test_db().await else { return };
+
+    let body = post_save_metrics(State(test_state(&db.pool, true))).await.unwrap().0;
+
+    let id = body["id"].as_i64().expect("response contains the new id");
+    assert_eq!(body["data"]["id"].as_i64(), Some(id));
+    assert_eq!(count_metrics(&db.pool).await.unwrap(), 1);
+    let current = get_current_metrics(&db.pool).await.unwrap().unwrap();
+    assert_eq!(current.id, Some(id));
+}
+
+#[tokio::test]
+async fn save_endpoint_requires_save_metrics() {
+    let Some(db) = test_db().await else { return };
+
+    let error = post_save_metrics(State(test_state(&db.pool, false))).await.unwrap_err();
+
+    assert_eq!(error.status_code(), axum::http::StatusCode::BAD_REQUEST);
+    assert_eq!(count_metrics(&db.pool).await.unwrap(), 0);
+}
+
+#[tokio::test]
+async fn background_saver_collects_through_the_shared_monitor() {
+    let Some(db) = test_db().await else { return };
+    let state = test_state(&db.pool, true);
+    let options = SaverOptions {
+        dedupe: false,
+        dedupe_tolerance: 0.0,
+        quiet: true,
+        save_processes: false,
+        memory_warn_available_mb: None,
+    };
+
+    // Kým handler drží zámok monitora v stave, ukladanie nemôže merať
+    let guard = state.system_monitor.lock().await;
+    spawn_metrics_saver(state.db_pool.clone(), state.system_monitor.clone(), options, SaveInterval::default(), None);
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    assert_eq!(count_metrics(&db.pool).await.unwrap(), 0);
+
+    drop(guard);
+    let mut saved = 0;
+    for _ in 0..100 {
+        saved = count_metrics(&db.pool).await.unwrap();
+        if saved > 0 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(saved, 1);
+}
+
+/// Query pre `/api/processes/top` s daným limitom a ostatnými parametrami predvolenými
+fn limit_query(limit: i64) -> LimitQuery {
+    LimitQuery { limit, sort: Default::default(), min_cpu: None, min_memory_mb: None }
+}
+
+#[test]
+fn huge_limit_is_capped_to_max_process_limit() {
+    assert_eq!(limit_query(1_000_000).capped(100).unwrap(), 100);
+    assert_eq!(limit_query(i64::MAX).capped(100).unwrap(), 100);
+    assert_eq!(limit_query(7).capped(100).unwrap(), 7);
+}
+
+#[test]
+fn limit_below_one_is_rejected_with_a_plain_message() {
+    for limit in [0, -5] {
+        let error = limit_query(limit).capped(100).unwrap_err();
+        assert_eq!(error.status_code(), StatusCode::BAD_REQUEST);
+        assert_eq!(error.to_string(), format!("Parameter 'limit' must be >= 1, got {limit}"));
+    }
+}
+
+/// Query pre `/api/metrics/latest` s daným limitom
+fn latest_query(limit: i64) -> LatestQuery {
+    LatestQuery { limit, order: Default::default() }
+}
+
+#[test]
+fn latest_limit_within_range_is_accepted() {
+    for limit in [1, 10, 1000] {
+        assert_eq!(latest_query(limit).validate().unwrap(), limit);
+    }
+}
+
+#[test]
+fn latest_limit_outside_range_is_rejected() {
+    for limit in [-1, 0, 1001, i64::MAX] {
+        let error = latest_query(limit).validate().unwrap_err();
+        assert_eq!(error.status_code(), StatusCode::BAD_REQUEST, "{limit}");
+        assert_eq!(error.to_string(), format!("Parameter 'limit' must be between 1 and 1000, got {limit}"));
+    }
+}
+
+#[test]
+fn latest_order_defaults_to_newest_first() {
+    assert_eq!(latest_query(10).order, SortOrder::Desc);
+    assert_eq!(SortOrder::Asc.as_sql(), "ASC");
+    assert_eq!(SortOrder::Desc.as_sql(), "DESC");
+}
+
+#[tokio::test]
+async fn unknown_latest_order_is_a_bad_request() {
+    let app = create_router(lazy_state());
+
+    assert_eq!(get(&app, "/api/metrics/latest?order=sideways").await.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn top_processes_reports_the_capped_limit() {
+    let app = create_router(lazy_state());
+
+    let response = get(&app, "/api/processes/top?limit=1000000").await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body["limit"], 100);
+    assert_eq!(body["requested_limit"], 1_000_000);
+    assert_eq!(body["max_limit"], 100);
+}
+
+#[tokio::test]
+async fn concurrent_requests_share_one_collection() {
+    let state = lazy_state();
+    let query = || Ok(Query(limit_query(10)));
+
+    let (first, second) = tokio::join!(
+        get_top_processes(State(state.clone()), query()),
+        get_top_processes(State(state.clone()), query()),
+    );
+
+    assert!(first.is_ok() && second.is_ok());
+    assert_eq!(state.system_monitor.refresh_count(), 1);
+}
+
+#[tokio::test]
+async fn concurrent_requests_do_not_queue_behind_one_collection() {
+    const REQUESTS: u32 = 8;
+    const COLLECTION: Duration = Duration::from_millis(200);
+
+    let mut monitor = ApiSystemMonitor::new(TemperatureMode::Off);
+    monitor.set_snapshot_ttl(Duration::ZERO);  // Bez TTL potrebuje obnovu každá požiadavka
+    let pool = PgPool::connect_lazy("postgres://localhost/unused").unwrap();
+    let state = AppState::new(pool, monitor, 100, Duration::from_secs(60), None);
+    state.system_monitor.set_refresh_delay(COLLECTION);
+
+    let start = std::time::Instant::now();
+    let requests = (0..REQUESTS).map(|i| {
+        let state = state.clone();
+        tokio::spawn(async move {
+            if i % 2 == 0 {
+                get_top_processes(State(state), Ok(Query(limit_query(10)))).await.map(|_| ())
+            } else {
+                state.with_monitor(|monitor| monitor.get_metrics()).await.map(|_| ())
+            }
+        })
+    });
+    for request in futures_util::future::join_all(requests).await {
+        assert!(request.unwrap().is_ok());
+    }
+
+    // Sériovo by požiadavky trvali REQUESTS × COLLECTION
+    let elapsed = start.elapsed();
+    assert!(elapsed < COLLECTION * REQUESTS / 2, "{elapsed:?}");
+    assert!(state.system_monitor.refresh_count() < u64::from(REQUESTS));
+}
+
+#[tokio::test]
+async fn base_path_nests_routes_under_the_prefix() {
+    let app = nest_under_base_path(create_router(lazy_state()), Some("/monitor"));
+
+    assert_eq!(get(&app, "/monitor/api/schema").await.status(), StatusCode::OK);
+    assert_eq!(get(&app, "/monitor/health").await.status(), StatusCode::OK);
+    assert_eq!(get(&app, "/api/schema").await.status(), StatusCode::NOT_FOUND);
+    assert_eq!(get(&app, "/health").await.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn process_routes_do_not_overlap() {
+    let app = create_router(lazy_state());
+
+    // História procesu podľa názvu - neplatné hodiny sa odmietnu pred dotazom do DB
+    assert_eq!(get(&app, "/api/processes/firefox/history?hours=0").await.status(), StatusCode::BAD_REQUEST);
+    // Názov bez `/history` padne do `:pid` a neprejde ako PID
+    assert_eq!(get(&app, "/api/processes/firefox").await.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(get(&app, "/api/processes/top?limit=1").await.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn invalid_limit_and_hours_return_json_bad_request() {
+    let app = create_router(lazy_state());
+
+    for uri in [
+        "/api/metrics/latest?limit=0",
+        "/api/metrics/latest?limit=abc",
+        "/api/metrics/history?hours=0",
+        "/api/metrics/history?hours=abc",
+    ] {
+        let response = get(&app, uri).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST, "{uri}");
+
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body: serde_json::Value = serde_json::from_slice(&bytes).expect("error body is JSON");
+        assert_eq!(body["success"], false, "{uri}");
+        assert!(body["error"].as_str().is_some_and(|error| !error.is_empty()), "{uri}");
+    }
+}
+
+#[tokio::test]
+async fn current_metrics_without_samples_is_a_json_404() {
+    let Some(db) = test_db().await else { return };
+    let app = create_router(test_state(&db.pool, false));
+
+    let response = get(&app, "/api/metrics/current").await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&bytes).unwrap();
+    assert_eq!(body, serde_json::json!({ "success": false, "error": "No metrics available yet" }));
+}
+
+#[test]
+fn etag_changes_with_the_row_and_the_format() {
+    let mut metrics = sample_metrics(chrono::Utc::now(), 10.0);
+    metrics.id = Some(7);
+    let full = metrics_etag(&metrics, MetricsFormat::Full);
+
+    assert_ne!(full, metrics_etag(&metrics, MetricsFormat::Compact));
+    metrics.id = Some(8);
+    assert_ne!(full, metrics_etag(&metrics, MetricsFormat::Full));
+}
+
+#[test]
+fn if_none_match_accepts_lists_weak_tags_and_wildcard() {
+    let headers = |value: &str| HeaderMap::from_iter([(header::IF_NONE_MATCH, HeaderValue::from_str(value).unwrap())]);
+
+    assert!(etag_matches(&headers("\"1-2-full\""), "\"1-2-full\""));
+    assert!(etag_matches(&headers("\"0-0-full\", W/\"1-2-full\""), "\"1-2-full\""));
+    assert!(etag_matches(&headers("*"), "\"1-2-full\""));
+    assert!(!etag_matches(&headers("\"1-2-compact\""), "\"1-2-full\""));
+    assert!(!etag_matches(&HeaderMap::new(), "\"1-2-full\""));
+}
+
+#[tokio::test]
+async fn repeated_request_with_the_etag_is_not_modified() {
+    let Some(db) = test_db().await else { return };
+    save_metrics(&db.pool, &sample_metrics(chrono::Utc::now(), 10.0)).await.unwrap();
+    let app = create_router(test_state(&db.pool, false));
+
+    let first = get(&app, "/api/metrics/current").await;
+    assert_eq!(first.status(), StatusCode::OK);
+    let etag = first.headers().get(header::ETAG).expect("response has an ETag").clone();
+
+    let request = Request::get("/api/metrics/current").header(header::IF_NONE_MATCH, etag).body(Body::empty()).unwrap();
+    let second = app.clone().call(request).await.unwrap();
+
+    assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+    assert!(axum::body::to_bytes(second.into_body(), usize::MAX).await.unwrap().is_empty());
+}
+
+/// Text odpovede (napr. výstup `/metrics`)
+async fn body_text(response: axum::response::Response) -> String {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn prometheus_counter_grows_with_each_request() {
+    let app = create_router(lazy_state());
+    let counter = |count: u32| format!("http_requests_total{{method=\"GET\",route=\"/api/schema\",status=\"200\"}} {count}");
+
+    get(&app, "/api/schema").await;
+    let output = body_text(get(&app, "/metrics").await).await;
+    assert!(output.contains(&counter(1)), "{output}");
+    assert!(output.contains("http_request_duration_seconds_count{method=\"GET\",route=\"/api/schema\"} 1"), "{output}");
+
+    get(&app, "/api/schema").await;
+    let output = body_text(get(&app, "/metrics").await).await;
+    assert!(output.contains(&counter(2)), "{output}");
+}
+
+#[test]
+fn min_cpu_excludes_lower_usage_processes() {
+    let query = LimitQuery { min_cpu: Some(10.0), ..limit_query(10) };
+    let minimums = query.minimums().unwrap();
+    let process = |cpu_usage: f32| ProcessInfo { cpu_usage, ..sample_process(1, "app") };
+
+    assert!(minimums.matches(&process(10.0)));
+    assert!(minimums.matches(&process(55.0)));
+    assert!(!minimums.matches(&process(9.9)));
+}
+
+#[test]
+fn min_memory_is_converted_from_megabytes() {
+    let query = LimitQuery { min_memory_mb: Some(1.5), ..limit_query(10) };
+    assert_eq!(query.minimums().unwrap().memory, Some(1_572_864));
+}
+
+#[tokio::test]
+async fn negative_minimums_are_a_bad_request() {
+    let app = create_router(lazy_state());
+
+    assert_eq!(get(&app, "/api/processes/top?min_cpu=-1").await.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(get(&app, "/api/processes/top?min_memory_mb=-5").await.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(get(&app, "/api/processes/top?min_cpu=10").await.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn logs_endpoint_filters_events_by_level() {
+    let state = lazy_state();
+    state.events.record(EventLevel::Info, "auto-save", "Metrics saved");
+    state.events.record(EventLevel::Warn, "auto-save", "Available memory is low");
+    state.events.record(EventLevel::Error, "auto-save", "Database unreachable");
+    let app = create_router(state);
+
+    let body: serde_json::Value = serde_json::from_str(&body_text(get(&app, "/api/logs?level=warn").await).await).unwrap();
+
+    let messages: Vec<&str> = body["data"].as_array().unwrap().iter().map(|event| event["message"].as_str().unwrap()).collect();
+    assert_eq!(messages, ["Database unreachable", "Available memory is low"]);
+    assert_eq!(body["level"], "warn");
+    assert_eq!(get(&app, "/api/logs?level=loud").await.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn health_reports_version_and_uptime() {
+    let mut state = lazy_state();
+    state.started_at = std::time::Instant::now() - Duration::from_secs(5);
+
+    let Json(body) = health_check(State(state)).await;
+
+    assert_eq!(body["status"], "ok");
+    assert_eq!(body["version"], env!("CARGO_PKG_VERSION"));
+    assert!(body["uptime_seconds"].as_f64().unwrap() >= 5.0);
+}
+
+#[tokio::test]
+async fn pretty_query_indents_json_responses() {
+    let app = create_router(lazy_state());
+
+    let compact = body_text(get(&app, "/api/schema").await).await;
+    let pretty = body_text(get(&app, "/api/schema?pretty=true").await).await;
+
+    assert!(!compact.contains('\n'));
+    assert!(pretty.contains("\n  \""), "{pretty}");
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&compact).unwrap(),
+        serde_json::from_str::<serde_json::Value>(&pretty).unwrap(),
+    );
+    assert_eq!(get(&app, "/api/schema?pretty=yes").await.status(), StatusCode::BAD_REQUEST);
+}
+
+/// Odpoveď routera na PUT požiadavku s JSON telom
+async fn put_json(app: &Router, uri: &str, json: &str) -> axum::response::Response {
+    let request = Request::put(uri)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(json.to_string()))
+        .unwrap();
+    app.clone().call(request).await.unwrap()
+}
+
+#[tokio::test]
+async fn save_interval_is_changed_at_runtime() {
+    let state = test_state(&PgPool::connect_lazy("postgres://localhost/unused").unwrap(), true);
+    let save_interval = state.save_interval.clone().unwrap();
+    let app = create_router(state);
+
+    let response = put_json(&app, "/api/config/save-interval", r#"{"seconds": 30}"#).await;
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let body: serde_json::Value = serde_json::from_str(&body_text(response).await).unwrap();
+    assert_eq!(body["data"]["previous_seconds"], SaveInterval::default().seconds());
+    assert_eq!(body["data"]["seconds"], 30);
+    assert_eq!(save_interval.seconds(), 30);  // Rovnaký interval vidí ukladanie na pozadí
+
+    // Ďalšie uloženie čaká už nový interval
+    tokio::time::pause();
+    let start = tokio::time::Instant::now();
+    wait_for_next_save(&save_interval).await;
+    let waited = start.elapsed();
+    assert!(waited >= Duration::from_secs(30) && waited < Duration::from_secs(31), "{waited:?}");  // Časovač zaokrúhľuje na milisekundy
+}
+
+#[tokio::test]
+async fn save_interval_rejects_out_of_range_values_and_disabled_saving() {
+    let state = test_state(&PgPool::connect_lazy("postgres://localhost/unused").unwrap(), true);
+    let save_interval = state.save_interval.clone().unwrap();
+    let app = create_router(state);
+
+    assert_eq!(put_json(&app, "/api/config/save-interval", r#"{"seconds": 1}"#).await.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(put_json(&app, "/api/config/save-interval", r#"{"secs": 30}"#).await.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(save_interval.seconds(), SaveInterval::default().seconds());
+
+    let disabled = create_router(lazy_state());
+    assert_eq!(put_json(&disabled, "/api/config/save-interval", r#"{"seconds": 30}"#).await.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn panicking_collection_is_contained() {
+    let state = lazy_state();
+    let app = create_router(state.clone());
+
+    let error = state.with_monitor(|_| -> () { panic!("unexpected sysinfo data") }).await.unwrap_err();
+
+    assert_eq!(error.status_code(), StatusCode::INTERNAL_SERVER_ERROR);
+    assert!(matches!(&error, ApiError::CollectionPanicked(message) if message == "unexpected sysinfo data"), "{error:?}");
+    assert_eq!(get(&app, "/api/processes/top?limit=3").await.status(), StatusCode::OK);
+    assert!(state.with_monitor(|monitor| monitor.get_metrics_for_db()).await.is_ok());
+}
@@ -1,11 +1,30 @@
 /// Moduly pre správu stavu aplikácie a systémových informácií
 mod app_state;          // Stav aplikácie a hlavné dátové štruktúry
 mod app_system_info;    // Získavanie a reprezentácia systémových informácií
+mod app_palette;        // Paleta príkazov (akcie a fuzzy vyhľadávanie)
+mod app_keybindings;    // Klávesové skratky podľa režimu
+mod app_ui_state;       // Stav TUI ukladaný medzi spusteniami
+mod app_clipboard;      // Kopírovanie procesu do schránky a správy v päte
+mod app_flash;          // Zvýraznenie výraznej zmeny metriky v prehľade
+mod app_db;             // Stav pripojenia k databáze na pozadí
+mod app_selection;      // Výber viacerých procesov pre hromadné akcie
+mod app_core_history;   // História využitia jednotlivých jadier CPU
+mod app_collector;      // Zber dát monitora na vlákne s časovým limitom
+mod app_peaks;          // Najvyššie hodnoty metrík v relácii
+mod app_refresh;        // Spinner a vek dát v päte
 
 /// Reexporty pre jednoduchší prístup z iných modulov
 // Hlavné typy z modulu stavu aplikácie
-pub use app_state::{TuiApp, Mode, NetworkConnection, HISTORY_SIZE};
+pub use app_state::{TuiApp, Mode, HISTORY_SIZE};
 // Systémové informácie
-pub use app_system_info::{SystemInfo, get_system_info};
+pub use app_system_info::{SystemInfo, get_system_info, get_hostname};
+// Klávesové skratky pre päty a pomocníka
+pub use app_keybindings::{KeyBinding, keybindings_for, mode_name, mode_id, KEYBINDING_MODES};
+// Zvýraznenie gauge po výraznej zmene
+pub use app_flash::GaugeMetric;
+// Stav databázy pre ukladanie metrík z TUI
+pub use app_db::{DbState, DbStatus};
 // Reexporty typov z models modulu pre konzistentný prístup
-pub use crate::models::{GpuInfo, ProcessInfo};
\ No newline at end of file
+pub use crate::models::{GpuInfo, ProcessInfo};
+// Sieťové spojenia (zisťuje ich services::connections)
+pub use crate::services::connections::NetworkConnection;
\ No newline at end of file
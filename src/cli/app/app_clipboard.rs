@@ -0,0 +1,80 @@
+// app_clipboard.rs
+
+use std::time::{Duration, Instant};
+
+/// Ako dlho zostáva správa v päte
+const STATUS_DURATION: Duration = Duration::from_secs(3);
+
+/// Text kopírovaný do schránky pre proces, napr. `"1234 firefox"`
+pub fn process_clipboard_text(pid: u32, name: &str) -> String {
+    format!("{} {}", pid, name)
+}
+
+/// Systémová schránka vytvorená pri prvom kopírovaní
+///
+/// Na Linuxe (X11/Wayland) obsah schránky patrí procesu, ktorý ju nastavil,
+/// preto sa inštancia drží počas celého behu TUI.
+#[derive(Default)]
+pub struct ProcessClipboard {
+    clipboard: Option<arboard::Clipboard>,
+}
+
+impl ProcessClipboard {
+    /// Skopírovanie textu do schránky
+    /// Bez dostupnej schránky (SSH bez X11, headless server) vráti chybu
+    pub fn copy(&mut self, text: &str) -> Result<(), String> {
+        let clipboard = match &mut self.clipboard {
+            Some(clipboard) => clipboard,
+            None => {
+                let clipboard = arboard::Clipboard::new()
+                    .map_err(|e| format!("Clipboard unavailable: {}", e))?;
+                self.clipboard.insert(clipboard)
+            }
+        };
+
+        clipboard.set_text(text).map_err(|e| format!("Copy failed: {}", e))
+    }
+}
+
+/// Krátka správa v päte obrazovky (potvrdenie alebo chyba)
+#[derive(Debug, Clone)]
+pub struct StatusMessage {
+    pub text: String,     // Text správy
+    pub is_error: bool,   // Chyba sa zobrazí červenou
+    shown_at: Instant,    // Čas zobrazenia
+}
+
+impl StatusMessage {
+    /// Potvrdenie úspešnej akcie
+    pub fn info(text: String) -> Self {
+        Self { text, is_error: false, shown_at: Instant::now() }
+    }
+
+    /// Chybové hlásenie
+    pub fn error(text: String) -> Self {
+        Self { text, is_error: true, shown_at: Instant::now() }
+    }
+
+    /// Je správa ešte aktuálna? (zobrazuje sa `STATUS_DURATION`)
+    pub fn is_active(&self) -> bool {
+        self.shown_at.elapsed() < STATUS_DURATION
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn clipboard_text_is_pid_then_name() {
+        assert_eq!(process_clipboard_text(1234, "firefox"), "1234 firefox");
+        assert_eq!(process_clipboard_text(7, "Web Content"), "7 Web Content");
+    }
+
+    #[test]
+    fn new_status_message_is_active() {
+        let message = StatusMessage::error("Clipboard unavailable".to_string());
+        assert!(message.is_active());
+        assert!(message.is_error);
+    }
+}
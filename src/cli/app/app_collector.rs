@@ -0,0 +1,130 @@
+// app_collector.rs
+
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::sync::Mutex;
+use std::thread;
+use std::time::Duration;
+use crate::models::{GpuInfo, ProcessInfo, SystemMetrics};
+use crate::services::connections::{ConnectionProvider, ConnectionSnapshot};
+use crate::services::memory_growth::MemoryGrowth;
+use crate::services::monitor::SystemMonitor;
+use crate::services::ProcessTree;
+
+/// Dáta z jedného zberu monitora pre `TuiApp::update`
+pub struct MonitorSample {
+    pub metrics: SystemMetrics,                     // Systémové metriky
+    pub gpu_info: Option<GpuInfo>,                  // GPU (ak je dostupná)
+    pub network_stats: HashMap<u32, (u64, u64)>,    // Sieť podľa PID (odoslané, prijaté)
+    pub interface_totals: (u64, u64),               // Súčty rozhraní od štartu systému
+    pub total_process_cpu: f32,                     // Súčet CPU procesov (podiel príkonu)
+    pub core_usages: Vec<f32>,                      // Využitie jednotlivých jadier
+    pub processes: Option<ProcessSample>,           // Zoznamy procesov (None = tentokrát sa neobnovujú)
+}
+
+/// Zoznamy procesov z jedného zberu
+/// Obnovujú sa v intervale `--process-refresh-ms`, gauge pri každom zbere
+#[derive(Default)]
+pub struct ProcessSample {
+    pub processes: Vec<ProcessInfo>,                // Top procesy
+    pub tree: Option<ProcessTree>,                  // Strom sledovaného procesu (`--watch-pid`)
+    pub memory_growth: Vec<MemoryGrowth>,           // Procesy s rastúcou pamäťou
+    pub system_members: Vec<ProcessInfo>,           // Procesy v "[system processes]"
+    pub connections: ConnectionSnapshot,            // Sieťové spojenia (ešte nespárované s procesmi)
+    pub swap_processes: Option<Vec<ProcessInfo>>,   // Procesy podľa swapu (len v pohľade Swap)
+}
+
+/// Zber všetkých dát monitora a sieťových spojení
+/// None, ak sa nepodarí získať zámok monitora (iné vlákno spanikárilo)
+/// Pri `process_count` None sa zoznamy procesov (zoradenie všetkých procesov,
+/// spojenia) preskočia; `swap_limit` zapína drahší zber swapu všetkých procesov
+pub fn collect_sample(
+    monitor: &Mutex<SystemMonitor>,
+    provider: Option<&dyn ConnectionProvider>,
+    process_count: Option<usize>,
+    watch_pid: Option<u32>,
+    swap_limit: Option<usize>,
+) -> Option<MonitorSample> {
+    let mut monitor = monitor.lock().ok()?;
+    let metrics = monitor.get_metrics_for_db();
+    let processes = process_count.map(|count| ProcessSample {
+        processes: monitor.get_top_processes(count),  // Zároveň vzorka pamäte procesov
+        tree: watch_pid.and_then(|pid| monitor.get_process_tree(pid)),
+        memory_growth: monitor.get_memory_growth(),
+        system_members: monitor.get_system_members(),
+        connections: ConnectionSnapshot::default(),
+        swap_processes: swap_limit.map(|limit| monitor.get_swap_processes(limit)),
+    });
+    let mut sample = MonitorSample {
+        metrics,
+        gpu_info: monitor.get_gpu_info(),
+        network_stats: monitor.get_network_stats_for_processes(),
+        interface_totals: monitor.get_interface_totals(),
+        total_process_cpu: monitor.total_process_cpu(),
+        core_usages: monitor.get_cpu_info().iter().map(|cpu| cpu.usage).collect(),
+        processes,
+    };
+    drop(monitor);  // Spojenia (externý príkaz) sa zisťujú bez zámku monitora
+
+    if let Some(processes) = &mut sample.processes {
+        processes.connections = provider.map(|provider| provider.snapshot()).unwrap_or_default();
+    }
+    Some(sample)
+}
+
+/// Výsledok čakania na zber
+pub enum CollectOutcome<T> {
+    Ready(T),   // Zber skončil v časovom limite (alebo oneskorený zber z minula)
+    TimedOut,   // Zber stále beží - volajúci ponechá predchádzajúce dáta
+    Failed,     // Vlákno zberu skončilo bez výsledku (panika)
+}
+
+/// Zber dát na pracovnom vlákne s časovým limitom (`--collect-timeout-ms`)
+///
+/// Zaseknuté volanie OS (napr. `netstat`) tak nezablokuje vykresľovanie.
+/// Kým predchádzajúci zber nedobehne, nový sa nespúšťa - ďalšie volanie
+/// čaká na ten istý, aby sa pracovné vlákna nehromadili.
+pub struct Collector<T> {
+    timeout: Duration,               // Najdlhšie čakanie na výsledok
+    pending: Option<Receiver<T>>,    // Zber, ktorý v limite nedobehol
+}
+
+impl<T: Send + 'static> Collector<T> {
+    pub fn new(timeout: Duration) -> Self {
+        Self { timeout, pending: None }
+    }
+
+    /// Časový limit zberu
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    /// Beží ešte zber, ktorý prekročil limit?
+    pub fn is_pending(&self) -> bool {
+        self.pending.is_some()
+    }
+
+    /// Spustenie `job` na novom vlákne (ak nebeží predchádzajúci) a čakanie
+    /// najviac `timeout` na výsledok
+    pub fn collect(&mut self, job: impl FnOnce() -> T + Send + 'static) -> CollectOutcome<T> {
+        let receiver = match self.pending.take() {
+            Some(receiver) => receiver,  // Oneskorený zber má prednosť pred novým
+            None => {
+                let (sender, receiver) = mpsc::channel();
+                thread::spawn(move || {
+                    let _ = sender.send(job());  // Príjemca mohol medzitým zaniknúť
+                });
+                receiver
+            }
+        };
+
+        match receiver.recv_timeout(self.timeout) {
+            Ok(value) => CollectOutcome::Ready(value),
+            Err(RecvTimeoutError::Timeout) => {
+                self.pending = Some(receiver);
+                CollectOutcome::TimedOut
+            }
+            Err(RecvTimeoutError::Disconnected) => CollectOutcome::Failed,
+        }
+    }
+}
@@ -0,0 +1,51 @@
+// app_core_history.rs
+
+use std::collections::VecDeque;
+
+/// Pridanie vzorky využitia každého logického jadra do jeho histórie
+///
+/// Každý buffer drží najviac `capacity` vzoriek (najstaršia sa zahodí).
+/// Pri zmene počtu jadier (hotplug CPU vo VM) sa vektor prispôsobí -
+/// nové jadrá začínajú s prázdnou históriou, história odobratých sa zahodí.
+pub fn record_core_usage(history: &mut Vec<VecDeque<u64>>, usages: &[f32], capacity: usize) {
+    let capacity = capacity.max(1);
+    history.resize_with(usages.len(), || VecDeque::with_capacity(capacity));
+
+    for (buffer, &usage) in history.iter_mut().zip(usages) {
+        while buffer.len() >= capacity {
+            buffer.pop_front();
+        }
+        buffer.push_back(usage.clamp(0.0, 100.0).round() as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn history_keeps_the_newest_samples_up_to_capacity() {
+        let mut history = Vec::new();
+        for usage in [10.0, 20.0, 30.0] {
+            record_core_usage(&mut history, &[usage, 150.0], 2);
+        }
+
+        assert_eq!(history[0], [20, 30]);
+        assert_eq!(history[1], [100, 100]);  // Hodnoty nad 100 % sa orežú
+    }
+
+    #[test]
+    fn core_count_change_resizes_the_history() {
+        let mut history = Vec::new();
+        record_core_usage(&mut history, &[10.0, 20.0], 4);
+
+        record_core_usage(&mut history, &[11.0, 21.0, 31.0], 4);  // Pridané jadro
+        assert_eq!(history.len(), 3);
+        assert_eq!(history[0], [10, 11]);
+        assert_eq!(history[2], [31]);
+
+        record_core_usage(&mut history, &[12.0], 4);  // Odobraté jadrá
+        assert_eq!(history.len(), 1);
+        assert_eq!(history[0], [10, 11, 12]);
+    }
+}
@@ -0,0 +1,43 @@
+// app_db.rs
+
+use std::sync::{Arc, Mutex};
+
+/// Stav pripojenia TUI k databáze (`--save-metrics`)
+#[derive(Debug, Clone, PartialEq)]
+pub enum DbStatus {
+    Disabled,             // Ukladanie nie je zapnuté
+    Connecting,           // Pripájanie na pozadí ešte prebieha
+    Connected,            // Metriky sa ukladajú
+    Unavailable(String),  // Pripojenie zlyhalo (skúša sa znova)
+}
+
+/// Zdieľaný stav databázy medzi úlohou na pozadí a TUI
+///
+/// Pripojenie beží na pozadí, aby nedostupná databáza nebrzdila štart TUI.
+/// Časti TUI bez databázy stav nečítajú a fungujú počas pripájania aj po chybe.
+#[derive(Debug, Clone)]
+pub struct DbState {
+    status: Arc<Mutex<DbStatus>>,
+}
+
+impl DbState {
+    /// Stav pre pripájanie (`enabled`) alebo vypnuté ukladanie
+    pub fn new(enabled: bool) -> Self {
+        let status = if enabled { DbStatus::Connecting } else { DbStatus::Disabled };
+        Self { status: Arc::new(Mutex::new(status)) }
+    }
+
+    /// Aktuálny stav (otrávený mutex sa považuje za nedostupnú databázu)
+    pub fn status(&self) -> DbStatus {
+        self.status.lock()
+            .map(|status| status.clone())
+            .unwrap_or_else(|_| DbStatus::Unavailable("state lock poisoned".to_string()))
+    }
+
+    /// Nastavenie stavu z úlohy na pozadí
+    pub fn set(&self, status: DbStatus) {
+        if let Ok(mut current) = self.status.lock() {
+            *current = status;
+        }
+    }
+}
@@ -0,0 +1,80 @@
+// app_flash.rs
+
+use std::collections::{HashMap, HashSet};
+
+/// Zmena v percentuálnych bodoch medzi dvoma obnoveniami,
+/// od ktorej sa popis gauge na jedno obnovenie zvýrazní
+pub const FLASH_THRESHOLD: f64 = 10.0;
+
+/// Gauge v prehľade, ktorých zmena sa sleduje
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GaugeMetric {
+    Cpu,   // Využitie CPU
+    Ram,   // Využitie RAM
+    Disk,  // Využitie disku
+    Gpu,   // Využitie GPU
+}
+
+/// Je zmena oproti predchádzajúcej hodnote výrazná?
+/// Prvá hodnota (bez predchádzajúcej) sa nezvýrazňuje
+pub fn is_significant_change(previous: Option<f64>, current: f64, threshold: f64) -> bool {
+    previous.is_some_and(|previous| (current - previous).abs() >= threshold)
+}
+
+/// Predchádzajúce hodnoty gauge a zoznam práve zvýraznených
+///
+/// `record` sa volá raz za obnovenie dát, takže zvýraznenie
+/// trvá práve jedno obnovenie (pri ďalšom sa prepočíta).
+#[derive(Debug, Default)]
+pub struct ChangeFlash {
+    previous: HashMap<GaugeMetric, f64>,  // Hodnota z posledného obnovenia
+    flashing: HashSet<GaugeMetric>,       // Gauge s výraznou zmenou v tomto obnovení
+}
+
+impl ChangeFlash {
+    /// Uloženie novej hodnoty a porovnanie s predchádzajúcou
+    pub fn record(&mut self, metric: GaugeMetric, value: f64) {
+        let previous = self.previous.insert(metric, value);
+        if is_significant_change(previous, value, FLASH_THRESHOLD) {
+            self.flashing.insert(metric);
+        } else {
+            self.flashing.remove(&metric);
+        }
+    }
+
+    /// Má sa popis gauge zvýrazniť?
+    pub fn is_flashing(&self, metric: GaugeMetric) -> bool {
+        self.flashing.contains(&metric)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn change_is_significant_from_the_threshold_in_both_directions() {
+        assert!(!is_significant_change(None, 90.0, FLASH_THRESHOLD));  // Prvá hodnota
+        assert!(!is_significant_change(Some(40.0), 49.9, FLASH_THRESHOLD));
+        assert!(is_significant_change(Some(40.0), 50.0, FLASH_THRESHOLD));
+        assert!(is_significant_change(Some(80.0), 20.0, FLASH_THRESHOLD));
+    }
+
+    #[test]
+    fn flash_lasts_one_reading_per_gauge() {
+        let mut flash = ChangeFlash::default();
+
+        flash.record(GaugeMetric::Cpu, 10.0);
+        flash.record(GaugeMetric::Ram, 50.0);
+        assert!(!flash.is_flashing(GaugeMetric::Cpu));
+
+        flash.record(GaugeMetric::Cpu, 60.0);
+        flash.record(GaugeMetric::Ram, 52.0);
+        assert!(flash.is_flashing(GaugeMetric::Cpu));
+        assert!(!flash.is_flashing(GaugeMetric::Ram));
+
+        flash.record(GaugeMetric::Cpu, 61.0);  // Ďalšie obnovenie bez výraznej zmeny
+        assert!(!flash.is_flashing(GaugeMetric::Cpu));
+        assert!(!flash.is_flashing(GaugeMetric::Gpu));  // Nikdy nezaznamenaný
+    }
+}
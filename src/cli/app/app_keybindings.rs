@@ -0,0 +1,200 @@
+use ratatui::style::Color;
+use super::app_state::Mode;
+
+/// Jedna klávesová skratka zobrazená v päte a na obrazovke pomoci
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct KeyBinding {
+    pub key: &'static str,      // Označenie klávesu (napr. "Q", "↑↓")
+    pub action: &'static str,   // Krátky popis akcie
+    pub color: Color,           // Farba označenia klávesu
+}
+
+/// Skrátený zápis pre definíciu tabuliek nižšie
+const fn kb(key: &'static str, action: &'static str, color: Color) -> KeyBinding {
+    KeyBinding { key, action, color }
+}
+
+// Tabuľky musia zodpovedať obsluhe kláves v `cli/runner.rs`
+
+const OVERVIEW: &[KeyBinding] = &[
+    kb("H", "Help", Color::Yellow),
+    kb("R", "Refresh", Color::Green),
+    kb("N/Tab", "Network", Color::Blue),
+    kb("T", "Tree", Color::Green),
+    kb("M", "Memory", Color::Blue),
+    kb("W", "Swap", Color::Blue),
+    kb("↑↓", "Navigate", Color::Cyan),
+    kb("Enter", "Details", Color::Magenta),
+    kb("Space", "Select", Color::Cyan),
+    kb("X", "Select all", Color::Cyan),
+    kb("Y", "Copy", Color::Green),
+    kb("I", "Ignore list", Color::Blue),
+    kb("S", "System group", Color::Blue),
+    kb("C", "Clear history", Color::Red),
+    kb("V", "RSS/Virtual", Color::Cyan),
+    kb("F", "Fit graphs", Color::Cyan),
+    kb("B", "Raw bytes", Color::Cyan),
+    kb("A", "About", Color::Blue),
+    kb(":", "Palette", Color::Yellow),
+    kb("Q/Esc", "Quit", Color::Red),
+];
+
+const NETWORK: &[KeyBinding] = &[
+    kb("Esc", "Back", Color::Yellow),
+    kb("R", "Refresh", Color::Green),
+    kb("↑↓", "Navigate", Color::Cyan),
+    kb("Enter", "Details", Color::Magenta),
+    kb("P", "Protocol (detail)", Color::Cyan),
+    kb("Tab", "Overview", Color::Magenta),
+    kb("+/-", "Count", Color::Cyan),
+    kb("S", "Sort", Color::Cyan),
+    kb("W", "Compact", Color::Cyan),
+    kb("C", "Clear history", Color::Red),
+    kb("V", "RSS/Virtual", Color::Cyan),
+    kb("Y", "Copy", Color::Green),
+    kb("H", "Help", Color::Yellow),
+    kb(":", "Palette", Color::Yellow),
+    kb("Q", "Quit", Color::Red),
+];
+
+const PROCESS_DETAIL: &[KeyBinding] = &[
+    kb("Esc", "Back", Color::Yellow),
+    kb("Y", "Copy", Color::Green),
+    kb("B", "Raw bytes", Color::Cyan),
+    kb(":", "Palette", Color::Yellow),
+    kb("Q", "Quit", Color::Red),
+];
+
+const PROCESS_TREE: &[KeyBinding] = &[
+    kb("↑↓", "Navigate", Color::Cyan),
+    kb("Enter", "Collapse/Expand", Color::Magenta),
+    kb("Y", "Copy", Color::Green),
+    kb("V", "RSS/Virtual", Color::Cyan),
+    kb("R", "Refresh", Color::Green),
+    kb("H", "Help", Color::Yellow),
+    kb("T/Esc", "Back", Color::Yellow),
+    kb(":", "Palette", Color::Yellow),
+    kb("Q", "Quit", Color::Red),
+];
+
+const MEMORY_GROWTH: &[KeyBinding] = &[
+    kb("↑↓", "Navigate", Color::Cyan),
+    kb("Y", "Copy", Color::Green),
+    kb("R", "Refresh", Color::Green),
+    kb("H", "Help", Color::Yellow),
+    kb("M/Esc", "Back", Color::Yellow),
+    kb(":", "Palette", Color::Yellow),
+    kb("Q", "Quit", Color::Red),
+];
+
+const SWAP: &[KeyBinding] = &[
+    kb("↑↓", "Navigate", Color::Cyan),
+    kb("Y", "Copy", Color::Green),
+    kb("R", "Refresh", Color::Green),
+    kb("H", "Help", Color::Yellow),
+    kb("W/Esc", "Back", Color::Yellow),
+    kb(":", "Palette", Color::Yellow),
+    kb("Q", "Quit", Color::Red),
+];
+
+const ABOUT: &[KeyBinding] = &[
+    kb("A/Esc", "Back", Color::Yellow),
+    kb("H", "Help", Color::Yellow),
+    kb("B", "Raw bytes", Color::Cyan),
+    kb(":", "Palette", Color::Yellow),
+    kb("Q", "Quit", Color::Red),
+];
+
+const HELP: &[KeyBinding] = &[
+    kb("H/Esc", "Back", Color::Yellow),
+    kb(":", "Palette", Color::Yellow),
+    kb("Q", "Quit", Color::Red),
+];
+
+/// Všetky režimy s tabuľkou skratiek (poradie výpisu `keybindings`)
+pub const KEYBINDING_MODES: [Mode; 8] = [
+    Mode::Overview,
+    Mode::NetworkView,
+    Mode::ProcessDetail,
+    Mode::ProcessTree,
+    Mode::MemoryGrowth,
+    Mode::Swap,
+    Mode::About,
+    Mode::Help,
+];
+
+/// Klávesové skratky dostupné v danom režime
+/// Jediný zdroj pre päty obrazoviek aj obrazovku pomoci
+pub fn keybindings_for(mode: Mode) -> &'static [KeyBinding] {
+    match mode {
+        Mode::Overview => OVERVIEW,
+        Mode::NetworkView => NETWORK,
+        Mode::ProcessDetail => PROCESS_DETAIL,
+        Mode::ProcessTree => PROCESS_TREE,
+        Mode::MemoryGrowth => MEMORY_GROWTH,
+        Mode::Swap => SWAP,
+        Mode::About => ABOUT,
+        Mode::Help => HELP,
+    }
+}
+
+/// Názov režimu zobrazený na obrazovke pomoci
+pub fn mode_name(mode: Mode) -> &'static str {
+    match mode {
+        Mode::Overview => "Overview",
+        Mode::NetworkView => "Network",
+        Mode::ProcessDetail => "Process Details",
+        Mode::ProcessTree => "Process Tree",
+        Mode::MemoryGrowth => "Memory Growth",
+        Mode::Swap => "Swap",
+        Mode::About => "About",
+        Mode::Help => "Help",
+    }
+}
+
+/// Strojový identifikátor režimu (kľúč v JSON výstupe `keybindings`)
+pub fn mode_id(mode: Mode) -> &'static str {
+    match mode {
+        Mode::Overview => "overview",
+        Mode::NetworkView => "network",
+        Mode::ProcessDetail => "process_detail",
+        Mode::ProcessTree => "process_tree",
+        Mode::MemoryGrowth => "memory_growth",
+        Mode::Swap => "swap",
+        Mode::About => "about",
+        Mode::Help => "help",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keys(mode: Mode) -> Vec<&'static str> {
+        keybindings_for(mode).iter().map(|binding| binding.key).collect()
+    }
+
+    #[test]
+    fn overview_and_detail_legends_differ() {
+        assert_ne!(keybindings_for(Mode::Overview), keybindings_for(Mode::ProcessDetail));
+    }
+
+    #[test]
+    fn overview_legend_lists_navigation_and_views() {
+        let overview = keys(Mode::Overview);
+
+        for key in ["H", "N/Tab", "↑↓", "Enter", "Q/Esc"] {
+            assert!(overview.contains(&key), "{key}");
+        }
+    }
+
+    #[test]
+    fn detail_legend_goes_back_without_list_navigation() {
+        let detail = keys(Mode::ProcessDetail);
+
+        assert_eq!(detail[0], "Esc");
+        assert_eq!(keybindings_for(Mode::ProcessDetail)[0].action, "Back");
+        assert!(!detail.contains(&"↑↓"));
+        assert!(!detail.contains(&"Enter"));
+    }
+}
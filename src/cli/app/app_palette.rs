@@ -0,0 +1,200 @@
+/// Akcie dostupné v palete príkazov
+/// Každá akcia zodpovedá existujúcej metóde na `TuiApp`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PaletteAction {
+    ShowOverview,     // Prepnutie na prehľad
+    ShowNetwork,      // Prepnutie na sieťový pohľad
+    ShowProcessTree,  // Stromové zobrazenie procesov
+    ShowMemoryGrowth, // Procesy s rastúcou pamäťou
+    ShowSwap,         // Procesy podľa swapu
+    ProcessDetails,   // Detail vybraného procesu
+    CopyProcess,      // Skopírovanie PID a názvu procesu
+    ToggleIgnoreList, // Zapnutie/vypnutie skrývania ignorovaných procesov
+    ToggleSystemGroup, // Zoskupenie systémových procesov do jedného riadku
+    ClearHistory,     // Vymazanie histórie grafov
+    ToggleMemoryMetric, // Prepnutie RSS / virtuálnej pamäte
+    ToggleNetworkSort, // Poradie sieťových procesov (celkom / odoslané / prijaté / spojenia)
+    ToggleCompactNetwork, // Kompaktné riadky sieťových procesov
+    ToggleFitSparklines, // Prispôsobenie grafov histórie šírke
+    ToggleRawBytes,      // Pamäť a disk v presných bajtoch
+    ToggleSelectAll,     // Výber všetkých procesov / zrušenie výberu
+    ToggleConnectionFilter, // Filter spojení v detaile podľa protokolu
+    KillProcess,         // Ukončenie vybraného procesu (alebo vybraných procesov)
+    SaveSnapshot,        // Uloženie snímky systému do JSON súboru
+    ShowAbout,        // Súhrn hardvéru a systému
+    ShowHelp,         // Obrazovka pomoci
+    Refresh,          // Okamžité obnovenie dát
+    Quit,             // Ukončenie aplikácie
+}
+
+impl PaletteAction {
+    /// Všetky akcie v poradí, v akom sa zobrazujú v palete
+    pub const ALL: [PaletteAction; 23] = [
+        PaletteAction::ShowOverview,
+        PaletteAction::ShowNetwork,
+        PaletteAction::ShowProcessTree,
+        PaletteAction::ShowMemoryGrowth,
+        PaletteAction::ShowSwap,
+        PaletteAction::ProcessDetails,
+        PaletteAction::CopyProcess,
+        PaletteAction::ToggleIgnoreList,
+        PaletteAction::ToggleSystemGroup,
+        PaletteAction::ClearHistory,
+        PaletteAction::ToggleMemoryMetric,
+        PaletteAction::ToggleNetworkSort,
+        PaletteAction::ToggleCompactNetwork,
+        PaletteAction::ToggleFitSparklines,
+        PaletteAction::ToggleRawBytes,
+        PaletteAction::ToggleSelectAll,
+        PaletteAction::ToggleConnectionFilter,
+        PaletteAction::KillProcess,
+        PaletteAction::SaveSnapshot,
+        PaletteAction::ShowAbout,
+        PaletteAction::ShowHelp,
+        PaletteAction::Refresh,
+        PaletteAction::Quit,
+    ];
+
+    /// Názov akcie zobrazený v palete (podľa neho sa aj vyhľadáva)
+    pub fn name(&self) -> &'static str {
+        match self {
+            PaletteAction::ShowOverview => "Switch view: Overview",
+            PaletteAction::ShowNetwork => "Switch view: Network",
+            PaletteAction::ShowProcessTree => "Switch view: Process tree",
+            PaletteAction::ShowMemoryGrowth => "Switch view: Memory growth",
+            PaletteAction::ShowSwap => "Switch view: Swap usage",
+            PaletteAction::ProcessDetails => "Show process details",
+            PaletteAction::CopyProcess => "Copy process PID and name",
+            PaletteAction::ToggleIgnoreList => "Filter: toggle ignored processes",
+            PaletteAction::ToggleSystemGroup => "Toggle grouped system processes",
+            PaletteAction::ClearHistory => "Clear history graphs",
+            PaletteAction::ToggleMemoryMetric => "Toggle memory: RSS / virtual",
+            PaletteAction::ToggleNetworkSort => "Sort network processes: total / sent / received / connections",
+            PaletteAction::ToggleCompactNetwork => "Toggle compact network rows",
+            PaletteAction::ToggleFitSparklines => "Toggle fitting history graphs to width",
+            PaletteAction::ToggleRawBytes => "Toggle raw bytes for memory and disk",
+            PaletteAction::ToggleSelectAll => "Select all processes / clear selection",
+            PaletteAction::ToggleConnectionFilter => "Filter connections: all / TCP / UDP",
+            PaletteAction::KillProcess => "Kill selected process",
+            PaletteAction::SaveSnapshot => "Save snapshot to JSON file",
+            PaletteAction::ShowAbout => "Show system info (About)",
+            PaletteAction::ShowHelp => "Show help",
+            PaletteAction::Refresh => "Refresh data",
+            PaletteAction::Quit => "Quit application",
+        }
+    }
+}
+
+/// Stav palety príkazov (otvára sa klávesom `:`)
+#[derive(Debug, Clone, Default)]
+pub struct CommandPalette {
+    pub open: bool,        // Je paleta zobrazená?
+    pub query: String,     // Zadaný text pre vyhľadávanie
+    pub selected: usize,   // Index vybranej akcie v zozname zhôd
+}
+
+impl CommandPalette {
+    /// Otvorenie palety s prázdnym vyhľadávaním
+    pub fn open(&mut self) {
+        self.open = true;
+        self.query.clear();
+        self.selected = 0;
+    }
+
+    /// Zatvorenie palety
+    pub fn close(&mut self) {
+        self.open = false;
+    }
+
+    /// Akcie zodpovedajúce aktuálnemu vyhľadávaniu
+    pub fn matches(&self) -> Vec<PaletteAction> {
+        PaletteAction::ALL
+            .iter()
+            .copied()
+            .filter(|action| fuzzy_match(&self.query, action.name()))
+            .collect()
+    }
+
+    /// Aktuálne vybraná akcia (ak nejaká zodpovedá vyhľadávaniu)
+    pub fn selected_action(&self) -> Option<PaletteAction> {
+        self.matches().get(self.selected).copied()
+    }
+
+    /// Pridanie znaku do vyhľadávania - výber sa vráti na prvú zhodu
+    pub fn push_char(&mut self, c: char) {
+        self.query.push(c);
+        self.selected = 0;
+    }
+
+    /// Zmazanie posledného znaku vyhľadávania
+    pub fn pop_char(&mut self) {
+        self.query.pop();
+        self.selected = 0;
+    }
+
+    /// Posun výberu nadol (cyklicky)
+    pub fn next(&mut self) {
+        let count = self.matches().len();
+        if count > 0 {
+            self.selected = (self.selected + 1) % count;
+        }
+    }
+
+    /// Posun výberu nahor (cyklicky)
+    pub fn previous(&mut self) {
+        let count = self.matches().len();
+        if count > 0 {
+            self.selected = if self.selected == 0 { count - 1 } else { self.selected - 1 };
+        }
+    }
+}
+
+/// Fuzzy vyhľadávanie - všetky znaky dotazu sa musia v názve nachádzať
+/// v rovnakom poradí (nie nutne za sebou), bez ohľadu na veľkosť písmen
+/// Napr. "netw" aj "swn" zodpovedajú "Switch view: Network"
+pub fn fuzzy_match(query: &str, candidate: &str) -> bool {
+    let candidate = candidate.to_lowercase();
+    let mut remaining = candidate.chars();
+
+    query
+        .to_lowercase()
+        .chars()
+        .filter(|c| !c.is_whitespace())  // Medzery v dotaze sa ignorujú
+        .all(|q| remaining.any(|c| c == q))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_match_needs_query_characters_in_order() {
+        assert!(fuzzy_match("netw", "Switch view: Network"));
+        assert!(fuzzy_match("swn", "Switch view: Network"));
+        assert!(fuzzy_match("KILL", "Kill selected process"));
+        assert!(fuzzy_match("sort net", "Sort network processes: total / sent / received / connections"));
+        assert!(!fuzzy_match("wen", "Kill selected process"));
+        assert!(!fuzzy_match("tn", "Network"));  // Poradie znakov sa musí zachovať
+    }
+
+    #[test]
+    fn empty_query_matches_every_action() {
+        let palette = CommandPalette::default();
+        assert_eq!(palette.matches(), PaletteAction::ALL.to_vec());
+    }
+
+    #[test]
+    fn palette_filters_actions_by_query() {
+        let mut palette = CommandPalette::default();
+        palette.open();
+        for c in "snapshot".chars() {
+            palette.push_char(c);
+        }
+        assert_eq!(palette.matches(), vec![PaletteAction::SaveSnapshot]);
+        assert_eq!(palette.selected_action(), Some(PaletteAction::SaveSnapshot));
+
+        palette.query = "zzz".to_string();
+        assert!(palette.matches().is_empty());
+        assert_eq!(palette.selected_action(), None);
+    }
+}
@@ -0,0 +1,67 @@
+// app_peaks.rs
+
+use std::collections::HashMap;
+use super::app_flash::GaugeMetric;
+
+/// Najvyššia hodnota každej metriky od spustenia TUI alebo vymazania histórie
+///
+/// Zobrazuje sa ako „peak N%“ v titulku grafu histórie. Na rozdiel od grafu
+/// sa neposúva - drží vrchol celej relácie, kým ho kláves C nevymaže.
+#[derive(Debug, Default)]
+pub struct SessionPeaks {
+    peaks: HashMap<GaugeMetric, f64>,  // Maximum podľa metriky (v percentách)
+}
+
+impl SessionPeaks {
+    /// Započítanie novej hodnoty - maximum sa len zvyšuje
+    /// Neplatné hodnoty (NaN) sa ignorujú
+    pub fn record(&mut self, metric: GaugeMetric, value: f64) {
+        if value.is_nan() {
+            return;
+        }
+        self.peaks.entry(metric)
+            .and_modify(|peak| *peak = peak.max(value))
+            .or_insert(value);
+    }
+
+    /// Vrchol metriky (None pred prvou hodnotou)
+    pub fn peak(&self, metric: GaugeMetric) -> Option<f64> {
+        self.peaks.get(&metric).copied()
+    }
+
+    /// Vymazanie všetkých vrcholov (spolu s históriou grafov)
+    pub fn clear(&mut self) {
+        self.peaks.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn peak_is_the_running_maximum() {
+        let mut peaks = SessionPeaks::default();
+        assert_eq!(peaks.peak(GaugeMetric::Cpu), None);
+
+        for value in [20.0, 75.0, 40.0, f64::NAN] {
+            peaks.record(GaugeMetric::Cpu, value);
+        }
+        peaks.record(GaugeMetric::Ram, 10.0);
+
+        assert_eq!(peaks.peak(GaugeMetric::Cpu), Some(75.0));
+        assert_eq!(peaks.peak(GaugeMetric::Ram), Some(10.0));
+    }
+
+    #[test]
+    fn clearing_resets_every_peak() {
+        let mut peaks = SessionPeaks::default();
+        peaks.record(GaugeMetric::Cpu, 90.0);
+
+        peaks.clear();
+        assert_eq!(peaks.peak(GaugeMetric::Cpu), None);
+
+        peaks.record(GaugeMetric::Cpu, 15.0);  // Po vymazaní začína odznova
+        assert_eq!(peaks.peak(GaugeMetric::Cpu), Some(15.0));
+    }
+}
@@ -0,0 +1,142 @@
+// app_refresh.rs
+
+use std::time::{Duration, Instant};
+use crate::config::RefreshIndicator;
+
+/// Snímky spinnera v päte (Braille bodky - úzke a nenápadné)
+pub const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+/// Stav indikátora obnovenia (`--refresh-indicator`)
+///
+/// Spinner sa posunie pri každom úspešnom zbere dát, takže stojí, keď
+/// zber visí (`--collect-timeout-ms`) - vek dát potom ďalej rastie.
+#[derive(Debug, Default)]
+pub struct RefreshTicker {
+    frame: usize,                             // Index aktuálneho snímku spinnera
+    last_successful_update: Option<Instant>,  // Čas posledného úspešného zberu
+}
+
+impl RefreshTicker {
+    /// Úspešný zber - posun spinnera a nový čas aktualizácie
+    pub fn record_success(&mut self, now: Instant) {
+        self.frame = next_frame(self.frame);
+        self.last_successful_update = Some(now);
+    }
+
+    /// Aktuálny snímok spinnera
+    pub fn spinner(&self) -> &'static str {
+        SPINNER_FRAMES[self.frame]
+    }
+
+    /// Vek dát k času `now` (None pred prvým úspešným zberom)
+    pub fn age(&self, now: Instant) -> Option<Duration> {
+        self.last_successful_update.map(|updated| now.saturating_duration_since(updated))
+    }
+
+    /// Text indikátora podľa nastavenia, napr. `⠙ updated 3s ago`
+    /// None pri `off` alebo pred prvým zberom
+    pub fn text(&self, indicator: RefreshIndicator, now: Instant) -> Option<String> {
+        let age = self.age(now)?;
+        match indicator {
+            RefreshIndicator::Full => Some(format!("{} {}", self.spinner(), format_ago(age))),
+            RefreshIndicator::Spinner => Some(self.spinner().to_string()),
+            RefreshIndicator::Off => None,
+        }
+    }
+}
+
+/// Index nasledujúceho snímku spinnera (cyklicky)
+pub fn next_frame(frame: usize) -> usize {
+    (frame + 1) % SPINNER_FRAMES.len()
+}
+
+/// Vek dát ako `updated 3s ago` (nad minútu v minútach, nad hodinu v hodinách)
+pub fn format_ago(age: Duration) -> String {
+    let seconds = age.as_secs();
+    match seconds {
+        0..=59 => format!("updated {}s ago", seconds),
+        60..=3599 => format!("updated {}m ago", seconds / 60),
+        _ => format!("updated {}h ago", seconds / 3600),
+    }
+}
+
+/// Časovač obnovy zoznamu procesov (`--process-refresh-ms`)
+///
+/// Zoradenie všetkých procesov a zisťovanie spojení je drahšie než gauge,
+/// preto sa zoznamy procesov obnovujú v dlhšom intervale než ostatné dáta.
+#[derive(Debug)]
+pub struct ProcessRefreshTimer {
+    interval: Duration,              // Najkratší čas medzi obnovami zoznamu
+    last_refresh: Option<Instant>,   // Posledná obnova (None = obnoviť pri najbližšom zbere)
+}
+
+impl ProcessRefreshTimer {
+    pub fn new(interval: Duration) -> Self {
+        Self { interval, last_refresh: None }
+    }
+
+    /// Má sa pri zbere v čase `now` obnoviť aj zoznam procesov?
+    pub fn is_due(&self, now: Instant) -> bool {
+        self.last_refresh
+            .is_none_or(|last| now.saturating_duration_since(last) >= self.interval)
+    }
+
+    /// Zoznam procesov bol obnovený v čase `now`
+    pub fn record(&mut self, now: Instant) {
+        self.last_refresh = Some(now);
+    }
+
+    /// Obnova pri najbližšom zbere bez ohľadu na interval (ručné obnovenie)
+    pub fn force(&mut self) {
+        self.last_refresh = None;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn next_frame_wraps_around() {
+        assert_eq!(next_frame(0), 1);
+        assert_eq!(next_frame(SPINNER_FRAMES.len() - 1), 0);
+    }
+
+    #[test]
+    fn format_ago_switches_units_at_minute_and_hour() {
+        assert_eq!(format_ago(Duration::ZERO), "updated 0s ago");
+        assert_eq!(format_ago(Duration::from_millis(59_999)), "updated 59s ago");
+        assert_eq!(format_ago(Duration::from_secs(60)), "updated 1m ago");
+        assert_eq!(format_ago(Duration::from_secs(3599)), "updated 59m ago");
+        assert_eq!(format_ago(Duration::from_secs(3600)), "updated 1h ago");
+        assert_eq!(format_ago(Duration::from_secs(3 * 86_400)), "updated 72h ago");
+    }
+
+    #[test]
+    fn ticker_text_follows_the_indicator_setting() {
+        let start = Instant::now();
+        let mut ticker = RefreshTicker::default();
+        assert_eq!(ticker.text(RefreshIndicator::Full, start), None);
+
+        ticker.record_success(start);
+        let later = start + Duration::from_secs(5);
+
+        assert_eq!(ticker.text(RefreshIndicator::Full, later).as_deref(), Some("⠙ updated 5s ago"));
+        assert_eq!(ticker.text(RefreshIndicator::Spinner, later).as_deref(), Some("⠙"));
+        assert_eq!(ticker.text(RefreshIndicator::Off, later), None);
+    }
+
+    #[test]
+    fn process_refresh_is_due_first_then_after_the_interval() {
+        let start = Instant::now();
+        let mut timer = ProcessRefreshTimer::new(Duration::from_secs(2));
+        assert!(timer.is_due(start));
+
+        timer.record(start);
+        assert!(!timer.is_due(start + Duration::from_secs(1)));
+        assert!(timer.is_due(start + Duration::from_secs(2)));
+
+        timer.force();
+        assert!(timer.is_due(start));
+    }
+}
@@ -0,0 +1,121 @@
+// app_selection.rs
+
+use std::collections::HashSet;
+use crate::models::ProcessInfo;
+use crate::services::system_processes::SYSTEM_GROUP_PID;
+
+/// Prepnutie výberu jedného procesu (medzerník)
+/// Vráti `true`, ak je proces po prepnutí vybraný
+pub fn toggle_pid(selected: &mut HashSet<u32>, pid: u32) -> bool {
+    if selected.remove(&pid) {
+        false
+    } else {
+        selected.insert(pid)
+    }
+}
+
+/// Vybrateľné procesy zoznamu - bez súhrnného riadku systémových procesov
+pub fn selectable_pids(processes: &[ProcessInfo]) -> impl Iterator<Item = u32> + '_ {
+    processes.iter()
+        .map(|process| process.pid)
+        .filter(|&pid| pid != SYSTEM_GROUP_PID)
+}
+
+/// Odstránenie opakovaných PID - ostane prvý výskyt
+/// Rozbalené systémové procesy alebo strom `--watch-pid` nesmú proces zobraziť dvakrát
+pub fn dedupe_by_pid(processes: &mut Vec<ProcessInfo>) {
+    let mut seen = HashSet::with_capacity(processes.len());
+    processes.retain(|process| seen.insert(process.pid));
+}
+
+/// Otázka pred ukončením procesov (PID, názov) zobrazená v päte
+pub fn kill_prompt(targets: &[(u32, String)]) -> String {
+    match targets {
+        [(pid, name)] => format!("Kill {} \"{}\"? [y/n]", pid, name),
+        _ => format!("Kill {} processes? [y/n]", targets.len()),
+    }
+}
+
+/// Procesy, na ktoré sa použije hromadná akcia (kopírovanie, ukončenie)
+///
+/// S neprázdnym výberom sú to vybrané procesy v poradí zoznamu,
+/// inak len proces pod kurzorom (`highlighted`). Súhrnný riadok sa neberie.
+pub fn action_targets<'a>(
+    processes: &'a [ProcessInfo],
+    selected: &HashSet<u32>,
+    highlighted: Option<usize>,
+) -> Vec<&'a ProcessInfo> {
+    if selected.is_empty() {
+        highlighted
+            .and_then(|index| processes.get(index))
+            .filter(|process| process.pid != SYSTEM_GROUP_PID)
+            .into_iter()
+            .collect()
+    } else {
+        processes.iter()
+            .filter(|process| selected.contains(&process.pid))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kill_prompt_names_a_single_process() {
+        assert_eq!(kill_prompt(&[(42, "firefox".to_string())]), "Kill 42 \"firefox\"? [y/n]");
+    }
+
+    #[test]
+    fn kill_prompt_counts_several_processes() {
+        let targets = [(1, "a".to_string()), (2, "b".to_string()), (3, "c".to_string())];
+        assert_eq!(kill_prompt(&targets), "Kill 3 processes? [y/n]");
+    }
+
+    fn pids(processes: &[&ProcessInfo]) -> Vec<u32> {
+        processes.iter().map(|process| process.pid).collect()
+    }
+
+    #[test]
+    fn toggle_pid_selects_and_deselects() {
+        let mut selected = HashSet::new();
+
+        assert!(toggle_pid(&mut selected, 7));
+        assert!(selected.contains(&7));
+        assert!(!toggle_pid(&mut selected, 7));
+        assert!(selected.is_empty());
+    }
+
+    #[test]
+    fn action_targets_prefer_the_selection_in_list_order() {
+        use crate::test_support::sample_process;
+        let processes = vec![sample_process(3, "c"), sample_process(1, "a"), sample_process(2, "b")];
+        let selected = HashSet::from([2, 3]);
+
+        assert_eq!(pids(&action_targets(&processes, &selected, Some(1))), [3, 2]);
+    }
+
+    #[test]
+    fn action_targets_fall_back_to_the_highlighted_row() {
+        use crate::test_support::sample_process;
+        let processes = vec![sample_process(SYSTEM_GROUP_PID, "system"), sample_process(1, "a")];
+        let none = HashSet::new();
+
+        assert_eq!(pids(&action_targets(&processes, &none, Some(1))), [1]);
+        assert!(action_targets(&processes, &none, Some(0)).is_empty());  // Súhrnný riadok sa neberie
+        assert!(action_targets(&processes, &none, Some(5)).is_empty());
+        assert!(action_targets(&processes, &none, None).is_empty());
+    }
+
+    #[test]
+    fn dedupe_keeps_the_first_occurrence_of_each_pid() {
+        use crate::test_support::sample_process;
+        let mut processes = vec![sample_process(1, "first"), sample_process(2, "b"), sample_process(1, "again")];
+
+        dedupe_by_pid(&mut processes);
+
+        let names: Vec<&str> = processes.iter().map(|process| process.name.as_str()).collect();
+        assert_eq!(names, ["first", "b"]);
+    }
+}
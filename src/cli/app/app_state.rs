@@ -1,10 +1,28 @@
 // Importy pre stav aplikácie a TUI komponenty
 use ratatui::widgets::ListState;  // Stav pre zoznamy (selekcia, scrollovanie)
 use std::sync::{Arc, Mutex};      // Bezpečné zdieľanie dát medzi vláknami
+use std::time::{Duration, Instant};  // Časový limit zberu dát, čas posledného zberu
 use crate::services::monitor::SystemMonitor;  // Monitorovací servis
-use crate::models::{SystemMetrics, GpuInfo, ProcessInfo as ModelsProcessInfo};  // Dátové modely
-use std::collections::HashMap;    // Hash map pre efektívne vyhľadávanie
-use std::process::Command;        // Spúšťanie externých príkazov
+use crate::services::ProcessTree;             // Sledovaný strom procesov
+use crate::services::link_speed::detect_link_speed_mbps;  // Rýchlosť sieťovej linky
+use crate::services::connections::{platform_provider, resolve_connections, ConnectionProvider, ConnectionsResult, NetworkConnection};  // Sieťové spojenia podľa OS
+use crate::services::process_tree::{build_tree_rows, TreeRow};  // Stromové zobrazenie procesov
+use crate::services::memory_growth::MemoryGrowth;  // Procesy s rastúcou pamäťou
+use crate::services::system_processes::SYSTEM_GROUP_PID;  // Súhrnný riadok systémových procesov
+use crate::config::TuiArgs;                   // Nastavenia TUI
+use crate::models::{Snapshot, SystemMetrics, GpuInfo, MemoryMetric, ProcessInfo as ModelsProcessInfo, TemperatureThresholds, CpuThresholds, name_or_pid};  // Dátové modely
+use std::collections::{HashMap, HashSet, VecDeque};  // Hash map pre efektívne vyhľadávanie
+use super::app_palette::{CommandPalette, PaletteAction};  // Paleta príkazov
+use super::app_ui_state::{UiState, SavedView, MAX_NETWORK_PROCESS_COUNT};  // Stav uložený medzi spusteniami
+use super::app_clipboard::{process_clipboard_text, ProcessClipboard, StatusMessage};  // Schránka
+use super::app_flash::{ChangeFlash, GaugeMetric};  // Zvýraznenie výraznej zmeny
+use super::app_peaks::SessionPeaks;  // Vrcholy metrík v relácii
+use super::app_refresh::{ProcessRefreshTimer, RefreshTicker};  // Indikátor obnovenia v päte, interval zoznamu procesov
+use super::app_db::DbState;  // Pripojenie k databáze na pozadí
+use super::app_selection::{action_targets, dedupe_by_pid, kill_prompt, selectable_pids, toggle_pid};  // Výber viacerých procesov
+use super::app_core_history::record_core_usage;  // História jednotlivých jadier
+use super::app_collector::{collect_sample, CollectOutcome, Collector, MonitorSample, ProcessSample};  // Zber dát s časovým limitom
+use super::app_system_info::{self, get_system_info};  // Podrobné informácie pre obrazovku About
 
 /// Informácie o systéme zobrazované v TUI
 /// Tieto informácie sa získavajú pri štarte aplikácie
@@ -16,18 +34,6 @@ pub struct SystemInfo {
     pub gpu_name: String,    // Model grafickej karty
 }
 
-/// Reprezentácia sieťového spojenia procesu
-/// Obsahuje informácie o lokálnom a vzdialenom konci spojenia
-#[derive(Debug, Clone)]
-pub struct NetworkConnection {
-    pub process_name: String,    // Názov procesu vytvárajúceho spojenie
-    pub local_address: String,   // Lokálna IP adresa a port
-    pub remote_address: String,  // Vzdialená IP adresa a port
-    pub protocol: String,        // Sieťový protokol (TCP/UDP)
-    pub state: String,           // Stav spojenia (ESTABLISHED, LISTENING, atď.)
-    pub pid: u32,               // PID procesu
-}
-
 /// Režimy zobrazenia TUI aplikácie
 /// Definuje, ktorá obrazovka sa má renderovať
 #[derive(PartialEq, Clone, Copy)]
@@ -36,18 +42,130 @@ pub enum Mode {
     ProcessDetail,   // Detailný pohľad na proces
     NetworkView,     // Sieťová aktivita a spojenia
     Help,            // Nápoveda a klávesové skratky
+    ProcessTree,     // Stromové zobrazenie procesov podľa rodičov
+    MemoryGrowth,    // Procesy s neustále rastúcou pamäťou
+    Swap,            // Procesy s najväčším swapom
+    About,           // Súhrn hardvéru a systému
+}
+
+/// Poradie procesov v sieťovom pohľade (kláves S)
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum NetworkSort {
+    #[default]
+    Total,           // Podľa odoslaných + prijatých bajtov
+    Sent,            // Podľa odoslaných bajtov
+    Recv,            // Podľa prijatých bajtov
+    Connections,     // Podľa počtu aktívnych spojení
+}
+
+impl NetworkSort {
+    /// Krátky názov pre titulok tabuľky a správu v päte
+    pub fn label(&self) -> &'static str {
+        match self {
+            NetworkSort::Total => "total traffic",
+            NetworkSort::Sent => "sent",
+            NetworkSort::Recv => "received",
+            NetworkSort::Connections => "connections",
+        }
+    }
+
+    /// Ďalšie poradie v cykle celkom → odoslané → prijaté → spojenia (kláves S)
+    pub fn next(&self) -> Self {
+        match self {
+            NetworkSort::Total => NetworkSort::Sent,
+            NetworkSort::Sent => NetworkSort::Recv,
+            NetworkSort::Recv => NetworkSort::Connections,
+            NetworkSort::Connections => NetworkSort::Total,
+        }
+    }
+
+    /// Zostupné zoradenie procesov; pri zhode rozhoduje celková prevádzka
+    /// `connection_count` vráti počet spojení procesu podľa PID
+    pub fn sort(&self, processes: &mut [ModelsProcessInfo], connection_count: impl Fn(u32) -> usize) {
+        let sent = |p: &ModelsProcessInfo| p.network_sent.unwrap_or(0);
+        let recv = |p: &ModelsProcessInfo| p.network_recv.unwrap_or(0);
+        processes.sort_by(|a, b| {
+            let primary = match self {
+                NetworkSort::Total => std::cmp::Ordering::Equal,
+                NetworkSort::Sent => sent(b).cmp(&sent(a)),
+                NetworkSort::Recv => recv(b).cmp(&recv(a)),
+                NetworkSort::Connections => connection_count(b.pid).cmp(&connection_count(a.pid)),
+            };
+            primary.then_with(|| b.network_bytes().cmp(&a.network_bytes()))
+        });
+    }
+}
+
+/// Filter spojení v detaile sieťového procesu podľa protokolu (kláves P)
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum ProtocolFilter {
+    #[default]
+    All,             // Všetky spojenia
+    Tcp,             // Len TCP (aj tcp6)
+    Udp,             // Len UDP (aj udp6)
+}
+
+impl ProtocolFilter {
+    /// Krátky názov pre titulok detailu a správu v päte
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProtocolFilter::All => "all",
+            ProtocolFilter::Tcp => "TCP only",
+            ProtocolFilter::Udp => "UDP only",
+        }
+    }
+
+    /// Ďalší filter v cykle všetky → TCP → UDP (kláves P)
+    pub fn next(&self) -> Self {
+        match self {
+            ProtocolFilter::All => ProtocolFilter::Tcp,
+            ProtocolFilter::Tcp => ProtocolFilter::Udp,
+            ProtocolFilter::Udp => ProtocolFilter::All,
+        }
+    }
+
+    /// Či spojenie prejde filtrom
+    /// Protokol sa porovnáva podľa začiatku bez ohľadu na veľkosť písmen
+    /// (`ss` vracia napr. `tcp6`, netstat `TCPv6`)
+    pub fn matches(&self, connection: &NetworkConnection) -> bool {
+        let prefix = match self {
+            ProtocolFilter::All => return true,
+            ProtocolFilter::Tcp => "tcp",
+            ProtocolFilter::Udp => "udp",
+        };
+        connection.protocol.get(..prefix.len())
+            .is_some_and(|start| start.eq_ignore_ascii_case(prefix))
+    }
 }
 
 /// Veľkosť histórie pre grafy (v počte záznamov)
 /// Každý záznam predstavuje jednu sekundu
 pub const HISTORY_SIZE: usize = 30;
 
+/// Percento ako záznam histórie grafu, orezané na 0 - 100
+/// (NaN z neplatnej hodnoty dá 0)
+fn history_percent(percent: f64) -> u64 {
+    percent.clamp(0.0, 100.0) as u64
+}
+
+/// Najviac procesov v pohľade Swap
+pub const SWAP_VIEW_LIMIT: usize = 50;
+
 /// Hlavná štruktúra aplikácie - obsahuje všetok stav TUI
 /// Táto štruktúra sa pravidelne aktualizuje a renderuje
 pub struct TuiApp {
     // ========== ZÁKLADNÝ STAV ==========
     pub mode: Mode,               // Aktuálny režim zobrazenia
     pub should_quit: bool,        // Príznak pre ukončenie aplikácie
+    pub palette: CommandPalette,  // Paleta príkazov (klávesa `:`)
+    pub config: TuiArgs,          // Nastavenia z príkazového riadku
+    pub temperature_thresholds: TemperatureThresholds,  // Prahy úrovne varovania teplôt
+    pub temperature_colors: TemperatureThresholds,      // Farebné pásma teplôt (`--temp-colors`)
+    pub cpu_thresholds: CpuThresholds,                  // Prahy pre farby CPU procesov
+    pub status: Option<StatusMessage>,  // Správa v päte (napr. potvrdenie kopírovania)
+    pub memory_metric: MemoryMetric,    // Zobrazená pamäť procesov (RSS / virtuálna)
+    clipboard: ProcessClipboard,        // Systémová schránka (vytvorí sa pri prvom použití)
+    pub db: DbState,                    // Stav ukladania do databázy (pripája sa na pozadí)
     
     // ========== MONITOROVACÍ SERVIS ==========
     /// Zdieľaný monitor chránený mutexom
@@ -56,12 +174,34 @@ pub struct TuiApp {
     
     // ========== SYSTÉMOVÉ INFORMÁCIE ==========
     pub system_info: SystemInfo,  // Statické informácie o systéme
+    pub about_info: Option<app_system_info::SystemInfo>,  // Podrobnosti pre About (zisťujú sa pri prvom otvorení)
     pub metrics: Option<SystemMetrics>,  // Aktuálne metriky (CPU, RAM, sieť)
     pub gpu_info: Option<GpuInfo>,       // Informácie o GPU
+    pub total_process_cpu: f32,          // Súčet CPU všetkých procesov (podiel príkonu)
     
     // ========== PROCESY ==========
     pub top_processes: Vec<ModelsProcessInfo>,  // Zoznam najnáročnejších procesov
+    pub process_count: usize,                   // Počet zobrazených procesov
     pub process_list_state: ListState,          // Stav navigácie v zozname procesov
+    pub watched_tree: Option<ProcessTree>,      // Strom pri `--watch-pid` (None = proces skončil)
+    pub system_group_expanded: bool,            // Rozbalený riadok "[system processes]"
+    pub system_members_shown: usize,            // Počet rozbalených systémových procesov za súhrnným riadkom
+    pub selected_pids: HashSet<u32>,            // Procesy vybrané medzerníkom pre hromadné akcie
+    pub pending_kill: Option<Vec<(u32, String)>>,  // Procesy čakajúce na potvrdenie ukončenia (y/n)
+    process_refresh: ProcessRefreshTimer,       // Obnova zoznamov procesov (`--process-refresh-ms`)
+    
+    // ========== STROM PROCESOV ==========
+    pub tree_rows: Vec<TreeRow>,                // Viditeľné riadky stromu procesov
+    pub tree_state: ListState,                  // Stav navigácie v strome
+    pub collapsed_pids: HashSet<u32>,           // Zbalené uzly stromu
+    
+    // ========== RAST PAMÄTE ==========
+    pub memory_growth: Vec<MemoryGrowth>,       // Procesy s rastúcou pamäťou (od najrýchlejšieho)
+    pub memory_growth_state: ListState,         // Stav navigácie v zozname
+    
+    // ========== SWAP ==========
+    pub swap_processes: Vec<ModelsProcessInfo>,     // Procesy so swapom (od najväčšieho, len v pohľade Swap)
+    pub swap_state: ListState,                  // Stav navigácie v zozname
     
     // ========== HISTÓRIA PRE GRAFY ==========
     /// Historické dáta pre časové grafy
@@ -70,16 +210,33 @@ pub struct TuiApp {
     pub ram_history: Vec<u64>,     // História využitia RAM (%)
     pub disk_history: Vec<u64>,    // História využitia disku (%)
     pub gpu_history: Vec<u64>,     // História využitia GPU (%)
+    pub cpu_core_history: Vec<VecDeque<u64>>,  // História využitia každého logického jadra (%, `--core-history` vzoriek)
+    pub fit_sparklines: bool,      // Prispôsobenie grafov histórie ich šírke (kláves F)
+    pub raw_bytes: bool,           // Pamäť a disk v presných bajtoch namiesto GB/MB (kláves B)
+    pub change_flash: ChangeFlash, // Gauge s výraznou zmenou od posledného obnovenia
+    pub peaks: SessionPeaks,       // Najvyššie hodnoty metrík od štartu / vymazania histórie
+    pub refresh_ticker: RefreshTicker,  // Spinner a čas posledného úspešného zberu
     
     // ========== SIETOVÉ DÁTA ==========
     pub network_sent_history: Vec<f64>,     // História odoslaných dát (KB/s)
     pub network_recv_history: Vec<f64>,     // História prijatých dát (KB/s)
-    pub network_sent_total: f64,            // Celkové odoslané dáta (KB/s)
-    pub network_recv_total: f64,            // Celkové prijaté dáta (KB/s)
+    pub network_sent_rate: f64,             // Aktuálna rýchlosť odosielania všetkých procesov (KB/s, nie súčet bajtov)
+    pub network_recv_rate: f64,             // Aktuálna rýchlosť prijímania všetkých procesov (KB/s, nie súčet bajtov)
+    pub network_boot_totals: (u64, u64),    // Bajty (odoslané, prijaté) rozhraní od štartu systému
+    network_session_start: Option<(u64, u64)>,  // Súčty rozhraní na začiatku relácie (alebo po vymazaní histórie)
     pub top_network_processes: Vec<ModelsProcessInfo>,  // Procesy so sieťovou aktivitou
-    pub network_connections: Vec<NetworkConnection>,     // Aktívne sieťové spojenia
+    pub network_process_count: usize,                   // Počet zobrazených sieťových procesov
+    pub network_connections: ConnectionsResult,         // Aktívne sieťové spojenia (+ poznámka)
+    pub connection_counts: HashMap<u32, usize>,         // Počet spojení podľa PID
+    pub network_sort: NetworkSort,                      // Poradie sieťových procesov
+    pub compact_network: bool,                          // Kompaktné riadky sieťových procesov (kláves W)
+    connection_provider: Option<Arc<dyn ConnectionProvider>>,  // Zdroj spojení pre aktuálny OS (zdieľaný s vláknom zberu)
+    collector: Collector<Option<MonitorSample>>,        // Zber dát s časovým limitom (`--collect-timeout-ms`)
     pub network_process_state: ListState,               // Stav navigácie v sieťových procesoch
     pub network_mode_detail: Option<String>,            // Detailný pohľad na sieťový proces
+    pub connection_selected: usize,                     // Vybrané spojenie v detaile (posun zoznamu spojení)
+    pub connection_filter: ProtocolFilter,              // Filter spojení v detaile podľa protokolu (kláves P)
+    pub link_speed_mbps: Option<u64>,                   // Rýchlosť linky pre škálovanie (ak je známa)
 }
 
 impl TuiApp {
@@ -87,49 +244,103 @@ impl TuiApp {
     ///
     /// # Argumenty
     /// * `monitor` - Zdieľaný monitorovací servis
+    /// * `config` - Nastavenia TUI (teplotné prahy, ...)
+    /// * `db` - Stav pripojenia k databáze (pri `--save-metrics` sa pripája na pozadí)
     ///
     /// # Inicializácia
     /// * Nastaví základný stav aplikácie
     /// * Získa statické informácie o systéme
     /// * Inicializuje prázdne histórie
-    pub fn new(monitor: Arc<Mutex<SystemMonitor>>) -> Self {
-        use whoami::fallible;
-        
-        // Získanie hostname s ošetrením chýb
-        let hostname = fallible::hostname()
-            .unwrap_or_else(|_| "Unknown".to_string());
-        
+    pub fn new(monitor: Arc<Mutex<SystemMonitor>>, config: TuiArgs, db: DbState) -> Self {
+        let collect_timeout = Duration::from_millis(config.collect_timeout_ms);  // Pred presunom `config`
+        let process_refresh = Duration::from_millis(config.process_refresh_ms);
         // Základné informácie o systéme
         let system_info = SystemInfo {
-            hostname,
+            hostname: app_system_info::get_hostname(),  // Nikdy prázdny
             os_name: format!("{} {}", whoami::platform(), whoami::arch()),
             cpu_name: "Unknown CPU".to_string(),
             gpu_name: "Unknown GPU".to_string(),
         };
         
+        // Neplatné prahy sa odmietajú už v run_tui_mode, tu stačí fallback
+        let temperature_thresholds = config.temperature_thresholds().unwrap_or_default();
+        let temperature_colors = config.temperature_colors().unwrap_or(TemperatureThresholds::color_bands());
+        let cpu_thresholds = config.cpu_thresholds().unwrap_or_default();
+        
+        // Obnovenie stavu z predchádzajúceho spustenia (ak nie je vypnuté)
+        let ui_state = if config.no_save_state { UiState::default() } else { UiState::load() };
+        let memory_metric = config.memory_metric;
+        
+        // Počet sieťových procesov z príkazového riadku má prednosť pred uloženým
+        let network_process_count = config.network_process_count
+            .map(|count| count as usize)
+            .unwrap_or(ui_state.network_process_count);
+        
         // Konštrukcia aplikácie s predvolenými hodnotami
         Self {
-            mode: Mode::Overview,
+            mode: ui_state.last_view.mode(),
             should_quit: false,
+            palette: CommandPalette::default(),
+            config,
+            temperature_thresholds,
+            temperature_colors,
+            cpu_thresholds,
+            status: None,
+            memory_metric,
+            clipboard: ProcessClipboard::default(),
+            db,
             monitor,
             system_info,
+            about_info: None,
             metrics: None,
             gpu_info: None,
+            total_process_cpu: 0.0,
             top_processes: Vec::new(),
+            process_count: ui_state.process_count,
             process_list_state: ListState::default(),
+            watched_tree: None,
+            system_group_expanded: false,
+            system_members_shown: 0,
+            tree_rows: Vec::new(),
+            tree_state: ListState::default(),
+            collapsed_pids: HashSet::new(),
+            selected_pids: HashSet::new(),
+            pending_kill: None,
+            process_refresh: ProcessRefreshTimer::new(process_refresh),
+            memory_growth: Vec::new(),
+            memory_growth_state: ListState::default(),
+            swap_processes: Vec::new(),
+            swap_state: ListState::default(),
+            fit_sparklines: false,
+            raw_bytes: false,
             cpu_history: Vec::with_capacity(HISTORY_SIZE),
             ram_history: Vec::with_capacity(HISTORY_SIZE),
             disk_history: Vec::with_capacity(HISTORY_SIZE),
             gpu_history: Vec::with_capacity(HISTORY_SIZE),
+            cpu_core_history: Vec::new(),
+            change_flash: ChangeFlash::default(),
+            peaks: SessionPeaks::default(),
+            refresh_ticker: RefreshTicker::default(),
             
             network_sent_history: Vec::with_capacity(HISTORY_SIZE),
             network_recv_history: Vec::with_capacity(HISTORY_SIZE),
-            network_sent_total: 0.0,
-            network_recv_total: 0.0,
+            network_sent_rate: 0.0,
+            network_recv_rate: 0.0,
+            network_boot_totals: (0, 0),
+            network_session_start: None,
             top_network_processes: Vec::new(),
-            network_connections: Vec::new(),
+            network_process_count,
+            network_connections: ConnectionsResult::default(),
+            connection_counts: HashMap::new(),
+            network_sort: NetworkSort::default(),
+            compact_network: false,
+            connection_provider: platform_provider().map(Arc::from),
+            collector: Collector::new(collect_timeout),
             network_process_state: ListState::default(),
             network_mode_detail: None,
+            connection_selected: 0,
+            connection_filter: ProtocolFilter::default(),
+            link_speed_mbps: detect_link_speed_mbps(),
         }
     }
     
@@ -144,40 +355,78 @@ impl TuiApp {
     pub fn update(&mut self) {
         // ========== ZÍSKANIE DÁT Z MONITORA ==========
         // Synchronizovaný prístup k monitoru cez mutex
-        let (metrics_result, top_processes_result, gpu_info_result, network_stats) = {
-            if let Ok(mut monitor) = self.monitor.lock() {
-                let metrics = Some(monitor.get_metrics_for_db());
-                let processes = monitor.get_top_processes(20);
-                let gpu_info = monitor.get_gpu_info();
-                let network_stats = monitor.get_network_stats_for_processes();
-                
-                (metrics, processes, gpu_info, network_stats)
-            } else {
-                // Fallback ak sa nepodarí získať zámok
-                (None, Vec::new(), None, HashMap::new())
+        // Zber beží na pracovnom vlákne - zaseknuté volanie OS nezablokuje UI
+        let watch_pid = self.config.watch_pid;
+        let monitor = Arc::clone(&self.monitor);
+        let provider = self.connection_provider.clone();
+        // Zoznamy procesov len v intervale `--process-refresh-ms`, gauge pri každom zbere
+        let process_count = self.process_refresh.is_due(Instant::now()).then_some(self.process_count);
+        let swap_limit = (self.mode == Mode::Swap).then_some(SWAP_VIEW_LIMIT);
+        let outcome = self.collector.collect(move || {
+            collect_sample(&monitor, provider.as_deref(), process_count, watch_pid, swap_limit)
+        });
+        let sample = match outcome {
+            CollectOutcome::Ready(sample) => sample,
+            CollectOutcome::TimedOut => {
+                // Predchádzajúce dáta ostávajú, správa sa obnovuje, kým zber nedobehne
+                self.status = Some(StatusMessage::error(format!(
+                    "Data collection timed out after {} ms, showing previous data",
+                    self.collector.timeout().as_millis()
+                )));
+                return;
             }
+            CollectOutcome::Failed => None,  // Vlákno zberu spanikárilo - rovnako ako chyba zámku
+        };
+        if sample.is_some() {
+            self.refresh_ticker.record_success(Instant::now());
+        }
+        
+        let (metrics_result, gpu_info_result, network_stats, interface_totals, total_process_cpu, core_usages, process_sample) = match sample {
+            Some(s) => (Some(s.metrics), s.gpu_info, s.network_stats, Some(s.interface_totals), s.total_process_cpu, Some(s.core_usages), s.processes),
+            // Fallback ak sa nepodarí získať zámok - zoznamy procesov sa vyprázdnia
+            None => (None, None, HashMap::new(), None, 0.0, None, Some(ProcessSample::default())),
         };
         
         // ========== AKTUALIZÁCIA ZÁKLADNÝCH DÁT ==========
         self.metrics = metrics_result;
-        self.top_processes = top_processes_result.clone();
+        self.total_process_cpu = total_process_cpu;
         self.gpu_info = gpu_info_result;
         
-        // ========== ZÍSKANIE SIETOVÝCH SPOJENÍ ==========
-        self.network_connections = self.get_real_network_connections(&top_processes_result);
+        // ========== ZOZNAMY PROCESOV ==========
+        // Medzi obnovami ostávajú predchádzajúce zoznamy
+        if let Some(process_sample) = process_sample {
+            self.process_refresh.record(Instant::now());
+            self.update_processes(process_sample);
+        }
         
         // ========== AKTUALIZÁCIA HISTÓRIE ==========
         if let Some(metrics) = &self.metrics {
+            // Zvýraznenie gauge s výraznou zmenou (na jedno obnovenie)
+            self.change_flash.record(GaugeMetric::Cpu, metrics.cpu_usage);
+            self.change_flash.record(GaugeMetric::Ram, metrics.memory_percent());
+            if metrics.disk_total > 0 {
+                self.change_flash.record(GaugeMetric::Disk, metrics.disk_percent());
+            }
+            
+            // Vrcholy relácie (nedostupná RAM ani neznámy disk sa nezapočítajú)
+            self.peaks.record(GaugeMetric::Cpu, metrics.cpu_usage);
+            if metrics.is_memory_known() {
+                self.peaks.record(GaugeMetric::Ram, metrics.memory_percent());
+            }
+            if metrics.disk_total > 0 {
+                self.peaks.record(GaugeMetric::Disk, metrics.disk_percent());
+            }
+            
             // CPU história - priame percento
             self.cpu_history.push(metrics.cpu_usage as u64);
             
-            // RAM história - výpočet percenta z celkovej pamäte
-            self.ram_history.push(((metrics.memory_used as f64 / metrics.memory_total as f64) * 100.0) as u64);
+            // RAM história - percento z celkovej pamäte
+            // Nulová celková pamäť (niektoré kontajnery) dá 0, hodnota sa oreže na 0 - 100
+            self.ram_history.push(history_percent(metrics.memory_percent()));
             
             // Disk história - ak je dostupná informácia o disku
             if metrics.disk_total > 0 {
-                let disk_percent = (metrics.disk_used as f64 / metrics.disk_total as f64) * 100.0;
-                self.disk_history.push(disk_percent as u64);
+                self.disk_history.push(history_percent(metrics.disk_percent()));
             }
             
             // Orezanie histórie na maximálnu veľkosť
@@ -186,204 +435,117 @@ impl TuiApp {
             if self.disk_history.len() > HISTORY_SIZE { self.disk_history.remove(0); }
         }
         
+        // História jadier len z úspešného merania (prázdny zoznam by ju zmazal)
+        if let Some(usages) = &core_usages {
+            record_core_usage(&mut self.cpu_core_history, usages, self.config.core_history as usize);
+        }
+        
         // ========== GPU HISTÓRIA ==========
         if let Some(gpu_info) = &self.gpu_info {
+            self.change_flash.record(GaugeMetric::Gpu, gpu_info.usage);
+            self.peaks.record(GaugeMetric::Gpu, gpu_info.usage);
             self.gpu_history.push(gpu_info.usage as u64);
             if self.gpu_history.len() > HISTORY_SIZE { self.gpu_history.remove(0); }
         }
         
         // ========== SIETOVÉ DÁTA ==========
         self.update_network_data(network_stats);
+        if let Some(totals) = interface_totals {
+            self.network_boot_totals = totals;
+            self.network_session_start.get_or_insert(totals);  // Prvá vzorka = začiatok relácie
+        }
     }
     
-    /// Získa reálne sieťové spojenia procesov
-    /// Implementácia je špecifická pre jednotlivé OS
-    ///
-    /// # Argumenty
-    /// * `processes` - Zoznam procesov na spárovanie so spojeniami
-    ///
-    /// # Platformy
-    /// - Windows: Používa `netstat -ano`
-    /// - Linux: Používa `ss -tuna` alebo `netstat -tuna`
-    /// - macOS: Podobné ako Linux
-    fn get_real_network_connections(&self, processes: &[ModelsProcessInfo]) -> Vec<NetworkConnection> {
-        let mut connections = Vec::new();
-        
-        // Platformovo špecifická implementácia
-        #[cfg(target_os = "windows")]
-        {
-            connections = self.get_windows_connections(processes);
+    /// Aktualizuje zoznamy procesov, spojenia a strom z jedného zberu
+    fn update_processes(&mut self, sample: ProcessSample) {
+        // Pri sledovaní PID sa zoznam procesov obmedzí na sledovaný strom
+        self.top_processes = if self.config.watch_pid.is_some() {
+            sample.tree.as_ref().map(|t| t.processes.clone()).unwrap_or_default()
+        } else {
+            sample.processes.clone()
+        };
+        self.expand_system_group(sample.system_members);
+        dedupe_by_pid(&mut self.top_processes);
+        // Ukončené procesy a procesy mimo zoznamu z výberu vypadnú
+        let listed: HashSet<u32> = selectable_pids(&self.top_processes).collect();
+        self.selected_pids.retain(|pid| listed.contains(pid));
+        self.watched_tree = sample.tree;
+        self.update_memory_growth(sample.memory_growth);
+        if let Some(swap_processes) = sample.swap_processes {
+            self.update_swap_processes(swap_processes);
         }
         
-        #[cfg(target_os = "linux")]
-        {
-            connections = self.get_linux_connections(processes);
-        }
+        // ========== ZÍSKANIE SIETOVÝCH SPOJENÍ ==========
+        self.network_connections = resolve_connections(sample.connections, &sample.processes);
+        self.connection_counts = self.network_connections.count_by_pid();
         
-        #[cfg(target_os = "macos")]
-        {
-            connections = self.get_macos_connections(processes);
-        }
+        // Sieťový zoznam z toho istého zberu ako zoznam procesov (rovnaké PID a hodnoty)
+        // až po spočítaní spojení - poradie podľa spojení ich potrebuje
+        self.rebuild_top_network_processes();
         
-        // Fallback ak sa nepodarilo získať reálne spojenia
-        if connections.is_empty() {
-            self.get_fallback_connections(processes)
-        } else {
-            connections
-        }
-    }
-    
-    /// Získa sieťové spojenia na Windows pomocou netstat
-    fn get_windows_connections(&self, processes: &[ModelsProcessInfo]) -> Vec<NetworkConnection> {
-        let mut connections = Vec::new();
-        
-        // Spustenie netstat na získanie TCP spojení s PID
-        match Command::new("netstat")
-            .args(&["-ano", "-p", "TCP"])
-            .output() 
-        {
-            Ok(output) => {
-                let output_str = String::from_utf8_lossy(&output.stdout);
-                
-                // Parsovanie výstupu riadok po riadku
-                for line in output_str.lines() {
-                    if line.contains("TCP") {
-                        let parts: Vec<&str> = line.split_whitespace().collect();
-                        if parts.len() >= 5 {
-                            // Extrakcia PID z piatého stĺpca
-                            if let Ok(pid_str) = parts[4].parse::<u32>() {
-                                // Nájdenie procesu podľa PID
-                                if let Some(process) = processes.iter().find(|p| p.pid == pid_str) {
-                                    let local_addr = parts[1].to_string();
-                                    let remote_addr = parts[2].to_string();
-                                    let state = parts[3].to_string();
-                                    
-                                    // Filtrovanie pasívnych spojení
-                                    if state != "LISTENING" && remote_addr != "[::]:0" {
-                                        connections.push(NetworkConnection {
-                                            process_name: process.name.clone(),
-                                            local_address: local_addr,
-                                            remote_address: remote_addr,
-                                            protocol: "TCP".to_string(),
-                                            state,
-                                            pid: pid_str,
-                                        });
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-            }
-            Err(_) => {
-                // netstat nie je dostupný - použije sa fallback
-            }
+        // ========== STROM PROCESOV ==========
+        // Všetky procesy sa načítavajú len keď je strom zobrazený
+        if self.mode == Mode::ProcessTree {
+            self.rebuild_process_tree();
         }
-        
-        connections
-    }
-    
-    /// Získa sieťové spojenia na Linux pomocou ss alebo netstat
-    fn get_linux_connections(&self, processes: &[ModelsProcessInfo]) -> Vec<NetworkConnection> {
-        let mut connections = Vec::new();
-        
-        // Možné príkazy v poradí pokusov
-        let commands = vec!["ss -tuna", "netstat -tuna"];
-        
-        for cmd in commands {
-            if let Ok(output) = Command::new("sh")
-                .arg("-c")
-                .arg(format!("{} 2>/dev/null", cmd))
-                .output()
-            {
-                let output_str = String::from_utf8_lossy(&output.stdout);
-                
-                // Preskočenie hlavičky
-                for line in output_str.lines().skip(1) {
-                    let parts: Vec<&str> = line.split_whitespace().collect();
-                    if parts.len() >= 6 {
-                        let state = parts[0];
-                        let local_addr = parts[4];
-                        let remote_addr = parts[5];
-                        
-                        // Filtrovanie pasívnych spojení
-                        if state != "LISTEN" && !remote_addr.ends_with(":*") {
-                            // Použitie lsof na získanie PID pre spojenie
-                            if let Ok(lsof_output) = Command::new("lsof")
-                                .args(&["-i", &format!("@{}", remote_addr.split(':').next().unwrap_or(""))])
-                                .output()
-                            {
-                                let lsof_str = String::from_utf8_lossy(&lsof_output.stdout);
-                                for lsof_line in lsof_str.lines().skip(1) {
-                                    let lsof_parts: Vec<&str> = lsof_line.split_whitespace().collect();
-                                    if lsof_parts.len() >= 2 {
-                                        if let (Ok(pid), process_name) = (lsof_parts[1].parse::<u32>(), lsof_parts[0]) {
-                                            if let Some(process) = processes.iter().find(|p| p.pid == pid) {
-                                                connections.push(NetworkConnection {
-                                                    process_name: process.name.clone(),
-                                                    local_address: local_addr.to_string(),
-                                                    remote_address: remote_addr.to_string(),
-                                                    protocol: "TCP".to_string(),
-                                                    state: state.to_string(),
-                                                    pid,
-                                                });
-                                            }
-                                        }
-                                    }
-                                }
-                            }
-                        }
-                    }
-                }
-                
-                // Ak sme našli spojenia, ukončíme hľadanie
-                if !connections.is_empty() {
-                    break;
-                }
-            }
+    }
+    
+    /// Stav na uloženie pri ukončení aplikácie
+    pub fn ui_state(&self) -> UiState {
+        UiState {
+            last_view: SavedView::from_mode(self.mode),
+            process_count: self.process_count,
+            network_process_count: self.network_process_count,
         }
-        
-        connections
     }
     
-    /// Získa sieťové spojenia na macOS (podobné ako Linux)
-    fn get_macos_connections(&self, processes: &[ModelsProcessInfo]) -> Vec<NetworkConnection> {
-        self.get_linux_connections(processes)
+    /// Aktualizuje zoznam procesov s rastúcou pamäťou
+    /// Výber zostáva na rovnakom PID, ak je proces stále v zozname
+    fn update_memory_growth(&mut self, memory_growth: Vec<MemoryGrowth>) {
+        let selected_pid = self.memory_growth_state.selected()
+            .and_then(|i| self.memory_growth.get(i))
+            .map(|growth| growth.pid);
+        self.memory_growth = memory_growth;
+        
+        let index = selected_pid
+            .and_then(|pid| self.memory_growth.iter().position(|growth| growth.pid == pid))
+            .unwrap_or(0);
+        self.memory_growth_state.select(if self.memory_growth.is_empty() { None } else { Some(index) });
     }
     
-    /// Fallback metóda pre získanie sieťových spojení
-    /// Používa sa ak OS-špecifické metódy zlyhajú
-    fn get_fallback_connections(&self, processes: &[ModelsProcessInfo]) -> Vec<NetworkConnection> {
-        let mut connections = Vec::new();
+    /// Aktualizuje zoznam procesov so swapom
+    /// Výber zostáva na rovnakom PID, ak je proces stále v zozname
+    fn update_swap_processes(&mut self, swap_processes: Vec<ModelsProcessInfo>) {
+        let selected_pid = self.swap_state.selected()
+            .and_then(|i| self.swap_processes.get(i))
+            .map(|process| process.pid);
+        self.swap_processes = swap_processes;
         
-        // Zostavenie spojení z procesov so sieťovou aktivitou
-        for proc in processes.iter().take(10) {
-            // Kontrola sieťovej aktivity procesu
-            if proc.network_sent.unwrap_or(0) > 100 || proc.network_recv.unwrap_or(0) > 100 {
-                connections.push(NetworkConnection {
-                    process_name: proc.name.clone(),
-                    local_address: format!("PID:{}", proc.pid),
-                    remote_address: "Network activity detected".to_string(),
-                    protocol: "DATA".to_string(),
-                    state: "ACTIVE".to_string(),
-                    pid: proc.pid,
-                });
-            }
+        let index = selected_pid
+            .and_then(|pid| self.swap_processes.iter().position(|process| process.pid == pid))
+            .unwrap_or(0);
+        self.swap_state.select(if self.swap_processes.is_empty() { None } else { Some(index) });
+    }
+    
+    /// Znovu zostaví riadky stromu procesov z aktuálnych dát monitora
+    /// Výber zostáva na rovnakom PID, ak proces stále existuje
+    fn rebuild_process_tree(&mut self) {
+        // Oneskorený zber môže monitor stále držať - strom počká na ďalšie obnovenie
+        if self.collector.is_pending() {
+            return;
         }
+        let processes = match self.monitor.lock() {
+            Ok(monitor) => monitor.get_processes(),
+            Err(_) => return,  // Pri chybe zámku zostanú staré riadky
+        };
         
-        // Informačná správa ak neboli nájdené žiadne spojenia
-        if connections.is_empty() {
-            connections.push(NetworkConnection {
-                process_name: "System".to_string(),
-                local_address: "N/A".to_string(),
-                remote_address: "Real connections require elevated privileges".to_string(),
-                protocol: "INFO".to_string(),
-                state: "UNAVAILABLE".to_string(),
-                pid: 0,
-            });
-        }
+        let selected_pid = self.selected_tree_row().map(|row| row.process.pid);
+        self.tree_rows = build_tree_rows(&processes, &self.collapsed_pids);
         
-        connections
+        let index = selected_pid
+            .and_then(|pid| self.tree_rows.iter().position(|row| row.process.pid == pid))
+            .unwrap_or(0);
+        self.tree_state.select(if self.tree_rows.is_empty() { None } else { Some(index) });
     }
     
     /// Aktualizuje sieťové dáta a štatistiky
@@ -401,25 +563,84 @@ impl TuiApp {
             if self.network_recv_history.len() > HISTORY_SIZE { self.network_recv_history.remove(0); }
         }
         
-        // ========== TOP SIETOVÉ PROCESY ==========
-        // Klonovanie a triedenie procesov podľa celkovej sieťovej aktivity
-        let mut network_procs: Vec<ModelsProcessInfo> = self.top_processes.clone();
-        network_procs.sort_by(|a, b| {
-            let a_total = a.network_sent.unwrap_or(0) + a.network_recv.unwrap_or(0);
-            let b_total = b.network_sent.unwrap_or(0) + b.network_recv.unwrap_or(0);
-            b_total.cmp(&a_total)  // Zostupné triedenie
-        });
-        
-        // Výber 15 najaktívnejších procesov
-        self.top_network_processes = network_procs.into_iter().take(15).collect();
-        
-        // ========== CELKOVÉ SIETOVÉ ŠTATISTIKY ==========
+        // ========== AKTUÁLNA SIETOVÁ RÝCHLOSŤ ==========
+        // Súčet za sekundu cez procesy - bajty za reláciu a od štartu sú v `network_boot_totals`
         let total_sent: u64 = network_stats.values().map(|&(sent, _)| sent).sum();
         let total_recv: u64 = network_stats.values().map(|&(_, recv)| recv).sum();
         
         // Konverzia na KB/s
-        self.network_sent_total = total_sent as f64 / 1024.0;
-        self.network_recv_total = total_recv as f64 / 1024.0;
+        self.network_sent_rate = total_sent as f64 / 1024.0;
+        self.network_recv_rate = total_recv as f64 / 1024.0;
+    }
+    
+    /// Výber najaktívnejších sieťových procesov z aktuálneho zoznamu procesov
+    /// Počet určuje `network_process_count`; každé PID je v zozname najviac raz
+    fn rebuild_top_network_processes(&mut self) {
+        // Klonovanie a triedenie procesov podľa zvoleného poradia
+        let mut network_procs: Vec<ModelsProcessInfo> = self.top_processes
+            .iter()
+            .filter(|process| process.pid != SYSTEM_GROUP_PID)  // Súhrnný riadok nie je proces
+            .cloned()
+            .collect();
+        self.network_sort.sort(&mut network_procs, |pid| self.connection_count(pid));
+        
+        self.top_network_processes = network_procs.into_iter().take(self.network_process_count).collect();
+        
+        // Výber nesmie ostať za koncom skráteného zoznamu
+        if let Some(selected) = self.network_process_state.selected() {
+            if selected >= self.top_network_processes.len() {
+                self.network_process_state.select(self.top_network_processes.len().checked_sub(1));
+            }
+        }
+    }
+    
+    /// Počet aktívnych spojení procesu (0, ak žiadne nemá)
+    pub fn connection_count(&self, pid: u32) -> usize {
+        self.connection_counts.get(&pid).copied().unwrap_or(0)
+    }
+
+    /// Ďalšie poradie sieťových procesov (celkom / odoslané / prijaté / spojenia)
+    pub fn toggle_network_sort(&mut self) {
+        self.network_sort = self.network_sort.next();
+        self.rebuild_top_network_processes();
+        self.status = Some(StatusMessage::info(format!(
+            "Sorting network processes by {}", self.network_sort.label()
+        )));
+    }
+
+    /// Prepnutie prispôsobenia grafov histórie ich šírke
+    /// Na širokom termináli sa história roztiahne, na úzkom zhustí
+    pub fn toggle_fit_sparklines(&mut self) {
+        self.fit_sparklines = !self.fit_sparklines;
+        let state = if self.fit_sparklines { "on" } else { "off" };
+        self.status = Some(StatusMessage::info(format!("Fit history graphs to width: {}", state)));
+    }
+
+    /// Prepnutie zobrazenia pamäte a disku medzi GB/MB a presnými bajtmi
+    pub fn toggle_raw_bytes(&mut self) {
+        self.raw_bytes = !self.raw_bytes;
+        let state = if self.raw_bytes { "raw bytes" } else { "formatted" };
+        self.status = Some(StatusMessage::info(format!("Memory and disk sizes: {}", state)));
+    }
+
+    /// Prepnutie kompaktných riadkov sieťových procesov
+    /// Úzky terminál zobrazí kompaktné riadky vždy
+    pub fn toggle_compact_network(&mut self) {
+        self.compact_network = !self.compact_network;
+        let state = if self.compact_network { "on" } else { "off (automatic on narrow terminals)" };
+        self.status = Some(StatusMessage::info(format!("Compact network rows: {}", state)));
+    }
+
+    /// Zmena počtu procesov v sieťovom pohľade o `delta` (1 - 50)
+    /// Zoznam sa hneď prepočíta z už načítaných procesov
+    pub fn adjust_network_process_count(&mut self, delta: isize) {
+        self.network_process_count = self.network_process_count
+            .saturating_add_signed(delta)
+            .clamp(1, MAX_NETWORK_PROCESS_COUNT);
+        self.rebuild_top_network_processes();
+        self.status = Some(StatusMessage::info(format!(
+            "Showing up to {} network processes", self.network_process_count
+        )));
     }
     
     // ========== PUBLICKÉ METÓDY PRE OVLÁDANIE APLIKÁCIE ==========
@@ -429,8 +650,9 @@ impl TuiApp {
         self.should_quit = true;
     }
     
-    /// Ručná aktualizácia dát (rovnaká ako automatická)
+    /// Ručná aktualizácia dát vrátane zoznamov procesov (bez ohľadu na `--process-refresh-ms`)
     pub fn refresh(&mut self) {
+        self.process_refresh.force();
         self.update();
     }
     
@@ -439,6 +661,346 @@ impl TuiApp {
         self.mode = Mode::NetworkView;
     }
     
+    /// Prepne aplikáciu do stromového zobrazenia procesov
+    pub fn enter_tree_mode(&mut self) {
+        self.mode = Mode::ProcessTree;
+        self.rebuild_process_tree();
+    }
+    
+    /// Prepne aplikáciu do zoznamu procesov s rastúcou pamäťou
+    pub fn enter_memory_growth_mode(&mut self) {
+        self.mode = Mode::MemoryGrowth;
+    }
+    
+    /// Prepne aplikáciu do zoznamu procesov podľa swapu
+    /// Swap sa zbiera len v tomto pohľade - hneď sa načíta prvý zoznam
+    pub fn enter_swap_mode(&mut self) {
+        self.mode = Mode::Swap;
+        self.refresh();
+    }
+    
+    /// Prepne na obrazovku About
+    /// Hardvér sa zisťuje len pri prvom otvorení (spúšťa napr. `lspci`)
+    pub fn enter_about_mode(&mut self) {
+        if self.about_info.is_none() {
+            self.about_info = Some(get_system_info(self.config.include_removable_disks));
+        }
+        self.mode = Mode::About;
+    }
+    
+    /// Proces vybraný v aktuálnom pohľade (PID, názov)
+    pub fn selected_process(&self) -> Option<(u32, String)> {
+        match self.mode {
+            Mode::Overview | Mode::ProcessDetail => self.process_list_state.selected()
+                .and_then(|i| self.top_processes.get(i))
+                .filter(|p| p.pid != SYSTEM_GROUP_PID)  // Súhrnný riadok nie je proces
+                .map(|p| (p.pid, p.display_name().into_owned())),
+            Mode::NetworkView => self.network_process_state.selected()
+                .and_then(|i| self.top_network_processes.get(i))
+                .map(|p| (p.pid, p.display_name().into_owned())),
+            Mode::ProcessTree => self.selected_tree_row()
+                .map(|row| (row.process.pid, row.process.display_name().into_owned())),
+            Mode::MemoryGrowth => self.memory_growth_state.selected()
+                .and_then(|i| self.memory_growth.get(i))
+                .map(|growth| (growth.pid, name_or_pid(growth.pid, &growth.name).into_owned())),
+            Mode::Swap => self.swap_state.selected()
+                .and_then(|i| self.swap_processes.get(i))
+                .map(|p| (p.pid, p.display_name().into_owned())),
+            Mode::Help | Mode::About => None,
+        }
+    }
+    
+    /// Skopíruje "PID názov" vybraného procesu do systémovej schránky
+    /// V prehľade s výberom (medzerník) sa skopírujú všetky vybrané procesy, každý na riadok
+    /// Výsledok (aj chyba pri chýbajúcej schránke) sa zobrazí v päte
+    pub fn copy_selected_process(&mut self) {
+        let targets = self.process_action_targets();
+        if targets.is_empty() {
+            self.status = Some(StatusMessage::error("No process selected".to_string()));
+            return;
+        }
+        
+        let text = targets.iter()
+            .map(|(pid, name)| process_clipboard_text(*pid, name))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let copied = match targets.as_slice() {
+            [_] => format!("\"{}\"", text),
+            _ => format!("{} processes", targets.len()),
+        };
+        self.status = Some(match self.clipboard.copy(&text) {
+            Ok(()) => StatusMessage::info(format!("Copied {} to clipboard", copied)),
+            Err(e) => StatusMessage::error(e),
+        });
+    }
+    
+    /// Vyžiada ukončenie vybraného procesu, v prehľade s výberom (medzerník) všetkých vybraných
+    /// Dostupné len z palety príkazov; procesy sa ukončia až po potvrdení klávesom `y`
+    pub fn request_kill_selected_processes(&mut self) {
+        let targets = self.process_action_targets();
+        if targets.is_empty() {
+            self.status = Some(StatusMessage::error("No process selected".to_string()));
+            return;
+        }
+        self.pending_kill = Some(targets);
+    }
+    
+    /// Otázka v päte, kým čaká ukončenie procesov na potvrdenie
+    pub fn kill_prompt(&self) -> Option<String> {
+        self.pending_kill.as_deref().map(kill_prompt)
+    }
+    
+    /// Zrušenie čakajúceho ukončenia (`n`, Esc alebo iný kláves)
+    pub fn cancel_kill(&mut self) {
+        if self.pending_kill.take().is_some() {
+            self.status = Some(StatusMessage::info("Kill cancelled".to_string()));
+        }
+    }
+    
+    /// Ukončí procesy potvrdené klávesom `y`; výsledok sa zobrazí v päte
+    pub fn confirm_kill(&mut self) {
+        let Some(targets) = self.pending_kill.take() else {
+            return;
+        };
+        if self.monitor_busy() {
+            self.status = Some(StatusMessage::error(
+                "Data collection is still running, no process was killed".to_string(),
+            ));
+            return;
+        }
+        
+        let killed = match self.monitor.lock() {
+            Ok(monitor) => targets.iter().filter(|(pid, _)| monitor.kill_process(*pid)).count(),
+            Err(_) => {
+                self.status = Some(StatusMessage::error("Monitor is unavailable, no process was killed".to_string()));
+                return;
+            }
+        };
+        
+        self.status = Some(match (targets.as_slice(), killed) {
+            ([(pid, name)], 1) => StatusMessage::info(format!("Killed {} \"{}\"", pid, name)),
+            (_, killed) if killed == targets.len() => StatusMessage::info(format!("Killed {} processes", killed)),
+            (_, killed) => StatusMessage::error(format!(
+                "Could not kill {} of {} processes (permission denied or already exited)",
+                targets.len() - killed, targets.len()
+            )),
+        });
+        self.refresh();  // Ukončené procesy hneď zmiznú zo zoznamu
+    }
+    
+    /// Uloží snímku systému (aktuálne metriky a všetky procesy) do JSON súboru
+    /// v aktuálnom adresári - rovnaký formát ako `system-monitor snapshot`, porovná ju `diff`
+    pub fn save_snapshot(&mut self) {
+        let Some(metrics) = self.metrics.clone() else {
+            self.status = Some(StatusMessage::error("No data collected yet".to_string()));
+            return;
+        };
+        if self.monitor_busy() {
+            return;
+        }
+        let processes = match self.monitor.lock() {
+            Ok(monitor) => monitor.get_processes(),
+            Err(_) => return,
+        };
+        
+        let path = format!("system-monitor-snapshot-{}.json", metrics.timestamp.format("%Y%m%d-%H%M%S"));
+        let written = serde_json::to_string_pretty(&Snapshot { metrics, processes })
+            .map_err(std::io::Error::other)
+            .and_then(|json| std::fs::write(&path, json));
+        self.status = Some(match written {
+            Ok(()) => StatusMessage::info(format!("Snapshot saved to {}", path)),
+            Err(e) => StatusMessage::error(format!("Could not save snapshot: {}", e)),
+        });
+    }
+    
+    /// Procesy pre hromadnú akciu (PID, názov)
+    /// V prehľade vybrané procesy, ak nejaké sú, inak proces vybraný v aktuálnom pohľade
+    pub fn process_action_targets(&self) -> Vec<(u32, String)> {
+        if self.mode != Mode::Overview {
+            return self.selected_process().into_iter().collect();
+        }
+        action_targets(&self.top_processes, &self.selected_pids, self.process_list_state.selected())
+            .into_iter()
+            .map(|process| (process.pid, process.display_name().into_owned()))
+            .collect()
+    }
+    
+    /// Prepnutie výberu procesu pod kurzorom (medzerník v prehľade)
+    pub fn toggle_process_selection(&mut self) {
+        let Some(process) = self.process_list_state.selected().and_then(|i| self.top_processes.get(i)) else {
+            return;
+        };
+        if process.pid == SYSTEM_GROUP_PID {
+            return;  // Súhrnný riadok nie je proces
+        }
+        
+        toggle_pid(&mut self.selected_pids, process.pid);
+        self.status = Some(StatusMessage::info(format!("{} process(es) selected", self.selected_pids.len())));
+    }
+    
+    /// Výber všetkých procesov v zozname, druhým stlačením zrušenie výberu (kláves X)
+    pub fn toggle_select_all(&mut self) {
+        let listed: HashSet<u32> = selectable_pids(&self.top_processes).collect();
+        if !listed.is_empty() && listed.is_subset(&self.selected_pids) {
+            self.selected_pids.clear();
+            self.status = Some(StatusMessage::info("Selection cleared".to_string()));
+        } else {
+            self.selected_pids.extend(listed);
+            self.status = Some(StatusMessage::info(format!("{} process(es) selected", self.selected_pids.len())));
+        }
+    }
+    
+    /// Aktuálna správa v päte (po niekoľkých sekundách zmizne)
+    pub fn active_status(&self) -> Option<&StatusMessage> {
+        self.status.as_ref().filter(|status| status.is_active())
+    }
+    
+    /// Text indikátora obnovenia v rohu päty (`--refresh-indicator`)
+    pub fn refresh_indicator(&self) -> Option<String> {
+        self.refresh_ticker.text(self.config.refresh_indicator, Instant::now())
+    }
+    
+    /// Drží monitor oneskorený zber? Zámok by vtedy zablokoval UI vlákno,
+    /// preto sa akcia odmietne so správou v päte (rovnako ako `rebuild_process_tree`)
+    fn monitor_busy(&mut self) -> bool {
+        let busy = self.collector.is_pending();
+        if busy {
+            self.status = Some(StatusMessage::error(
+                "Data collection is still running, try again in a moment".to_string(),
+            ));
+        }
+        busy
+    }
+    
+    /// Zapne/vypne skrývanie procesov z `--ignore-process` / `--ignore-self`
+    pub fn toggle_ignore_list(&mut self) {
+        if self.monitor_busy() {
+            return;
+        }
+        let toggled = match self.monitor.lock() {
+            Ok(mut monitor) => monitor.toggle_process_filter(),
+            Err(_) => return,
+        };
+        
+        self.status = Some(match toggled {
+            Some(true) => StatusMessage::info("Ignore list on - ignored processes hidden".to_string()),
+            Some(false) => StatusMessage::info("Ignore list off - showing all processes".to_string()),
+            None => StatusMessage::error("No ignore list - use --ignore-process or --ignore-self".to_string()),
+        });
+        
+        if toggled.is_some() {
+            self.refresh();  // Zoznam procesov hneď podľa nového stavu
+        }
+    }
+    
+    /// Zapnutie/vypnutie zoskupenia systémových procesov do jedného riadku
+    pub fn toggle_system_group(&mut self) {
+        if self.monitor_busy() {
+            return;
+        }
+        let enabled = match self.monitor.lock() {
+            Ok(mut monitor) => monitor.toggle_system_group(),
+            Err(_) => return,
+        };
+        
+        self.status = Some(StatusMessage::info(if enabled {
+            "System processes grouped - Enter on the row expands it".to_string()
+        } else {
+            "System processes shown individually".to_string()
+        }));
+        self.refresh();  // Zoznam procesov hneď podľa nového stavu
+    }
+    
+    /// Je vybraný súhrnný riadok "[system processes]"?
+    pub fn system_group_selected(&self) -> bool {
+        self.process_list_state.selected()
+            .and_then(|i| self.top_processes.get(i))
+            .is_some_and(|process| process.pid == SYSTEM_GROUP_PID)
+    }
+    
+    /// Rozbalenie alebo zbalenie súhrnného riadku systémových procesov
+    pub fn toggle_system_group_expanded(&mut self) {
+        self.system_group_expanded = !self.system_group_expanded;
+        self.refresh();
+    }
+    
+    /// Vloženie systémových procesov za súhrnný riadok, ak je rozbalený
+    fn expand_system_group(&mut self, members: Vec<ModelsProcessInfo>) {
+        self.system_members_shown = 0;
+        if !self.system_group_expanded {
+            return;
+        }
+        
+        if let Some(index) = self.top_processes.iter().position(|process| process.pid == SYSTEM_GROUP_PID) {
+            self.system_members_shown = members.len();
+            self.top_processes.splice(index + 1..index + 1, members);
+        }
+    }
+    
+    /// Vymazanie histórie grafov (CPU, RAM, disk, GPU, sieť) a sieťových súčtov
+    /// Po špičke sa tak trend začne kresliť odznova; ďalšie obnovy ho znovu plnia
+    pub fn clear_history(&mut self) {
+        self.cpu_history.clear();
+        self.ram_history.clear();
+        self.disk_history.clear();
+        self.gpu_history.clear();
+        self.cpu_core_history.clear();
+        self.peaks.clear();
+        self.network_sent_history.clear();
+        self.network_recv_history.clear();
+        self.network_sent_rate = 0.0;
+        self.network_recv_rate = 0.0;
+        self.network_session_start = Some(self.network_boot_totals);  // Súčty relácie od nuly
+        
+        self.status = Some(StatusMessage::info("History cleared".to_string()));
+    }
+    
+    /// Bajty (odoslané, prijaté) od spustenia TUI alebo posledného vymazania histórie
+    /// Pri reštarte rozhrania (počítadlá klesnú) sa použije 0
+    pub fn network_session_totals(&self) -> (u64, u64) {
+        let (sent, recv) = self.network_boot_totals;
+        let (start_sent, start_recv) = self.network_session_start.unwrap_or(self.network_boot_totals);
+        (sent.saturating_sub(start_sent), recv.saturating_sub(start_recv))
+    }
+    
+    /// Prepnutie zobrazenej pamäte procesov medzi RSS a virtuálnou
+    pub fn toggle_memory_metric(&mut self) {
+        self.memory_metric = self.memory_metric.toggled();
+        self.status = Some(StatusMessage::info(format!(
+            "Showing {} memory", self.memory_metric.label()
+        )));
+    }
+    
+    /// Vykoná akciu vybranú v palete príkazov
+    /// Používa rovnaké metódy ako klávesové skratky
+    pub fn execute_action(&mut self, action: PaletteAction) {
+        match action {
+            PaletteAction::ShowOverview => self.mode = Mode::Overview,
+            PaletteAction::ShowNetwork => self.enter_network_mode(),
+            PaletteAction::ShowProcessTree => self.enter_tree_mode(),
+            PaletteAction::ShowMemoryGrowth => self.enter_memory_growth_mode(),
+            PaletteAction::ShowSwap => self.enter_swap_mode(),
+            PaletteAction::ProcessDetails => self.activate_selected_process(),
+            PaletteAction::CopyProcess => self.copy_selected_process(),
+            PaletteAction::ToggleIgnoreList => self.toggle_ignore_list(),
+            PaletteAction::ToggleSystemGroup => self.toggle_system_group(),
+            PaletteAction::ClearHistory => self.clear_history(),
+            PaletteAction::ToggleMemoryMetric => self.toggle_memory_metric(),
+            PaletteAction::ToggleNetworkSort => self.toggle_network_sort(),
+            PaletteAction::ToggleCompactNetwork => self.toggle_compact_network(),
+            PaletteAction::ToggleFitSparklines => self.toggle_fit_sparklines(),
+            PaletteAction::ToggleRawBytes => self.toggle_raw_bytes(),
+            PaletteAction::ToggleSelectAll => self.toggle_select_all(),
+            PaletteAction::ToggleConnectionFilter => self.toggle_connection_filter(),
+            PaletteAction::KillProcess => self.request_kill_selected_processes(),
+            PaletteAction::SaveSnapshot => self.save_snapshot(),
+            PaletteAction::ShowAbout => self.enter_about_mode(),
+            PaletteAction::ShowHelp => self.mode = Mode::Help,
+            PaletteAction::Refresh => self.refresh(),
+            PaletteAction::Quit => self.quit(),
+        }
+    }
+    
     // ========== NAVIGÁCIA V PROCESOCH ==========
     
     /// Posunie výber v zozname procesov o jeden krok nahor
@@ -470,6 +1032,16 @@ impl TuiApp {
         self.mode = Mode::ProcessDetail;
     }
     
+    /// Enter v prehľade - súhrnný riadok systémových procesov sa rozbalí/zbalí,
+    /// pri ostatných procesoch sa otvorí detail
+    pub fn activate_selected_process(&mut self) {
+        if self.system_group_selected() {
+            self.toggle_system_group_expanded();
+        } else {
+            self.enter_detail_mode();
+        }
+    }
+    
     /// Návrat z detailného režimu do prehľadu
     pub fn exit_detail_mode(&mut self) {
         self.mode = Mode::Overview;
@@ -498,4 +1070,319 @@ impl TuiApp {
             self.network_process_state.select(Some(new_index));
         }
     }
-}
\ No newline at end of file
+    
+    /// Otvorenie detailu vybraného sieťového procesu (zoznam spojení od začiatku)
+    pub fn open_network_detail(&mut self) {
+        let selected = self.network_process_state.selected()
+            .and_then(|i| self.top_network_processes.get(i));
+        if let Some(process) = selected {
+            self.network_mode_detail = Some(process.name.clone());
+            self.connection_selected = 0;
+        }
+    }
+    
+    /// Posunie výber v spojeniach detailu nahor
+    pub fn previous_connection(&mut self) {
+        self.connection_selected = self.connection_selected.saturating_sub(1);
+    }
+    
+    /// Posunie výber v spojeniach detailu nadol (bez pretočenia na začiatok)
+    pub fn next_connection(&mut self) {
+        let count = self.detail_connection_count();
+        if self.connection_selected + 1 < count {
+            self.connection_selected += 1;
+        }
+    }
+    
+    /// Počet spojení procesu zobrazeného v detaile (po filtri protokolu)
+    fn detail_connection_count(&self) -> usize {
+        let Some(name) = &self.network_mode_detail else {
+            return 0;
+        };
+        self.top_network_processes.iter()
+            .find(|p| &p.name == name)
+            .map_or(0, |p| self.detail_connections(p.pid).len())
+    }
+    
+    /// Spojenia procesu v detaile, ktoré prejdú filtrom protokolu
+    pub fn detail_connections(&self, pid: u32) -> Vec<&NetworkConnection> {
+        self.network_connections.connections.iter()
+            .filter(|conn| conn.pid == pid && self.connection_filter.matches(conn))
+            .collect()
+    }
+    
+    /// Ďalší filter spojení v detaile (všetky / TCP / UDP); zoznam sa vráti na začiatok
+    pub fn toggle_connection_filter(&mut self) {
+        self.connection_filter = self.connection_filter.next();
+        self.connection_selected = 0;
+        self.status = Some(StatusMessage::info(format!(
+            "Showing {} connections", self.connection_filter.label()
+        )));
+    }
+    
+    // ========== NAVIGÁCIA V ZOZNAME RASTU PAMÄTE ==========
+    
+    /// Posunie výber v zozname procesov s rastúcou pamäťou nahor
+    pub fn previous_memory_growth(&mut self) {
+        if !self.memory_growth.is_empty() {
+            let current = self.memory_growth_state.selected();
+            let new_index = current.map_or(0, |i| {
+                if i == 0 { self.memory_growth.len() - 1 } else { i - 1 }
+            });
+            self.memory_growth_state.select(Some(new_index));
+        }
+    }
+    
+    /// Posunie výber v zozname procesov s rastúcou pamäťou nadol
+    pub fn next_memory_growth(&mut self) {
+        if !self.memory_growth.is_empty() {
+            let current = self.memory_growth_state.selected();
+            let new_index = current.map_or(0, |i| {
+                if i >= self.memory_growth.len() - 1 { 0 } else { i + 1 }
+            });
+            self.memory_growth_state.select(Some(new_index));
+        }
+    }
+    
+    // ========== NAVIGÁCIA V ZOZNAME SWAPU ==========
+    
+    /// Posunie výber v zozname procesov so swapom nahor
+    pub fn previous_swap_process(&mut self) {
+        if !self.swap_processes.is_empty() {
+            let current = self.swap_state.selected();
+            let new_index = current.map_or(0, |i| {
+                if i == 0 { self.swap_processes.len() - 1 } else { i - 1 }
+            });
+            self.swap_state.select(Some(new_index));
+        }
+    }
+    
+    /// Posunie výber v zozname procesov so swapom nadol
+    pub fn next_swap_process(&mut self) {
+        if !self.swap_processes.is_empty() {
+            let current = self.swap_state.selected();
+            let new_index = current.map_or(0, |i| {
+                if i >= self.swap_processes.len() - 1 { 0 } else { i + 1 }
+            });
+            self.swap_state.select(Some(new_index));
+        }
+    }
+    
+    // ========== NAVIGÁCIA V STROME PROCESOV ==========
+    
+    /// Aktuálne vybraný riadok stromu
+    pub fn selected_tree_row(&self) -> Option<&TreeRow> {
+        self.tree_state.selected().and_then(|i| self.tree_rows.get(i))
+    }
+    
+    /// Posunie výber v strome procesov nahor
+    pub fn previous_tree_row(&mut self) {
+        if !self.tree_rows.is_empty() {
+            let current = self.tree_state.selected();
+            let new_index = current.map_or(0, |i| {
+                if i == 0 { self.tree_rows.len() - 1 } else { i - 1 }
+            });
+            self.tree_state.select(Some(new_index));
+        }
+    }
+    
+    /// Posunie výber v strome procesov nadol
+    pub fn next_tree_row(&mut self) {
+        if !self.tree_rows.is_empty() {
+            let current = self.tree_state.selected();
+            let new_index = current.map_or(0, |i| {
+                if i >= self.tree_rows.len() - 1 { 0 } else { i + 1 }
+            });
+            self.tree_state.select(Some(new_index));
+        }
+    }
+    
+    /// Zbalí alebo rozbalí potomkov vybraného uzla
+    pub fn toggle_tree_node(&mut self) {
+        let pid = match self.selected_tree_row() {
+            Some(row) if row.child_count > 0 => row.process.pid,
+            _ => return,  // Listy stromu nie je čo zbaliť
+        };
+        
+        if !self.collapsed_pids.remove(&pid) {
+            self.collapsed_pids.insert(pid);
+        }
+        self.rebuild_process_tree();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{sample_process, test_app};
+    use chrono::Utc;
+
+    #[test]
+    fn zero_total_memory_gives_a_zero_history_entry() {
+        let metrics = SystemMetrics::builder(Utc::now()).memory(0, 4_000, 0).build();
+        assert_eq!(history_percent(metrics.memory_percent()), 0);
+    }
+
+    #[test]
+    fn history_entries_are_clamped() {
+        assert_eq!(history_percent(150.0), 100);
+        assert_eq!(history_percent(-5.0), 0);
+        assert_eq!(history_percent(f64::NAN), 0);
+        assert_eq!(history_percent(42.9), 42);
+    }
+
+    fn processes(count: u32) -> Vec<ModelsProcessInfo> {
+        (1..=count)
+            .map(|pid| ModelsProcessInfo { network_sent: Some(pid as u64 * 1024), ..sample_process(pid, "worker") })
+            .collect()
+    }
+
+    #[test]
+    fn network_process_count_limits_the_list() {
+        let mut app = test_app(TuiArgs { network_process_count: Some(5), ..TuiArgs::default() });
+        app.top_processes = processes(30);
+
+        app.rebuild_top_network_processes();
+
+        assert_eq!(app.top_network_processes.len(), 5);
+        assert_eq!(app.top_network_processes[0].pid, 30);  // Najaktívnejší prvý
+    }
+
+    #[test]
+    fn adjusting_the_network_count_stays_in_range() {
+        let mut app = test_app(TuiArgs { network_process_count: Some(2), ..TuiArgs::default() });
+        app.top_processes = processes(80);
+
+        app.adjust_network_process_count(-5);
+        assert_eq!(app.top_network_processes.len(), 1);
+
+        app.adjust_network_process_count(100);
+        assert_eq!(app.network_process_count, MAX_NETWORK_PROCESS_COUNT);
+        assert_eq!(app.top_network_processes.len(), MAX_NETWORK_PROCESS_COUNT);
+    }
+
+    #[test]
+    fn clearing_history_empties_the_buffers_and_session_totals() {
+        let mut app = test_app(TuiArgs::default());
+        app.cpu_history = vec![10, 20];
+        app.ram_history = vec![30];
+        app.disk_history = vec![40];
+        app.gpu_history = vec![50];
+        app.network_sent_history = vec![1.0];
+        app.network_recv_history = vec![2.0];
+        app.network_session_start = Some((100, 100));
+        app.network_boot_totals = (500, 900);
+
+        app.clear_history();
+
+        assert!(app.cpu_history.is_empty() && app.ram_history.is_empty());
+        assert!(app.disk_history.is_empty() && app.gpu_history.is_empty());
+        assert!(app.network_sent_history.is_empty() && app.network_recv_history.is_empty());
+        assert_eq!(app.network_session_totals(), (0, 0));
+    }
+
+    #[test]
+    fn history_fills_again_after_clearing() {
+        let mut app = test_app(TuiArgs::default());
+        app.network_sent_history = vec![1.0];
+        app.clear_history();
+
+        app.metrics = Some(SystemMetrics::builder(Utc::now()).network_bytes(2048, 1024).build());
+        app.update_network_data(HashMap::new());
+
+        assert_eq!(app.network_sent_history, [2.0]);
+        assert_eq!(app.network_recv_history, [1.0]);
+    }
+
+    /// Procesy so (odoslané, prijaté) bajtmi: 1 viac odosiela, 2 viac prijíma, 3 má najviac spolu
+    fn network_processes() -> Vec<ModelsProcessInfo> {
+        [(1, 900, 0), (2, 100, 700), (3, 500, 500)]
+            .into_iter()
+            .map(|(pid, sent, recv)| ModelsProcessInfo {
+                network_sent: Some(sent),
+                network_recv: Some(recv),
+                ..sample_process(pid, "worker")
+            })
+            .collect()
+    }
+
+    fn sorted_pids(sort: NetworkSort, connections: impl Fn(u32) -> usize) -> Vec<u32> {
+        let mut processes = network_processes();
+        sort.sort(&mut processes, connections);
+        processes.iter().map(|process| process.pid).collect()
+    }
+
+    #[test]
+    fn each_network_sort_orders_processes() {
+        let connections = |pid: u32| if pid == 2 { 5 } else { 1 };
+
+        assert_eq!(sorted_pids(NetworkSort::Total, connections), [3, 1, 2]);
+        assert_eq!(sorted_pids(NetworkSort::Sent, connections), [1, 3, 2]);
+        assert_eq!(sorted_pids(NetworkSort::Recv, connections), [2, 3, 1]);
+        assert_eq!(sorted_pids(NetworkSort::Connections, connections), [2, 3, 1]);  // Zhoda podľa celkovej prevádzky
+    }
+
+    #[test]
+    fn network_sort_cycles_through_every_mode() {
+        let mut sort = NetworkSort::default();
+        let mut seen = Vec::new();
+        for _ in 0..4 {
+            seen.push(sort.label());
+            sort = sort.next();
+        }
+
+        assert_eq!(sort, NetworkSort::Total);
+        assert_eq!(seen, ["total traffic", "sent", "received", "connections"]);
+    }
+
+    fn connection_with(protocol: &str) -> NetworkConnection {
+        NetworkConnection {
+            process_name: "nginx".to_string(),
+            local_address: "10.0.0.5:80".to_string(),
+            remote_address: "10.0.0.9:443".to_string(),
+            protocol: protocol.to_string(),
+            state: "ESTABLISHED".to_string(),
+            pid: 1,
+            bytes_sent: None,
+            bytes_received: None,
+        }
+    }
+
+    #[test]
+    fn protocol_filter_matches_by_prefix_ignoring_case() {
+        for protocol in ["tcp", "tcp6", "TCPv6"] {
+            assert!(ProtocolFilter::Tcp.matches(&connection_with(protocol)), "{protocol}");
+            assert!(!ProtocolFilter::Udp.matches(&connection_with(protocol)), "{protocol}");
+        }
+        for protocol in ["udp", "UDPv6"] {
+            assert!(ProtocolFilter::Udp.matches(&connection_with(protocol)), "{protocol}");
+            assert!(!ProtocolFilter::Tcp.matches(&connection_with(protocol)), "{protocol}");
+        }
+
+        // Krátky alebo prázdny protokol nespôsobí panic
+        assert!(!ProtocolFilter::Tcp.matches(&connection_with("tc")));
+        assert!(ProtocolFilter::All.matches(&connection_with("")));
+    }
+
+    #[test]
+    fn process_and_network_lists_share_one_snapshot_without_duplicates() {
+        let mut app = test_app(TuiArgs::default());
+        app.system_group_expanded = true;
+        let mut top = processes(3);
+        top.push(sample_process(SYSTEM_GROUP_PID, "[system processes]"));
+        let members = vec![sample_process(2, "worker"), sample_process(9, "kthreadd")];  // PID 2 je už v zozname
+
+        app.update_processes(ProcessSample { processes: top, system_members: members, ..ProcessSample::default() });
+
+        let listed: Vec<u32> = app.top_processes.iter().map(|process| process.pid).collect();
+        assert_eq!(listed.iter().collect::<HashSet<_>>().len(), listed.len(), "{listed:?}");
+        let network: Vec<u32> = app.top_network_processes.iter().map(|process| process.pid).collect();
+        assert_eq!(network.iter().collect::<HashSet<_>>().len(), network.len(), "{network:?}");
+
+        // Sieťový zoznam má rovnaké hodnoty ako zoznam procesov
+        for process in &app.top_network_processes {
+            let same = app.top_processes.iter().find(|listed| listed.pid == process.pid).unwrap();
+            assert_eq!(process.network_sent, same.network_sent);
+        }
+    }
+}
@@ -1,24 +1,37 @@
+use crate::services::disks::{disk_spaces, total_space};  // Kapacita diskov bez vymeniteľných médií
+
 /// Komplexné informácie o systéme
 /// Obsahuje všetky statické informácie, ktoré sa nemenia počas behu aplikácie
 #[derive(Debug, Clone)]
 pub struct SystemInfo {
     pub cpu_name: String,      // Model a frekvencia procesora
+    pub cpu_cores: Option<usize>,        // Počet fyzických jadier (ak je známy)
+    pub cpu_threads: usize,              // Počet logických procesorov
+    pub cpu_frequency_mhz: Option<u64>,  // Frekvencia procesora v MHz
     pub gpu_name: String,      // Model grafickej karty
     pub ram_total_gb: u64,     // Celková RAM v GB
     pub disk_total_gb: u64,    // Celková kapacita disku v GB
     pub os_name: String,       // Názov a verzia OS
+    pub kernel_version: Option<String>,  // Verzia jadra (Linux, macOS) alebo build (Windows)
     pub hostname: String,      // Názov počítača v sieti
 }
 
 /// Hlavná funkcia pre získanie všetkých systémových informácií
 /// Získava informácie z rôznych zdrojov podľa platformy
-pub fn get_system_info() -> SystemInfo {
+/// `include_removable_disks` započíta do kapacity diskov aj vymeniteľné médiá
+pub fn get_system_info(include_removable_disks: bool) -> SystemInfo {
+    let (cpu_cores, cpu_threads, cpu_frequency_mhz) = get_cpu_topology();
+
     SystemInfo {
         cpu_name: get_cpu_name(),
+        cpu_cores,
+        cpu_threads,
+        cpu_frequency_mhz,
         gpu_name: get_gpu_name(),
         ram_total_gb: get_total_ram_gb(),
-        disk_total_gb: get_total_ram_gb(),  // TODO: Opraviť na get_total_disk_gb()
+        disk_total_gb: get_total_disk_gb(include_removable_disks),
         os_name: get_os_name(),
+        kernel_version: sysinfo::System::kernel_version(),
         hostname: get_hostname(),
     }
 }
@@ -32,14 +45,10 @@ fn get_cpu_name() -> String {
     #[cfg(target_os = "linux")]
     {
         // Linux: Čítanie z /proc/cpuinfo
-        if let Ok(content) = std::fs::read_to_string("/proc/cpuinfo") {
-            for line in content.lines() {
-                if line.starts_with("model name") {
-                    return line.split(':').nth(1).unwrap_or("Unknown CPU").trim().to_string();
-                }
-            }
-        }
-        "Unknown CPU".to_string()
+        std::fs::read_to_string("/proc/cpuinfo")
+            .ok()
+            .and_then(|content| parse_cpuinfo_name(&content))
+            .unwrap_or_else(|| "Unknown CPU".to_string())
     }
     
     #[cfg(target_os = "windows")]
@@ -109,6 +118,40 @@ fn get_cpu_name() -> String {
     }
 }
 
+/// Kľúče `/proc/cpuinfo` s názvom procesora v poradí priority
+/// x86 má `model name`, ARM `Hardware` alebo `Processor`, MIPS `cpu model`
+#[cfg(target_os = "linux")]
+const CPUINFO_NAME_KEYS: [&str; 4] = ["model name", "Hardware", "Processor", "cpu model"];
+
+/// Názov procesora z obsahu `/proc/cpuinfo`
+/// Prvý neprázdny kľúč z `CPUINFO_NAME_KEYS`; malé `processor` (číslo jadra) sa nezhoduje
+#[cfg(target_os = "linux")]
+pub fn parse_cpuinfo_name(content: &str) -> Option<String> {
+    let fields: Vec<(&str, &str)> = content
+        .lines()
+        .filter_map(|line| line.split_once(':'))
+        .map(|(key, value)| (key.trim(), value.trim()))
+        .collect();
+
+    CPUINFO_NAME_KEYS.iter().find_map(|wanted| {
+        fields.iter()
+            .find(|(key, value)| key == wanted && !value.is_empty())
+            .map(|(_, value)| value.to_string())
+    })
+}
+
+/// Získa počet jadier, logických procesorov a frekvenciu CPU (cez sysinfo)
+/// Vráti (fyzické jadrá, logické procesory, frekvencia v MHz)
+fn get_cpu_topology() -> (Option<usize>, usize, Option<u64>) {
+    let sys = sysinfo::System::new_with_specifics(
+        sysinfo::RefreshKind::new().with_cpu(sysinfo::CpuRefreshKind::new().with_frequency())
+    );
+
+    // Frekvencia 0 znamená, že ju platforma neposkytuje
+    let frequency = sys.cpus().iter().map(|cpu| cpu.frequency()).max().filter(|&mhz| mhz > 0);
+    (sys.physical_core_count(), sys.cpus().len(), frequency)
+}
+
 /// Získa názov grafickej karty
 fn get_gpu_name() -> String {
     #[cfg(target_os = "windows")]
@@ -173,18 +216,9 @@ fn get_gpu_name() -> String {
 fn get_total_ram_gb() -> u64 {
     // Platformovo špecifická implementácia
     
-    #[cfg(target_os = "linux")]
-    {
-        // Linux: Použitie sys_info knižnice
-        if let Ok(meminfo) = sys_info::mem_info() {
-            return (meminfo.total / (1024 * 1024)) as u64;  // Konverzia z KB na GB
-        }
-        return 16;  // Predvolená hodnota
-    }
-    
-    #[cfg(target_os = "windows")]
+    #[cfg(any(target_os = "linux", target_os = "windows"))]
     {
-        // Windows: Použitie sysinfo knižnice
+        // Linux a Windows: Použitie sysinfo knižnice
         let sys = sysinfo::System::new_with_specifics(
             sysinfo::RefreshKind::new().with_memory(sysinfo::MemoryRefreshKind::everything())
         );
@@ -215,6 +249,13 @@ fn get_total_ram_gb() -> u64 {
     }
 }
 
+/// Získa celkovú kapacitu diskov v GB (súčet pripojených diskov bez vymeniteľných médií)
+fn get_total_disk_gb(include_removable: bool) -> u64 {
+    let disks = sysinfo::Disks::new_with_refreshed_list();
+    let total = total_space(&disk_spaces(&disks), include_removable);
+    total / (1024 * 1024 * 1024)  // Konverzia z B na GB
+}
+
 /// Získa názov operačného systému
 fn get_os_name() -> String {
     #[cfg(target_os = "linux")]
@@ -270,11 +311,66 @@ fn get_os_name() -> String {
     }
 }
 
-/// Získa hostname počítača
-fn get_hostname() -> String {
-    // Použitie hostname knižnice s ošetrením chýb
-    hostname::get()
-        .unwrap_or_else(|_| "localhost".into())
-        .to_string_lossy()
-        .to_string()
-}
\ No newline at end of file
+/// Náhradný hostname, ak ho systém neposkytne
+pub const FALLBACK_HOSTNAME: &str = "localhost";
+
+/// Získa hostname počítača - jediné miesto pre TUI aj `collect`
+///
+/// Najprv sa skúsi `hostname` knižnica, potom `whoami`. Chyba aj prázdny
+/// názov (napr. kontajner bez nastaveného hostname) vráti `FALLBACK_HOSTNAME`,
+/// výsledok preto nikdy nie je prázdny.
+pub fn get_hostname() -> String {
+    let from_hostname = hostname::get()
+        .ok()
+        .map(|name| name.to_string_lossy().to_string());
+    let from_whoami = || whoami::fallible::hostname().ok();
+
+    choose_hostname(from_hostname, from_whoami)
+}
+
+/// Prvý neprázdny názov (bez okrajových medzier), inak `FALLBACK_HOSTNAME`
+/// `whoami` sa volá, len ak `hostname` knižnica neuspeje
+fn choose_hostname(from_hostname: Option<String>, from_whoami: impl FnOnce() -> Option<String>) -> String {
+    let non_empty = |name: Option<String>| name.map(|name| name.trim().to_string()).filter(|name| !name.is_empty());
+
+    non_empty(from_hostname)
+        .or_else(|| non_empty(from_whoami()))
+        .unwrap_or_else(|| FALLBACK_HOSTNAME.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hostname_falls_back_and_is_never_empty() {
+        assert_eq!(choose_hostname(Some(" web-01\n".to_string()), || panic!("whoami not needed")), "web-01");
+        assert_eq!(choose_hostname(Some("  ".to_string()), || Some("from-whoami".to_string())), "from-whoami");
+        assert_eq!(choose_hostname(None, || Some(String::new())), FALLBACK_HOSTNAME);
+        assert!(!get_hostname().is_empty());
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn x86_model_name_is_used() {
+        let cpuinfo = "processor\t: 0\nvendor_id\t: GenuineIntel\nmodel name\t: Intel(R) Core(TM) i7-8550U CPU @ 1.80GHz\n\n\
+                       processor\t: 1\nmodel name\t: Intel(R) Core(TM) i7-8550U CPU @ 1.80GHz\n";
+
+        assert_eq!(parse_cpuinfo_name(cpuinfo).as_deref(), Some("Intel(R) Core(TM) i7-8550U CPU @ 1.80GHz"));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn arm_hardware_field_is_used_when_model_name_is_missing() {
+        let cpuinfo = "processor\t: 0\nBogoMIPS\t: 108.00\nCPU implementer\t: 0x41\n\nHardware\t: BCM2835\nModel\t: Raspberry Pi 4\n";
+
+        assert_eq!(parse_cpuinfo_name(cpuinfo).as_deref(), Some("BCM2835"));
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn processor_index_and_empty_values_are_ignored() {
+        assert_eq!(parse_cpuinfo_name("processor\t: 0\nmodel name\t:\n"), None);
+        assert_eq!(parse_cpuinfo_name(""), None);
+    }
+}
@@ -0,0 +1,180 @@
+// app_ui_state.rs
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use super::app_state::Mode;
+
+/// Predvolený počet zobrazených procesov
+pub const DEFAULT_PROCESS_COUNT: usize = 20;
+
+/// Predvolený počet procesov v sieťovom pohľade
+pub const DEFAULT_NETWORK_PROCESS_COUNT: usize = 15;
+
+/// Najvyšší nastaviteľný počet procesov v sieťovom pohľade
+pub const MAX_NETWORK_PROCESS_COUNT: usize = 50;
+
+/// Posledný obsluhovateľný pohľad uložený medzi spusteniami
+/// Detail procesu a nápoveda sa neukladajú - po štarte by nedávali zmysel
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SavedView {
+    #[default]
+    Overview,     // Hlavný prehľad
+    Network,      // Sieťová aktivita
+    ProcessTree,  // Strom procesov
+}
+
+impl SavedView {
+    /// Pohľad, ktorý sa uloží pre daný režim
+    pub fn from_mode(mode: Mode) -> Self {
+        match mode {
+            Mode::NetworkView => SavedView::Network,
+            Mode::ProcessTree => SavedView::ProcessTree,
+            _ => SavedView::Overview,
+        }
+    }
+
+    /// Režim TUI zodpovedajúci uloženému pohľadu
+    pub fn mode(self) -> Mode {
+        match self {
+            SavedView::Overview => Mode::Overview,
+            SavedView::Network => Mode::NetworkView,
+            SavedView::ProcessTree => Mode::ProcessTree,
+        }
+    }
+}
+
+/// Stav TUI ukladaný pri ukončení do `~/.cache/system-monitor/ui_state.json`
+/// Chýbajúce polia (napr. zo staršej verzie) dostanú predvolené hodnoty
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct UiState {
+    pub last_view: SavedView,    // Posledný pohľad
+    pub process_count: usize,    // Počet zobrazených procesov
+    pub network_process_count: usize,  // Počet procesov v sieťovom pohľade
+}
+
+impl Default for UiState {
+    fn default() -> Self {
+        Self {
+            last_view: SavedView::default(),
+            process_count: DEFAULT_PROCESS_COUNT,
+            network_process_count: DEFAULT_NETWORK_PROCESS_COUNT,
+        }
+    }
+}
+
+impl UiState {
+    /// Cesta k súboru so stavom (None ak nie je známy domovský adresár)
+    pub fn path() -> Option<PathBuf> {
+        std::env::var_os("HOME")
+            .or_else(|| std::env::var_os("USERPROFILE"))  // Windows
+            .map(|home| PathBuf::from(home).join(".cache").join("system-monitor").join("ui_state.json"))
+    }
+
+    /// Načítanie uloženého stavu
+    /// Chýbajúci alebo poškodený súbor vráti predvolený stav
+    pub fn load() -> Self {
+        Self::path().map(|path| Self::load_from(&path)).unwrap_or_default()
+    }
+
+    /// Načítanie stavu zo súboru `path` (chýbajúci alebo poškodený = predvolený stav)
+    pub fn load_from(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|content| serde_json::from_str::<UiState>(&content).ok())
+            .map(|state| Self {
+                // Nulový počet procesov by vyprázdnil zoznam
+                process_count: if state.process_count == 0 { DEFAULT_PROCESS_COUNT } else { state.process_count },
+                network_process_count: match state.network_process_count {
+                    0 => DEFAULT_NETWORK_PROCESS_COUNT,
+                    count => count.min(MAX_NETWORK_PROCESS_COUNT),
+                },
+                ..state
+            })
+            .unwrap_or_default()
+    }
+
+    /// Uloženie stavu (adresár sa vytvorí, ak neexistuje)
+    pub fn save(&self) -> Result<(), String> {
+        let path = Self::path().ok_or("Unknown home directory")?;
+        self.save_to(&path)
+    }
+
+    /// Uloženie stavu do súboru `path` (adresár sa vytvorí, ak neexistuje)
+    pub fn save_to(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| format!("{}: {}", dir.display(), e))?;
+        }
+        let content = serde_json::to_string_pretty(self).map_err(|e| e.to_string())?;
+        fs::write(path, content).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Súbor v dočasnom adresári jedinečný pre test (zmaže sa pri zahodení)
+    struct TempFile(PathBuf);
+
+    impl TempFile {
+        fn new(name: &str) -> Self {
+            let dir = std::env::temp_dir().join(format!("system-monitor-test-{}-{}", std::process::id(), name));
+            Self(dir.join("ui_state.json"))
+        }
+    }
+
+    impl Drop for TempFile {
+        fn drop(&mut self) {
+            if let Some(dir) = self.0.parent() {
+                let _ = fs::remove_dir_all(dir);
+            }
+        }
+    }
+
+    #[test]
+    fn saved_state_is_loaded_back() {
+        let file = TempFile::new("round-trip");
+        let state = UiState { last_view: SavedView::Network, process_count: 35, network_process_count: 12 };
+
+        state.save_to(&file.0).unwrap();
+        let loaded = UiState::load_from(&file.0);
+
+        assert_eq!(loaded.last_view, SavedView::Network);
+        assert_eq!((loaded.process_count, loaded.network_process_count), (35, 12));
+    }
+
+    #[test]
+    fn missing_or_corrupt_file_gives_defaults() {
+        let file = TempFile::new("corrupt");
+        assert_eq!(UiState::load_from(&file.0).process_count, DEFAULT_PROCESS_COUNT);
+
+        fs::create_dir_all(file.0.parent().unwrap()).unwrap();
+        fs::write(&file.0, "{ not json").unwrap();
+        let loaded = UiState::load_from(&file.0);
+
+        assert_eq!(loaded.last_view, SavedView::Overview);
+        assert_eq!(loaded.network_process_count, DEFAULT_NETWORK_PROCESS_COUNT);
+    }
+
+    #[test]
+    fn out_of_range_counts_are_corrected_on_load() {
+        let file = TempFile::new("counts");
+        fs::create_dir_all(file.0.parent().unwrap()).unwrap();
+        fs::write(&file.0, r#"{ "process_count": 0, "network_process_count": 500 }"#).unwrap();
+
+        let loaded = UiState::load_from(&file.0);
+
+        assert_eq!(loaded.process_count, DEFAULT_PROCESS_COUNT);
+        assert_eq!(loaded.network_process_count, MAX_NETWORK_PROCESS_COUNT);
+    }
+
+    #[test]
+    fn only_restorable_views_are_saved() {
+        assert_eq!(SavedView::from_mode(Mode::ProcessTree), SavedView::ProcessTree);
+        assert_eq!(SavedView::from_mode(Mode::Help), SavedView::Overview);
+        assert!(SavedView::Network.mode() == Mode::NetworkView);
+    }
+}
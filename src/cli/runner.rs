@@ -7,14 +7,22 @@ use crossterm::{
 };
 use std::io;
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use crate::services::monitor::SystemMonitor;
-use super::{app::{TuiApp, Mode}, ui, Result};
+use crate::config::TuiArgs;
+use super::{app::{TuiApp, Mode, DbState}, ui, Result};
+
+/// Ako často sa kontroluje vstup z klávesnice
+/// Krátky interval = okamžitá odozva nezávisle od intervalu obnovy dát
+const INPUT_POLL_INTERVAL: Duration = Duration::from_millis(50);
 
 /// Hlavná funkcia pre spustenie TUI aplikácie
 /// Inicializuje terminál, spustí hlavnú slučku a spravuje životný cyklus aplikácie
 ///
 /// # Argumenty
 /// * `monitor` - Inštancia systémového monitora pre získavanie dát
+/// * `config` - Nastavenia TUI z príkazového riadku
+/// * `db` - Stav pripojenia k databáze (TUI naň nečaká)
 ///
 /// # Návratová hodnota
 /// * `Result<()>` - Úspech alebo chyba počas behu aplikácie
@@ -23,7 +31,7 @@ use super::{app::{TuiApp, Mode}, ui, Result};
 /// * Chyby pri inicializácii terminálu (raw mode, alternate screen)
 /// * Chyby pri čítaní vstupu z klávesnice
 /// * Chyby pri renderingu UI
-pub fn run_tui(monitor: SystemMonitor) -> Result<()> {
+pub fn run_tui(monitor: SystemMonitor, config: TuiArgs, db: DbState) -> Result<()> {
     // ========== INICIALIZÁCIA TERMINÁLU ==========
     // Povolenie raw módu - priamy prístup k terminálu bez buffrovania
     enable_raw_mode()?;
@@ -39,53 +47,51 @@ pub fn run_tui(monitor: SystemMonitor) -> Result<()> {
     // ========== INICIALIZÁCIA APLIKÁCIE ==========
     // Zdieľaná inštancia monitora (pre viacvláknový prístup)
     let monitor_arc = Arc::new(Mutex::new(monitor));
+    let data_interval = Duration::from_millis(config.refresh_ms);  // Interval obnovy dát
     // Hlavná aplikácia
-    let mut app = TuiApp::new(Arc::clone(&monitor_arc));
+    let mut app = TuiApp::new(Arc::clone(&monitor_arc), config, db);
     
     // Prvá aktualizácia dát
     app.update();
     
-    // Časovač pre pravidelné aktualizácie
-    let mut last_tick = std::time::Instant::now();
-    let tick_rate = std::time::Duration::from_millis(1000);  // Aktualizácia každú sekundu
+    // Časovač pre pravidelné aktualizácie dát
+    let mut last_update = Instant::now();
+    let mut needs_redraw = true;  // Prvé vykreslenie hneď po štarte
 
     // ========== HLAVNÁ SLOČKA APLIKÁCIE ==========
     loop {
-        // Výpočet timeoutu pre poll udalostí
-        let timeout = tick_rate
-            .checked_sub(last_tick.elapsed())
-            .unwrap_or_else(|| std::time::Duration::from_secs(0));
-
-        // Čítanie vstupu z klávesnice s timeoutom
-        if event::poll(timeout)? {
-            if let Event::Key(key) = event::read()? {
-                // Ignorovanie opakovaných stlačení (len prvý press)
-                if key.kind != KeyEventKind::Press {
-                    continue;
+        // Renderovanie UI len po zmene (vstup alebo nové dáta)
+        if needs_redraw {
+            terminal.draw(|f| ui::render(f, &mut app))?;
+            needs_redraw = false;
+        }
+        
+        // Kontrola ukončenia aplikácie
+        if app.should_quit {
+            break;
+        }
+
+        // Čítanie vstupu z klávesnice s krátkym timeoutom
+        if event::poll(INPUT_POLL_INTERVAL)? {
+            match event::read()? {
+                // Len prvé stlačenie (ignorovanie opakovaní a uvoľnení)
+                Event::Key(key) if key.kind == KeyEventKind::Press => {
+                    handle_key(&mut app, key.code);
+                    needs_redraw = true;
                 }
-                
-                // Smerovanie kláves podľa aktuálneho režimu
-                match app.mode {
-                    Mode::Overview => handle_overview_keys(&mut app, key.code),
-                    Mode::NetworkView => handle_network_keys(&mut app, key.code),
-                    Mode::ProcessDetail => handle_process_detail_keys(&mut app, key.code),
-                    Mode::Help => handle_help_keys(&mut app, key.code),
+                // Zmena veľkosti terminálu vyžaduje nové vykreslenie
+                Event::Resize(_, _) => {
+                    needs_redraw = true;
                 }
+                _ => {}
             }
         }
 
-        // Pravidelná aktualizácia dát (každú sekundu)
-        if last_tick.elapsed() >= tick_rate {
+        // Pravidelná aktualizácia dát podľa nastaveného intervalu
+        if data_update_due(last_update.elapsed(), data_interval) {
             app.update();
-            last_tick = std::time::Instant::now();
-        }
-
-        // Renderovanie UI
-        terminal.draw(|f| ui::render(f, &mut app))?;
-        
-        // Kontrola ukončenia aplikácie
-        if app.should_quit {
-            break;
+            last_update = Instant::now();
+            needs_redraw = true;
         }
     }
 
@@ -94,9 +100,49 @@ pub fn run_tui(monitor: SystemMonitor) -> Result<()> {
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
     terminal.show_cursor()?;
 
+    // Uloženie stavu TUI pre ďalšie spustenie - chyba nesmie zablokovať ukončenie
+    if !app.config.no_save_state {
+        if let Err(e) = app.ui_state().save() {
+            eprintln!("⚠️  Failed to save UI state: {}", e);
+        }
+    }
+
     Ok(())
 }
 
+/// Je čas na obnovu dát? (uplynul aspoň jeden dátový interval)
+fn data_update_due(elapsed: Duration, data_interval: Duration) -> bool {
+    elapsed >= data_interval
+}
+
+/// Smerovanie stlačeného klávesu - potvrdenie, paleta, globálne skratky, potom režim
+/// Čakajúce ukončenie procesov potvrdí len `y`, každý iný kláves ho zruší
+fn handle_key(app: &mut TuiApp, key_code: KeyCode) {
+    if app.pending_kill.is_some() {
+        match key_code {
+            KeyCode::Char('y') | KeyCode::Char('Y') => app.confirm_kill(),
+            _ => app.cancel_kill(),
+        }
+    } else if app.palette.open {
+        // Otvorená paleta príkazov zachytáva všetky klávesy
+        handle_palette_keys(app, key_code);
+    } else if key_code == KeyCode::Char(':') {
+        app.palette.open();
+    } else {
+        // Smerovanie kláves podľa aktuálneho režimu
+        match app.mode {
+            Mode::Overview => handle_overview_keys(app, key_code),
+            Mode::NetworkView => handle_network_keys(app, key_code),
+            Mode::ProcessDetail => handle_process_detail_keys(app, key_code),
+            Mode::Help => handle_help_keys(app, key_code),
+            Mode::ProcessTree => handle_tree_keys(app, key_code),
+            Mode::MemoryGrowth => handle_memory_growth_keys(app, key_code),
+            Mode::Swap => handle_swap_keys(app, key_code),
+            Mode::About => handle_about_keys(app, key_code),
+        }
+    }
+}
+
 // ==================== OBSLUHA KLÁVES PRE JEDNOTLIVÉ REŽIMY ====================
 
 /// Spracovanie klávesových vstupov v režime prehľadu (Overview)
@@ -123,6 +169,54 @@ fn handle_overview_keys(app: &mut TuiApp, key_code: KeyCode) {
         KeyCode::Char('n') | KeyCode::Char('N') => {
             app.enter_network_mode();
         }
+        // Prechod do stromového zobrazenia procesov
+        KeyCode::Char('t') | KeyCode::Char('T') => {
+            app.enter_tree_mode();
+        }
+        // Prechod do zoznamu procesov s rastúcou pamäťou
+        KeyCode::Char('m') | KeyCode::Char('M') => {
+            app.enter_memory_growth_mode();
+        }
+        // Prechod do zoznamu procesov podľa swapu
+        KeyCode::Char('w') | KeyCode::Char('W') => {
+            app.enter_swap_mode();
+        }
+        // Zapnutie/vypnutie ignorovaných procesov
+        KeyCode::Char('i') | KeyCode::Char('I') => {
+            app.toggle_ignore_list();
+        }
+        // Zoskupenie systémových procesov do jedného riadku
+        KeyCode::Char('s') | KeyCode::Char('S') => {
+            app.toggle_system_group();
+        }
+        // Vymazanie histórie grafov
+        KeyCode::Char('c') | KeyCode::Char('C') => {
+            app.clear_history();
+        }
+        // Prepnutie zobrazenej pamäte (RSS / virtuálna)
+        KeyCode::Char('v') | KeyCode::Char('V') => {
+            app.toggle_memory_metric();
+        }
+        // Prispôsobenie grafov histórie šírke
+        KeyCode::Char('f') | KeyCode::Char('F') => {
+            app.toggle_fit_sparklines();
+        }
+        // Pamäť a disk v presných bajtoch
+        KeyCode::Char('b') | KeyCode::Char('B') => {
+            app.toggle_raw_bytes();
+        }
+        // Výber procesu pod kurzorom pre hromadné akcie
+        KeyCode::Char(' ') => {
+            app.toggle_process_selection();
+        }
+        // Výber všetkých procesov / zrušenie výberu
+        KeyCode::Char('x') | KeyCode::Char('X') => {
+            app.toggle_select_all();
+        }
+        // Prechod na súhrn systému
+        KeyCode::Char('a') | KeyCode::Char('A') => {
+            app.enter_about_mode();
+        }
         
         // ========== NAVIGÁCIA V PROCESOCH ==========
         // Pohyb nahor v zozname procesov
@@ -133,15 +227,19 @@ fn handle_overview_keys(app: &mut TuiApp, key_code: KeyCode) {
         KeyCode::Down => {
             app.next_process();
         }
-        // Vstup do detailu vybraného procesu
+        // Vstup do detailu vybraného procesu (súhrnný riadok sa rozbalí)
         KeyCode::Enter => {
-            app.enter_detail_mode();
+            app.activate_selected_process();
         }
         // Rýchly prechod do sieťového režimu (Tab)
         KeyCode::Tab => {
             app.enter_network_mode();
         }
         
+        // Skopírovanie PID a názvu vybraného procesu
+        KeyCode::Char('y') | KeyCode::Char('Y') => {
+            app.copy_selected_process();
+        }
         // Ignorovanie ostatných klávesov
         _ => {}
     }
@@ -175,6 +273,17 @@ fn handle_network_keys(app: &mut TuiApp, key_code: KeyCode) {
         }
         
         // ========== NAVIGÁCIA V SIEŤOVÝCH PROCESOCH ==========
+        // Posun v spojeniach procesu v detailnom zobrazení
+        KeyCode::Up if app.network_mode_detail.is_some() => {
+            app.previous_connection();
+        }
+        KeyCode::Down if app.network_mode_detail.is_some() => {
+            app.next_connection();
+        }
+        // Filter spojení v detaile podľa protokolu (všetky / TCP / UDP)
+        KeyCode::Char('p') | KeyCode::Char('P') if app.network_mode_detail.is_some() => {
+            app.toggle_connection_filter();
+        }
         // Pohyb nahor v zozname sieťových procesov
         KeyCode::Up => {
             app.previous_network_process();
@@ -185,11 +294,31 @@ fn handle_network_keys(app: &mut TuiApp, key_code: KeyCode) {
         }
         // Vstup do detailu vybraného sieťového procesu
         KeyCode::Enter => {
-            if let Some(selected) = app.network_process_state.selected() {
-                if let Some(process) = app.top_network_processes.get(selected) {
-                    app.network_mode_detail = Some(process.name.clone());
-                }
-            }
+            app.open_network_detail();
+        }
+        
+        // Zmena počtu zobrazených sieťových procesov
+        KeyCode::Char('+') | KeyCode::Char('=') => {
+            app.adjust_network_process_count(1);
+        }
+        KeyCode::Char('-') => {
+            app.adjust_network_process_count(-1);
+        }
+        // Poradie podľa celkovej / odoslanej / prijatej prevádzky alebo počtu spojení
+        KeyCode::Char('s') | KeyCode::Char('S') => {
+            app.toggle_network_sort();
+        }
+        // Kompaktné riadky (len celková prevádzka)
+        KeyCode::Char('w') | KeyCode::Char('W') => {
+            app.toggle_compact_network();
+        }
+        // Vymazanie histórie grafov
+        KeyCode::Char('c') | KeyCode::Char('C') => {
+            app.clear_history();
+        }
+        // Prepnutie zobrazenej pamäte (RSS / virtuálna)
+        KeyCode::Char('v') | KeyCode::Char('V') => {
+            app.toggle_memory_metric();
         }
         
         // ========== PREPÍNANIE MEDZI REŽIMAMI ==========
@@ -202,6 +331,10 @@ fn handle_network_keys(app: &mut TuiApp, key_code: KeyCode) {
             app.mode = Mode::Help;
         }
         
+        // Skopírovanie PID a názvu vybraného procesu
+        KeyCode::Char('y') | KeyCode::Char('Y') => {
+            app.copy_selected_process();
+        }
         // Ignorovanie ostatných klávesov
         _ => {}
     }
@@ -222,6 +355,14 @@ fn handle_process_detail_keys(app: &mut TuiApp, key_code: KeyCode) {
         KeyCode::Char('q') | KeyCode::Char('Q') => {
             app.quit();
         }
+        // Skopírovanie PID a názvu vybraného procesu
+        KeyCode::Char('y') | KeyCode::Char('Y') => {
+            app.copy_selected_process();
+        }
+        // Pamäť procesu v presných bajtoch
+        KeyCode::Char('b') | KeyCode::Char('B') => {
+            app.toggle_raw_bytes();
+        }
         // Ignorovanie ostatných klávesov
         _ => {}
     }
@@ -245,4 +386,281 @@ fn handle_help_keys(app: &mut TuiApp, key_code: KeyCode) {
         // Ignorovanie ostatných klávesov
         _ => {}
     }
-}
\ No newline at end of file
+}
+
+/// Spracovanie klávesových vstupov v stromovom zobrazení procesov
+///
+/// # Argumenty
+/// * `app` - Referencia na aplikáciu
+/// * `key_code` - Stlačený kláves
+fn handle_tree_keys(app: &mut TuiApp, key_code: KeyCode) {
+    match key_code {
+        // Návrat do prehľadového režimu
+        KeyCode::Esc | KeyCode::Char('t') | KeyCode::Char('T') => {
+            app.mode = Mode::Overview;
+        }
+        // Ukončenie aplikácie
+        KeyCode::Char('q') | KeyCode::Char('Q') => {
+            app.quit();
+        }
+        // Ručná aktualizácia dát
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            app.refresh();
+        }
+        // Prechod na pomocníka
+        KeyCode::Char('h') | KeyCode::Char('H') => {
+            app.mode = Mode::Help;
+        }
+        // Navigácia v strome
+        KeyCode::Up => {
+            app.previous_tree_row();
+        }
+        KeyCode::Down => {
+            app.next_tree_row();
+        }
+        // Zbalenie/rozbalenie vybraného uzla
+        KeyCode::Enter => {
+            app.toggle_tree_node();
+        }
+        // Prepnutie zobrazenej pamäte (RSS / virtuálna)
+        KeyCode::Char('v') | KeyCode::Char('V') => {
+            app.toggle_memory_metric();
+        }
+        // Skopírovanie PID a názvu vybraného procesu
+        KeyCode::Char('y') | KeyCode::Char('Y') => {
+            app.copy_selected_process();
+        }
+        // Ignorovanie ostatných klávesov
+        _ => {}
+    }
+}
+
+/// Spracovanie klávesových vstupov v zozname procesov s rastúcou pamäťou
+///
+/// # Argumenty
+/// * `app` - Referencia na aplikáciu
+/// * `key_code` - Stlačený kláves
+fn handle_memory_growth_keys(app: &mut TuiApp, key_code: KeyCode) {
+    match key_code {
+        // Návrat do prehľadového režimu
+        KeyCode::Esc | KeyCode::Char('m') | KeyCode::Char('M') => {
+            app.mode = Mode::Overview;
+        }
+        // Ukončenie aplikácie
+        KeyCode::Char('q') | KeyCode::Char('Q') => {
+            app.quit();
+        }
+        // Ručná aktualizácia dát
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            app.refresh();
+        }
+        // Prechod na pomocníka
+        KeyCode::Char('h') | KeyCode::Char('H') => {
+            app.mode = Mode::Help;
+        }
+        // Navigácia v zozname
+        KeyCode::Up => {
+            app.previous_memory_growth();
+        }
+        KeyCode::Down => {
+            app.next_memory_growth();
+        }
+        // Skopírovanie PID a názvu vybraného procesu
+        KeyCode::Char('y') | KeyCode::Char('Y') => {
+            app.copy_selected_process();
+        }
+        // Ignorovanie ostatných klávesov
+        _ => {}
+    }
+}
+
+/// Spracovanie klávesových vstupov v zozname procesov podľa swapu
+///
+/// # Argumenty
+/// * `app` - Referencia na aplikáciu
+/// * `key_code` - Stlačený kláves
+fn handle_swap_keys(app: &mut TuiApp, key_code: KeyCode) {
+    match key_code {
+        // Návrat do prehľadového režimu
+        KeyCode::Esc | KeyCode::Char('w') | KeyCode::Char('W') => {
+            app.mode = Mode::Overview;
+        }
+        // Ukončenie aplikácie
+        KeyCode::Char('q') | KeyCode::Char('Q') => {
+            app.quit();
+        }
+        // Ručná aktualizácia dát
+        KeyCode::Char('r') | KeyCode::Char('R') => {
+            app.refresh();
+        }
+        // Prechod na pomocníka
+        KeyCode::Char('h') | KeyCode::Char('H') => {
+            app.mode = Mode::Help;
+        }
+        // Navigácia v zozname
+        KeyCode::Up => {
+            app.previous_swap_process();
+        }
+        KeyCode::Down => {
+            app.next_swap_process();
+        }
+        // Skopírovanie PID a názvu vybraného procesu
+        KeyCode::Char('y') | KeyCode::Char('Y') => {
+            app.copy_selected_process();
+        }
+        // Ignorovanie ostatných klávesov
+        _ => {}
+    }
+}
+
+/// Spracovanie klávesových vstupov na obrazovke About
+///
+/// # Argumenty
+/// * `app` - Referencia na aplikáciu
+/// * `key_code` - Stlačený kláves
+fn handle_about_keys(app: &mut TuiApp, key_code: KeyCode) {
+    match key_code {
+        // Návrat do prehľadového režimu
+        KeyCode::Esc | KeyCode::Char('a') | KeyCode::Char('A') => {
+            app.mode = Mode::Overview;
+        }
+        // Prechod na pomocníka
+        KeyCode::Char('h') | KeyCode::Char('H') => {
+            app.mode = Mode::Help;
+        }
+        // RAM a disk v presných bajtoch
+        KeyCode::Char('b') | KeyCode::Char('B') => {
+            app.toggle_raw_bytes();
+        }
+        // Ukončenie aplikácie
+        KeyCode::Char('q') | KeyCode::Char('Q') => {
+            app.quit();
+        }
+        // Ignorovanie ostatných klávesov
+        _ => {}
+    }
+}
+
+/// Spracovanie klávesových vstupov v otvorenej palete príkazov
+///
+/// # Argumenty
+/// * `app` - Referencia na aplikáciu
+/// * `key_code` - Stlačený kláves
+fn handle_palette_keys(app: &mut TuiApp, key_code: KeyCode) {
+    match key_code {
+        // Zatvorenie palety bez akcie
+        KeyCode::Esc => {
+            app.palette.close();
+        }
+        // Vykonanie vybranej akcie
+        KeyCode::Enter => {
+            let action = app.palette.selected_action();
+            app.palette.close();
+            if let Some(action) = action {
+                app.execute_action(action);
+            }
+        }
+        // Pohyb vo výsledkoch vyhľadávania
+        KeyCode::Up => {
+            app.palette.previous();
+        }
+        KeyCode::Down | KeyCode::Tab => {
+            app.palette.next();
+        }
+        // Úprava vyhľadávaného textu
+        KeyCode::Backspace => {
+            app.palette.pop_char();
+        }
+        KeyCode::Char(c) => {
+            app.palette.push_char(c);
+        }
+        // Ignorovanie ostatných klávesov
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::app::keybindings_for;
+
+    #[test]
+    fn data_updates_fire_at_the_data_interval() {
+        let interval = Duration::from_millis(1000);
+
+        assert!(!data_update_due(Duration::from_millis(999), interval));
+        assert!(data_update_due(Duration::from_millis(1000), interval));
+        assert!(data_update_due(Duration::from_millis(2500), interval));
+    }
+
+    #[test]
+    fn input_is_polled_many_times_per_data_update() {
+        let interval = Duration::from_millis(1000);
+
+        // Simulácia hlavnej slučky: každá iterácia čaká najviac INPUT_POLL_INTERVAL
+        let mut elapsed = Duration::ZERO;
+        let mut polls = 0;
+        while !data_update_due(elapsed, interval) {
+            elapsed += INPUT_POLL_INTERVAL;
+            polls += 1;
+        }
+
+        assert_eq!(polls, 20);
+    }
+
+    /// Klávesy obsluhované vo funkcii `handler` tohto súboru v tvare z tabuliek
+    /// (`KeyCode::Char('q')` -> "Q", `KeyCode::Up` -> "↑")
+    fn handled_keys(handler: &str) -> Vec<String> {
+        let source = include_str!("runner.rs");
+        let start = source.find(&format!("fn {}(", handler)).expect("handler exists");
+        let body = &source[start..];
+        let body = &body[..body[1..].find("\nfn ").map_or(body.len(), |end| end + 1)];
+
+        body.split("KeyCode::")
+            .skip(1)
+            .map(|rest| match rest.strip_prefix("Char('") {
+                Some(c) => c.chars().next().unwrap().to_uppercase().to_string(),
+                None => rest.split(|c: char| !c.is_alphanumeric()).next().unwrap().to_string(),
+            })
+            .map(|key| match key.as_str() {
+                "Up" => "↑".to_string(),
+                "Down" => "↓".to_string(),
+                " " => "Space".to_string(),
+                "=" => "+".to_string(),  // Alias `+` bez Shiftu
+                _ => key,
+            })
+            .collect()
+    }
+
+    /// Pokrýva označenie z tabuľky (napr. "Q/Esc", "↑↓", "+/-") daný kláves?
+    fn documented(label: &str, key: &str) -> bool {
+        label.split('/').any(|part| part == key) || (label == "↑↓" && (key == "↑" || key == "↓"))
+    }
+
+    #[test]
+    fn every_handled_key_is_in_the_keybinding_table() {
+        let handlers = [
+            (Mode::Overview, "handle_overview_keys"),
+            (Mode::NetworkView, "handle_network_keys"),
+            (Mode::ProcessDetail, "handle_process_detail_keys"),
+            (Mode::Help, "handle_help_keys"),
+            (Mode::ProcessTree, "handle_tree_keys"),
+            (Mode::MemoryGrowth, "handle_memory_growth_keys"),
+            (Mode::Swap, "handle_swap_keys"),
+            (Mode::About, "handle_about_keys"),
+        ];
+
+        for (mode, handler) in handlers {
+            let keys = handled_keys(handler);
+            assert!(!keys.is_empty(), "{handler}");
+            for key in keys {
+                assert!(
+                    keybindings_for(mode).iter().any(|binding| documented(binding.key, &key)),
+                    "key `{key}` handled in {handler} is missing from the table",
+                );
+            }
+            // Paletu otvára `handle_key` v každom režime
+            assert!(keybindings_for(mode).iter().any(|binding| binding.key == ":"), "{handler}");
+        }
+    }
+}
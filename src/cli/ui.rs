@@ -1,9 +1,15 @@
 /// Moduly pre jednotlivé obrazovky používateľského rozhrania
 pub mod ui_widgets;    // Spoločné widgety a komponenty
+pub mod ui_categories; // Kategórie procesov (ikona a farba podľa názvu)
 pub mod ui_overview;   // Hlavná obrazovka s prehľadom systému
 pub mod ui_process;    // Zobrazenie procesov a ich detailov
 pub mod ui_network;    // Sieťová aktivita a spojenia
 pub mod ui_help;       // Obrazovka s pomocníkom a klávesovými skratkami
+pub mod ui_palette;    // Paleta príkazov (prekryvné okno)
+pub mod ui_tree;       // Stromové zobrazenie procesov
+pub mod ui_memory;     // Procesy s rastúcou pamäťou
+pub mod ui_swap;       // Procesy s najväčším swapom
+pub mod ui_about;      // Súhrn hardvéru a systému
 
 // Importy pre rendering
 use ratatui::Frame;
@@ -29,5 +35,18 @@ pub fn render(f: &mut Frame, app: &mut TuiApp) {
         Mode::NetworkView => ui_network::render(f, app),
         // Režim pomocníka
         Mode::Help => ui_help::render(f, app),
+        // Stromový režim procesov
+        Mode::ProcessTree => ui_tree::render(f, app),
+        // Procesy s rastúcou pamäťou
+        Mode::MemoryGrowth => ui_memory::render(f, app),
+        // Procesy s najväčším swapom
+        Mode::Swap => ui_swap::render(f, app),
+        // Súhrn hardvéru a systému
+        Mode::About => ui_about::render(f, app),
+    }
+
+    // Paleta príkazov sa vykresľuje nad aktuálnou obrazovkou
+    if app.palette.open {
+        ui_palette::render(f, app);
     }
 }
\ No newline at end of file
@@ -0,0 +1,247 @@
+use ratatui::{
+    Frame,
+    layout::{Layout, Constraint, Direction, Rect},
+    style::{Style, Color, Modifier},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, BorderType, Gauge, Sparkline},
+};
+use crate::cli::app::TuiApp;
+use super::ui_widgets::{get_cpu_color, render_key_legend, NumberFormat};
+
+/// Šírka jednej bunky jadra (gauge a graf histórie) v znakoch
+const CORE_CELL_WIDTH: u16 = 24;
+
+/// Render funkcia pre obrazovku About (súhrn hardvéru a systému)
+pub fn render(f: &mut Frame, app: &mut TuiApp) {
+    let area = f.area();
+
+    // Rozdelenie na súhrn a pätu
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Length(12),  // Súhrn systému (10 riadkov a okraje)
+            Constraint::Min(4),      // Jadrá CPU
+            Constraint::Length(3),   // Päta
+        ])
+        .split(area);
+
+    render_summary(f, app, chunks[0]);
+    render_core_history(f, app, chunks[1]);
+    render_key_legend(f, chunks[2], app);  // Päta so skratkami
+}
+
+/// Vykreslenie využitia jednotlivých logických jadier
+/// Každé jadro má gauge s aktuálnou hodnotou a pod ním malý graf histórie;
+/// jadrá, ktoré sa do plochy nezmestia, sa vynechajú (počet je v titulku)
+fn render_core_history(f: &mut Frame, app: &TuiApp, area: Rect) {
+    let nf = NumberFormat::new(app.config.precision).with_rounding(app.config.percent_rounding);
+    let cores = &app.cpu_core_history;
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(Color::Blue));
+    let inner = block.inner(area);
+
+    // Mriežka buniek - každá má 2 riadky (gauge a graf)
+    let columns = (inner.width / CORE_CELL_WIDTH).max(1) as usize;
+    let rows = (inner.height / 2) as usize;
+    let shown = cores.len().min(columns * rows);
+
+    let title = if shown < cores.len() {
+        format!("🧮 CPU Cores (showing {} of {})", shown, cores.len())
+    } else {
+        "🧮 CPU Cores".to_string()
+    };
+    f.render_widget(block.title(title), area);
+
+    for (index, history) in cores.iter().take(shown).enumerate() {
+        let cell = Rect {
+            x: inner.x + (index % columns) as u16 * CORE_CELL_WIDTH,
+            y: inner.y + (index / columns) as u16 * 2,
+            width: CORE_CELL_WIDTH.min(inner.width).saturating_sub(1),  // Medzera medzi stĺpcami
+            height: 2,
+        };
+        let usage = history.back().copied().unwrap_or(0) as f64;
+
+        let gauge = Gauge::default()
+            .gauge_style(Style::default().fg(get_cpu_color(usage, &app.cpu_thresholds)).bg(Color::DarkGray))
+            .label(format!("CPU {} {}", index + 1, nf.whole_percent(usage)))
+            .percent(nf.gauge_percent(usage));
+        f.render_widget(gauge, Rect { height: 1, ..cell });
+
+        // Najnovšie vzorky, ktoré sa zmestia do šírky bunky
+        let data: Vec<u64> = history.iter().copied().collect();
+        let start = data.len().saturating_sub(cell.width as usize);
+        let sparkline = Sparkline::default()
+            .data(&data[start..])
+            .max(100)
+            .style(Style::default().fg(Color::Cyan))
+            .bar_set(ratatui::symbols::bar::NINE_LEVELS);
+        f.render_widget(sparkline, Rect { y: cell.y + 1, height: 1, ..cell });
+    }
+}
+
+/// Vykreslenie tabuľky "názov: hodnota" so statickými údajmi o systéme
+/// RAM, disk a uptime sa berú z aktuálnych metrík, ak sú k dispozícii
+fn render_summary(f: &mut Frame, app: &TuiApp, area: Rect) {
+    let nf = NumberFormat::new(app.config.precision).with_raw_bytes(app.raw_bytes);
+    let unknown = || "Unknown".to_string();
+    let about = app.about_info.as_ref();
+
+    // CPU: model, jadrá/vlákna a frekvencia
+    let cpu_model = about.map(|info| info.cpu_name.clone()).unwrap_or_else(|| app.system_info.cpu_name.clone());
+    let cpu_cores = about
+        .map(|info| match info.cpu_cores {
+            Some(cores) => format!("{} cores / {} threads", cores, info.cpu_threads),
+            None => format!("{} threads", info.cpu_threads),
+        })
+        .unwrap_or_else(unknown);
+    let cpu_frequency = about
+        .and_then(|info| info.cpu_frequency_mhz)
+        .map(|mhz| format!("{} GHz", nf.number(mhz as f64 / 1000.0, 2)))
+        .unwrap_or_else(unknown);
+
+    // RAM a disk - presné hodnoty z metrík, inak zaokrúhlené GB zo zistenia pri štarte
+    let ram_total = app.metrics.as_ref()
+        .map(|metrics| nf.format_bytes(metrics.memory_total.max(0) as u64))
+        .or_else(|| about.map(|info| format!("{} GB", info.ram_total_gb)))
+        .unwrap_or_else(unknown);
+    let disk_total = app.metrics.as_ref()
+        .map(|metrics| nf.format_bytes(metrics.disk_total.max(0) as u64))
+        .or_else(|| about.map(|info| format!("{} GB", info.disk_total_gb)))
+        .unwrap_or_else(unknown);
+
+    let uptime = app.metrics.as_ref()
+        .map(|metrics| metrics.system_uptime.max(0) as u64)
+        .unwrap_or_else(sysinfo::System::uptime);
+
+    let rows = [
+        ("Hostname", about.map(|info| info.hostname.clone()).unwrap_or_else(|| app.system_info.hostname.clone())),
+        ("OS", about.map(|info| info.os_name.clone()).unwrap_or_else(|| app.system_info.os_name.clone())),
+        ("Kernel", about.and_then(|info| info.kernel_version.clone()).unwrap_or_else(unknown)),
+        ("CPU", cpu_model),
+        ("CPU cores", cpu_cores),
+        ("CPU frequency", cpu_frequency),
+        ("Total RAM", ram_total),
+        ("Total disk", disk_total),
+        ("GPU", about.map(|info| info.gpu_name.clone()).unwrap_or_else(|| app.system_info.gpu_name.clone())),
+        ("Uptime", format_uptime(uptime)),
+    ];
+
+    let lines: Vec<Line> = rows
+        .into_iter()
+        .map(|(label, value)| Line::from(vec![
+            Span::styled(format!("{:<15}", label), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+            Span::styled(value, Style::default().fg(Color::White)),
+        ]))
+        .collect();
+
+    let summary = Paragraph::new(lines)
+        .block(Block::default()
+            .title("ℹ️  About This System")
+            .borders(Borders::ALL)                            // Všetky okraje
+            .border_type(BorderType::Rounded)                 // Okrúhle rohy
+            .border_style(Style::default().fg(Color::Blue))); // Modrá farba okrajov
+    f.render_widget(summary, area);
+}
+
+/// Formátovanie doby behu, napr. `"3d 4h 12m"` (pod minútu `"0m"`)
+fn format_uptime(seconds: u64) -> String {
+    let days = seconds / 86_400;
+    let hours = seconds % 86_400 / 3_600;
+    let minutes = seconds % 3_600 / 60;
+
+    if days > 0 {
+        format!("{}d {}h {}m", days, hours, minutes)
+    } else if hours > 0 {
+        format!("{}h {}m", hours, minutes)
+    } else {
+        format!("{}m", minutes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cli::app::{Mode, SystemInfo};
+    use crate::config::TuiArgs;
+    use crate::test_support::test_app;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    /// Údaje, aké zistí systém bez `lspci`, frekvencie a verzie jadra
+    fn partial_info() -> SystemInfo {
+        SystemInfo {
+            cpu_name: "Unknown CPU".to_string(),
+            cpu_cores: None,
+            cpu_threads: 4,
+            cpu_frequency_mhz: None,
+            gpu_name: "Unknown GPU".to_string(),
+            ram_total_gb: 8,
+            disk_total_gb: 0,
+            os_name: "Linux".to_string(),
+            kernel_version: None,
+            hostname: "test-host".to_string(),
+        }
+    }
+
+    /// Text vykreslenej obrazovky po riadkoch
+    fn rendered_lines(app: &mut TuiApp) -> Vec<String> {
+        let mut terminal = Terminal::new(TestBackend::new(80, 30)).unwrap();
+        terminal.draw(|f| render(f, app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect())
+            .collect()
+    }
+
+    fn row<'a>(lines: &'a [String], label: &str) -> &'a str {
+        lines.iter().find(|line| line.contains(label)).unwrap_or_else(|| panic!("no `{label}` row")).as_str()
+    }
+
+    #[test]
+    fn entering_about_keeps_already_detected_info() {
+        let mut app = test_app(TuiArgs::default());
+        app.about_info = Some(partial_info());
+
+        app.enter_about_mode();
+
+        assert!(app.mode == Mode::About);
+        assert_eq!(app.about_info.as_ref().unwrap().hostname, "test-host");
+    }
+
+    #[test]
+    fn missing_fields_render_as_unknown() {
+        let mut app = test_app(TuiArgs::default());
+        app.about_info = Some(partial_info());
+
+        let lines = rendered_lines(&mut app);
+
+        assert!(row(&lines, "Hostname").contains("test-host"));
+        assert!(row(&lines, "Kernel").contains("Unknown"));
+        assert!(row(&lines, "CPU cores").contains("4 threads"));
+        assert!(row(&lines, "CPU frequency").contains("Unknown"));
+        assert!(row(&lines, "Total RAM").contains("8 GB"));
+    }
+
+    #[test]
+    fn unknown_physical_cores_show_only_threads() {
+        let mut app = test_app(TuiArgs::default());
+        app.about_info = Some(partial_info());
+        let unknown = row(&rendered_lines(&mut app), "CPU cores").to_string();
+
+        app.about_info = Some(SystemInfo { cpu_cores: Some(2), ..partial_info() });
+        let known = row(&rendered_lines(&mut app), "CPU cores").to_string();
+
+        assert!(!unknown.contains("cores /"), "{unknown}");
+        assert!(known.contains("2 cores / 4 threads"), "{known}");
+    }
+
+    #[test]
+    fn uptime_is_shown_in_the_largest_units() {
+        assert_eq!(format_uptime(30), "0m");
+        assert_eq!(format_uptime(3 * 3_600 + 5 * 60), "3h 5m");
+        assert_eq!(format_uptime(2 * 86_400 + 60), "2d 0h 1m");
+    }
+}
@@ -0,0 +1,144 @@
+// ui_categories.rs
+
+use ratatui::style::Color;
+
+/// Kategória procesu podľa názvu (určuje farbu a typ prevádzky)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProcessCategory {
+    Browser,        // Webové prehliadače
+    Communication,  // Chat, hovory, videokonferencie
+    Gaming,         // Herné klienty
+    Media,          // Hudba a video
+    P2P,            // Torrent klienty
+    Updates,        // Aktualizácie systému a aplikácií
+    Development,    // Vývojové prostredia
+    Other,          // Všetko ostatné
+}
+
+impl ProcessCategory {
+    /// Farba názvu procesu v tabuľkách
+    pub fn color(&self) -> Color {
+        match self {
+            ProcessCategory::Browser => Color::Green,
+            ProcessCategory::Communication | ProcessCategory::Gaming => Color::Blue,
+            ProcessCategory::Media => Color::Cyan,
+            ProcessCategory::P2P => Color::Red,
+            ProcessCategory::Updates => Color::Yellow,
+            ProcessCategory::Development => Color::Magenta,
+            ProcessCategory::Other => Color::Gray,
+        }
+    }
+
+    /// Popis typu prevádzky v detaile sieťového procesu
+    pub fn label(&self) -> &'static str {
+        match self {
+            ProcessCategory::Browser => "Web Browsing",
+            ProcessCategory::Communication => "Communication",
+            ProcessCategory::Gaming => "Gaming",
+            ProcessCategory::Media => "Media",
+            ProcessCategory::P2P => "P2P",
+            ProcessCategory::Updates => "Updates",
+            ProcessCategory::Development => "Development",
+            ProcessCategory::Other => "Other",
+        }
+    }
+}
+
+/// Výsledok klasifikácie procesu
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ProcessClass {
+    pub category: ProcessCategory,  // Kategória (farba, typ prevádzky)
+    pub icon: &'static str,         // Ikona pred názvom procesu
+}
+
+/// Ikona procesov, ktoré nezodpovedajú žiadnemu vzoru
+const DEFAULT_ICON: &str = "📄";
+
+/// Časť názvu (malými písmenami) -> kategória a ikona
+///
+/// Vyhodnocuje sa zhora nadol a vyhráva prvá zhoda, preto konkrétnejšie
+/// vzory musia byť pred všeobecnejšími (napr. `update` až za aplikáciami,
+/// ktorých updater by inak dostal ikonu aktualizácií).
+/// Ikony sú jednoduché emodži so šírkou 2 stĺpce - sekvencie so spojovačom
+/// (ZWJ) alebo selektorom variantu majú v termináloch rôznu šírku
+/// a rozbíjajú zarovnanie tabuliek.
+const PROCESS_PATTERNS: &[(&str, ProcessCategory, &str)] = &[
+    // Prehliadače
+    ("chrome", ProcessCategory::Browser, "🌐"),
+    ("chromium", ProcessCategory::Browser, "🌐"),
+    ("firefox", ProcessCategory::Browser, "🦊"),
+    ("edge", ProcessCategory::Browser, "🧭"),
+    ("brave", ProcessCategory::Browser, "🦁"),
+    ("opera", ProcessCategory::Browser, "⭕"),
+    ("vivaldi", ProcessCategory::Browser, "🌐"),
+    ("safari", ProcessCategory::Browser, "🧭"),
+    // Komunikácia
+    ("discord", ProcessCategory::Communication, "💬"),
+    ("slack", ProcessCategory::Communication, "💼"),
+    ("teams", ProcessCategory::Communication, "👥"),
+    ("telegram", ProcessCategory::Communication, "📨"),
+    ("signal", ProcessCategory::Communication, "💬"),
+    ("skype", ProcessCategory::Communication, "📞"),
+    ("zoom", ProcessCategory::Communication, "📹"),
+    // Hry
+    ("steam", ProcessCategory::Gaming, "🎮"),
+    ("epicgames", ProcessCategory::Gaming, "🎮"),
+    ("battle.net", ProcessCategory::Gaming, "🎮"),
+    // Médiá
+    ("spotify", ProcessCategory::Media, "🎵"),
+    ("vlc", ProcessCategory::Media, "🎬"),
+    ("mpv", ProcessCategory::Media, "🎬"),
+    ("netflix", ProcessCategory::Media, "🎬"),
+    // P2P
+    ("torrent", ProcessCategory::P2P, "🌀"),
+    ("transmission", ProcessCategory::P2P, "🌀"),
+    // Vývoj
+    ("code", ProcessCategory::Development, "💻"),
+    ("idea", ProcessCategory::Development, "💻"),
+    ("cargo", ProcessCategory::Development, "🦀"),
+    ("rust-analyzer", ProcessCategory::Development, "🦀"),
+    // Aktualizácie
+    ("update", ProcessCategory::Updates, "🔄"),
+    ("windows", ProcessCategory::Updates, "🪟"),
+];
+
+/// Klasifikácia procesu podľa názvu (bez ohľadu na veľkosť písmen, aj pre Unicode názvy)
+pub fn classify_process(process_name: &str) -> ProcessClass {
+    let name_lower = process_name.to_lowercase();
+
+    PROCESS_PATTERNS
+        .iter()
+        .find(|(pattern, _, _)| name_lower.contains(pattern))
+        .map(|&(_, category, icon)| ProcessClass { category, icon })
+        .unwrap_or(ProcessClass { category: ProcessCategory::Other, icon: DEFAULT_ICON })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn known_process_names_map_to_their_category() {
+        assert_eq!(classify_process("brave").category, ProcessCategory::Browser);
+        assert_eq!(classify_process("Safari").category, ProcessCategory::Browser);
+        assert_eq!(classify_process("slack").category, ProcessCategory::Communication);
+        assert_eq!(classify_process("Microsoft Teams").category, ProcessCategory::Communication);
+        assert_eq!(classify_process("Spotify.exe").category, ProcessCategory::Media);
+        assert_eq!(classify_process("firefox").icon, "🦊");
+    }
+
+    #[test]
+    fn unknown_and_unicode_names_fall_back_to_other() {
+        let class = classify_process("Ärger-Prozess");
+        assert_eq!(class.category, ProcessCategory::Other);
+        assert_eq!(class.icon, DEFAULT_ICON);
+        assert_eq!(classify_process("").category, ProcessCategory::Other);
+    }
+
+    #[test]
+    fn first_matching_pattern_wins() {
+        // Updater aplikácie patrí k aplikácii, nie k aktualizáciám
+        assert_eq!(classify_process("SteamUpdate").category, ProcessCategory::Gaming);
+        assert_eq!(classify_process("softwareupdate").category, ProcessCategory::Updates);
+    }
+}
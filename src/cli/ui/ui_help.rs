@@ -4,7 +4,8 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, BorderType, Wrap},
     text::{Line, Span},
 };
-use crate::cli::app::TuiApp;
+use crate::cli::app::{TuiApp, Mode, keybindings_for, mode_name};
+use super::ui_widgets::key_legend_line;
 
 /// Render funkcia pre zobrazenie obrazovky pomoci
 /// Zobrazuje klávesové skratky a popis dostupných pohľadov
@@ -20,7 +21,7 @@ pub fn render(f: &mut Frame, _app: &mut TuiApp) {
         .border_style(Style::default().fg(Color::Cyan)); // Tyrkysová farba okrajov
 
     // Definovanie obsahu pomoci - zoznam riadkov
-    let help_content = vec![
+    let mut help_content = vec![
         // Nadpis sekcie klávesových skratiek
         Line::from(vec![
             Span::styled("Keyboard Shortcuts:", Style::default()
@@ -28,57 +29,19 @@ pub fn render(f: &mut Frame, _app: &mut TuiApp) {
                 .add_modifier(Modifier::BOLD)),        // Tučné písmo
         ]),
         Line::from(""), // Prázdny riadok
-        
-        // Skratka Q - ukončenie aplikácie
-        Line::from(vec![
-            Span::styled("[Q] ", Style::default().fg(Color::Red)), // Červené [Q]
-            Span::styled("Quit application", Style::default().fg(Color::White)),
-        ]),
-        
-        // Skratka H - zobrazenie/skrytie pomoci
-        Line::from(vec![
-            Span::styled("[H] ", Style::default().fg(Color::Yellow)), // Žlté [H]
-            Span::styled("Show/hide this help screen", Style::default().fg(Color::White)),
-        ]),
-        
-        // Skratka R - vynútené obnovenie dát
-        Line::from(vec![
-            Span::styled("[R] ", Style::default().fg(Color::Green)), // Zelené [R]
-            Span::styled("Force refresh data", Style::default().fg(Color::White)),
-        ]),
-        
-        // Skratka N - prepnutie na sieťový pohľad
-        Line::from(vec![
-            Span::styled("[N] ", Style::default().fg(Color::Blue)), // Modré [N]
-            Span::styled("Switch to Network view", Style::default().fg(Color::White)),
-        ]),
-        
-        // Skratka Tab - prepínanie medzi pohľadmi
-        Line::from(vec![
-            Span::styled("[Tab] ", Style::default().fg(Color::Magenta)), // Fialové [Tab]
-            Span::styled("Toggle between views", Style::default().fg(Color::White)),
-        ]),
-        
-        // Šípky hore/dole - navigácia v zozname procesov
-        Line::from(vec![
-            Span::styled("[↑↓] ", Style::default().fg(Color::Cyan)), // Tyrkysové šípky
-            Span::styled("Navigate process list", Style::default().fg(Color::White)),
-        ]),
-        
-        // Enter - zobrazenie detailov procesu
-        Line::from(vec![
-            Span::styled("[Enter] ", Style::default().fg(Color::Magenta)), // Fialový Enter
-            Span::styled("View process details", Style::default().fg(Color::White)),
-        ]),
-        
-        // Esc - návrat/ukončenie
-        Line::from(vec![
-            Span::styled("[Esc] ", Style::default().fg(Color::Red)), // Červený Esc
-            Span::styled("Go back/Exit", Style::default().fg(Color::White)),
-        ]),
-        
-        Line::from(""), // Prázdny riadok
-        
+    ];
+
+    // Skratky pre každú obrazovku - z rovnakej tabuľky ako päty
+    for mode in [Mode::Overview, Mode::NetworkView, Mode::ProcessTree, Mode::MemoryGrowth, Mode::Swap, Mode::ProcessDetail, Mode::About] {
+        help_content.push(Line::from(Span::styled(
+            format!("{}:", mode_name(mode)),
+            Style::default().fg(Color::Cyan),          // Tyrkysový názov obrazovky
+        )));
+        help_content.push(key_legend_line(keybindings_for(mode)));
+        help_content.push(Line::from(""));             // Prázdny riadok
+    }
+
+    help_content.extend([
         // Nadpis sekcie pohľadov
         Line::from(vec![
             Span::styled("Views:", Style::default()
@@ -91,12 +54,14 @@ pub fn render(f: &mut Frame, _app: &mut TuiApp) {
         Line::from("• Overview: System metrics and top processes"),
         Line::from("• Network: Bandwidth usage and network processes"),
         Line::from("• Process Details: Detailed info about selected process"),
+        Line::from("• Process Tree: Processes indented under their parents"),
+        Line::from("• Memory Growth: Processes whose memory keeps climbing (MB/min)"),
+        Line::from("• About: Hardware and OS summary (CPU, RAM, disk, GPU, uptime)"),
         Line::from(""), // Prázdny riadok
         
-        // Inštrukcia pre návrat
-        Line::from(Span::styled("Press [H] or [Esc] to go back",
-            Style::default().fg(Color::DarkGray))), // Tmavosivý text
-    ];
+        // Skratky samotnej obrazovky pomoci
+        key_legend_line(keybindings_for(Mode::Help)),
+    ]);
 
     // Vytvorenie odstavca (paragraph) s obsahom pomoci
     let paragraph = Paragraph::new(help_content)
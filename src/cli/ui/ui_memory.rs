@@ -0,0 +1,97 @@
+use ratatui::{
+    Frame,
+    layout::{Layout, Constraint, Direction, Rect},
+    style::{Style, Color, Modifier},
+    widgets::{Block, Borders, Paragraph, Table, Row, Cell, BorderType},
+};
+use crate::cli::app::TuiApp;
+use crate::models::name_or_pid;
+use crate::services::memory_growth::MEMORY_GROWTH_WINDOW;
+use super::ui_widgets::{truncate_str, render_key_legend, visible_row_count, scroll_offset, NumberFormat};
+
+/// Render funkcia pre zoznam procesov s rastúcou pamäťou
+/// Proces sa zobrazí, ak jeho pamäť počas posledných vzoriek nikdy neklesla
+pub fn render(f: &mut Frame, app: &mut TuiApp) {
+    let area = f.area();
+
+    // Rozdelenie na zoznam a pätu
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(5),      // Zoznam procesov
+            Constraint::Length(3),   // Päta
+        ])
+        .split(area);
+
+    render_growth_list(f, app, chunks[0]);
+    render_key_legend(f, chunks[1], app);  // Päta so skratkami
+}
+
+/// Vykreslenie tabuľky procesov s rastúcou pamäťou
+fn render_growth_list(f: &mut Frame, app: &TuiApp, area: Rect) {
+    let nf = NumberFormat::new(app.config.precision);  // Desatinné miesta podľa `--precision`
+    let block = Block::default()
+        .title(format!("📈 Memory Growth (last {} samples, {} growing)", MEMORY_GROWTH_WINDOW, app.memory_growth.len()))
+        .borders(Borders::ALL)                            // Všetky okraje
+        .border_type(BorderType::Rounded)                 // Okrúhle rohy
+        .border_style(Style::default().fg(Color::Blue));  // Modrá farba okrajov
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.memory_growth.is_empty() {
+        let message = format!(
+            "No process with steadily growing memory (each process needs {} samples first)",
+            MEMORY_GROWTH_WINDOW
+        );
+        let empty = Paragraph::new(message)
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(empty, inner_area);
+        return;
+    }
+
+    // Posun zobrazenia tak, aby bol vybraný riadok vždy viditeľný
+    let visible = visible_row_count(inner_area.height);
+    let selected = app.memory_growth_state.selected().unwrap_or(0);
+    let offset = scroll_offset(selected, visible);
+
+    let rows: Vec<Row> = app.memory_growth
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .map(|(i, growth)| {
+            let style = if i == selected {
+                Style::default().bg(Color::DarkGray).fg(Color::Yellow)  // Žltý text na šedom pozadí
+            } else {
+                Style::default()
+            };
+
+            Row::new(vec![
+                Cell::from(format!("{:>7}", growth.pid)).style(style),               // PID
+                Cell::from(truncate_str(&name_or_pid(growth.pid, &growth.name), 48)).style(style),            // Názov
+                Cell::from(format!("{:>8} MB", nf.number(growth.memory as f64 / 1024.0 / 1024.0, 1))).style(style),  // Pamäť
+                Cell::from(format!("{:>8} MB/min", nf.number(growth.growth_mb_per_min, 2)))
+                    .style(style.fg(Color::Red)),                                   // Rýchlosť rastu
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(8),    // PID
+        Constraint::Min(20),      // Názov
+        Constraint::Length(12),   // Pamäť
+        Constraint::Length(16),   // Rast
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(vec!["PID", "Process", "Memory", "Growth"])
+                .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                .bottom_margin(1),
+        )
+        .column_spacing(1);
+
+    f.render_widget(table, inner_area);
+}
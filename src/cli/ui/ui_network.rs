@@ -6,7 +6,11 @@ use ratatui::{
     text::{Line, Span},
 };
 use crate::cli::app::{TuiApp, Mode, NetworkConnection};
+use crate::services::connections::traffic_shares;
 use crate::models::ProcessInfo;
+use crate::services::link_speed::link_usage_percent;
+use super::ui_widgets::{truncate_str, render_key_legend, visible_row_count, scroll_offset, safe_percent, compute_bandwidth_scale, NumberFormat};
+use super::ui_categories::classify_process;
 
 /// Hlavná render funkcia pre sieťový pohľad
 /// Rozhoduje medzi prehľadom a detailným pohľadom procesu
@@ -45,11 +49,13 @@ fn render_network_overview(f: &mut Frame, app: &mut TuiApp) {
     render_bandwidth_usage(f, app, chunks[1]);         // Využitie šírky pásma
     render_network_totals(f, app, chunks[2]);         // Celkové štatistiky
     render_network_process_table(f, app, chunks[3]);  // Tabuľka procesov
-    render_network_footer(f, chunks[4]);              // Päta
+    render_key_legend(f, chunks[4], app);        // Päta so skratkami
 }
 
 /// Vykreslenie grafu využitia šírky pásma
 fn render_bandwidth_usage(f: &mut Frame, app: &TuiApp, area: Rect) {
+    let nf = NumberFormat::new(app.config.precision)  // Desatinné miesta podľa `--precision`
+        .with_rounding(app.config.percent_rounding);  // Zaokrúhlenie percent v gauge
     let block = Block::default()
         .title("📶 Bandwidth Usage")                    // Titulok s emodži
         .borders(Borders::ALL)                          // Všetky okraje
@@ -68,38 +74,41 @@ fn render_bandwidth_usage(f: &mut Frame, app: &TuiApp, area: Rect) {
         ])
         .split(inner_area);
     
-    // Aktuálne hodnoty odoslaných a prijatých dát
+    let unit = app.config.network_unit;  // KB/s alebo Mbps (`--network-unit`)
+    
+    // Aktuálne hodnoty odoslaných a prijatých dát (v KB/s)
     let current_sent = app.network_sent_history.last().copied().unwrap_or(0.0);
     let current_recv = app.network_recv_history.last().copied().unwrap_or(0.0);
     
-    // Historické maximá pre škálovanie
-    let max_historical_sent = app.network_sent_history.iter()
-        .copied()
-        .reduce(f64::max)
-        .unwrap_or(1.0);
-    let max_historical_recv = app.network_recv_history.iter()
-        .copied()
-        .reduce(f64::max)
-        .unwrap_or(1.0);
+    // Spoločná mierka odoslaných a prijatých dát (historické maximum, najmenej 100 KB/s)
+    let history = app.network_sent_history.iter().chain(&app.network_recv_history).copied();
+    let max_value = compute_bandwidth_scale(history, current_sent.max(current_recv));
     
-    // Celkové maximum pre škálovanie
-    let max_value = max_historical_sent.max(max_historical_recv).max(100.0).max(current_sent.max(current_recv));
+    // Pri známej rýchlosti linky sa škáluje voči nej, inak voči historickému maximu
+    let percent_of_scale = |value: f64| match app.link_speed_mbps {
+        Some(link_mbps) => link_usage_percent(value, link_mbps),
+        None => safe_percent(value, max_value),
+    };
+    let scale_label = match app.link_speed_mbps {
+        Some(link_mbps) => format!("{} Mbps link", link_mbps),
+        None => nf.format_network_rate(max_value, unit),
+    };
     
     // Popisky s aktuálnymi hodnotami
     let labels = Paragraph::new(vec![
         Line::from(vec![
             Span::styled("↑ Sent: ", Style::default().fg(Color::Red)),          // Červený odoslané
-            Span::styled(format!("{:.1} KB/s", current_sent), Style::default().fg(Color::White)),
+            Span::styled(nf.format_network_rate(current_sent, unit), Style::default().fg(Color::White)),
             Span::raw("   "),                                                   // Medzera
             Span::styled("↓ Received: ", Style::default().fg(Color::Green)),    // Zelené prijaté
-            Span::styled(format!("{:.1} KB/s", current_recv), Style::default().fg(Color::White)),
+            Span::styled(nf.format_network_rate(current_recv, unit), Style::default().fg(Color::White)),
         ]),
         Line::from(vec![
             Span::styled("Max: ", Style::default().fg(Color::Yellow)),         // Žlté maximum
-            Span::styled(format!("{:.1} KB/s", max_value), Style::default().fg(Color::White)),
+            Span::styled(nf.format_network_rate(max_value, unit), Style::default().fg(Color::White)),
             Span::raw("   "),                                                  // Medzera
             Span::styled("Scale: 0 - ", Style::default().fg(Color::DarkGray)), // Šedé mierka
-            Span::styled(format!("{:.0} KB/s", max_value), Style::default().fg(Color::White)),
+            Span::styled(scale_label, Style::default().fg(Color::White)),
         ]),
     ]);
     
@@ -115,20 +124,20 @@ fn render_bandwidth_usage(f: &mut Frame, app: &TuiApp, area: Rect) {
         .split(chunks[1]);
     
     // Gauge pre odoslané dáta
-    let sent_percent = (current_sent / max_value.max(1.0) * 100.0).min(100.0);
+    let sent_percent = percent_of_scale(current_sent);
     let sent_gauge = Gauge::default()
         .block(Block::default().borders(Borders::NONE))          // Bez okrajov
         .gauge_style(Style::default().fg(Color::Red).bg(Color::DarkGray)) // Červený na šedom
-        .percent(sent_percent as u16)                           // Percentuálne vyplnenie
-        .label(format!("↑ {:.1} KB/s", current_sent));          // Popisok s hodnotou
+        .percent(nf.gauge_percent(sent_percent))                 // Percentuálne vyplnenie
+        .label(format!("↑ {} ({})", nf.format_network_rate(current_sent, unit), nf.whole_percent(sent_percent)));  // Popisok s hodnotou
     
     // Gauge pre prijaté dáta
-    let recv_percent = (current_recv / max_value.max(1.0) * 100.0).min(100.0);
+    let recv_percent = percent_of_scale(current_recv);
     let recv_gauge = Gauge::default()
         .block(Block::default().borders(Borders::NONE))          // Bez okrajov
         .gauge_style(Style::default().fg(Color::Green).bg(Color::DarkGray)) // Zelený na šedom
-        .percent(recv_percent as u16)                           // Percentuálne vyplnenie
-        .label(format!("↓ {:.1} KB/s", current_recv));          // Popisok s hodnotou
+        .percent(nf.gauge_percent(recv_percent))                 // Percentuálne vyplnenie
+        .label(format!("↓ {} ({})", nf.format_network_rate(current_recv, unit), nf.whole_percent(recv_percent)));  // Popisok s hodnotou
     
     // Vykreslenie oboch grafov
     f.render_widget(sent_gauge, gauge_chunks[0]);
@@ -137,8 +146,10 @@ fn render_bandwidth_usage(f: &mut Frame, app: &TuiApp, area: Rect) {
 
 /// Vykreslenie tabuľky sieťových procesov
 fn render_network_process_table(f: &mut Frame, app: &mut TuiApp, area: Rect) {
+    let nf = NumberFormat::new(app.config.precision);  // Desatinné miesta podľa `--precision`
+    let unit = app.config.network_unit;  // KB/s alebo Mbps (`--network-unit`)
     let block = Block::default()
-        .title("🔥 Top Network Processes")                // Titulok
+        .title(format!("🔥 Top {} Network Processes (by {})", app.network_process_count, app.network_sort.label()))  // Titulok s počtom a poradím
         .borders(Borders::ALL)                           // Všetky okraje
         .border_type(BorderType::Rounded)                // Okrúhle rohy
         .border_style(Style::default().fg(Color::Yellow)); // Žltá farba okrajov
@@ -178,6 +189,9 @@ fn render_network_process_table(f: &mut Frame, app: &mut TuiApp, area: Rect) {
         return;
     }
     
+    // Úzky terminál alebo kláves W - odoslané/prijaté sa zlúčia do stĺpca Celkom
+    let layout = NetworkRowLayout::select(inner_area.width, app.compact_network);
+    
     // Vytvorenie riadkov tabuľky
     let rows: Vec<Row> = app.top_network_processes
         .iter()
@@ -191,79 +205,65 @@ fn render_network_process_table(f: &mut Frame, app: &mut TuiApp, area: Rect) {
                 Style::default()
             };
             
-            // Farba podľa typu procesu
-            let process_color = get_process_color(&proc.name);
-            let name_style = base_style.fg(process_color);
-            
-            // Ikona podľa typu procesu
-            let process_icon = get_process_icon(&proc.name);
-            let process_name = format!("{} {}", process_icon, truncate_name(&proc.name, 18));
-            
-            // Konverzia bajtov na KB/s
-            let sent_bytes = proc.network_sent.unwrap_or(0);
-            let recv_bytes = proc.network_recv.unwrap_or(0);
+            // Farba a ikona podľa typu procesu
+            let class = classify_process(&proc.name);
+            let name = proc.display_name();
+            let name_style = base_style.fg(class.category.color());
             
-            // Kontrola realistických hodnôt (ochrana proti chybným dátam)
-            let max_realistic = 100 * 1024 * 1024; // 100 MB/s
-            let sent_kbps = if sent_bytes > max_realistic {
-                println!("[UI WARN] Unrealistic sent value for {}: {} bytes", 
-                    proc.name, sent_bytes);
-                0.0  // Nulovanie nereálnych hodnôt
-            } else {
-                sent_bytes as f64 / 1024.0
-            };
-            
-            let recv_kbps = if recv_bytes > max_realistic {
-                println!("[UI WARN] Unrealistic recv value for {}: {} bytes", 
-                    proc.name, recv_bytes);
-                0.0  // Nulovanie nereálnych hodnôt
-            } else {
-                recv_bytes as f64 / 1024.0
-            };
+            // Konverzia bajtov na KB/s (nereálne hodnoty vynuloval už monitor cez `sanitize()`)
+            let sent_kbps = proc.network_sent.unwrap_or(0) as f64 / 1024.0;
+            let recv_kbps = proc.network_recv.unwrap_or(0) as f64 / 1024.0;
             
             let total_kbps = sent_kbps + recv_kbps;
             
-            // Počet aktívnych spojení pre proces
-            let connection_count = app.network_connections.iter()
-                .filter(|conn| conn.pid == proc.pid)
-                .count();
-            
-            // Formátovanie názvu s počtom spojení
-            let name_with_connections = if connection_count > 0 {
-                format!("{} ({})", truncate_name(&proc.name, 16), connection_count)
+            // Počet aktívnych spojení pre proces (bez spojení pomlčka)
+            let connection_count = app.connection_count(proc.pid);
+            let connections = if connection_count > 0 {
+                format!("{:>5}", connection_count)
             } else {
-                truncate_name(&proc.name, 20)
+                format!("{:>5}", "-")
             };
             
             // Vytvorenie riadku tabuľky
-            Row::new(vec![
+            let mut cells = vec![
                 Cell::from(format!("{:2}", i + 1)).style(base_style),                     // Poradové číslo
-                Cell::from(name_with_connections).style(name_style),                     // Názov procesu
-                Cell::from(format!("{:>7.1}", sent_kbps))                                // Odoslané KB/s
-                    .style(base_style.fg(Color::Red)),                                   // Červená farba
-                Cell::from(format!("{:>7.1}", recv_kbps))                                // Prijaté KB/s
-                    .style(base_style.fg(Color::Green)),                                 // Zelená farba
-                Cell::from(format!("{:>7.1}", total_kbps))                               // Celkom KB/s
-                    .style(base_style.fg(Color::Cyan)),                                  // Tyrkysová farba
-                Cell::from(get_traffic_bar(total_kbps as u64)).style(base_style),        // Grafický ukazovateľ
-            ])
+                Cell::from(format!("{} {}", class.icon, truncate_str(&name, 17))).style(name_style),  // Názov procesu
+                Cell::from(connections).style(base_style.fg(Color::Magenta)),            // Počet spojení
+            ];
+            if layout == NetworkRowLayout::Full {
+                cells.push(Cell::from(format!("{:>7}", nf.number(unit.convert_kbps(sent_kbps), 1)))    // Odoslané
+                    .style(base_style.fg(Color::Red)));                                  // Červená farba
+                cells.push(Cell::from(format!("{:>7}", nf.number(unit.convert_kbps(recv_kbps), 1)))    // Prijaté
+                    .style(base_style.fg(Color::Green)));                                // Zelená farba
+            }
+            cells.push(Cell::from(format!("{:>7}", nf.number(unit.convert_kbps(total_kbps), 1)))      // Celkom
+                .style(base_style.fg(Color::Cyan)));                                     // Tyrkysová farba
+            cells.push(Cell::from(get_traffic_bar(total_kbps as u64)).style(base_style)); // Grafický ukazovateľ
+            Row::new(cells)
         })
         .collect();
     
-    // Šírky stĺpcov
-    let widths = [
+    // Šírky stĺpcov a hlavička podľa rozloženia
+    let mut widths = vec![
         Constraint::Length(3),    // Poradové číslo
         Constraint::Length(22),   // Názov procesu
-        Constraint::Length(10),   // Odoslané
-        Constraint::Length(10),   // Prijaté
+        Constraint::Length(5),    // Spojenia
+    ];
+    let mut header = vec!["#".to_string(), "Process".to_string(), "Conns".to_string()];
+    if layout == NetworkRowLayout::Full {
+        widths.extend([Constraint::Length(10), Constraint::Length(10)]);  // Odoslané, prijaté
+        header.extend([format!("Sent {}", unit.label()), format!("Recv {}", unit.label())]);
+    }
+    widths.extend([
         Constraint::Length(10),   // Celkom
         Constraint::Min(10),      // Ukazovateľ (minimálne 10)
-    ];
+    ]);
+    header.extend([format!("Total {}", unit.label()), "Usage".to_string()]);
     
     // Vytvorenie tabuľky
     let table = Table::new(rows, widths)
         .header(
-            Row::new(vec!["#", "Process", "Sent KB/s", "Recv KB/s", "Total KB/s", "Usage"])
+            Row::new(header)
                 .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))  // Tyrkysový tučný hlavičok
                 .bottom_margin(1),  // Spodný okraj
         )
@@ -272,6 +272,28 @@ fn render_network_process_table(f: &mut Frame, app: &mut TuiApp, area: Rect) {
     f.render_widget(table, inner_area);
 }
 
+/// Najmenšia šírka tabuľky sieťových procesov pre všetky stĺpce
+/// (súčet šírok stĺpcov a medzier); užšia tabuľka sa zobrazí kompaktne
+const FULL_NETWORK_TABLE_WIDTH: u16 = 76;
+
+/// Rozloženie riadkov tabuľky sieťových procesov
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NetworkRowLayout {
+    Full,     // Odoslané, prijaté a celkom v samostatných stĺpcoch
+    Compact,  // Len celkom a ukazovateľ
+}
+
+impl NetworkRowLayout {
+    /// Výber rozloženia podľa šírky plochy tabuľky a prepínača (kláves W)
+    fn select(width: u16, compact: bool) -> Self {
+        if compact || width < FULL_NETWORK_TABLE_WIDTH {
+            NetworkRowLayout::Compact
+        } else {
+            NetworkRowLayout::Full
+        }
+    }
+}
+
 /// Vykreslenie detailného pohľadu na sieťovú aktivitu procesu
 fn render_network_process_detail(f: &mut Frame, app: &TuiApp, process_name: &str) {
     let area = f.area();
@@ -296,7 +318,7 @@ fn render_network_process_detail(f: &mut Frame, app: &TuiApp, process_name: &str
 
 /// Vykreslenie titulku detailného pohľadu
 fn render_detail_title(f: &mut Frame, process_name: &str, area: Rect) {
-    let title = format!("🔍 {} - NETWORK DETAILS", truncate_name(process_name, 30));
+    let title = format!("🔍 {} - NETWORK DETAILS", truncate_str(process_name, 30));
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
@@ -312,6 +334,7 @@ fn render_detail_title(f: &mut Frame, process_name: &str, area: Rect) {
 
 /// Vykreslenie informácií o procese v detailnom pohľade
 fn render_process_info(f: &mut Frame, app: &TuiApp, process_name: &str, area: Rect) {
+    let nf = NumberFormat::new(app.config.precision);  // Desatinné miesta podľa `--precision`
     // Nájdenie procesu podľa názvu
     let proc_info = app.top_network_processes.iter()
         .find(|p| p.name == process_name);
@@ -323,7 +346,7 @@ fn render_process_info(f: &mut Frame, app: &TuiApp, process_name: &str, area: Re
         let total_kb = sent_kb + recv_kb;
         
         // Získanie reálnych spojení pre proces
-        let real_connections: Vec<&NetworkConnection> = app.network_connections
+        let real_connections: Vec<&NetworkConnection> = app.network_connections.connections
             .iter()
             .filter(|conn| conn.pid == proc.pid)
             .collect();
@@ -335,28 +358,34 @@ fn render_process_info(f: &mut Frame, app: &TuiApp, process_name: &str, area: Re
             "No connections".to_string()
         };
         
+        let category = classify_process(&proc.name).category;
+        
         // Vytvorenie informačných riadkov
         let lines = vec![
             Line::from(vec![
                 Span::styled("• PID: ", Style::default().fg(Color::Yellow)),               // Žltý PID
                 Span::styled(format!("{}", proc.pid), Style::default().fg(Color::White)),
                 Span::styled("   • CPU: ", Style::default().fg(Color::Yellow)),           // Žltý CPU
-                Span::styled(format!("{:.1}%", proc.cpu_usage), Style::default().fg(Color::White)),
-                Span::styled("   • Memory: ", Style::default().fg(Color::Yellow)),        // Žltá pamäť
-                Span::styled(format!("{:.1} MB", proc.memory as f64 / 1024.0 / 1024.0), Style::default().fg(Color::White)),
+                Span::styled(nf.percent(proc.cpu_usage as f64, 1), Style::default().fg(Color::White)),
+                Span::styled(format!("   • {}: ", app.memory_metric.label()), Style::default().fg(Color::Yellow)),  // Žltá pamäť
+                Span::styled(format!("{} MB", nf.number(app.memory_metric.bytes(proc) as f64 / 1024.0 / 1024.0, 1)), Style::default().fg(Color::White)),
             ]),
             Line::from(vec![
                 Span::styled("• Sent: ", Style::default().fg(Color::Red)),                // Červené odoslané
-                Span::styled(format!("{:.1} KB/s", sent_kb), Style::default().fg(Color::White)),
+                Span::styled(nf.format_network_rate(sent_kb, app.config.network_unit), Style::default().fg(Color::White)),
                 Span::styled("   • Received: ", Style::default().fg(Color::Green)),      // Zelené prijaté
-                Span::styled(format!("{:.1} KB/s", recv_kb), Style::default().fg(Color::White)),
+                Span::styled(nf.format_network_rate(recv_kb, app.config.network_unit), Style::default().fg(Color::White)),
             ]),
             Line::from(vec![
                 Span::styled("• Total: ", Style::default().fg(Color::Cyan)),              // Tyrkysové celkom
-                Span::styled(format!("{:.1} KB/s", total_kb), Style::default().fg(Color::White)),
+                Span::styled(nf.format_network_rate(total_kb, app.config.network_unit), Style::default().fg(Color::White)),
                 Span::styled("   • Connections: ", Style::default().fg(Color::Yellow)),  // Žlté spojenia
                 Span::styled(connection_info, Style::default().fg(Color::White)),
             ]),
+            Line::from(vec![
+                Span::styled("• Type: ", Style::default().fg(Color::Magenta)),           // Fialový typ prevádzky
+                Span::styled(category.label(), Style::default().fg(category.color())),
+            ]),
         ];
         
         let info_block = Block::default()
@@ -374,27 +403,61 @@ fn render_process_info(f: &mut Frame, app: &TuiApp, process_name: &str, area: Re
     }
 }
 
+/// Počet spojení, ktoré sa zobrazia pri `visible` riadkoch tabuľky
+/// Ak sa všetky nezmestia, posledný riadok patrí ukazovateľu "+N more"
+fn shown_connection_rows(total: usize, visible: usize) -> usize {
+    if total > visible { visible.saturating_sub(1) } else { total }
+}
+
+/// Text ukazovateľa skrytých spojení (None, ak sa zmestia všetky)
+fn more_connections_text(total: usize, offset: usize, shown: usize) -> Option<String> {
+    let below = total.saturating_sub(offset + shown);
+    match (offset, below) {
+        (0, 0) => None,
+        (0, below) => Some(format!("+{} more (↑↓ to scroll)", below)),
+        (above, 0) => Some(format!("{} above (↑↓ to scroll)", above)),
+        (above, below) => Some(format!("{} above, +{} more (↑↓ to scroll)", above, below)),
+    }
+}
+
 /// Vykreslenie reálnych sieťových spojení procesu
+/// Spojení môže byť stovky - zobrazí sa len toľko, koľko sa zmestí do plochy,
+/// a zoznam sa posúva šípkami
 fn render_real_connections(f: &mut Frame, app: &TuiApp, process_name: &str, area: Rect) {
+    // Poznámka (napr. chýbajúce práva) sa zobrazí v titulku, nie ako riadok tabuľky
+    // Aktívny filter protokolu (kláves P) je v titulku vždy
+    let title = match &app.network_connections.note {
+        Some(note) => format!("🌐 Real Network Connections [{}] - {}", app.connection_filter.label(), note),
+        None => format!("🌐 Real Network Connections [{}]", app.connection_filter.label()),
+    };
+    
     let block = Block::default()
-        .title("🌐 Real Network Connections")  // Titulok s emodži
+        .title(title)  // Titulok s emodži
         .borders(Borders::ALL)
         .border_type(BorderType::Plain);      // Jednoduché okraje
     
     let inner_area = block.inner(area);
     f.render_widget(block, area);
     
-    // Nájdenie PID procesu
-    let pid = app.top_network_processes.iter()
-        .find(|p| p.name == process_name)
-        .map(|p| p.pid)
-        .unwrap_or(0);
+    // Nájdenie procesu (PID a celková prevádzka v KB/s)
+    let process = app.top_network_processes.iter()
+        .find(|p| p.name == process_name);
+    let pid = process.map(|p| p.pid).unwrap_or(0);
+    let total_kbps = process.map(|p| p.network_bytes() as f64 / 1024.0).unwrap_or(0.0);
     
-    // Filtrovanie spojení podľa PID
-    let connections: Vec<&NetworkConnection> = app.network_connections
-        .iter()
-        .filter(|conn| conn.pid == pid)
-        .collect();
+    // Filtrovanie spojení podľa PID a protokolu
+    let connections = app.detail_connections(pid);
+    
+    // Spojenia existujú, ale žiadne nezodpovedá filtru protokolu
+    if connections.is_empty() && app.connection_count(pid) > 0 {
+        let filtered = Paragraph::new(format!(
+            "No {} connections (P to change the filter)", app.connection_filter.label()
+        ))
+        .alignment(Alignment::Center);
+        
+        f.render_widget(filtered, inner_area);
+        return;
+    }
     
     // Ak nie sú žiadne spojenia
     if connections.is_empty() {
@@ -412,12 +475,27 @@ fn render_real_connections(f: &mut Frame, app: &TuiApp, process_name: &str, area
         return;
     }
     
+    // Rozdelenie prevádzky procesu medzi spojenia (podľa bajtov, inak rovnomerne)
+    let (shares, measured) = traffic_shares(&connections);
+    let nf = NumberFormat::new(app.config.precision);
+    
+    // Výrez spojení podľa výšky plochy a vybraného spojenia
+    let visible = visible_row_count(inner_area.height);
+    let shown = shown_connection_rows(connections.len(), visible);
+    let selected = app.connection_selected.min(connections.len() - 1);
+    let offset = scroll_offset(selected, shown.max(1));
+    
     // Vytvorenie riadkov tabuľky spojení
     let rows: Vec<Row> = connections.iter()
+        .zip(&shares)
         .enumerate()
-        .map(|(i, conn)| {
-            // Striedavé farby pozadia pre lepšiu čitateľnosť
-            let row_style = if i % 2 == 0 {
+        .skip(offset)
+        .take(shown)
+        .map(|(i, (conn, &share))| {
+            // Striedavé farby pozadia pre lepšiu čitateľnosť, vybrané spojenie obrátene
+            let row_style = if i == selected {
+                Style::default().add_modifier(Modifier::REVERSED)
+            } else if i % 2 == 0 {
                 Style::default().bg(Color::DarkGray)
             } else {
                 Style::default()
@@ -428,6 +506,8 @@ fn render_real_connections(f: &mut Frame, app: &TuiApp, process_name: &str, area
                 Cell::from(truncate_str(&conn.remote_address, 25)).style(row_style),     // Vzdialená adresa
                 Cell::from(truncate_str(&conn.protocol, 8)).style(row_style),            // Protokol
                 Cell::from(format!("{:12}", conn.state)).style(row_style),               // Stav spojenia
+                Cell::from(format!("{:>12} {:>4}%", nf.format_network_rate(total_kbps * share, app.config.network_unit), nf.number(share * 100.0, 0)))
+                    .style(row_style.fg(Color::Cyan)),                                   // Podiel na prevádzke
             ])
         })
         .collect();
@@ -438,17 +518,30 @@ fn render_real_connections(f: &mut Frame, app: &TuiApp, process_name: &str, area
         Constraint::Length(27),   // Vzdialená adresa
         Constraint::Length(10),   // Protokol
         Constraint::Length(14),   // Stav
+        Constraint::Min(18),      // Prevádzka
     ];
     
+    // Odhad pri rovnomernom rozdelení je označený v hlavičke
+    let traffic_header = if measured { "Traffic" } else { "Traffic (even split)" };
+    
     // Vytvorenie tabuľky
     let table = Table::new(rows, widths)
         .header(
-            Row::new(vec!["Local Address", "Remote Address", "Protocol", "State"])
+            Row::new(vec!["Local Address", "Remote Address", "Protocol", "State", traffic_header])
                 .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))  // Tyrkysový tučný hlavičok
+                .bottom_margin(1)
         )
         .column_spacing(1);  // Medzera medzi stĺpcami
     
     f.render_widget(table, inner_area);
+    
+    // Ukazovateľ skrytých spojení v poslednom riadku plochy
+    if let Some(text) = more_connections_text(connections.len(), offset, shown) {
+        let last_row = Rect { y: inner_area.bottom().saturating_sub(1), height: 1, ..inner_area };
+        let more = Paragraph::new(Line::from(Span::styled(text, Style::default().fg(Color::Yellow))))
+            .alignment(Alignment::Right);
+        f.render_widget(more, last_row);
+    }
 }
 
 /// Vykreslenie hlavného titulku sieťového pohľadu
@@ -464,19 +557,22 @@ fn render_network_title(f: &mut Frame, app: &TuiApp, area: Rect) {
 }
 
 /// Vykreslenie celkových štatistík siete
+/// Aktuálna rýchlosť, bajty za reláciu (od spustenia alebo vymazania histórie)
+/// a bajty rozhraní od štartu systému
 fn render_network_totals(f: &mut Frame, app: &TuiApp, area: Rect) {
-    let sent_kbps = app.network_sent_total;
-    let recv_kbps = app.network_recv_total;
-    let sent_mb = sent_kbps as f64 / 1024.0;
-    let recv_mb = recv_kbps as f64 / 1024.0;
+    let nf = NumberFormat::new(app.config.precision);  // Desatinné miesta podľa `--precision`
+    let (session_sent, session_recv) = app.network_session_totals();
+    let (boot_sent, boot_recv) = app.network_boot_totals;
     
     // Formátovanie textu s celkovými štatistikami
     let text = format!(
-        "📊 Network Totals: ↑ {:.1} KB/s ({:.1} MB total) | ↓ {:.1} KB/s ({:.1} MB total)",
-        sent_kbps,
-        sent_mb,
-        recv_kbps,
-        recv_mb
+        "📊 Rate: ↑ {} ↓ {} | Session: ↑ {} ↓ {} | Since boot: ↑ {} ↓ {}",
+        nf.format_network_rate(app.network_sent_rate, app.config.network_unit),
+        nf.format_network_rate(app.network_recv_rate, app.config.network_unit),
+        nf.format_bytes(session_sent),
+        nf.format_bytes(session_recv),
+        nf.format_bytes(boot_sent),
+        nf.format_bytes(boot_recv)
     );
     
     let block = Block::default()
@@ -491,28 +587,6 @@ fn render_network_totals(f: &mut Frame, app: &TuiApp, area: Rect) {
     f.render_widget(para, area);
 }
 
-/// Vykreslenie päty hlavného sieťového pohľadu
-fn render_network_footer(f: &mut Frame, area: Rect) {
-    let footer_text = vec![
-        Line::from(vec![
-            Span::styled("[Esc] ", Style::default().fg(Color::Yellow)),     // Žltý Esc
-            Span::styled("Back", Style::default().fg(Color::DarkGray)),
-            Span::styled("  [R] ", Style::default().fg(Color::Green)),      // Zelený R
-            Span::styled("Refresh", Style::default().fg(Color::DarkGray)),
-            Span::styled("  [Q] ", Style::default().fg(Color::Red)),        // Červený Q
-            Span::styled("Quit", Style::default().fg(Color::DarkGray)),
-            Span::styled("  [Enter] ", Style::default().fg(Color::Magenta)), // Fialový Enter
-            Span::styled("Details", Style::default().fg(Color::DarkGray)),
-        ])
-    ];
-    
-    let footer = Paragraph::new(footer_text)
-        .block(Block::default().borders(Borders::ALL).border_type(BorderType::Rounded))
-        .alignment(Alignment::Center);
-    
-    f.render_widget(footer, area);
-}
-
 /// Vykreslenie päty detailného pohľadu
 fn render_detail_footer(f: &mut Frame, area: Rect) {
     let footer = Paragraph::new("[Esc] Back to Network View")
@@ -523,81 +597,13 @@ fn render_detail_footer(f: &mut Frame, area: Rect) {
     f.render_widget(footer, area);
 }
 
-/// Pomocná funkcia - získanie farby podľa názvu procesu
-fn get_process_color(process_name: &str) -> Color {
-    let name_lower = process_name.to_lowercase();
-    
-    if name_lower.contains("chrome") || name_lower.contains("firefox") || name_lower.contains("edge") {
-        Color::Green      // Prehliadače - zelená
-    } else if name_lower.contains("steam") || name_lower.contains("discord") || name_lower.contains("zoom") {
-        Color::Blue       // Herné/komunikačné - modrá
-    } else if name_lower.contains("torrent") || name_lower.contains("bittorrent") {
-        Color::Red        // P2P - červená
-    } else if name_lower.contains("update") || name_lower.contains("windows") {
-        Color::Yellow     // Aktualizácie - žltá
-    } else if name_lower.contains("code") || name_lower.contains("vscode") {
-        Color::Magenta    // Vývojové prostredia - fialová
-    } else {
-        Color::Gray       // Ostatné - šedá
-    }
-}
-
-/// Pomocná funkcia - získanie ikony podľa názvu procesu
-fn get_process_icon(process_name: &str) -> &'static str {
-    let name_lower = process_name.to_lowercase();
-    
-    if name_lower.contains("chrome") {
-        "🌐"      // Chrome - zemeguľa
-    } else if name_lower.contains("firefox") {
-        "🦊"      // Firefox - líška
-    } else if name_lower.contains("edge") {
-        "🧭"      // Edge - kompas
-    } else if name_lower.contains("steam") {
-        "🎮"      // Steam - ovládač
-    } else if name_lower.contains("discord") {
-        "💬"      // Discord - rečňa
-    } else if name_lower.contains("zoom") {
-        "📹"      // Zoom - kamera
-    } else if name_lower.contains("torrent") {
-        "🌀"      // Torrent - vír
-    } else if name_lower.contains("code") {
-        "👨‍💻"     // VS Code - programátor
-    } else if name_lower.contains("windows") {
-        "🪟"      // Windows - okno
-    } else {
-        "📄"      // Ostatné - stránka
-    }
-}
-
-/// Pomocná funkcia - získanie typu sieťovej aktivity
-fn get_traffic_type(process_name: &str) -> &'static str {
-    let name_lower = process_name.to_lowercase();
-    
-    if name_lower.contains("chrome") || name_lower.contains("firefox") || name_lower.contains("edge") {
-        "Web Browsing"       // Prehliadanie webu
-    } else if name_lower.contains("steam") {
-        "Gaming"             // Hranie hier
-    } else if name_lower.contains("discord") || name_lower.contains("zoom") {
-        "Communication"      // Komunikácia
-    } else if name_lower.contains("torrent") {
-        "P2P"                // Peer-to-peer
-    } else if name_lower.contains("update") {
-        "Updates"            // Aktualizácie
-    } else if name_lower.contains("code") {
-        "Development"        // Vývoj
-    } else {
-        "Other"              // Ostatné
-    }
-}
-
 /// Pomocná funkcia - vytvorenie grafického ukazovateľa sieťovej aktivity
 fn get_traffic_bar(value: u64) -> String {
     let width = 15;          // Šírka ukazovateľa
     let max_value = 5000;    // Maximálna hodnota pre škálovanie
     
     // Výpočet vyplnených a prázdnych častí
-    let scaled_value = (value as f64 * width as f64 / max_value as f64) as usize;
-    let filled = scaled_value.min(width);
+    let filled = (safe_percent(value as f64, max_value as f64) / 100.0 * width as f64) as usize;
     let empty = width - filled;
     
     // Výber znaku podľa intenzity
@@ -612,16 +618,84 @@ fn get_traffic_bar(value: u64) -> String {
     filled_char.repeat(filled) + &" ".repeat(empty)
 }
 
-/// Pomocná funkcia - skrátenie dlhého názvu
-fn truncate_name(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
-        s.to_string()                          // Ak sa zmestí, ponechať
-    } else {
-        format!("{}...", &s[..max_len-3])      // Inak skrátiť a pridať "..."
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::TuiArgs;
+    use crate::services::connections::ConnectionsResult;
+    use crate::test_support::{sample_process, test_app};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn connection(pid: u32, port: u16) -> NetworkConnection {
+        NetworkConnection {
+            process_name: "nginx".to_string(),
+            local_address: format!("10.0.0.5:{}", port),
+            remote_address: "10.0.0.9:443".to_string(),
+            protocol: "TCP".to_string(),
+            state: "ESTABLISHED".to_string(),
+            pid,
+            bytes_sent: None,
+            bytes_received: None,
+        }
     }
-}
 
-/// Alias pre truncate_name (pre konzistentnosť)
-fn truncate_str(s: &str, max_len: usize) -> String {
-    truncate_name(s, max_len)
-}
\ No newline at end of file
+    #[test]
+    fn narrow_tables_switch_to_the_compact_layout() {
+        assert_eq!(NetworkRowLayout::select(120, false), NetworkRowLayout::Full);
+        assert_eq!(NetworkRowLayout::select(FULL_NETWORK_TABLE_WIDTH, false), NetworkRowLayout::Full);
+        assert_eq!(NetworkRowLayout::select(FULL_NETWORK_TABLE_WIDTH - 1, false), NetworkRowLayout::Compact);
+        assert_eq!(NetworkRowLayout::select(40, false), NetworkRowLayout::Compact);
+        assert_eq!(NetworkRowLayout::select(120, true), NetworkRowLayout::Compact);  // Prepínač W
+    }
+
+    #[test]
+    fn connection_rows_are_capped_to_the_visible_height() {
+        let visible = visible_row_count(12);
+        let shown = shown_connection_rows(300, visible);
+
+        assert_eq!(shown, visible - 1);  // Posledný riadok patrí ukazovateľu
+        assert_eq!(more_connections_text(300, 0, shown).as_deref(), Some("+291 more (↑↓ to scroll)"));
+        assert_eq!(more_connections_text(300, 291, shown).as_deref(), Some("291 above (↑↓ to scroll)"));
+        assert_eq!(more_connections_text(300, 10, shown).as_deref(), Some("10 above, +281 more (↑↓ to scroll)"));
+        assert_eq!(shown_connection_rows(5, visible), 5);
+        assert_eq!(more_connections_text(5, 0, 5), None);
+    }
+
+    #[test]
+    fn large_connection_list_renders_the_more_indicator() {
+        let mut app = test_app(TuiArgs::default());
+        app.top_network_processes = vec![sample_process(900, "nginx")];
+        app.network_connections = ConnectionsResult {
+            connections: (0..300).map(|i| connection(900, 40_000 + i)).collect(),
+            note: None,
+        };
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 14)).unwrap();
+        terminal.draw(|f| render_real_connections(f, &app, "nginx", f.area())).unwrap();
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect::<String>() + "\n")
+            .collect();
+
+        assert!(text.contains("+291 more"), "{text}");  // 12 riadkov: hlavička, medzera, 9 spojení, ukazovateľ
+        assert!(text.contains("10.0.0.5:40008"));
+        assert!(!text.contains("10.0.0.5:40009"));
+    }
+
+    #[test]
+    fn totals_line_labels_rates_per_second_and_totals_in_bytes() {
+        let mut app = test_app(TuiArgs { network_unit: crate::config::NetworkUnit::KBps, ..TuiArgs::default() });
+        app.network_sent_rate = 1000.0;
+        app.network_recv_rate = 500.0;
+        app.network_boot_totals = (4 * 1024 * 1024, 2048);
+
+        let mut terminal = Terminal::new(TestBackend::new(160, 3)).unwrap();
+        terminal.draw(|f| render_network_totals(f, &app, f.area())).unwrap();
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.width).map(|x| buffer[(x, 1)].symbol()).collect();
+
+        assert!(text.contains("Rate: ↑ 1000.0 KB/s ↓ 500.0 KB/s"), "{text}");
+        assert!(text.contains("Session: ↑ 0 B ↓ 0 B"), "{text}");  // Súčty bez „/s“
+        assert!(text.contains("Since boot: ↑ 4.0 MB ↓ 2.0 KB"), "{text}");
+    }
+}
@@ -1,12 +1,86 @@
 use ratatui::{
     Frame,
-    layout::{Layout, Constraint, Direction, Rect},
+    layout::{Alignment, Layout, Constraint, Direction, Rect},
     style::{Style, Color, Modifier},
-    widgets::{Block, Borders, Paragraph, Table, Row, Cell, BorderType, Gauge, Sparkline},
+    widgets::{block::Title, Block, Borders, Paragraph, Table, Row, Cell, BorderType, Gauge, Sparkline},
     text::{Line, Span},
 };
-use crate::cli::app::TuiApp;
-use super::ui_widgets::{truncate_str, get_process_bar};
+use crate::cli::app::{DbStatus, GaugeMetric, TuiApp};
+use crate::config::{GpuDisplay, GpuMemoryFormat};
+use crate::models::{GpuInfo, GpuSource, SystemMetrics, TemperatureInfo, TemperatureThresholds, TemperatureWarning, TemperatureMode};
+use super::ui_widgets::{truncate_str, fit_series, get_cpu_color, get_process_bar, render_key_legend, visible_row_count, scroll_offset, NumberFormat};
+use super::ui_categories::{classify_process, ProcessCategory};
+use crate::services::system_processes::SYSTEM_GROUP_PID;
+use std::borrow::Cow;
+
+/// Stav ukladania do databázy v titulku (None bez `--save-metrics`)
+/// Pripájanie beží na pozadí, zvyšok prehľadu naň nečaká
+fn db_status_span(status: &DbStatus) -> Option<Span<'static>> {
+    let (text, color) = match status {
+        DbStatus::Disabled => return None,
+        DbStatus::Connecting => (" | DB: connecting...".to_string(), Color::Yellow),
+        DbStatus::Connected => (" | DB: saving".to_string(), Color::Green),
+        DbStatus::Unavailable(_) => (" | database unavailable".to_string(), Color::Red),
+    };
+    Some(Span::styled(text, Style::default().fg(color)))
+}
+
+/// Stav batérie v titulku (None bez batérie)
+fn battery_span(percent: Option<f32>, charging: Option<bool>) -> Option<Span<'static>> {
+    let percent = percent?;
+    let state = match charging {
+        Some(true) => " charging",
+        _ => "",
+    };
+    let color = if charging == Some(true) {
+        Color::Green
+    } else if percent < 20.0 {
+        Color::Red     // Takmer vybitá
+    } else {
+        Color::White
+    };
+    Some(Span::styled(format!(" | 🔋 {:.0}%{}", percent, state), Style::default().fg(color)))
+}
+
+/// Varovanie pri nízkej dostupnej RAM v titulku (None bez `--memory-warn-available-mb`)
+fn low_memory_span(metrics: &SystemMetrics, threshold_mb: Option<u64>) -> Option<Span<'static>> {
+    let threshold_mb = threshold_mb.filter(|&mb| metrics.is_memory_low(mb))?;
+    Some(Span::styled(
+        format!(" | ⚠️ LOW MEMORY: {} MB available (< {} MB)", metrics.memory_available_mb(), threshold_mb),
+        Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+    ))
+}
+
+/// Text GPU pamäte podľa `--gpu-memory`
+/// Integrované GPU bez vlastnej pamäte (0 B celkom) nezobrazia nič
+fn gpu_memory_info(gpu: &GpuInfo, format: GpuMemoryFormat, nf: NumberFormat) -> Option<String> {
+    if gpu.memory_total == 0 {
+        return None;
+    }
+
+    Some(match format {
+        GpuMemoryFormat::Size => format!("{}/{}", nf.format_bytes(gpu.memory_used), nf.format_bytes(gpu.memory_total)),
+        GpuMemoryFormat::Percent => format!(
+            "{} mem",
+            nf.percent(gpu.memory_used as f64 / gpu.memory_total as f64 * 100.0, 0)
+        ),
+    })
+}
+
+/// Varovanie pri vysokej teplote v titulku (None pri normálnej a strednej úrovni)
+/// Úroveň určujú prahy `--temp-medium`, `--temp-high` a `--temp-critical`
+fn temperature_warning_span(metrics: &SystemMetrics, thresholds: &TemperatureThresholds) -> Option<Span<'static>> {
+    let (text, color) = match TemperatureInfo::from_metrics(metrics).get_warning_level_with(thresholds) {
+        TemperatureWarning::High => ("HIGH TEMPERATURE", Color::Red),
+        TemperatureWarning::Critical => ("CRITICAL TEMPERATURE", Color::Magenta),
+        _ => return None,
+    };
+    let max = metrics.max_temperature.unwrap_or(0.0);
+    Some(Span::styled(
+        format!(" | 🌡️ {}: {:.0}°C", text, max),
+        Style::default().fg(color).add_modifier(Modifier::BOLD),
+    ))
+}
 
 /// Hlavná render funkcia pre prehľadový pohľad systému
 /// Zobrazuje systémové metriky a zoznam procesov
@@ -19,7 +93,7 @@ pub fn render(f: &mut Frame, app: &mut TuiApp) {
         .margin(1)                               // Okraj 1 znak
         .constraints([
             Constraint::Length(3),   // Titulok
-            Constraint::Length(19),  // Metriky (zväčšené pre teploty)
+            Constraint::Length(metrics_height(show_temperatures(app), show_gpu(app))),  // Metriky (zväčšené pre teploty, bez GPU menšie)
             Constraint::Min(12),     // Procesy
             Constraint::Length(3),   // Päta
         ])
@@ -28,7 +102,7 @@ pub fn render(f: &mut Frame, app: &mut TuiApp) {
     render_title(f, app, chunks[0]);           // Vykreslenie titulku
     render_system_metrics(f, app, chunks[1]);  // Vykreslenie systémových metrík
     render_process_list(f, app, chunks[2]);    // Vykreslenie zoznamu procesov
-    render_footer(f, chunks[3]);               // Vykreslenie päty
+    render_key_legend(f, chunks[3], app); // Vykreslenie päty so skratkami
 }
 
 /// Vykreslenie titulku s informáciami o systéme
@@ -39,14 +113,24 @@ fn render_title(f: &mut Frame, app: &TuiApp, area: Rect) {
         .border_style(Style::default().fg(Color::LightBlue)); // Svetlomodrá farba okrajov
 
     // Vytvorenie titulkového obsahu
+    let mut header = vec![
+        Span::styled("🖥️  SYSTEM MONITOR ", Style::default()
+            .fg(Color::Cyan)                      // Tyrkysový text
+            .add_modifier(Modifier::BOLD)),       // Tučné písmo
+        Span::styled(format!("| {} @ {}", app.system_info.hostname, app.system_info.os_name),
+            Style::default().fg(Color::DarkGray)), // Šedý text
+    ];
+    if let Some(metrics) = &app.metrics {
+        header.extend(battery_span(metrics.battery_percent, metrics.battery_charging));  // Len notebooky
+        header.extend(low_memory_span(metrics, app.config.memory_warn_available_mb));
+        if show_temperatures(app) {
+            header.extend(temperature_warning_span(metrics, &app.temperature_thresholds));
+        }
+    }
+    header.extend(db_status_span(&app.db.status()));  // Stav DB pri `--save-metrics`
+
     let title_content = Paragraph::new(vec![
-        Line::from(vec![
-            Span::styled("🖥️  SYSTEM MONITOR ", Style::default()
-                .fg(Color::Cyan)                      // Tyrkysový text
-                .add_modifier(Modifier::BOLD)),       // Tučné písmo
-            Span::styled(format!("| {} @ {}", app.system_info.hostname, app.system_info.os_name),
-                Style::default().fg(Color::DarkGray)), // Šedý text
-        ]),
+        Line::from(header),
         Line::from(vec![
             Span::styled("CPU: ", Style::default().fg(Color::Yellow)), // Žltý "CPU:"
             Span::styled(truncate_str(&app.system_info.cpu_name, 40), Style::default().fg(Color::White)), // Biely názov CPU
@@ -63,106 +147,132 @@ fn render_title(f: &mut Frame, app: &TuiApp, area: Rect) {
 
 /// Vykreslenie systémových metrík (CPU, RAM, DISK, GPU, teploty)
 fn render_system_metrics(f: &mut Frame, app: &mut TuiApp, area: Rect) {
+    let show_temps = show_temperatures(app);  // Teploty vypnuté cez `--temperatures off`
+    let gpu_visible = show_gpu(app);          // Riadok GPU podľa `--show-gpu`
+    let nf = NumberFormat::new(app.config.precision)  // Desatinné miesta podľa `--precision`
+        .with_raw_bytes(app.raw_bytes)                // Presné bajty (kláves B)
+        .with_rounding(app.config.percent_rounding);  // Zaokrúhlenie percent v gauge
+    
     // Rozdelenie oblasti metrík na podoblasti
     let metric_chunks = Layout::default()
         .direction(Direction::Vertical)  // Vertikálne usporiadanie
         .margin(1)                       // Okraj
-        .constraints([
-            Constraint::Length(4),  // CPU
-            Constraint::Length(4),  // RAM
-            Constraint::Length(4),  // DISK
-            Constraint::Length(4),  // GPU
-            Constraint::Length(3),  // Teploty (NOVÝ RIADOK)
-        ])
+        .constraints(metric_row_heights(show_temps, gpu_visible).map(Constraint::Length))
         .split(area);
 
-    // Získanie metrík aplikácie
+    // Získanie metrík aplikácie a teplotných prahov
     let m = app.metrics.as_ref();
+    let thresholds = &app.temperature_colors;  // Farebné pásma (nie prahy varovania)
     
     // Výpočet percentuálneho využitia CPU
     let cpu_usage = m.map_or(0.0, |m| m.cpu_usage);
     
-    // Výpočet percentuálneho využitia RAM
-    let ram_percent = m.map_or(0.0, |m| (m.memory_used as f64 / m.memory_total as f64) * 100.0);
-    
-    // Výpočet percentuálneho využitia disku
-    let disk_percent = if let Some(m) = m {
-        if m.disk_total > 0 {
-            (m.disk_used as f64 / m.disk_total as f64) * 100.0
-        } else {
-            0.0
-        }
-    } else {
-        0.0
-    };
+    // Percentuálne využitie RAM, swapu a disku (0 pri nulovom celku)
+    let ram_percent = m.map_or(0.0, |m| m.memory_percent());
+    let swap_percent = m.map_or(0.0, |m| m.swap_percent());
+    let disk_percent = m.map_or(0.0, |m| m.disk_percent());
     
     // Získanie využitia GPU
     let gpu_percent = app.gpu_info.as_ref().map_or(0.0, |g| g.usage);
 
     // CPU s teplotou
     let cpu_temp = m.and_then(|m| m.cpu_temperature).unwrap_or(0.0);  // Teplota CPU
-    render_metric_with_chart(
-        f, metric_chunks[0],              // Plocha
-        "CPU", cpu_usage, &app.cpu_history,  // Názov, hodnota, história
-        get_temp_color(cpu_temp),          // Farba podľa teploty
-        &format!("{:.0}°C", cpu_temp)     // Dodatočné info
-    );
+    render_metric_with_chart(f, metric_chunks[0], MetricRow {
+        label: "CPU", value: cpu_usage, history: &app.cpu_history,  // Názov, hodnota, história
+        color: if show_temps { get_temp_color(cpu_temp, thresholds) } else { Color::Cyan },  // Farba podľa teploty
+        extra_info: if show_temps { format!("{:.0}°C", cpu_temp) } else { String::new() },  // Dodatočné info
+        chart_note: None,
+        peak: app.peaks.peak(GaugeMetric::Cpu),
+        flash: app.change_flash.is_flashing(GaugeMetric::Cpu),
+        fit: app.fit_sparklines,
+    }, nf);
 
     // RAM
-    let ram_used_gb = m.map_or(0.0, |m| m.memory_used as f64 / 1024.0 / 1024.0 / 1024.0);    // Použitá RAM v GB
-    let ram_total_gb = m.map_or(0.0, |m| m.memory_total as f64 / 1024.0 / 1024.0 / 1024.0);  // Celková RAM v GB
-    render_metric_with_chart(
-        f, metric_chunks[1],              // Plocha
-        "RAM", ram_percent, &app.ram_history,  // Názov, hodnota, história
-        Color::Green,                     // Zelená farba
-        &format!("{:.1}/{:.1}GB", ram_used_gb, ram_total_gb)  // Info o pamäti
-    );
+    let ram_used = m.map_or(0, |m| m.memory_used.max(0) as u64);    // Použitá RAM v bajtoch
+    let ram_total = m.map_or(0, |m| m.memory_total.max(0) as u64);  // Celková RAM v bajtoch
+    render_metric_with_chart(f, metric_chunks[1], MetricRow {
+        label: "RAM", value: ram_percent, history: &app.ram_history,  // Názov, hodnota, história
+        color: Color::Green,              // Zelená farba
+        extra_info: match m {
+            Some(m) if !m.is_memory_known() => "unavailable".to_string(),  // Sysinfo ani /proc/meminfo
+            _ => nf.format_bytes_ratio(ram_used, ram_total),               // Info o pamäti
+        },
+        chart_note: Some(format!("swap {}", nf.percent(swap_percent, 0))),  // Využitie swapu
+        peak: app.peaks.peak(GaugeMetric::Ram),
+        flash: app.change_flash.is_flashing(GaugeMetric::Ram),
+        fit: app.fit_sparklines,
+    }, nf);
 
     // DISK s teplotou
-    let disk_used_gb = m.map_or(0.0, |m| m.disk_used as f64 / 1024.0 / 1024.0 / 1024.0);    // Použitý disk v GB
-    let disk_total_gb = m.map_or(0.0, |m| m.disk_total as f64 / 1024.0 / 1024.0 / 1024.0);  // Celkový disk v GB
+    let disk_used = m.map_or(0, |m| m.disk_used.max(0) as u64);    // Použitý disk v bajtoch
+    let disk_total = m.map_or(0, |m| m.disk_total.max(0) as u64);  // Celkový disk v bajtoch
     let disk_temp = m.and_then(|m| m.disk_temperature).unwrap_or(0.0);  // Teplota disku
-    render_metric_with_chart(
-        f, metric_chunks[2],              // Plocha
-        "DISK", disk_percent, &app.disk_history,  // Názov, hodnota, história
-        get_temp_color(disk_temp),        // Farba podľa teploty
-        &format!("{:.1}/{:.1}GB | {:.0}°C", disk_used_gb, disk_total_gb, disk_temp)  // Info o disku a teplote
-    );
+    render_metric_with_chart(f, metric_chunks[2], MetricRow {
+        label: "DISK", value: disk_percent, history: &app.disk_history,  // Názov, hodnota, história
+        color: if show_temps { get_temp_color(disk_temp, thresholds) } else { Color::Blue },  // Farba podľa teploty
+        extra_info: if show_temps {
+            format!("{} | {:.0}°C", nf.format_bytes_ratio(disk_used, disk_total), disk_temp)  // Info o disku a teplote
+        } else {
+            nf.format_bytes_ratio(disk_used, disk_total)
+        },
+        chart_note: None,
+        peak: app.peaks.peak(GaugeMetric::Disk),
+        flash: app.change_flash.is_flashing(GaugeMetric::Disk),
+        fit: app.fit_sparklines,
+    }, nf);
 
     // GPU s teplotou
-    if let Some(gpu) = &app.gpu_info {
-        let gpu_mem_used_gb = gpu.memory_used as f64 / 1024.0 / 1024.0 / 1024.0;    // Použitá GPU pamäť v GB
-        let gpu_mem_total_gb = gpu.memory_total as f64 / 1024.0 / 1024.0 / 1024.0;  // Celková GPU pamäť v GB
+    if let Some(gpu) = app.gpu_info.as_ref().filter(|_| gpu_visible) {
         let gpu_temp = gpu.temperature.unwrap_or(0.0);  // Teplota GPU
         
-        render_metric_with_chart(
-            f, metric_chunks[3],              // Plocha
-            "GPU", gpu_percent, &app.gpu_history,  // Názov, hodnota, história
-            get_temp_color(gpu_temp),         // Farba podľa teploty
-            &format!("{:.1}/{:.1}GB | {:.0}°C", gpu_mem_used_gb, gpu_mem_total_gb, gpu_temp)  // Info o GPU
-        );
+        // Pamäť (ak ju GPU hlási) a teplota oddelené " | "
+        let extra_info = [
+            gpu_memory_info(gpu, app.config.gpu_memory, nf),
+            show_temps.then(|| format!("{:.0}°C", gpu_temp)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(" | ");
+        
+        render_metric_with_chart(f, metric_chunks[3], MetricRow {
+            label: "GPU", value: gpu_percent, history: &app.gpu_history,  // Názov, hodnota, história
+            color: if show_temps { get_temp_color(gpu_temp, thresholds) } else { Color::Magenta },  // Farba podľa teploty
+            extra_info,
+            chart_note: Some(gpu.source.label().to_string()),  // Simulované / reálne dáta
+            peak: app.peaks.peak(GaugeMetric::Gpu),
+            flash: app.change_flash.is_flashing(GaugeMetric::Gpu),
+            fit: app.fit_sparklines,
+        }, nf);
     }
 
-    // Zobrazenie dodatočných teplôt
-    if let Some(m) = m {
+    // Zobrazenie dodatočných teplôt (riadok chýba v režime `off`)
+    if let Some(m) = m.filter(|_| show_temps) {
         let mb_temp = m.motherboard_temperature.unwrap_or(0.0);  // Teplota základnej dosky
         let max_temp = m.max_temperature.unwrap_or(0.0);         // Maximálna teplota
         
-        render_temperature_summary(f, metric_chunks[4], mb_temp, max_temp);  // Zobrazenie súhrnu teplôt
+        render_temperature_summary(f, metric_chunks[4], mb_temp, max_temp, thresholds);  // Zobrazenie súhrnu teplôt
     }
 }
 
+/// Jedna metrika prehľadu - gauge s popisom a graf histórie
+struct MetricRow<'a> {
+    label: &'a str,               // Názov metriky
+    value: f64,                   // Aktuálna hodnota v percentách
+    history: &'a [u64],           // História hodnôt pre graf
+    color: Color,                 // Farba gauge aj grafu
+    extra_info: String,           // Text v gauge (veľkosť, teplota)
+    chart_note: Option<String>,   // Poznámka v titulku grafu, napr. "simulated"
+    peak: Option<f64>,            // Vrchol relácie vpravo v titulku grafu
+    flash: bool,                  // Výrazná zmena od posledného obnovenia
+    fit: bool,                    // Prispôsobiť históriu šírke grafu (kláves F)
+}
+
 /// NOVÁ FUNKCIA: Widget metriky s grafom
 /// Vytvára kombináciu grafu a gauge s históriou
-fn render_metric_with_chart(
-    f: &mut Frame,
-    area: Rect,
-    label: &str,
-    value: f64,
-    history: &[u64],
-    color: Color,
-    extra_info: &str
-) {
+fn render_metric_with_chart(f: &mut Frame, area: Rect, metric: MetricRow, nf: NumberFormat) {
+    let MetricRow { label, value, history, color, extra_info, chart_note, peak, flash, fit } = metric;
+
     // Rozdelenie oblasti na popisok a graf
     let inner_chunks = Layout::default()
         .direction(Direction::Horizontal)  // Horizontálne usporiadanie
@@ -172,18 +282,23 @@ fn render_metric_with_chart(
         ])
         .split(area);
 
-    // Vytvorenie popisku s percentami
-    let label_text = format!("{}: {:.0}%", label, value);
+    // Vytvorenie popisku s percentami (po výraznej zmene na jedno obnovenie zvýraznený)
+    let label_text = format!("{}: {}", label, nf.whole_percent(value));
+    let label_style = if flash {
+        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
+    } else {
+        Style::default()
+    };
     
     // Vytvorenie gauge (ukazovateľa)
     let gauge = Gauge::default()
         .gauge_style(Style::default().fg(color).bg(Color::DarkGray))  // Farba na šedom pozadí
         .label(extra_info)              // Dodatočné informácie
-        .percent(value.round() as u16); // Percentuálne vyplnenie
+        .percent(nf.gauge_percent(value)); // Percentuálne vyplnenie (0 - 100)
 
     // Blok pre gauge
     let gauge_block = Block::default()
-        .title(label_text)                     // Titulok s názvom metriky
+        .title(Span::styled(label_text, label_style))  // Titulok s názvom metriky
         .borders(Borders::ALL)                 // Všetky okraje
         .border_type(BorderType::Plain);       // Jednoduché okraje
 
@@ -191,18 +306,35 @@ fn render_metric_with_chart(
 
     // Kontrola, či existuje história
     if !history.is_empty() {
+        // Po zapnutí fitovania sa história roztiahne alebo zhustí na šírku grafu (bez okrajov)
+        let data = if fit {
+            fit_series(history, inner_chunks[1].width.saturating_sub(2) as usize)
+        } else {
+            history.to_vec()
+        };
+
         // Vytvorenie sparkline grafu (mini grafu)
         let sparkline = Sparkline::default()
-            .data(history)                            // Dáta histórie
+            .data(&data)                              // Dáta histórie
             .max(100)                                 // Maximálna hodnota
             .style(Style::default().fg(color))        // Farba grafu
             .bar_set(ratatui::symbols::bar::NINE_LEVELS);  // Štyl stĺpcov
 
-        // Blok pre graf
-        let chart_block = Block::default()
-            .title("History")                         // Titulok "History"
+        // Blok pre graf (s poznámkou, napr. pôvod dát GPU alebo swap pri RAM)
+        let chart_title = match chart_note {
+            Some(note) => format!("History ({})", note),
+            None => "History".to_string(),
+        };
+        let mut chart_block = Block::default()
+            .title(chart_title)                       // Titulok "History"
             .borders(Borders::ALL)                    // Všetky okraje
             .border_type(BorderType::Plain);          // Jednoduché okraje
+        if let Some(peak) = peak {
+            // Vrchol relácie vpravo (ostáva aj po vypadnutí z grafu, kláves C ho vymaže)
+            chart_block = chart_block.title(
+                Title::from(format!("peak {}", nf.percent(peak, 0))).alignment(Alignment::Right)
+            );
+        }
 
         f.render_widget(sparkline.block(chart_block), inner_chunks[1]);  // Vykreslenie grafu
     }
@@ -210,7 +342,7 @@ fn render_metric_with_chart(
 
 /// NOVÁ FUNKCIA: Súhrn teplôt
 /// Zobrazuje teplotu základnej dosky a maximálnu teplotu
-fn render_temperature_summary(f: &mut Frame, area: Rect, mb_temp: f64, max_temp: f64) {
+fn render_temperature_summary(f: &mut Frame, area: Rect, mb_temp: f64, max_temp: f64, thresholds: &TemperatureThresholds) {
     // Rozdelenie oblasti na dve časti
     let temp_chunks = Layout::default()
         .direction(Direction::Horizontal)  // Horizontálne usporiadanie
@@ -225,10 +357,10 @@ fn render_temperature_summary(f: &mut Frame, area: Rect, mb_temp: f64, max_temp:
         .title("Motherboard")                                 // Titulok "Motherboard"
         .borders(Borders::ALL)                               // Všetky okraje
         .border_type(BorderType::Plain)                      // Jednoduché okraje
-        .border_style(Style::default().fg(get_temp_color(mb_temp)));  // Farba okrajov podľa teploty
+        .border_style(Style::default().fg(get_temp_color(mb_temp, thresholds)));  // Farba okrajov podľa teploty
     
-    let mb_content = Paragraph::new(format!("{} {:.0}°C", get_temp_icon(mb_temp), mb_temp))
-        .style(Style::default().fg(get_temp_color(mb_temp)))  // Farba textu podľa teploty
+    let mb_content = Paragraph::new(format!("{} {:.0}°C", get_temp_icon(mb_temp, thresholds), mb_temp))
+        .style(Style::default().fg(get_temp_color(mb_temp, thresholds)))  // Farba textu podľa teploty
         .block(mb_block)                                      // Pridanie bloku
         .alignment(ratatui::layout::Alignment::Center);       // Zarovnanie na stred
 
@@ -237,10 +369,10 @@ fn render_temperature_summary(f: &mut Frame, area: Rect, mb_temp: f64, max_temp:
         .title("Max Temperature")                               // Titulok "Max Temperature"
         .borders(Borders::ALL)                                 // Všetky okraje
         .border_type(BorderType::Plain)                        // Jednoduché okraje
-        .border_style(Style::default().fg(get_temp_color(max_temp)));  // Farba okrajov podľa teploty
+        .border_style(Style::default().fg(get_temp_color(max_temp, thresholds)));  // Farba okrajov podľa teploty
     
-    let max_content = Paragraph::new(format!("{} {:.0}°C", get_temp_icon(max_temp), max_temp))
-        .style(Style::default().fg(get_temp_color(max_temp)))  // Farba textu podľa teploty
+    let max_content = Paragraph::new(format!("{} {:.0}°C", get_temp_icon(max_temp, thresholds), max_temp))
+        .style(Style::default().fg(get_temp_color(max_temp, thresholds)))  // Farba textu podľa teploty
         .block(max_block)                                      // Pridanie bloku
         .alignment(ratatui::layout::Alignment::Center);        // Zarovnanie na stred
 
@@ -248,32 +380,75 @@ fn render_temperature_summary(f: &mut Frame, area: Rect, mb_temp: f64, max_temp:
     f.render_widget(max_content, temp_chunks[1]); // Vykreslenie maximálnej teploty
 }
 
-/// Pomocné funkcie pre teploty
+// Pomocné funkcie pre teploty
+
+/// Zobrazujú sa teploty? (nie pri `--temperatures off`)
+fn show_temperatures(app: &TuiApp) -> bool {
+    app.config.temperatures != TemperatureMode::Off
+}
+
+/// Zobrazuje sa riadok GPU? (`--show-gpu`; v režime `auto` len pri reálnej GPU)
+fn show_gpu(app: &TuiApp) -> bool {
+    match app.config.show_gpu {
+        GpuDisplay::Always => true,
+        GpuDisplay::Never => false,
+        GpuDisplay::Auto => app.gpu_info.as_ref().is_some_and(|gpu| gpu.source == GpuSource::Real),
+    }
+}
+
+/// Výšky riadkov CPU, RAM, DISK, GPU a teplôt v oblasti metrík
+/// GPU je skryté bez reálnej GPU / pri `never`, teploty v režime `off`
+fn metric_row_heights(show_temps: bool, gpu_visible: bool) -> [u16; 5] {
+    [4, 4, 4, if gpu_visible { 4 } else { 0 }, if show_temps { 3 } else { 0 }]
+}
+
+/// Výška oblasti metrík - riadok teplôt pridáva 3, chýbajúci riadok GPU uberá 4
+fn metrics_height(show_temps: bool, gpu_visible: bool) -> u16 {
+    let temperatures = if show_temps { 3 } else { 0 };
+    let gpu = if gpu_visible { 0 } else { 4 };
+    16 + temperatures - gpu
+}
 
-/// Určenie farby podľa teploty
-fn get_temp_color(temp: f64) -> Color {
-    match temp {
-        t if t < 50.0 => Color::Green,     // Zelená - bezpečná teplota
-        t if t < 70.0 => Color::Yellow,    // Žltá - stredná teplota
-        t if t < 85.0 => Color::Red,       // Červená - vysoká teplota
-        _ => Color::Magenta,               // Fialová - kritická teplota
+/// Určenie farby podľa teploty a farebných pásiem (`--temp-colors`)
+fn get_temp_color(temp: f64, thresholds: &TemperatureThresholds) -> Color {
+    match thresholds.classify(temp) {
+        TemperatureWarning::Normal => Color::Green,     // Zelená - bezpečná teplota
+        TemperatureWarning::Medium => Color::Yellow,    // Žltá - stredná teplota
+        TemperatureWarning::High => Color::Red,         // Červená - vysoká teplota
+        TemperatureWarning::Critical => Color::Magenta, // Fialová - kritická teplota
+        TemperatureWarning::Unknown => Color::Gray,     // Šedá - neznámy stav
     }
 }
 
-/// Určenie ikony podľa teploty
-fn get_temp_icon(temp: f64) -> &'static str {
-    match temp {
-        t if t < 50.0 => "🟢",  // Zelený kruh - bezpečná
-        t if t < 70.0 => "🟡",  // Žltý kruh - varovanie
-        t if t < 85.0 => "🔴",  // Červený kruh - nebezpečná
-        _ => "🔥",              // Oheň - kritická
+/// Určenie ikony podľa teploty a farebných pásiem (`--temp-colors`)
+fn get_temp_icon(temp: f64, thresholds: &TemperatureThresholds) -> &'static str {
+    match thresholds.classify(temp) {
+        TemperatureWarning::Normal => "🟢",    // Zelený kruh - bezpečná
+        TemperatureWarning::Medium => "🟡",    // Žltý kruh - varovanie
+        TemperatureWarning::High => "🔴",      // Červený kruh - nebezpečná
+        TemperatureWarning::Critical => "🔥",  // Oheň - kritická
+        TemperatureWarning::Unknown => "⚪",   // Biely kruh - neznáma
     }
 }
 
 /// Vykreslenie zoznamu procesov
 fn render_process_list(f: &mut Frame, app: &mut TuiApp, area: Rect) {
+    let nf = NumberFormat::new(app.config.precision);  // Desatinné miesta podľa `--precision`
+    // Pri `--watch-pid` titulok zobrazuje súhrn celého stromu
+    let title = match (app.config.watch_pid, &app.watched_tree) {
+        (Some(_), Some(tree)) => format!(
+            "👁️  Watching PID {} | {} procs | CPU {} | MEM {} MB",
+            tree.root_pid,
+            tree.processes.len(),
+            nf.percent(tree.total_cpu as f64, 1),
+            nf.number(tree.total_memory as f64 / 1024.0 / 1024.0, 1)
+        ),
+        (Some(pid), None) => format!("👁️  Watching PID {} | process ended", pid),
+        (None, _) => "🔥 Top Processes".to_string(),
+    };
+
     let block = Block::default()
-        .title("🔥 Top Processes")                // Titulok s emodži
+        .title(title)                            // Titulok s emodži
         .borders(Borders::ALL)                   // Všetky okraje
         .border_type(BorderType::Rounded)        // Okrúhle rohy
         .border_style(Style::default().fg(Color::Yellow));  // Žltá farba okrajov
@@ -283,16 +458,33 @@ fn render_process_list(f: &mut Frame, app: &mut TuiApp, area: Rect) {
 
     // Kontrola prázdneho zoznamu procesov
     if app.top_processes.is_empty() {
-        let no_processes = Paragraph::new("No processes found")  // Správa "Žiadne procesy"
+        let message = if app.config.watch_pid.is_some() {
+            "Process ended"        // Sledovaný proces už neexistuje
+        } else {
+            "No processes found"   // Správa "Žiadne procesy"
+        };
+        let no_processes = Paragraph::new(message)
             .alignment(ratatui::layout::Alignment::Center);
         f.render_widget(no_processes, inner_area);
         return;
     }
 
+    // Len riadky, ktoré sa zmestia do plochy - zvyšok je dostupný posunom
+    let visible = visible_row_count(inner_area.height);
+    // Šírka stĺpca názvu - príkazový riadok (`--show-cmd`) dostane viac miesta
+    let name_width: usize = if app.config.show_cmd { 42 } else { 22 };
+    let offset = scroll_offset(app.process_list_state.selected().unwrap_or(0), visible);
+
+    // Rozbalené systémové procesy nasledujú hneď za súhrnným riadkom
+    let group_index = app.top_processes.iter().position(|proc| proc.pid == SYSTEM_GROUP_PID);
+    let is_group_member = |i: usize| group_index.is_some_and(|group| i > group && i <= group + app.system_members_shown);
+
     // Vytvorenie riadkov tabuľky procesov
     let rows: Vec<Row> = app.top_processes
         .iter()
         .enumerate()
+        .skip(offset)
+        .take(visible)
         .map(|(i, proc)| {
             // Kontrola výberu riadku
             let is_selected = app.process_list_state.selected() == Some(i);
@@ -302,12 +494,40 @@ fn render_process_list(f: &mut Frame, app: &mut TuiApp, area: Rect) {
                 Style::default()
             };
 
+            // Ikona a farba názvu podľa typu procesu (vybraný a nezaradený ostávajú bez zmeny)
+            let class = classify_process(&proc.name);
+            let name_style = match class.category {
+                _ if is_selected => style,
+                ProcessCategory::Other => style,
+                category => style.fg(category.color()),
+            };
+            let cpu_style = if is_selected { style } else { style.fg(get_cpu_color(proc.cpu_usage as f64, &app.cpu_thresholds)) };
+
+            // Súhrnný riadok so šípkou rozbalenia, rozbalené procesy odsadené
+            // S `--show-cmd` sa namiesto názvu zobrazí príkazový riadok (ak je známy)
+            let display_name = match proc.command_line() {
+                Some(cmd) if app.config.show_cmd => Cow::Owned(cmd),
+                _ => proc.display_name(),
+            };
+            let name = if proc.pid == SYSTEM_GROUP_PID {
+                let arrow = if app.system_group_expanded { "▾" } else { "▸" };
+                format!("{} {}", arrow, display_name)
+            } else if is_group_member(i) {
+                format!("└ {} {}", class.icon, truncate_str(&display_name, name_width - 7))
+            } else {
+                format!("{} {}", class.icon, truncate_str(&display_name, name_width - 5))
+            };
+
+            // Značka výberu pre hromadné akcie (medzerník / X)
+            let mark = if app.selected_pids.contains(&proc.pid) { "✓" } else { " " };
+
             // Vytvorenie riadku s informáciami o procese
             Row::new(vec![
+                Cell::from(mark).style(style.fg(Color::Green)),     // Vybraný proces
                 Cell::from(format!("{:3}", i + 1)).style(style),  // Poradové číslo
-                Cell::from(truncate_str(&proc.name, 20)).style(style),  // Názov procesu (skrátený)
-                Cell::from(format!("{:5.1}%", proc.cpu_usage)).style(style),  // Využitie CPU
-                Cell::from(format!("{:6.1} MB", proc.memory as f64 / 1024.0 / 1024.0)).style(style),  // Pamäť
+                Cell::from(name).style(name_style),  // Ikona a názov procesu (skrátený)
+                Cell::from(format!("{:>6}", nf.percent(proc.cpu_usage as f64, 1))).style(cpu_style),  // Využitie CPU (farba podľa prahov)
+                Cell::from(format!("{:>6} MB", nf.number(app.memory_metric.bytes(proc) as f64 / 1024.0 / 1024.0, 1))).style(style),  // Pamäť (RSS / virtuálna)
                 Cell::from(get_process_bar(proc.cpu_usage as u8)).style(style),  // Grafický ukazovateľ
             ])
         })
@@ -315,17 +535,18 @@ fn render_process_list(f: &mut Frame, app: &mut TuiApp, area: Rect) {
 
     // Šírky stĺpcov tabuľky
     let widths = [
+        Constraint::Length(1),    // Značka výberu
         Constraint::Length(4),    // Poradové číslo
-        Constraint::Length(22),   // Názov procesu
+        Constraint::Length(name_width as u16),  // Názov procesu (alebo príkaz)
         Constraint::Length(8),    // CPU
-        Constraint::Length(10),   // Pamäť
+        Constraint::Length(11),   // Pamäť
         Constraint::Min(10),      // Grafický ukazovateľ
     ];
 
     // Vytvorenie tabuľky
     let table = Table::new(rows, widths)
         .header(
-            Row::new(vec!["#", "Process", "CPU", "Memory", "Usage"])  // Hlavička tabuľky
+            Row::new(vec!["", "#", "Process", "CPU", app.memory_metric.label(), "Usage"])  // Hlavička tabuľky
                 .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))  // Tyrkysová tučná
                 .bottom_margin(1),  // Spodný okraj hlavičky
         )
@@ -334,30 +555,147 @@ fn render_process_list(f: &mut Frame, app: &mut TuiApp, area: Rect) {
     f.render_widget(table, inner_area);  // Vykreslenie tabuľky
 }
 
-/// Vykreslenie päty s klávesovými skratkami
-fn render_footer(f: &mut Frame, area: Rect) {
-    let footer_text = vec![
-        Line::from(vec![
-            Span::styled("[H] ", Style::default().fg(Color::Yellow)),      // Žltý H
-            Span::styled("Help", Style::default().fg(Color::DarkGray)),    // Šedá nápoveda
-            Span::styled("  [R] ", Style::default().fg(Color::Green)),     // Zelený R
-            Span::styled("Refresh", Style::default().fg(Color::DarkGray)), // Šedé obnovenie
-            Span::styled("  [Q] ", Style::default().fg(Color::Red)),       // Červený Q
-            Span::styled("Quit", Style::default().fg(Color::DarkGray)),    // Šedé ukončenie
-            Span::styled("  [N] ", Style::default().fg(Color::Blue)),      // Modrý N
-            Span::styled("Network", Style::default().fg(Color::DarkGray)), // Šedá sieť
-            Span::styled("  [↑↓] ", Style::default().fg(Color::Cyan)),     // Tyrkysové šípky
-            Span::styled("Navigate", Style::default().fg(Color::DarkGray)), // Šedá navigácia
-            Span::styled("  [Enter] ", Style::default().fg(Color::Magenta)), // Fialový Enter
-            Span::styled("Details", Style::default().fg(Color::DarkGray)), // Šedé detaily
-        ])
-    ];
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_color_bands_match_the_original_colors() {
+        let bands = TemperatureThresholds::color_bands();
+        assert_eq!(get_temp_color(45.0, &bands), Color::Green);
+        assert_eq!(get_temp_color(60.0, &bands), Color::Yellow);
+        assert_eq!(get_temp_color(80.0, &bands), Color::Red);
+        assert_eq!(get_temp_color(90.0, &bands), Color::Magenta);
+    }
+
+    #[test]
+    fn custom_color_bands_reclassify_a_temperature() {
+        let bands = TemperatureThresholds::new(70.0, 85.0, 95.0).unwrap();
+        assert_eq!(get_temp_color(60.0, &bands), Color::Green);
+        assert_eq!(get_temp_icon(60.0, &bands), "🟢");
+    }
+
+    #[test]
+    fn temperature_row_is_omitted_in_off_mode() {
+        assert_eq!(metric_row_heights(false, true)[4], 0);
+        assert_eq!(metric_row_heights(true, true)[4], 3);
+        assert_eq!(metrics_height(false, true), metrics_height(true, true) - 3);
+    }
+
+    fn gpu(memory_used: u64, memory_total: u64) -> GpuInfo {
+        GpuInfo {
+            name: "Test GPU".to_string(),
+            usage: 10.0,
+            memory_total,
+            memory_used,
+            temperature: None,
+            source: GpuSource::Real,
+        }
+    }
+
+    #[test]
+    fn zero_gpu_memory_total_omits_the_memory_text() {
+        let nf = NumberFormat::new(None);
+        assert_eq!(gpu_memory_info(&gpu(0, 0), GpuMemoryFormat::Size, nf), None);
+        assert_eq!(gpu_memory_info(&gpu(512, 0), GpuMemoryFormat::Percent, nf), None);
+    }
+
+    #[test]
+    fn gpu_memory_as_size_or_percent() {
+        let nf = NumberFormat::new(None);
+        let gpu = gpu(2 * 1024 * 1024 * 1024, 8 * 1024 * 1024 * 1024);
+
+        assert_eq!(gpu_memory_info(&gpu, GpuMemoryFormat::Size, nf).as_deref(), Some("2.0 GB/8.0 GB"));
+        assert_eq!(gpu_memory_info(&gpu, GpuMemoryFormat::Percent, nf).as_deref(), Some("25% mem"));
+    }
+
+    /// Text vykreslenej obrazovky prehľadu
+    fn rendered_text(app: &mut TuiApp) -> String {
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(160, 50)).unwrap();
+        terminal.draw(|f| render(f, app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect::<String>() + "\n")
+            .collect()
+    }
+
+    #[test]
+    fn overview_works_while_the_database_is_pending_or_failed() {
+        let mut app = crate::test_support::test_app(crate::config::TuiArgs::default());
+        app.db = crate::cli::app::DbState::new(true);  // Pripájanie ešte prebieha
+
+        app.update();
+        assert!(app.metrics.is_some());
+        let pending = rendered_text(&mut app);
+        assert!(pending.contains("DB: connecting..."), "{pending}");
+        assert!(pending.contains("CPU"));
+
+        app.db.set(DbStatus::Unavailable("connection refused".to_string()));
+        app.update();
+        assert!(rendered_text(&mut app).contains("database unavailable"));
+        assert!(app.metrics.is_some());
+    }
+
+    #[test]
+    fn raw_bytes_toggle_flips_the_memory_readout() {
+        let mut app = crate::test_support::test_app(crate::config::TuiArgs::default());
+        app.metrics = Some(crate::test_support::sample_metrics(chrono::Utc::now(), 10.0));
+
+        let formatted = rendered_text(&mut app);
+        app.toggle_raw_bytes();
+        let raw = rendered_text(&mut app);
+
+        assert!(formatted.contains("6.0/16.0GB"), "{formatted}");
+        assert!(raw.contains("6442450944"), "{raw}");  // Úzky gauge ukáže začiatok `6442450944/17179869184 B`
+        assert!(!raw.contains("6.0/16.0GB"));
+    }
 
-    let footer = Paragraph::new(footer_text)
-        .block(Block::default()
-            .borders(Borders::ALL)                      // Všetky okraje
-            .border_type(BorderType::Rounded))          // Okrúhle rohy
-        .alignment(ratatui::layout::Alignment::Center); // Zarovnanie na stred
+    #[test]
+    fn low_memory_warning_appears_only_below_the_threshold() {
+        let metrics = crate::test_support::sample_metrics(chrono::Utc::now(), 10.0);  // 10 GB dostupných
 
-    f.render_widget(footer, area);  // Vykreslenie päty
-}
\ No newline at end of file
+        let span = low_memory_span(&metrics, Some(12_000)).expect("10240 MB < 12000 MB");
+        assert!(span.content.contains("10240 MB available (< 12000 MB)"), "{}", span.content);
+        assert!(low_memory_span(&metrics, Some(8_000)).is_none());
+        assert!(low_memory_span(&metrics, None).is_none());
+    }
+
+    #[test]
+    fn zero_total_memory_is_shown_as_unavailable() {
+        let mut app = crate::test_support::test_app(crate::config::TuiArgs::default());
+        app.metrics = Some(SystemMetrics::builder(chrono::Utc::now()).memory(0, 0, 0).build());
+
+        let text = rendered_text(&mut app);
+        assert!(text.contains("RAM: 0%"), "{text}");
+        assert!(text.contains("unavailabl"), "{text}");  // Úzky gauge oreže posledné písmeno
+        assert!(!text.contains("NaN"), "{text}");
+        assert_eq!(app.metrics.as_ref().unwrap().memory_percent(), 0.0);
+    }
+
+    #[test]
+    fn hidden_gpu_row_takes_no_space() {
+        for show_temps in [true, false] {
+            let heights = metric_row_heights(show_temps, false);
+            assert_eq!(heights[3], 0);
+            assert_eq!(heights.iter().sum::<u16>(), metrics_height(show_temps, false));
+            assert_eq!(metric_row_heights(show_temps, true).iter().sum::<u16>(), metrics_height(show_temps, true));
+        }
+    }
+
+    #[test]
+    fn show_gpu_never_renders_the_other_metrics_without_a_gpu_row() {
+        let args = crate::config::TuiArgs { show_gpu: GpuDisplay::Never, ..crate::config::TuiArgs::default() };
+        let mut app = crate::test_support::test_app(args);
+        app.update();
+        assert!(app.gpu_info.is_some());
+
+        let text = rendered_text(&mut app);
+        assert!(!text.contains("GPU: "), "{text}");
+        for label in ["CPU: ", "RAM: ", "DISK: "] {
+            assert!(text.contains(label), "{label} missing in {text}");
+        }
+
+        app.config.show_gpu = GpuDisplay::Always;
+        assert!(rendered_text(&mut app).contains("GPU: "));
+    }
+}
@@ -0,0 +1,88 @@
+use ratatui::{
+    Frame,
+    layout::{Layout, Constraint, Direction, Rect},
+    style::{Style, Color, Modifier},
+    widgets::{Block, Borders, BorderType, Clear, Paragraph},
+    text::{Line, Span},
+};
+use crate::cli::app::TuiApp;
+
+/// Render funkcia pre paletu príkazov
+/// Vykresľuje sa ako vycentrované okno nad aktuálnou obrazovkou
+pub fn render(f: &mut Frame, app: &mut TuiApp) {
+    let area = centered_rect(50, 12, f.area());  // 50% šírky, 12 riadkov
+
+    let block = Block::default()
+        .title("⌨️  Command Palette")                      // Titulok s emodži
+        .borders(Borders::ALL)                             // Všetky okraje
+        .border_type(BorderType::Rounded)                  // Okrúhle rohy
+        .border_style(Style::default().fg(Color::Magenta)); // Fialová farba okrajov
+
+    let inner_area = block.inner(area);
+    f.render_widget(Clear, area);   // Vyčistenie plochy pod oknom
+    f.render_widget(block, area);
+
+    // Rozdelenie na riadok vyhľadávania a zoznam akcií
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(2),  // Vyhľadávanie
+            Constraint::Min(1),     // Zoznam akcií
+        ])
+        .split(inner_area);
+
+    // Riadok s dotazom
+    let query = Paragraph::new(Line::from(vec![
+        Span::styled("> ", Style::default().fg(Color::Yellow)),    // Žltý prompt
+        Span::styled(app.palette.query.as_str(), Style::default().fg(Color::White)),
+        Span::styled("_", Style::default().fg(Color::DarkGray)),   // Kurzor
+    ]));
+    f.render_widget(query, chunks[0]);
+
+    // Zoznam zodpovedajúcich akcií
+    let matches = app.palette.matches();
+    let lines: Vec<Line> = if matches.is_empty() {
+        vec![Line::from(Span::styled("No matching actions", Style::default().fg(Color::DarkGray)))]
+    } else {
+        matches
+            .iter()
+            .enumerate()
+            .map(|(i, action)| {
+                if i == app.palette.selected {
+                    Line::from(Span::styled(
+                        format!("▶ {}", action.name()),
+                        Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),  // Vybraná akcia
+                    ))
+                } else {
+                    Line::from(Span::styled(
+                        format!("  {}", action.name()),
+                        Style::default().fg(Color::White),
+                    ))
+                }
+            })
+            .collect()
+    };
+
+    f.render_widget(Paragraph::new(lines), chunks[1]);
+}
+
+/// Výpočet vycentrovanej plochy danej šírky (v %) a výšky (v riadkoch)
+fn centered_rect(percent_x: u16, height: u16, area: Rect) -> Rect {
+    let vertical = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Fill(1),
+            Constraint::Length(height),
+            Constraint::Fill(1),
+        ])
+        .split(area);
+
+    Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage((100 - percent_x) / 2),
+            Constraint::Percentage(percent_x),
+            Constraint::Percentage((100 - percent_x) / 2),
+        ])
+        .split(vertical[1])[1]
+}
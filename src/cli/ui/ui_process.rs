@@ -1,16 +1,28 @@
 use ratatui::{
     Frame,
+    layout::{Layout, Constraint, Direction},
     style::{Style, Color},
-    widgets::{Block, Borders, Paragraph, BorderType},
+    widgets::{Block, Borders, Paragraph, BorderType, Wrap},
     text::{Line, Span},
 };
 use crate::cli::app::TuiApp;
-use super::ui_widgets::get_cpu_color;
+use crate::services::power::attribute_power;
+use super::ui_widgets::{get_cpu_color, render_key_legend, NumberFormat};
 
 /// Hlavná render funkcia pre detailný pohľad na proces
 /// Zobrazuje podrobné informácie o vybranom procese
 pub fn render(f: &mut Frame, app: &mut TuiApp) {
-    let area = f.area();  // Získanie celej dostupnej plochy frame
+    let nf = NumberFormat::new(app.config.precision)  // Desatinné miesta podľa `--precision`
+        .with_raw_bytes(app.raw_bytes);               // Presné bajty (kláves B)
+    // Rozdelenie na detaily a pätu so skratkami
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Min(5),      // Detaily procesu
+            Constraint::Length(3),   // Päta
+        ])
+        .split(f.area());
+    let area = chunks[0];
 
     // Vytvorenie bloku (boxu) pre obsah detailov procesu
     let block = Block::default()
@@ -23,15 +35,30 @@ pub fn render(f: &mut Frame, app: &mut TuiApp) {
     let details = if let Some(index) = app.process_list_state.selected() {
         // Ak je vybratý nejaký proces
         if let Some(proc) = app.top_processes.get(index) {
-            // Konverzia pamäte z bajtov na GB
-            let memory_gb = proc.memory as f64 / 1024.0 / 1024.0 / 1024.0;
+            // Odhad príkonu podľa podielu na CPU (len s RAPL)
+            let power = app.metrics.as_ref()
+                .and_then(|m| m.package_power_watts)
+                .map(|watts| attribute_power(watts, proc.cpu_usage, app.total_process_cpu));
+            let power_text = match power {
+                Some(watts) => format!("{} W", nf.number(watts, 1)),
+                None => "n/a (RAPL unavailable)".to_string(),
+            };
 
             // Vytvorenie zoznamu informačných riadkov
             vec![
                 // Riadok 1: Názov procesu
                 Line::from(vec![
                     Span::styled("Process: ", Style::default().fg(Color::Cyan)),  // Tyrkysový štítok
-                    Span::styled(&proc.name, Style::default().fg(Color::White)),  // Biely názov procesu
+                    Span::styled(proc.display_name(), Style::default().fg(Color::White)),  // Biely názov procesu
+                ]),
+                
+                // Riadok 1b: Príkazový riadok (dlhý sa zalomí)
+                Line::from(vec![
+                    Span::styled("Command: ", Style::default().fg(Color::Cyan)),  // Tyrkysový štítok
+                    Span::styled(
+                        proc.command_line().unwrap_or_else(|| "n/a".to_string()),  // Bez práv je prázdny
+                        Style::default().fg(Color::White)
+                    ),
                 ]),
                 
                 // Riadok 2: PID procesu
@@ -44,33 +71,49 @@ pub fn render(f: &mut Frame, app: &mut TuiApp) {
                 Line::from(vec![
                     Span::styled("CPU Usage: ", Style::default().fg(Color::Cyan)),  // Tyrkysový štítok
                     Span::styled(
-                        format!("{:.2}%", proc.cpu_usage),                         // Formátované percento
-                        Style::default().fg(get_cpu_color(proc.cpu_usage as f64))  // Farba podľa zaťaženia
+                        nf.percent(proc.cpu_usage as f64, 2),                      // Formátované percento
+                        Style::default().fg(get_cpu_color(proc.cpu_usage as f64, &app.cpu_thresholds))  // Farba podľa zaťaženia
                     ),
                 ]),
                 
+                // Riadok 3b: Odhad príkonu
+                Line::from(vec![
+                    Span::styled("Power (est.): ", Style::default().fg(Color::Cyan)),  // Tyrkysový štítok
+                    Span::styled(power_text, Style::default().fg(Color::Yellow)),      // Žltý odhad
+                ]),
+                
                 // Riadok 4: Využitie pamäte
                 Line::from(vec![
-                    Span::styled("Memory: ", Style::default().fg(Color::Cyan)),   // Tyrkysový štítok
+                    Span::styled("Memory (RSS): ", Style::default().fg(Color::Cyan)),   // Tyrkysový štítok
+                    Span::styled(
+                        nf.format_gigabytes(proc.memory, 2),                      // GB alebo bajty
+                        Style::default().fg(Color::Green)                         // Zelená farba
+                    ),
+                ]),
+                
+                // Riadok 4b: Virtuálna pamäť
+                Line::from(vec![
+                    Span::styled("Virtual Memory: ", Style::default().fg(Color::Cyan)),  // Tyrkysový štítok
                     Span::styled(
-                        format!("{:.2} GB", memory_gb),                           // Formátované GB
+                        nf.format_gigabytes(proc.virtual_memory, 2),              // GB alebo bajty
                         Style::default().fg(Color::Green)                         // Zelená farba
                     ),
                 ]),
                 
+                // Riadok 4c: Swap (len Linux)
+                Line::from(vec![
+                    Span::styled("Swap: ", Style::default().fg(Color::Cyan)),    // Tyrkysový štítok
+                    Span::styled(
+                        proc.swap.map_or_else(|| "N/A".to_string(), |swap| nf.format_bytes(swap)),
+                        Style::default().fg(Color::Magenta)                       // Fialová farba
+                    ),
+                ]),
+                
                 // Riadok 5: Stav procesu
                 Line::from(vec![
                     Span::styled("Status: ", Style::default().fg(Color::Cyan)),   // Tyrkysový štítok
                     Span::styled("Running", Style::default().fg(Color::Green)),   // Zelený "Running"
                 ]),
-                
-                Line::from(""),  // Prázdny riadok pre oddelenie
-                
-                // Riadok 7: Návod na návrat
-                Line::from(Span::styled(
-                    "Press [Esc] to go back",                                     // Text nápovedy
-                    Style::default().fg(Color::DarkGray)                          // Tmavosivá farba
-                )),
             ]
         } else {
             // Chybové hlásenie, ak proces neexistuje
@@ -84,8 +127,49 @@ pub fn render(f: &mut Frame, app: &mut TuiApp) {
     // Vytvorenie odstavca (paragraph) s detailmi
     let paragraph = Paragraph::new(details)
         .block(block)                                           // Pridanie bloku
+        .wrap(Wrap { trim: false })                             // Zalomenie dlhého príkazu
         .alignment(ratatui::layout::Alignment::Left);           // Zarovnanie doľava
 
     // Vykreslenie widgetu na plochu
     f.render_widget(paragraph, area);
-}
\ No newline at end of file
+    render_key_legend(f, chunks[1], app);  // Päta so skratkami
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ProcessInfo;
+    use crate::test_support::{sample_process, test_app};
+
+    fn rendered_text(app: &mut TuiApp) -> String {
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(60, 30)).unwrap();
+        terminal.draw(|f| render(f, app)).unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| (0..buffer.area.width).map(|x| buffer[(x, y)].symbol()).collect::<String>() + "\n")
+            .collect()
+    }
+
+    #[test]
+    fn empty_command_line_renders_as_unavailable() {
+        let mut app = test_app(crate::config::TuiArgs::default());
+        app.top_processes = vec![sample_process(42, "python")];
+        app.process_list_state.select(Some(0));
+
+        let text = rendered_text(&mut app);
+        assert!(text.contains("Command: n/a"), "{text}");
+    }
+
+    #[test]
+    fn long_command_line_wraps_in_the_detail() {
+        let mut app = test_app(crate::config::TuiArgs::default());
+        let args = (1..=12).map(|i| format!("--option-{i}"));
+        let cmd = std::iter::once("python3".to_string()).chain(args).collect();
+        app.top_processes = vec![ProcessInfo { cmd, ..sample_process(42, "python") }];
+        app.process_list_state.select(Some(0));
+
+        let text = rendered_text(&mut app);
+        assert!(text.contains("Command: python3 --option-1"), "{text}");
+        assert!(text.contains("--option-12"), "{text}");  // Koniec po zalomení ostane viditeľný
+    }
+}
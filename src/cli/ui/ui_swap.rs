@@ -0,0 +1,98 @@
+// ui_swap.rs
+
+use ratatui::{
+    Frame,
+    layout::{Layout, Constraint, Direction, Rect},
+    style::{Style, Color, Modifier},
+    widgets::{Block, Borders, Paragraph, Table, Row, Cell, BorderType},
+};
+use crate::cli::app::TuiApp;
+use super::ui_widgets::{truncate_str, render_key_legend, visible_row_count, scroll_offset, NumberFormat};
+
+/// Render funkcia pre zoznam procesov s najväčším swapom
+/// Swap procesov poskytuje len Linux (`VmSwap` v `/proc/<pid>/status`)
+pub fn render(f: &mut Frame, app: &mut TuiApp) {
+    let area = f.area();
+
+    // Rozdelenie na zoznam a pätu
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(5),      // Zoznam procesov
+            Constraint::Length(3),   // Päta
+        ])
+        .split(area);
+
+    render_swap_list(f, app, chunks[0]);
+    render_key_legend(f, chunks[1], app);  // Päta so skratkami
+}
+
+/// Vykreslenie tabuľky procesov podľa swapu
+fn render_swap_list(f: &mut Frame, app: &TuiApp, area: Rect) {
+    let nf = NumberFormat::new(app.config.precision).with_raw_bytes(app.raw_bytes);
+    let block = Block::default()
+        .title(format!("💾 Swap Usage ({} processes using swap)", app.swap_processes.len()))
+        .borders(Borders::ALL)                            // Všetky okraje
+        .border_type(BorderType::Rounded)                 // Okrúhle rohy
+        .border_style(Style::default().fg(Color::Blue));  // Modrá farba okrajov
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.swap_processes.is_empty() {
+        let message = if cfg!(target_os = "linux") {
+            "No process is using swap"
+        } else {
+            "Per-process swap is only available on Linux"
+        };
+        let empty = Paragraph::new(message)
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(empty, inner_area);
+        return;
+    }
+
+    // Posun zobrazenia tak, aby bol vybraný riadok vždy viditeľný
+    let visible = visible_row_count(inner_area.height);
+    let selected = app.swap_state.selected().unwrap_or(0);
+    let offset = scroll_offset(selected, visible);
+
+    let rows: Vec<Row> = app.swap_processes
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .map(|(i, process)| {
+            let style = if i == selected {
+                Style::default().bg(Color::DarkGray).fg(Color::Yellow)  // Žltý text na šedom pozadí
+            } else {
+                Style::default()
+            };
+
+            Row::new(vec![
+                Cell::from(format!("{:>7}", process.pid)).style(style),                          // PID
+                Cell::from(truncate_str(&process.display_name(), 48)).style(style),              // Názov
+                Cell::from(format!("{:>12}", nf.format_bytes(process.swap.unwrap_or(0))))
+                    .style(style.fg(Color::Magenta)),                                            // Swap
+                Cell::from(format!("{:>12}", nf.format_bytes(process.memory))).style(style),     // RSS
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(8),    // PID
+        Constraint::Min(20),      // Názov
+        Constraint::Length(14),   // Swap
+        Constraint::Length(14),   // RSS
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(vec!["PID", "Process", "Swap", "RSS"])
+                .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                .bottom_margin(1),
+        )
+        .column_spacing(1);
+
+    f.render_widget(table, inner_area);
+}
@@ -0,0 +1,101 @@
+use ratatui::{
+    Frame,
+    layout::{Layout, Constraint, Direction, Rect},
+    style::{Style, Color, Modifier},
+    widgets::{Block, Borders, Paragraph, Table, Row, Cell, BorderType},
+};
+use crate::cli::app::TuiApp;
+use super::ui_widgets::{truncate_str, get_cpu_color, render_key_legend, visible_row_count, scroll_offset, NumberFormat};
+
+/// Render funkcia pre stromové zobrazenie procesov
+/// Procesy sú odsadené pod svojimi rodičmi, Enter zbalí/rozbalí uzol
+pub fn render(f: &mut Frame, app: &mut TuiApp) {
+    let area = f.area();
+
+    // Rozdelenie na strom a pätu
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .margin(1)
+        .constraints([
+            Constraint::Min(5),      // Strom procesov
+            Constraint::Length(3),   // Päta
+        ])
+        .split(area);
+
+    render_tree(f, app, chunks[0]);
+    render_key_legend(f, chunks[1], app);  // Päta so skratkami
+}
+
+/// Vykreslenie tabuľky so stromom procesov
+fn render_tree(f: &mut Frame, app: &TuiApp, area: Rect) {
+    let nf = NumberFormat::new(app.config.precision);  // Desatinné miesta podľa `--precision`
+    let block = Block::default()
+        .title(format!("🌳 Process Tree ({} shown)", app.tree_rows.len()))  // Titulok s počtom riadkov
+        .borders(Borders::ALL)                           // Všetky okraje
+        .border_type(BorderType::Rounded)                // Okrúhle rohy
+        .border_style(Style::default().fg(Color::Green)); // Zelená farba okrajov
+
+    let inner_area = block.inner(area);
+    f.render_widget(block, area);
+
+    if app.tree_rows.is_empty() {
+        let no_processes = Paragraph::new("No processes found")
+            .alignment(ratatui::layout::Alignment::Center);
+        f.render_widget(no_processes, inner_area);
+        return;
+    }
+
+    // Posun zobrazenia tak, aby bol vybraný riadok vždy viditeľný
+    let visible = visible_row_count(inner_area.height);
+    let selected = app.tree_state.selected().unwrap_or(0);
+    let offset = scroll_offset(selected, visible);
+
+    let rows: Vec<Row> = app.tree_rows
+        .iter()
+        .enumerate()
+        .skip(offset)
+        .take(visible)
+        .map(|(i, row)| {
+            let style = if i == selected {
+                Style::default().bg(Color::DarkGray).fg(Color::Yellow)  // Žltý text na šedom pozadí
+            } else {
+                Style::default()
+            };
+
+            // Značka uzla: ▸ zbalený, ▾ rozbalený, • list
+            let marker = if row.child_count == 0 {
+                "•"
+            } else if row.collapsed {
+                "▸"
+            } else {
+                "▾"
+            };
+            let name = format!("{}{} {}", "  ".repeat(row.depth), marker, row.process.display_name());
+
+            Row::new(vec![
+                Cell::from(format!("{:>7}", row.process.pid)).style(style),  // PID
+                Cell::from(truncate_str(&name, 48)).style(style),            // Odsadený názov
+                Cell::from(format!("{:>6}", nf.percent(row.process.cpu_usage as f64, 1)))
+                    .style(style.fg(get_cpu_color(row.process.cpu_usage as f64, &app.cpu_thresholds))),  // CPU
+                Cell::from(format!("{:>8} MB", nf.number(app.memory_metric.bytes(&row.process) as f64 / 1024.0 / 1024.0, 1))).style(style),  // Pamäť
+            ])
+        })
+        .collect();
+
+    let widths = [
+        Constraint::Length(8),    // PID
+        Constraint::Min(20),      // Názov
+        Constraint::Length(8),    // CPU
+        Constraint::Length(12),   // Pamäť
+    ];
+
+    let table = Table::new(rows, widths)
+        .header(
+            Row::new(vec!["PID", "Process", "CPU", app.memory_metric.label()])
+                .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
+                .bottom_margin(1),
+        )
+        .column_spacing(1);
+
+    f.render_widget(table, inner_area);
+}
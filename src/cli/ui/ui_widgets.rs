@@ -1,12 +1,114 @@
 use ratatui::{
     Frame,
-    layout::{Layout, Constraint, Direction, Rect},
+    layout::{Layout, Constraint, Direction, Rect, Alignment},
     style::{Style, Color},
-    widgets::{Block, Borders, Gauge, Sparkline, BorderType},
+    widgets::{Block, Borders, Gauge, Sparkline, BorderType, Paragraph, block::{Position, Title}},
+    text::{Line, Span},
 };
 use unicode_width::UnicodeWidthStr;
+use crate::cli::app::{KeyBinding, TuiApp, keybindings_for};
+use crate::config::{NetworkUnit, PercentRounding};
+use crate::models::CpuThresholds;
 
 
+/// Formátovanie percent a veľkostí s nastaviteľným počtom desatinných miest
+/// Bez `--precision` každé miesto v UI používa svoj predvolený počet
+#[derive(Debug, Clone, Copy)]
+pub struct NumberFormat {
+    precision: Option<usize>,  // Počet desatinných miest z `--precision`
+    raw_bytes: bool,           // Veľkosti v presných bajtoch namiesto jednotiek (kláves B)
+    rounding: PercentRounding, // Zaokrúhlenie percent v gauge (`--percent-rounding`)
+}
+
+impl NumberFormat {
+    /// Vytvorenie formátovača z nastavenia TUI
+    pub fn new(precision: Option<u8>) -> Self {
+        Self { precision: precision.map(usize::from), raw_bytes: false, rounding: PercentRounding::default() }
+    }
+
+    /// Zaokrúhlenie percent v gauge
+    pub fn with_rounding(mut self, rounding: PercentRounding) -> Self {
+        self.rounding = rounding;
+        self
+    }
+
+    /// Zapnutie zobrazenia veľkostí v presných bajtoch
+    pub fn with_raw_bytes(mut self, raw_bytes: bool) -> Self {
+        self.raw_bytes = raw_bytes;
+        self
+    }
+
+    /// Číslo s nastaveným alebo predvoleným počtom desatinných miest
+    pub fn number(&self, value: f64, default_decimals: usize) -> String {
+        format!("{:.*}", self.precision.unwrap_or(default_decimals), value)
+    }
+
+    /// Percento, napr. `45.2%`
+    pub fn percent(&self, value: f64, default_decimals: usize) -> String {
+        format!("{}%", self.number(value, default_decimals))
+    }
+
+    /// Celé percento pre popis gauge podľa `--percent-rounding`, napr. `1%` pre 0.4
+    /// So zadaným `--precision` sa zobrazia desatinné miesta ako inde
+    pub fn whole_percent(&self, value: f64) -> String {
+        match self.precision {
+            Some(_) => self.percent(value, 0),
+            None => format!("{:.0}%", self.rounding.apply(value)),
+        }
+    }
+
+    /// Vyplnenie gauge zaokrúhlené rovnako ako jeho popis
+    pub fn gauge_percent(&self, value: f64) -> u16 {
+        clamp_percent(self.rounding.apply(value))
+    }
+
+    /// Sieťová rýchlosť zadaná v KB/s v zvolenej jednotke, napr. `8.0 Mbps`
+    pub fn format_network_rate(&self, kbps: f64, unit: NetworkUnit) -> String {
+        format!("{} {}", self.number(unit.convert_kbps(kbps), 1), unit.label())
+    }
+
+    /// Veľkosť v bajtoch s najvhodnejšou jednotkou (B, KB, MB, GB, TB)
+    /// Bajty sú bez desatinných miest, ostatné jednotky s jedným (alebo `--precision`)
+    /// V režime presných bajtov sa vždy vypíše počet bajtov
+    pub fn format_bytes(&self, bytes: u64) -> String {
+        const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+
+        if self.raw_bytes {
+            return format!("{} B", bytes);
+        }
+
+        let mut value = bytes as f64;
+        let mut unit = 0;
+        while value >= 1024.0 && unit < UNITS.len() - 1 {
+            value /= 1024.0;
+            unit += 1;
+        }
+
+        if unit == 0 {
+            format!("{} B", bytes)
+        } else {
+            format!("{} {}", self.number(value, 1), UNITS[unit])
+        }
+    }
+
+    /// Pomer použité/celkové, napr. `7.8/15.5GB` alebo `8375934976/16642998272 B`
+    pub fn format_bytes_ratio(&self, used: u64, total: u64) -> String {
+        if self.raw_bytes {
+            return format!("{}/{} B", used, total);
+        }
+        let gb = |bytes: u64| bytes as f64 / 1024.0 / 1024.0 / 1024.0;
+        format!("{}/{}GB", self.number(gb(used), 1), self.number(gb(total), 1))
+    }
+
+    /// Veľkosť v GB, napr. `1.25 GB`, v režime presných bajtov počet bajtov
+    pub fn format_gigabytes(&self, bytes: u64, default_decimals: usize) -> String {
+        if self.raw_bytes {
+            return format!("{} B", bytes);
+        }
+        format!("{} GB", self.number(bytes as f64 / 1024.0 / 1024.0 / 1024.0, default_decimals))
+    }
+}
+
 /// Pomocná funkcia na skrátenie reťazca s ohľadom na unicode šírku znakov
 /// Táto funkcia je inteligentnejšia ako štandardné skracovanie, pretože berie do úvahy
 /// šírku znakov (napr. emodži majú väčšiu šírku ako bežné znaky)
@@ -52,6 +154,62 @@ pub fn truncate_str(s: &str, max_len: usize) -> String {
     }
 }
 
+/// Riadky hlavičky tabuľky vrátane jej spodného okraja (`bottom_margin(1)`)
+const TABLE_HEADER_ROWS: u16 = 2;
+
+/// Počet riadkov tabuľky, ktoré sa zmestia do plochy danej výšky
+/// (bez hlavičky a jej okraja)
+pub fn visible_row_count(area_height: u16) -> usize {
+    area_height.saturating_sub(TABLE_HEADER_ROWS) as usize
+}
+
+/// Posun zobrazenia tak, aby bol vybraný riadok vždy viditeľný
+///
+/// # Argumenty
+/// * `selected` - Index vybraného riadku
+/// * `visible` - Počet viditeľných riadkov (z `visible_row_count`)
+pub fn scroll_offset(selected: usize, visible: usize) -> usize {
+    (selected + 1).saturating_sub(visible)
+}
+
+/// Podiel `value` z `max` v percentách (0 - 100)
+///
+/// Nulové, záporné alebo neplatné maximum (NaN, nekonečno) dá 0 %,
+/// takže výsledok sa dá bez kontroly použiť v `Gauge::percent`.
+pub fn safe_percent(value: f64, max: f64) -> f64 {
+    if max <= 0.0 || !max.is_finite() || !value.is_finite() {
+        return 0.0;
+    }
+    (value / max * 100.0).clamp(0.0, 100.0)
+}
+
+/// Najmenšia mierka sieťových grafov v KB/s - pri nečinnej sieti
+/// by inak pár bajtov vyplnilo celý gauge
+pub const MIN_BANDWIDTH_SCALE_KBPS: f64 = 100.0;
+
+/// Mierka sieťových gauge a grafov v KB/s (bez známej rýchlosti linky)
+///
+/// Maximum z histórie a aktuálnej hodnoty, najmenej `MIN_BANDWIDTH_SCALE_KBPS`.
+/// Odoslané aj prijaté dáta zdieľajú jednu mierku - `history` môže obsahovať
+/// obe histórie za sebou. Prázdna história dá minimum (ak aktuálna hodnota
+/// nie je vyššia), NaN sa ignoruje.
+pub fn compute_bandwidth_scale(history: impl IntoIterator<Item = f64>, current: f64) -> f64 {
+    history.into_iter()
+        .fold(current, f64::max)
+        .max(MIN_BANDWIDTH_SCALE_KBPS)
+}
+
+/// Percento pre `Gauge::percent` zaokrúhlené a obmedzené na 0 - 100
+///
+/// `Gauge::percent` pri hodnote nad 100 spadne (panic); záporné hodnoty
+/// a NaN dajú 0, nekonečno 100.
+pub fn clamp_percent(value: f64) -> u16 {
+    if value.is_nan() {
+        return 0;
+    }
+    value.round().clamp(0.0, 100.0) as u16
+}
+
 /// Pomocná funkcia na vytvorenie grafického ukazovateľa pre proces
 /// Vracia reťazec s vizuálnym indikátorom zaťaženia (napr. "██████░░░░░░░░░░░░░░")
 pub fn get_process_bar(percent: u8) -> String {
@@ -72,13 +230,231 @@ pub fn get_process_bar(percent: u8) -> String {
     filled_char.repeat(filled) + &" ".repeat(empty)
 }
 
+/// Prispôsobenie série hodnôt šírke grafu (`width` stĺpcov)
+///
+/// Kratšia séria sa lineárne interpoluje, dlhšia sa zhustí výberom vzoriek
+/// v rovnomerných rozostupoch. Prvá a posledná hodnota ostávajú zachované.
+pub fn fit_series(data: &[u64], width: usize) -> Vec<u64> {
+    match (data.len(), width) {
+        (0, _) | (_, 0) => Vec::new(),
+        (len, width) if len == width => data.to_vec(),
+        (1, width) => vec![data[0]; width],      // Jediná hodnota sa zopakuje
+        (len, 1) => vec![data[len - 1]],          // Jediný stĺpec ukáže najnovšiu hodnotu
+        (len, width) => (0..width)
+            .map(|i| {
+                // Pozícia stĺpca v pôvodnej sérii (0 ..= len - 1)
+                let pos = i as f64 * (len - 1) as f64 / (width - 1) as f64;
+                let lower = pos.floor() as usize;
+                let upper = (lower + 1).min(len - 1);
+                let frac = pos - lower as f64;
+                let value = data[lower] as f64 + (data[upper] as f64 - data[lower] as f64) * frac;
+                value.round() as u64
+            })
+            .collect(),
+    }
+}
+
 /// Pomocná funkcia na získanie farby pre indikáciu využitia CPU
 /// Farba sa mení podľa zaťaženia CPU (zelená → žltá → červená)
-#[allow(dead_code)]  // Povolenie nepoužívanej funkcie
-pub fn get_cpu_color(usage: f64) -> Color {
+/// Hranice udávajú `--cpu-warn` a `--cpu-critical`
+pub fn get_cpu_color(usage: f64, thresholds: &CpuThresholds) -> Color {
     match usage {
-        x if x < 30.0 => Color::Green,    // Nízke zaťaženie - zelená
-        x if x < 70.0 => Color::Yellow,   // Stredné zaťaženie - žltá
-        _ => Color::Red,                  // Vysoké zaťaženie - červená
+        x if x < thresholds.warn => Color::Green,       // Nízke zaťaženie - zelená
+        x if x < thresholds.critical => Color::Yellow,  // Stredné zaťaženie - žltá
+        _ => Color::Red,                                // Vysoké zaťaženie - červená
+    }
+}
+
+/// Riadok so skratkami v tvare `[Q] Quit  [R] Refresh ...`
+/// Označenie klávesu je farebné, popis akcie šedý
+pub fn key_legend_line(bindings: &[KeyBinding]) -> Line<'static> {
+    let mut spans = Vec::with_capacity(bindings.len() * 2);
+    for (i, binding) in bindings.iter().enumerate() {
+        let prefix = if i == 0 { "" } else { "  " };  // Medzera medzi skratkami
+        spans.push(Span::styled(format!("{}[{}] ", prefix, binding.key), Style::default().fg(binding.color)));
+        spans.push(Span::styled(binding.action, Style::default().fg(Color::DarkGray)));
+    }
+    Line::from(spans)
+}
+
+/// Vykreslenie päty so skratkami dostupnými v aktuálnom režime
+/// Otázka pred ukončením procesov, inak aktívna správa (napr. potvrdenie kopírovania)
+/// sa zobrazí namiesto skratiek, v pravom dolnom rohu je indikátor obnovenia (`--refresh-indicator`)
+pub fn render_key_legend(f: &mut Frame, area: Rect, app: &TuiApp) {
+    let line = match (app.kill_prompt(), app.active_status()) {
+        (Some(prompt), _) => Line::from(Span::styled(prompt, Style::default().fg(Color::Yellow))),
+        (None, Some(status)) => {
+            let color = if status.is_error { Color::Red } else { Color::Green };
+            Line::from(Span::styled(status.text.clone(), Style::default().fg(color)))
+        }
+        (None, None) => key_legend_line(keybindings_for(app.mode)),
+    };
+
+    let mut block = Block::default()
+        .borders(Borders::ALL)                      // Všetky okraje
+        .border_type(BorderType::Rounded);          // Okrúhle rohy
+    if let Some(indicator) = app.refresh_indicator() {
+        block = block.title(
+            Title::from(Span::styled(format!(" {} ", indicator), Style::default().fg(Color::DarkGray)))
+                .alignment(Alignment::Right)
+                .position(Position::Bottom),
+        );
+    }
+
+    let footer = Paragraph::new(line)
+        .block(block)
+        .alignment(Alignment::Center);                  // Zarovnanie na stred
+
+    f.render_widget(footer, area);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn safe_percent_of_zero_or_invalid_max_is_zero() {
+        assert_eq!(safe_percent(5.0, 0.0), 0.0);
+        assert_eq!(safe_percent(5.0, -10.0), 0.0);
+        assert_eq!(safe_percent(5.0, f64::NAN), 0.0);
+        assert_eq!(safe_percent(5.0, f64::INFINITY), 0.0);
+        assert_eq!(safe_percent(f64::NAN, 10.0), 0.0);
+    }
+
+    #[test]
+    fn safe_percent_is_clamped_to_0_100() {
+        assert_eq!(safe_percent(25.0, 100.0), 25.0);
+        assert_eq!(safe_percent(150.0, 100.0), 100.0);
+        assert_eq!(safe_percent(-5.0, 100.0), 0.0);
+    }
+
+    #[test]
+    fn fit_series_handles_empty_input_and_zero_width() {
+        assert!(fit_series(&[], 10).is_empty());
+        assert!(fit_series(&[1, 2, 3], 0).is_empty());
+    }
+
+    #[test]
+    fn fit_series_to_one_column_keeps_the_newest_value() {
+        assert_eq!(fit_series(&[1, 2, 3], 1), vec![3]);
+        assert_eq!(fit_series(&[7], 1), vec![7]);
+    }
+
+    #[test]
+    fn fit_series_stretches_and_shrinks_keeping_the_ends() {
+        assert_eq!(fit_series(&[4], 3), vec![4, 4, 4]);
+        assert_eq!(fit_series(&[0, 10], 3), vec![0, 5, 10]);
+        assert_eq!(fit_series(&[0, 1, 2, 3, 4], 3), vec![0, 2, 4]);
+    }
+
+    #[test]
+    fn clamp_percent_caps_values_above_100() {
+        assert_eq!(clamp_percent(100.4), 100);
+        assert_eq!(clamp_percent(250.0), 100);
+        assert_eq!(clamp_percent(f64::INFINITY), 100);
+    }
+
+    #[test]
+    fn clamp_percent_maps_nan_and_negatives_to_zero() {
+        assert_eq!(clamp_percent(f64::NAN), 0);
+        assert_eq!(clamp_percent(-3.0), 0);
+        assert_eq!(clamp_percent(f64::NEG_INFINITY), 0);
+        assert_eq!(clamp_percent(42.6), 43);
+    }
+
+    #[test]
+    fn bandwidth_scale_of_empty_history_is_the_minimum() {
+        assert_eq!(compute_bandwidth_scale([], 0.0), MIN_BANDWIDTH_SCALE_KBPS);
+        assert_eq!(compute_bandwidth_scale([], 500.0), 500.0);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn bandwidth_scale_follows_the_history_peak_and_ignores_nan() {
+        assert_eq!(compute_bandwidth_scale([50.0, 800.0, 120.0], 10.0), 800.0);
+        assert_eq!(compute_bandwidth_scale([f64::NAN, 300.0], f64::NAN), 300.0);
+        assert_eq!(compute_bandwidth_scale([f64::NAN], 1.0), MIN_BANDWIDTH_SCALE_KBPS);
+    }
+
+    #[test]
+    fn visible_rows_exclude_the_header() {
+        assert_eq!(visible_row_count(12), 10);
+        assert_eq!(visible_row_count(2), 0);
+        assert_eq!(visible_row_count(0), 0);  // Príliš malý terminál
+    }
+
+    #[test]
+    fn scroll_keeps_the_selected_row_visible() {
+        assert_eq!(scroll_offset(3, 10), 0);
+        assert_eq!(scroll_offset(9, 10), 0);
+        assert_eq!(scroll_offset(15, 10), 6);
+    }
+
+    #[test]
+    fn precision_controls_decimal_places() {
+        let coarse = NumberFormat::new(Some(0));
+        let fine = NumberFormat::new(Some(2));
+
+        assert_eq!(coarse.percent(45.678, 1), "46%");
+        assert_eq!(fine.percent(45.678, 1), "45.68%");
+        assert_eq!(coarse.format_bytes(1536 * 1024), "2 MB");
+        assert_eq!(fine.format_bytes(1536 * 1024), "1.50 MB");
+    }
+
+    #[test]
+    fn default_precision_keeps_each_call_sites_decimals() {
+        let nf = NumberFormat::new(None);
+
+        assert_eq!(nf.percent(45.678, 1), "45.7%");
+        assert_eq!(nf.number(45.678, 0), "46");
+        assert_eq!(nf.format_bytes(512), "512 B");
+    }
+
+    #[test]
+    fn large_cumulative_totals_stay_in_terabytes() {
+        let nf = NumberFormat::new(None);
+        const TB: u64 = 1024 * 1024 * 1024 * 1024;
+
+        assert_eq!(nf.format_bytes(3 * TB / 2), "1.5 TB");
+        assert_eq!(nf.format_bytes(5 * 1024 * TB), "5120.0 TB");  // Nad TB sa jednotka nezväčšuje
+        assert_eq!(nf.format_bytes(u64::MAX), "16777216.0 TB");
+        assert_eq!(nf.with_raw_bytes(true).format_bytes(u64::MAX), format!("{} B", u64::MAX));
+    }
+
+    #[test]
+    fn network_rate_is_converted_to_the_chosen_unit() {
+        let nf = NumberFormat::new(None);
+
+        assert_eq!(nf.format_network_rate(1000.0, NetworkUnit::Mbps), "8.0 Mbps");
+        assert_eq!(nf.format_network_rate(1000.0, NetworkUnit::KBps), "1000.0 KB/s");
+        assert_eq!(NumberFormat::new(Some(2)).format_network_rate(12.5, NetworkUnit::Mbps), "0.10 Mbps");
+    }
+
+    #[test]
+    fn cpu_color_follows_default_and_custom_thresholds() {
+        let defaults = CpuThresholds::default();
+        assert_eq!(get_cpu_color(29.9, &defaults), Color::Green);
+        assert_eq!(get_cpu_color(30.0, &defaults), Color::Yellow);
+        assert_eq!(get_cpu_color(70.0, &defaults), Color::Red);
+
+        let custom = CpuThresholds::new(10.0, 20.0).unwrap();
+        assert_eq!(get_cpu_color(15.0, &custom), Color::Yellow);
+        assert_eq!(get_cpu_color(25.0, &custom), Color::Red);
+        assert!(CpuThresholds::new(80.0, 50.0).is_err());
+    }
+
+    #[test]
+    fn nonzero_percent_never_displays_as_zero() {
+        let floor = NumberFormat::new(None).with_rounding(PercentRounding::Floor);
+        assert_eq!(floor.whole_percent(0.4), "1%");
+        assert_eq!(floor.gauge_percent(0.4), 1);
+        assert_eq!(floor.whole_percent(99.6), "99%");
+        assert_eq!(floor.whole_percent(0.0), "0%");
+
+        let nearest = NumberFormat::new(None);
+        assert_eq!(nearest.whole_percent(0.4), "1%");
+        assert_eq!(nearest.whole_percent(99.6), "100%");
+
+        let ceil = NumberFormat::new(None).with_rounding(PercentRounding::Ceil);
+        assert_eq!(ceil.whole_percent(42.1), "43%");
+    }
+}
@@ -1,6 +1,10 @@
 // cli.rs
 
-use clap::{Parser, Subcommand};  // Import knižnice pre CLI parsovanie
+use clap::{Args, Parser, Subcommand};  // Import knižnice pre CLI parsovanie
+use std::path::PathBuf;  // Cesta k Unix socketu
+use crate::services::system_processes::DEFAULT_SYSTEM_PID_BELOW;  // Hranica PID systémových procesov
+use crate::db::validate_table_prefix;  // Kontrola prefixu tabuľky
+use crate::models::{TemperatureThresholds, TemperatureMode, MemoryMetric, CpuThresholds, DEFAULT_MAX_NETWORK_KBPS};  // Teplotné a CPU prahy, režim teplôt
 
 /// Hlavná CLI štruktúra aplikácie
 /// Definuje základné nastavenia a príkazy
@@ -8,10 +12,16 @@ use clap::{Parser, Subcommand};  // Import knižnice pre CLI parsovanie
 #[command(name = "system-monitor")]        // Názov aplikácie
 #[command(about = "🖥️ System Monitor - TUI and REST API")]  // Popis aplikácie
 #[command(version = "1.0")]               // Verzia aplikácie
+#[command(after_help = crate::error::EXIT_CODES_HELP)]  // Exit kódy podľa kategórie chyby
 pub struct Cli {
     /// Podpríkazy aplikácie
     #[command(subcommand)]
     pub command: Option<Commands>,  // Možné príkazy (optional)
+
+    /// Prefix názvu tabuľky metrík a jej indexov (napr. `tenant1_` ->
+    /// `tenant1_system_metrics`); inak premenná TABLE_PREFIX, štandardne žiadny
+    #[arg(long, global = true, value_name = "PREFIX", value_parser = validate_table_prefix)]
+    pub table_prefix: Option<String>,
 }
 
 /// Enum definujúci dostupné príkazy aplikácie
@@ -19,21 +29,556 @@ pub struct Cli {
 pub enum Commands {
     /// Spustenie TUI (Terminal User Interface) módu
     /// Grafické rozhranie v termináli
-    Tui,
-    
+    Tui(TuiArgs),
+
     /// Spustenie REST API módu
     /// Webové rozhranie pre vzdialený prístup
-    Api {
-        /// Hostname pre API server (štandardne localhost)
-        #[arg(short = 'H', long, default_value = "127.0.0.1")]  // Skratka -H alebo --host
-        host: String,
-        
-        /// Port pre API server (štandardne 3000)
-        #[arg(short, long, default_value = "3000")]  // Skratka -p alebo --port
-        port: u16,
-      
-        /// Prepínač pre ukladanie metrík do databázy
-        #[arg(short, long)]  // Skratka -s alebo --save-metrics
-        save_metrics: bool,  // Boolean hodnota - true/false
-    },
-}
\ No newline at end of file
+    Api(ApiArgs),
+
+    /// Výpis nájdených teplotných senzorov a ich hodnôt
+    /// Diagnostika chýbajúcich teplôt (WMI, hwmon, lm-sensors)
+    Sensors,
+
+    /// Headless zber metrík do databázy (bez HTTP servera a TUI)
+    /// Nenáročný démon - v intervale ukladá metriky, Ctrl+C ho ukončí
+    Collect(CollectArgs),
+
+    /// Snímka aktuálnych metrík a procesov (JSON alebo YAML)
+    /// Výstup sa dá porovnať príkazom `diff`
+    Snapshot(SnapshotArgs),
+
+    /// Porovnanie dvoch snímok (JSON alebo YAML) - zmeny metrík a procesov
+    Diff(DiffArgs),
+
+    /// Výpis klávesových skratok TUI ako JSON (podľa režimov)
+    /// Rovnaká tabuľka ako päty a obrazovka pomoci
+    Keybindings,
+}
+
+/// Cieľ vzoriek headless zberu
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum CollectFormat {
+    #[default]
+    Db,      // Uloženie do PostgreSQL
+    Influx,  // InfluxDB line protocol na stdout (napr. `| influx write`)
+}
+
+/// Nastavenia headless zberu metrík
+#[derive(Args, Clone, Debug)]
+pub struct CollectArgs {
+    /// Interval medzi uloženiami v sekundách (štandardne 60)
+    #[arg(short, long, default_value_t = 60, value_parser = clap::value_parser!(u64).range(1..=86400))]
+    pub interval: u64,
+
+    /// Výstup: db (PostgreSQL) alebo influx (line protocol na stdout, bez DB)
+    #[arg(long, value_enum, default_value_t = CollectFormat::Db)]
+    pub format: CollectFormat,
+
+    /// Režim teplôt: off (v DB sú NULL), estimate (odhady), real (len senzory)
+    #[arg(long, value_enum, default_value_t = TemperatureMode::Estimate)]
+    pub temperatures: TemperatureMode,
+
+    /// Strop sieťovej rýchlosti v KB/s (štandardne 102400 = 100 MB/s)
+    /// Vyššie alebo záporné hodnoty sa považujú za chybné a vynulujú sa
+    #[arg(long, default_value_t = DEFAULT_MAX_NETWORK_KBPS, value_parser = clap::value_parser!(u64).range(1..))]
+    pub max_network_kbps: u64,
+
+    /// Sieťové štatistiky len pre N procesov s najvyšším CPU (šetrí zber na
+    /// systémoch s tisícmi procesov); ostatné procesy sieťové hodnoty nemajú
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    pub network_sample: Option<u32>,
+
+    /// Započítanie vymeniteľných médií (USB kľúče, DVD) do diskových metrík
+    #[arg(long)]
+    pub include_removable_disks: bool,
+}
+
+/// Formát výstupu snímky
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum SnapshotFormat {
+    #[default]
+    Json,    // Kompaktný JSON na jednom riadku
+    Pretty,  // Odsadený JSON
+    Yaml,    // YAML s rovnakými poľami
+}
+
+/// Nastavenia snímky systému
+#[derive(Args, Clone, Debug)]
+pub struct SnapshotArgs {
+    /// Formát výstupu: json, pretty (odsadený JSON) alebo yaml
+    #[arg(long, value_enum, default_value_t = SnapshotFormat::Json)]
+    pub format: SnapshotFormat,
+
+    /// Zápis do súboru namiesto stdout
+    #[arg(short, long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Režim teplôt: off (bez teplôt), estimate (odhady), real (len senzory)
+    #[arg(long, value_enum, default_value_t = TemperatureMode::Estimate)]
+    pub temperatures: TemperatureMode,
+}
+
+/// Súbory snímok pre `diff`
+#[derive(Args, Clone, Debug)]
+pub struct DiffArgs {
+    /// Staršia snímka (`SystemMetrics` a voliteľné `processes`; `.yaml`/`.yml` ako YAML, inak JSON)
+    #[arg(value_name = "FILE1")]
+    pub before: PathBuf,
+
+    /// Novšia snímka
+    #[arg(value_name = "FILE2")]
+    pub after: PathBuf,
+}
+
+/// Nastavenia REST API módu
+/// Zdieľané medzi CLI príkazom `api` a interaktívnym menu
+#[derive(Args, Clone, Debug)]
+pub struct ApiArgs {
+    /// Hostname pre API server (štandardne localhost)
+    #[arg(short = 'H', long, default_value = "127.0.0.1")]  // Skratka -H alebo --host
+    pub host: String,
+
+    /// Port pre API server (štandardne 3000, 0 = voľný port pridelí systém)
+    #[arg(short, long, default_value = "3000")]  // Skratka -p alebo --port
+    pub port: u16,
+
+    /// Unix socket namiesto TCP (napr. za nginx), len Unix
+    /// Nedá sa kombinovať s --host/--port; súbor sa pri ukončení zmaže
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["host", "port"])]
+    pub uds: Option<PathBuf>,
+
+    /// Prefix všetkých ciest pri reverse proxy pod podcestou (napr. `/monitor`)
+    /// `/monitor/api/metrics/current` potom funguje, `/api/metrics/current` vráti 404
+    #[arg(long, value_name = "PREFIX")]
+    pub base_path: Option<String>,
+
+    /// Prepínač pre ukladanie metrík do databázy
+    #[arg(short, long)]  // Skratka -s alebo --save-metrics
+    pub save_metrics: bool,  // Boolean hodnota - true/false
+
+    /// Preskočenie uloženia takmer identických po sebe idúcich vzoriek
+    /// Namiesto nového riadku sa aktualizuje posledný uložený záznam
+    #[arg(long)]
+    pub dedupe: bool,
+
+    /// Tolerancia pre deduplikáciu v percentuálnych bodoch
+    /// Platí pre CPU, RAM aj disk (štandardne 1.0, konečné nezáporné číslo)
+    #[arg(long, default_value_t = 1.0, value_parser = parse_dedupe_tolerance)]
+    pub dedupe_tolerance: f64,
+
+    /// Ukladanie 10 procesov s najvyšším CPU pri každom uložení metrík
+    /// Históriu procesu vracia `/api/processes/{name}/history`
+    #[arg(long, requires = "save_metrics")]
+    pub save_processes: bool,
+
+    /// Varovanie v `/api/logs`, keď dostupná RAM klesne pod zadaný počet MB
+    /// Zapíše sa pri poklese pod prah a pri návrate nad neho (kontrola pri každom uložení)
+    #[arg(long, value_name = "MB", requires = "save_metrics")]
+    pub memory_warn_available_mb: Option<u64>,
+
+    /// Maximálny počet procesov vrátených z `/api/processes/top`
+    /// Vyššie požadované `limit` sa orežú na túto hodnotu (štandardne 100)
+    #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u32).range(1..))]
+    pub max_process_limit: u32,
+
+    /// Overenie konfigurácie (DB, port, host) bez spustenia servera
+    /// Pri chybe sa aplikácia ukončí s nenulovým kódom
+    #[arg(long)]
+    pub dry_run: bool,
+
+    /// Zápis udalostí servera do súboru (denná rotácia, napr. `api.log.2026-10-16`)
+    /// Výpisy na stdout ostávajú; nezapisovateľná cesta ukončí štart s chybou
+    #[arg(long, value_name = "PATH")]
+    pub log_file: Option<PathBuf>,
+
+    /// Varovania a chyby úloh na pozadí (napr. nízka RAM) aj do syslogu
+    /// Len Unix; nedostupný syslog ukončí štart s chybou
+    #[arg(long)]
+    pub syslog: bool,
+
+    /// Režim teplôt: off (v DB sú NULL), estimate (odhady), real (len senzory)
+    #[arg(long, value_enum, default_value_t = TemperatureMode::Estimate)]
+    pub temperatures: TemperatureMode,
+
+    /// Interval vzoriek pre `/api/events/metrics` v sekundách (štandardne 1)
+    #[arg(long, default_value_t = 1, value_parser = clap::value_parser!(u64).range(1..=3600))]
+    pub stream_interval: u64,
+
+    /// Strop sieťovej rýchlosti v KB/s (štandardne 102400 = 100 MB/s)
+    /// Vyššie alebo záporné hodnoty sa považujú za chybné a vynulujú sa
+    #[arg(long, default_value_t = DEFAULT_MAX_NETWORK_KBPS, value_parser = clap::value_parser!(u64).range(1..))]
+    pub max_network_kbps: u64,
+
+    /// Sieťové štatistiky len pre N procesov s najvyšším CPU (šetrí zber na
+    /// systémoch s tisícmi procesov); ostatné procesy sieťové hodnoty nemajú
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    pub network_sample: Option<u32>,
+
+    /// Započítanie vymeniteľných médií (USB kľúče, DVD) do diskových metrík
+    #[arg(long)]
+    pub include_removable_disks: bool,
+
+    /// Ako dlho v ms zdieľajú požiadavky jednu obnovu dát (štandardne 500)
+    /// 0 = každá požiadavka meria systém nanovo
+    #[arg(long, value_name = "MS", default_value_t = 500, value_parser = clap::value_parser!(u64).range(0..=60_000))]
+    pub snapshot_ttl_ms: u64,
+}
+
+impl Default for ApiArgs {
+    /// Predvolené nastavenia - rovnaké ako pri `system-monitor api` bez argumentov
+    fn default() -> Self {
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 3000,
+            uds: None,
+            base_path: None,
+            save_metrics: false,
+            dedupe: false,
+            dedupe_tolerance: 1.0,
+            save_processes: false,
+            memory_warn_available_mb: None,
+            max_process_limit: 100,
+            dry_run: false,
+            log_file: None,
+            syslog: false,
+            temperatures: TemperatureMode::Estimate,
+            stream_interval: 1,
+            max_network_kbps: DEFAULT_MAX_NETWORK_KBPS,
+            network_sample: None,
+            include_removable_disks: false,
+            snapshot_ttl_ms: 500,
+        }
+    }
+}
+
+/// Tolerancia deduplikácie (`--dedupe-tolerance`)
+/// Záporná by každú vzorku považovala za zmenenú, NaN by zlyhalo v každom porovnaní
+fn parse_dedupe_tolerance(value: &str) -> Result<f64, String> {
+    let tolerance: f64 = value.parse().map_err(|e| format!("{}", e))?;
+    if tolerance.is_finite() && tolerance >= 0.0 {
+        Ok(tolerance)
+    } else {
+        Err(format!("must be a finite number >= 0, got {}", value))
+    }
+}
+
+/// Zobrazenie GPU pamäte v prehľade TUI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum GpuMemoryFormat {
+    #[default]
+    Size,     // Použitá / celková veľkosť, napr. `1.2 GB/8.0 GB`
+    Percent,  // Podiel použitej pamäte, napr. `15% mem`
+}
+
+/// Zobrazenie riadku GPU v prehľade TUI (`--show-gpu`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum GpuDisplay {
+    #[default]
+    Always,   // Vždy, aj so simulovanými hodnotami
+    Auto,     // Len pri reálnej GPU (simulované hodnoty sa skryjú)
+    Never,    // Nikdy (servery bez GPU)
+}
+
+/// Jednotka sieťových rýchlostí v TUI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum NetworkUnit {
+    #[default]
+    #[value(name = "kbps")]
+    KBps,     // Kilobajty za sekundu, napr. `125.0 KB/s`
+    Mbps,     // Megabity za sekundu, napr. `1.0 Mbps`
+}
+
+impl NetworkUnit {
+    /// Prepočet z KB/s (1000 KB/s = 8 Mbps)
+    pub fn convert_kbps(self, kbps: f64) -> f64 {
+        match self {
+            NetworkUnit::KBps => kbps,
+            NetworkUnit::Mbps => kbps * 8.0 / 1000.0,
+        }
+    }
+
+    /// Označenie jednotky pre popisy a hlavičky tabuliek
+    pub fn label(&self) -> &'static str {
+        match self {
+            NetworkUnit::KBps => "KB/s",
+            NetworkUnit::Mbps => "Mbps",
+        }
+    }
+}
+
+/// Zaokrúhlenie percent v gauge TUI na celé čísla
+/// Vo všetkých režimoch sa nenulová hodnota zobrazí aspoň ako 1%
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum PercentRounding {
+    #[default]
+    Nearest,  // Najbližšie celé číslo (99.6% -> 100%)
+    Floor,    // Nadol - 100% len pri skutočne plnom využití
+    Ceil,     // Nahor
+}
+
+impl PercentRounding {
+    /// Zaokrúhlenie na celé percento; hodnota nad 0 neklesne pod 1
+    pub fn apply(&self, value: f64) -> f64 {
+        let rounded = match self {
+            PercentRounding::Nearest => value.round(),
+            PercentRounding::Floor => value.floor(),
+            PercentRounding::Ceil => value.ceil(),
+        };
+        if value > 0.0 { rounded.max(1.0) } else { rounded }
+    }
+}
+
+/// Indikátor obnovenia dát v päte TUI
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum RefreshIndicator {
+    #[default]
+    Full,     // Spinner a vek dát (`⠙ updated 3s ago`)
+    Spinner,  // Len spinner
+    Off,      // Bez indikátora
+}
+
+/// Nastavenia TUI módu
+/// Zdieľané medzi CLI príkazom `tui` a interaktívnym menu
+#[derive(Args, Clone, Debug)]
+pub struct TuiArgs {
+    /// Teplota v °C, nad ktorou sa hlási stredná úroveň (žltá)
+    #[arg(long, default_value_t = 65.0)]
+    pub temp_medium: f64,
+
+    /// Teplota v °C, nad ktorou sa hlási vysoká úroveň (červená)
+    #[arg(long, default_value_t = 75.0)]
+    pub temp_high: f64,
+
+    /// Teplota v °C, nad ktorou sa hlási kritická úroveň
+    #[arg(long, default_value_t = 85.0)]
+    pub temp_critical: f64,
+
+    /// Farebné pásma teplôt v prehľade v °C: žltá, červená, fialová nad (štandardne 50,70,85)
+    /// Nezávislé od prahov varovania (`--temp-medium` a ďalšie)
+    #[arg(long, value_name = "MEDIUM,HIGH,CRITICAL", value_delimiter = ',', num_args = 3,
+          default_values_t = [50.0, 70.0, 85.0])]
+    pub temp_colors: Vec<f64>,
+
+    /// Využitie CPU procesu v %, od ktorého je hodnota v zozname žltá
+    #[arg(long, default_value_t = 30.0)]
+    pub cpu_warn: f64,
+
+    /// Využitie CPU procesu v %, od ktorého je hodnota v zozname červená
+    #[arg(long, default_value_t = 70.0)]
+    pub cpu_critical: f64,
+
+    /// Varovanie v titulku prehľadu, keď dostupná RAM klesne pod zadaný počet MB
+    #[arg(long, value_name = "MB")]
+    pub memory_warn_available_mb: Option<u64>,
+
+    /// Počet vzoriek histórie každého jadra CPU v pohľade About (štandardne 30)
+    #[arg(long, value_name = "SAMPLES", default_value_t = 30, value_parser = clap::value_parser!(u32).range(1..=600))]
+    pub core_history: u32,
+
+    /// Sledovanie jedného procesu a jeho potomkov podľa PID
+    /// Zoznam procesov sa obmedzí na tento strom
+    #[arg(long, value_name = "PID")]
+    pub watch_pid: Option<u32>,
+
+    /// Interval obnovy dát v milisekundách (štandardne 1000)
+    /// Klávesy sa spracúvajú nezávisle od tohto intervalu
+    #[arg(long, default_value_t = 1000, value_parser = clap::value_parser!(u64).range(100..))]
+    pub refresh_ms: u64,
+
+    /// Interval obnovy zoznamov procesov v milisekundách (štandardne 2000)
+    /// Zoradenie všetkých procesov je drahšie než gauge, ktoré sa obnovujú po `--refresh-ms`
+    #[arg(long, value_name = "MS", default_value_t = 2000, value_parser = clap::value_parser!(u64).range(100..))]
+    pub process_refresh_ms: u64,
+
+    /// Najdlhšie čakanie na zber dát v milisekundách (100 - 60000, štandardne 2000)
+    /// Po prekročení ostanú predchádzajúce dáta a päta hlási "timed out"
+    #[arg(long, value_name = "MS", default_value_t = 2000, value_parser = clap::value_parser!(u64).range(100..=60000))]
+    pub collect_timeout_ms: u64,
+
+    /// Neukladať ani neobnovovať stav TUI (pohľad, počet procesov)
+    /// medzi spusteniami (~/.cache/system-monitor/ui_state.json)
+    #[arg(long)]
+    pub no_save_state: bool,
+
+    /// Režim teplôt: off (riadok teplôt sa skryje), estimate (odhad pri
+    /// chýbajúcich senzoroch), real (len reálne senzory)
+    #[arg(long, value_enum, default_value_t = TemperatureMode::Estimate)]
+    pub temperatures: TemperatureMode,
+
+    /// Počet desatinných miest pre percentá a veľkosti (0 - 6)
+    /// Bez zadania sa použijú predvolené hodnoty jednotlivých obrazoviek
+    #[arg(long, value_parser = clap::value_parser!(u8).range(0..=6))]
+    pub precision: Option<u8>,
+
+    /// Ukladanie metrík do databázy na pozadí (každých 60 sekúnd)
+    /// Pri nedostupnej databáze TUI beží ďalej bez ukladania
+    #[arg(short, long)]  // Skratka -s alebo --save-metrics
+    pub save_metrics: bool,
+
+    /// Skrytie procesov, ktorých názov obsahuje zadaný text (bez ohľadu
+    /// na veľkosť písmen); dá sa zadať viackrát alebo oddeliť čiarkou
+    #[arg(long = "ignore-process", value_name = "NAME", value_delimiter = ',')]
+    pub ignore_processes: Vec<String>,
+
+    /// Skrytie procesu samotného monitora zo zoznamu procesov
+    #[arg(long)]
+    pub ignore_self: bool,
+
+    /// Len tieto sieťové rozhrania v súčtoch prenesených dát (napr. `eth0,wlan*`);
+    /// `*` na konci = ľubovoľné pokračovanie, bez zadania všetky okrem loopbacku
+    #[arg(long = "include-interface", value_name = "NAME", value_delimiter = ',')]
+    pub include_interfaces: Vec<String>,
+
+    /// Vynechanie sieťových rozhraní zo súčtov (napr. `docker0,veth*,tun*`)
+    #[arg(long = "exclude-interface", value_name = "NAME", value_delimiter = ',')]
+    pub exclude_interfaces: Vec<String>,
+
+    /// Zoskupenie systémových procesov (vlákna jadra, démony, nízke PID)
+    /// do jedného riadku "[system processes]"; za behu kláves S, Enter rozbalí
+    #[arg(long)]
+    pub collapse_system: bool,
+
+    /// Príkazový riadok (program a argumenty) namiesto názvu v zozname procesov
+    /// Dlhé príkazy sa skrátia; celý príkaz je v detaile procesu
+    #[arg(long)]
+    pub show_cmd: bool,
+
+    /// Procesy s PID pod touto hranicou sa považujú za systémové (štandardne 100)
+    #[arg(long, value_name = "PID", default_value_t = DEFAULT_SYSTEM_PID_BELOW)]
+    pub system_pid_below: u32,
+
+    /// Počet procesov v sieťovom pohľade (1 - 50, štandardne 15)
+    /// Bez zadania sa použije hodnota z minulého spustenia; za behu klávesy +/-
+    #[arg(long, value_parser = clap::value_parser!(u32).range(1..=50))]
+    pub network_process_count: Option<u32>,
+
+    /// Zobrazená pamäť procesov: rss (rezidentná) alebo virtual (virtuálna)
+    /// Za behu sa prepína klávesom V
+    #[arg(long, value_enum, default_value_t = MemoryMetric::Rss)]
+    pub memory_metric: MemoryMetric,
+
+    /// GPU pamäť v prehľade: size (použitá/celková) alebo percent (podiel)
+    /// GPU bez vlastnej pamäte (integrované, 0 B celkom) ju nezobrazí
+    #[arg(long, value_enum, default_value_t = GpuMemoryFormat::Size)]
+    pub gpu_memory: GpuMemoryFormat,
+
+    /// Riadok GPU v prehľade: always (štandardne), auto (len pri reálnej GPU) alebo never
+    /// Bez detekcie reálnej karty sú hodnoty GPU len odhad z CPU
+    #[arg(long, value_enum, default_value_t = GpuDisplay::Always)]
+    pub show_gpu: GpuDisplay,
+
+    /// Zaokrúhlenie percent v gauge: nearest, floor alebo ceil
+    /// Nenulové využitie sa nikdy nezobrazí ako 0%
+    #[arg(long, value_enum, default_value_t = PercentRounding::Nearest)]
+    pub percent_rounding: PercentRounding,
+
+    /// Indikátor obnovenia v päte: full (spinner a vek dát), spinner alebo off
+    #[arg(long, value_enum, default_value_t = RefreshIndicator::Full)]
+    pub refresh_indicator: RefreshIndicator,
+
+    /// Jednotka sieťových rýchlostí: kbps (KB/s) alebo mbps (megabity za sekundu)
+    #[arg(long, value_enum, default_value_t = NetworkUnit::KBps)]
+    pub network_unit: NetworkUnit,
+
+    /// Strop sieťovej rýchlosti v KB/s (štandardne 102400 = 100 MB/s)
+    /// Vyššie alebo záporné hodnoty sa považujú za chybné a vynulujú sa
+    #[arg(long, default_value_t = DEFAULT_MAX_NETWORK_KBPS, value_parser = clap::value_parser!(u64).range(1..))]
+    pub max_network_kbps: u64,
+
+    /// Sieťové štatistiky len pre N procesov s najvyšším CPU (šetrí zber na
+    /// systémoch s tisícmi procesov); ostatné procesy sieťové hodnoty nemajú
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    pub network_sample: Option<u32>,
+
+    /// Započítanie vymeniteľných médií (USB kľúče, DVD) do diskových metrík
+    #[arg(long)]
+    pub include_removable_disks: bool,
+}
+
+impl Default for TuiArgs {
+    /// Predvolené nastavenia - rovnaké ako pri `system-monitor tui` bez argumentov
+    fn default() -> Self {
+        let thresholds = TemperatureThresholds::default();
+        let colors = TemperatureThresholds::color_bands();
+        let cpu = CpuThresholds::default();
+        Self {
+            temp_medium: thresholds.medium,
+            temp_high: thresholds.high,
+            temp_critical: thresholds.critical,
+            temp_colors: vec![colors.medium, colors.high, colors.critical],
+            cpu_warn: cpu.warn,
+            cpu_critical: cpu.critical,
+            memory_warn_available_mb: None,
+            core_history: 30,
+            watch_pid: None,
+            refresh_ms: 1000,
+            process_refresh_ms: 2000,
+            collect_timeout_ms: 2000,
+            no_save_state: false,
+            temperatures: TemperatureMode::Estimate,
+            precision: None,
+            save_metrics: false,
+            ignore_processes: Vec::new(),
+            ignore_self: false,
+            include_interfaces: Vec::new(),
+            exclude_interfaces: Vec::new(),
+            collapse_system: false,
+            show_cmd: false,
+            system_pid_below: DEFAULT_SYSTEM_PID_BELOW,
+            network_process_count: None,
+            memory_metric: MemoryMetric::Rss,
+            gpu_memory: GpuMemoryFormat::Size,
+            show_gpu: GpuDisplay::Always,
+            percent_rounding: PercentRounding::Nearest,
+            refresh_indicator: RefreshIndicator::Full,
+            network_unit: NetworkUnit::KBps,
+            max_network_kbps: DEFAULT_MAX_NETWORK_KBPS,
+            network_sample: None,
+            include_removable_disks: false,
+        }
+    }
+}
+
+impl TuiArgs {
+    /// Teplotné prahy zo zadaných hodnôt (s kontrolou poradia)
+    pub fn temperature_thresholds(&self) -> Result<TemperatureThresholds, String> {
+        TemperatureThresholds::new(self.temp_medium, self.temp_high, self.temp_critical)
+    }
+
+    /// Farebné pásma teplôt zo zadaných hodnôt (s kontrolou poradia)
+    pub fn temperature_colors(&self) -> Result<TemperatureThresholds, String> {
+        match self.temp_colors[..] {
+            [medium, high, critical] => TemperatureThresholds::new(medium, high, critical),
+            _ => Err(format!("Expected three temperature color bands, got {}", self.temp_colors.len())),
+        }
+    }
+
+    /// Prahy farby CPU procesov zo zadaných hodnôt (s kontrolou poradia)
+    pub fn cpu_thresholds(&self) -> Result<CpuThresholds, String> {
+        CpuThresholds::new(self.cpu_warn, self.cpu_critical)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dedupe_tolerance_accepts_finite_non_negative_values() {
+        assert_eq!(parse_dedupe_tolerance("0"), Ok(0.0));
+        assert_eq!(parse_dedupe_tolerance("2.5"), Ok(2.5));
+    }
+
+    #[test]
+    fn dedupe_tolerance_rejects_negative_and_non_finite_values() {
+        for value in ["-1", "NaN", "inf", "-inf", "abc"] {
+            assert!(parse_dedupe_tolerance(value).is_err(), "{value} should be rejected");
+        }
+    }
+
+    #[test]
+    fn network_unit_converts_from_kbps() {
+        assert_eq!(NetworkUnit::KBps.convert_kbps(125.0), 125.0);
+        assert_eq!(NetworkUnit::Mbps.convert_kbps(125.0), 1.0);
+    }
+}
@@ -9,8 +9,9 @@ pub fn init_environment() {
     // .ok() konvertuje Result na Option, ignoruje chyby ak súbor neexistuje
     dotenv::dotenv().ok();
     
-    // Potvrdenie úspešnej inicializácie
-    println!("✅ Environment initialized");
+    // Potvrdenie úspešnej inicializácie (na stderr - stdout môže niesť dáta,
+    // napr. `collect --format influx | influx write`)
+    eprintln!("✅ Environment initialized");
 }
 
 /// Validácia hostname (názvu hostiteľa)
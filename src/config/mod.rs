@@ -5,5 +5,5 @@ pub mod cli;       // Modul pre CLI (Command Line Interface)
 pub mod helpers;   // Modul pre pomocné funkcie
 
 /// Re-export dôležitých typov pre jednoduchší import v iných moduloch
-pub use cli::{Cli, Commands};  // Export CLI štruktúr a príkazov
+pub use cli::{Cli, Commands, ApiArgs, TuiArgs, CollectArgs, CollectFormat, SnapshotArgs, SnapshotFormat, DiffArgs, GpuMemoryFormat, GpuDisplay, NetworkUnit, PercentRounding, RefreshIndicator};  // Export CLI štruktúr a príkazov
 pub use helpers::*;            // Export všetkých pomocných funkcií
\ No newline at end of file
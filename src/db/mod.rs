@@ -3,14 +3,22 @@
 /// Databázový modul - obsahuje funkcionalitu pre prácu s databázou
 pub mod connection;  // Modul pre pripojenie k databáze
 pub mod queries;     // Modul pre databázové dotazy
+pub mod table;       // Názvy tabuľky a indexov s prefixom
 
 /// Export dôležitých funkcií pre jednoduchší import
 pub use connection::create_pool;  // Export funkcie na vytvorenie connection pool
+pub use connection::SharedPool;   // Pool zdieľaný s ukladaním na pozadí
+pub use table::{init_table_prefix, validate_table_prefix};  // Prefix tabuľky (`--table-prefix`)
 pub use queries::{                // Export všetkých dotazových funkcií
+    SortOrder,              // Poradie záznamov (asc/desc)
     save_metrics,           // Uloženie metrík
+    touch_metrics,          // Deduplikácia - aktualizácia posledného záznamu
     get_current_metrics,    // Získanie aktuálnych metrík
     get_latest_metrics,     // Získanie posledných metrík
     get_metrics_since,      // Získanie metrík od určitého času
+    get_window_endpoints,   // Najstaršia a najnovšia vzorka v okne
+    save_process_metrics,   // Uloženie vzoriek top procesov
+    get_process_history,    // História procesu podľa názvu
     get_average_cpu,        // Výpočet priemerného CPU
     count_metrics,          // Spočítanie metrík
     cleanup_old_metrics,    // Vyčistenie starých metrík
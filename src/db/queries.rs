@@ -1,68 +1,125 @@
 // queries.rs
 
-use crate::models::{SystemMetrics, GpuInfo};
+use crate::models::{SystemMetrics, MetricsPoint, ProcessInfo, ProcessPoint};
+use super::table::{metrics_table, process_metrics_table};  // Názvy tabuliek s prefixom (`--table-prefix`)
 use chrono::{DateTime, Duration, Utc};
-use sqlx::{PgPool, Row, Result};
+use serde::Deserialize;
+use sqlx::{PgPool, Postgres, QueryBuilder, Row, Result};
+
+/// Poradie vrátených záznamov podľa časovej pečiatky
+/// V query parametri sa zapisuje malými písmenami (`asc` / `desc`)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SortOrder {
+    Asc,            // Od najstaršieho (chronologicky)
+    #[default]
+    Desc,           // Od najnovšieho
+}
+
+impl SortOrder {
+    /// SQL kľúčové slovo pre ORDER BY
+    pub fn as_sql(&self) -> &'static str {
+        match self {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        }
+    }
+}
 
 /// Uloženie systémových metrík do databázy
 /// Ukladá kompletnú sadu systémových metrík vrátane GPU informácií
-pub async fn save_metrics(pool: &PgPool, metrics: &SystemMetrics, gpu_info: Option<&GpuInfo>) -> Result<i64> {
-    let result = sqlx::query!(
+/// GPU polia sa berú z `metrics` (doplnené cez `apply_gpu_info`), nie z ďalšieho merania
+pub async fn save_metrics(pool: &PgPool, metrics: &SystemMetrics) -> Result<i64> {
+    let sql = format!(
         r#"
-        INSERT INTO system_metrics 
+        INSERT INTO {} 
         (timestamp, cpu_usage, memory_total, memory_used, memory_available, 
          swap_total, swap_used, disk_total, disk_used, disk_available,
          gpu_name, gpu_usage, gpu_memory_total, gpu_memory_used, gpu_temperature,
          network_sent_kbps, network_recv_kbps,
          process_count, system_uptime,
-         cpu_temperature, motherboard_temperature, disk_temperature, max_temperature)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23)
+         cpu_temperature, motherboard_temperature, disk_temperature, max_temperature,
+         open_file_descriptors, entropy_available, package_power_watts,
+         battery_percent, battery_charging)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28)
         RETURNING id
         "#,
-        metrics.timestamp,              // Časová značka
-        metrics.cpu_usage,              // Využitie CPU v %
-        metrics.memory_total,           // Celková RAM v bajtoch
-        metrics.memory_used,            // Použitá RAM v bajtoch
-        metrics.memory_available,       // Dostupné RAM v bajtoch
-        metrics.swap_total,             // Celkový swap
-        metrics.swap_used,              // Použitý swap
-        metrics.disk_total,             // Celková veľkosť disku
-        metrics.disk_used,              // Použitý priestor na disku
-        metrics.disk_available,         // Dostupné miesto na disku
-        gpu_info.map(|g| g.name.clone()),  // Názov GPU
-        gpu_info.map(|g| g.usage),      // Využitie GPU v %
-        gpu_info.map(|g| g.memory_total as i64),  // Celková GPU pamäť
-        gpu_info.map(|g| g.memory_used as i64),   // Použitá GPU pamäť
-        gpu_info.and_then(|g| g.temperature),  // Teplota GPU
-        metrics.network_sent_kbps,      // Odoslané dáta v KB/s
-        metrics.network_recv_kbps,      // Prijaté dáta v KB/s
-        metrics.process_count,          // Počet procesov
-        metrics.system_uptime,          // Doba behu systému v sekundách
-        metrics.cpu_temperature,        // Teplota CPU
-        metrics.motherboard_temperature, // Teplota základnej dosky
-        metrics.disk_temperature,       // Teplota disku
-        metrics.max_temperature        // Maximálna teplota
-    )
-    .fetch_one(pool)                   // Vykonanie dotazu a získanie jedného riadku
-    .await?;                           // Async čakanie na výsledok
-
-    Ok(result.id)                      // Vrátenie ID nového záznamu
+        metrics_table()
+    );
+
+    let row = sqlx::query(&sql)
+        .bind(metrics.timestamp)               // Časová značka
+        .bind(metrics.cpu_usage)               // Využitie CPU v %
+        .bind(metrics.memory_total)            // Celková RAM v bajtoch
+        .bind(metrics.memory_used)             // Použitá RAM v bajtoch
+        .bind(metrics.memory_available)        // Dostupné RAM v bajtoch
+        .bind(metrics.swap_total)              // Celkový swap
+        .bind(metrics.swap_used)               // Použitý swap
+        .bind(metrics.disk_total)              // Celková veľkosť disku
+        .bind(metrics.disk_used)               // Použitý priestor na disku
+        .bind(metrics.disk_available)          // Dostupné miesto na disku
+        .bind(&metrics.gpu_name)               // Názov GPU
+        .bind(metrics.gpu_usage)               // Využitie GPU v %
+        .bind(metrics.gpu_memory_total)        // Celková GPU pamäť
+        .bind(metrics.gpu_memory_used)         // Použitá GPU pamäť
+        .bind(metrics.gpu_temperature)         // Teplota GPU (jediný zdroj: get_gpu_info)
+        .bind(metrics.network_sent_kbps)       // Odoslané dáta v KB/s
+        .bind(metrics.network_recv_kbps)       // Prijaté dáta v KB/s
+        .bind(metrics.process_count)           // Počet procesov
+        .bind(metrics.system_uptime)           // Doba behu systému v sekundách
+        .bind(metrics.cpu_temperature)         // Teplota CPU
+        .bind(metrics.motherboard_temperature) // Teplota základnej dosky
+        .bind(metrics.disk_temperature)        // Teplota disku
+        .bind(metrics.max_temperature)         // Maximálna teplota
+        .bind(metrics.open_file_descriptors)   // Otvorené deskriptory (Linux)
+        .bind(metrics.entropy_available)       // Dostupná entropia (Linux)
+        .bind(metrics.package_power_watts)     // Príkon procesora (RAPL)
+        .bind(metrics.battery_percent)         // Nabitie batérie
+        .bind(metrics.battery_charging)        // Nabíjanie batérie
+        .fetch_one(pool)                       // Vykonanie dotazu a získanie jedného riadku
+        .await?;                               // Async čakanie na výsledok
+
+    row.try_get("id")                  // Vrátenie ID nového záznamu
+}
+
+/// Označenie existujúceho záznamu ako znovu videného (deduplikácia)
+/// Namiesto nového riadku sa posunie `last_seen` a zvýši počítadlo vzoriek
+pub async fn touch_metrics(pool: &PgPool, id: i64, seen_at: DateTime<Utc>) -> Result<()> {
+    let sql = format!(
+        r#"UPDATE {}
+           SET last_seen = $2, sample_count = sample_count + 1
+           WHERE id = $1"#,
+        metrics_table()
+    );
+
+    sqlx::query(&sql)
+    .bind(id)                          // ID posledného uloženého záznamu
+    .bind(seen_at)                     // Čas novej (zhodnej) vzorky
+    .execute(pool)
+    .await?;
+
+    Ok(())
 }
 
 /// Získanie aktuálnych metrík z databázy
 /// Vráti posledný uložený záznam systémových metrík
 pub async fn get_current_metrics(pool: &PgPool) -> Result<Option<SystemMetrics>> {
-    let row = sqlx::query(
+    let sql = format!(
         r#"SELECT id, timestamp, cpu_usage, memory_total, memory_used, 
            memory_available, swap_total, swap_used, disk_total, disk_used, 
            disk_available, 
            gpu_name, gpu_usage, gpu_memory_total, gpu_memory_used, gpu_temperature,
            network_sent_kbps, network_recv_kbps,
            process_count, system_uptime,
-           cpu_temperature, motherboard_temperature, disk_temperature, max_temperature
-           FROM system_metrics 
-           ORDER BY timestamp DESC LIMIT 1"#  // Zoradenie podľa času, najnovší prvý
-    )
+           cpu_temperature, motherboard_temperature, disk_temperature, max_temperature,
+           open_file_descriptors, entropy_available, package_power_watts,
+           battery_percent, battery_charging
+           FROM {} 
+           ORDER BY timestamp DESC LIMIT 1"#,  // Zoradenie podľa času, najnovší prvý
+        metrics_table()
+    );
+
+    let row = sqlx::query(&sql)
     .fetch_optional(pool)              // Možný výsledok (môže byť None)
     .await?;
 
@@ -92,6 +149,11 @@ pub async fn get_current_metrics(pool: &PgPool) -> Result<Option<SystemMetrics>>
             motherboard_temperature: row.try_get("motherboard_temperature")?, // Teplota základnej dosky
             disk_temperature: row.try_get("disk_temperature")?, // Teplota disku
             max_temperature: row.try_get("max_temperature")?,   // Maximálna teplota
+            open_file_descriptors: row.try_get("open_file_descriptors")?, // Otvorené deskriptory
+            entropy_available: row.try_get("entropy_available")?,         // Dostupná entropia
+            package_power_watts: row.try_get("package_power_watts")?,     // Príkon procesora
+            battery_percent: row.try_get("battery_percent")?,             // Nabitie batérie
+            battery_charging: row.try_get("battery_charging")?,           // Nabíjanie batérie
         })),
         None => Ok(None),  // Ak neexistujú žiadne záznamy
     }
@@ -99,18 +161,29 @@ pub async fn get_current_metrics(pool: &PgPool) -> Result<Option<SystemMetrics>>
 
 /// Získanie posledných N metrík z databázy
 /// Používa sa pre históriu alebo pre zobrazenie posledných meraní
-pub async fn get_latest_metrics(pool: &PgPool, limit: i64) -> Result<Vec<SystemMetrics>> {
-    let rows = sqlx::query(
-        r#"SELECT id, timestamp, cpu_usage, memory_total, memory_used, 
-           memory_available, swap_total, swap_used, disk_total, disk_used, 
-           disk_available,
-           gpu_name, gpu_usage, gpu_memory_total, gpu_memory_used, gpu_temperature,
-           network_sent_kbps, network_recv_kbps,
-           process_count, system_uptime,
-           cpu_temperature, motherboard_temperature, disk_temperature, max_temperature
-           FROM system_metrics 
-           ORDER BY timestamp DESC LIMIT $1"#  // Limit počtu záznamov
-    )
+pub async fn get_latest_metrics(pool: &PgPool, limit: i64, order: SortOrder) -> Result<Vec<SystemMetrics>> {
+    // Vždy sa vyberie N najnovších záznamov, `order` mení len poradie vo výsledku
+    // Smer triedenia pochádza z enumu a názov tabuľky z overeného prefixu, nie zo vstupu klienta
+    let sql = format!(
+        r#"SELECT * FROM (
+               SELECT id, timestamp, cpu_usage, memory_total, memory_used, 
+               memory_available, swap_total, swap_used, disk_total, disk_used, 
+               disk_available,
+               gpu_name, gpu_usage, gpu_memory_total, gpu_memory_used, gpu_temperature,
+               network_sent_kbps, network_recv_kbps,
+               process_count, system_uptime,
+               cpu_temperature, motherboard_temperature, disk_temperature, max_temperature,
+               open_file_descriptors, entropy_available, package_power_watts,
+               battery_percent, battery_charging
+               FROM {} 
+               ORDER BY timestamp DESC LIMIT $1
+           ) latest
+           ORDER BY timestamp {}"#,
+        metrics_table(),
+        order.as_sql()
+    );
+
+    let rows = sqlx::query(&sql)
     .bind(limit)                           // Parameter pre limit
     .fetch_all(pool)                       // Získanie všetkých riadkov
     .await?;
@@ -142,6 +215,11 @@ pub async fn get_latest_metrics(pool: &PgPool, limit: i64) -> Result<Vec<SystemM
             motherboard_temperature: row.try_get("motherboard_temperature")?,
             disk_temperature: row.try_get("disk_temperature")?,
             max_temperature: row.try_get("max_temperature")?,
+            open_file_descriptors: row.try_get("open_file_descriptors")?,
+            entropy_available: row.try_get("entropy_available")?,
+            package_power_watts: row.try_get("package_power_watts")?,
+            battery_percent: row.try_get("battery_percent")?,
+            battery_charging: row.try_get("battery_charging")?,
         });
     }
 
@@ -153,18 +231,23 @@ pub async fn get_latest_metrics(pool: &PgPool, limit: i64) -> Result<Vec<SystemM
 pub async fn get_metrics_since(pool: &PgPool, hours: i64) -> Result<Vec<SystemMetrics>> {
     let since = Utc::now() - Duration::hours(hours);  // Výpočet časového limitu
     
-    let rows = sqlx::query(
+    let sql = format!(
         r#"SELECT id, timestamp, cpu_usage, memory_total, memory_used, 
            memory_available, swap_total, swap_used, disk_total, disk_used, 
            disk_available,
            gpu_name, gpu_usage, gpu_memory_total, gpu_memory_used, gpu_temperature,
            network_sent_kbps, network_recv_kbps,
            process_count, system_uptime,
-           cpu_temperature, motherboard_temperature, disk_temperature, max_temperature
-           FROM system_metrics 
+           cpu_temperature, motherboard_temperature, disk_temperature, max_temperature,
+           open_file_descriptors, entropy_available, package_power_watts,
+           battery_percent, battery_charging
+           FROM {} 
            WHERE timestamp > $1 
-           ORDER BY timestamp ASC"#  // Chronologické zoradenie
-    )
+           ORDER BY timestamp ASC"#,  // Chronologické zoradenie
+        metrics_table()
+    );
+
+    let rows = sqlx::query(&sql)
     .bind(since)                     // Parameter pre časový limit
     .fetch_all(pool)
     .await?;
@@ -196,34 +279,114 @@ pub async fn get_metrics_since(pool: &PgPool, hours: i64) -> Result<Vec<SystemMe
             motherboard_temperature: row.try_get("motherboard_temperature")?,
             disk_temperature: row.try_get("disk_temperature")?,
             max_temperature: row.try_get("max_temperature")?,
+            open_file_descriptors: row.try_get("open_file_descriptors")?,
+            entropy_available: row.try_get("entropy_available")?,
+            package_power_watts: row.try_get("package_power_watts")?,
+            battery_percent: row.try_get("battery_percent")?,
+            battery_charging: row.try_get("battery_charging")?,
         });
     }
 
     Ok(metrics)
 }
 
+/// Najstaršia a najnovšia vzorka za posledných N hodín
+/// Vráti `(None, None)` ak v okne nie je žiadna vzorka; pri jedinej vzorke
+/// sú obe hodnoty rovnaké
+pub async fn get_window_endpoints(pool: &PgPool, hours: i64) -> Result<(Option<MetricsPoint>, Option<MetricsPoint>)> {
+    let since = Utc::now() - Duration::hours(hours);  // Začiatok okna
+    
+    let table = metrics_table();
+    
+    let oldest = sqlx::query_as::<_, MetricsPoint>(&format!(
+        r#"SELECT timestamp, disk_used, memory_used, process_count
+           FROM {}
+           WHERE timestamp > $1
+           ORDER BY timestamp ASC LIMIT 1"#,
+        table
+    ))
+    .bind(since)
+    .fetch_optional(pool)
+    .await?;
+    
+    let newest = sqlx::query_as::<_, MetricsPoint>(&format!(
+        r#"SELECT timestamp, disk_used, memory_used, process_count
+           FROM {}
+           WHERE timestamp > $1
+           ORDER BY timestamp DESC LIMIT 1"#,
+        table
+    ))
+    .bind(since)
+    .fetch_optional(pool)
+    .await?;
+    
+    Ok((oldest, newest))
+}
+
+/// Uloženie vzoriek procesov z jedného cyklu ukladania
+/// Všetky riadky dostanú rovnaký čas ako uložené metriky; vráti počet riadkov
+pub async fn save_process_metrics(pool: &PgPool, timestamp: DateTime<Utc>, processes: &[ProcessInfo]) -> Result<u64> {
+    if processes.is_empty() {
+        return Ok(0);  // Prázdny VALUES by bol neplatný SQL
+    }
+
+    let mut query: QueryBuilder<Postgres> = QueryBuilder::new(format!(
+        "INSERT INTO {} (timestamp, pid, name, cpu_usage, memory) ",
+        process_metrics_table()
+    ));
+    query.push_values(processes, |mut row, process| {
+        row.push_bind(timestamp)
+            .push_bind(process.pid as i64)
+            .push_bind(&process.name)
+            .push_bind(process.cpu_usage)
+            .push_bind(process.memory as i64);
+    });
+
+    let result = query.build().execute(pool).await?;
+    Ok(result.rows_affected())
+}
+
+/// História procesu podľa názvu za posledných N hodín (chronologicky)
+/// Pri viacerých procesoch s rovnakým názvom obsahuje vzorky všetkých
+pub async fn get_process_history(pool: &PgPool, name: &str, hours: i64) -> Result<Vec<ProcessPoint>> {
+    let since = Utc::now() - Duration::hours(hours);
+
+    sqlx::query_as::<_, ProcessPoint>(&format!(
+        r#"SELECT timestamp, pid, cpu_usage, memory
+           FROM {}
+           WHERE name = $1 AND timestamp > $2
+           ORDER BY timestamp ASC, pid ASC"#,
+        process_metrics_table()
+    ))
+    .bind(name)
+    .bind(since)
+    .fetch_all(pool)
+    .await
+}
+
 /// Výpočet priemerného využitia CPU za posledných N hodín
 /// Používa sa pre dlhodobé štatistiky a analýzy
 pub async fn get_average_cpu(pool: &PgPool, hours: i64) -> Result<f64> {
     let since = Utc::now() - Duration::hours(hours);
     
-    let result = sqlx::query!(
-        "SELECT AVG(cpu_usage) as avg_cpu FROM system_metrics WHERE timestamp > $1",
-        since
-    )
+    let avg_cpu: Option<f64> = sqlx::query_scalar(&format!(
+        "SELECT AVG(cpu_usage) as avg_cpu FROM {} WHERE timestamp > $1",
+        metrics_table()
+    ))
+    .bind(since)
     .fetch_one(pool)
     .await?;
 
-    Ok(result.avg_cpu.unwrap_or(0.0))  // Vrátenie priemeru alebo 0.0 ak žiadne dáta
+    Ok(avg_cpu.unwrap_or(0.0))  // Vrátenie priemeru alebo 0.0 ak žiadne dáta
 }
 
 /// Spočítanie celkového počtu metrík v databáze
 /// Používa sa pre monitorovanie veľkosti databázy
 pub async fn count_metrics(pool: &PgPool) -> Result<i64> {
-    let row = sqlx::query!("SELECT COUNT(*) as count FROM system_metrics")
+    let count: Option<i64> = sqlx::query_scalar(&format!("SELECT COUNT(*) as count FROM {}", metrics_table()))
         .fetch_one(pool)
         .await?;
-    Ok(row.count.unwrap_or(0))  // Vrátenie počtu alebo 0
+    Ok(count.unwrap_or(0))  // Vrátenie počtu alebo 0
 }
 
 /// Vyčistenie starých metrík z databázy
@@ -231,10 +394,8 @@ pub async fn count_metrics(pool: &PgPool) -> Result<i64> {
 pub async fn cleanup_old_metrics(pool: &PgPool, days: i64) -> Result<u64> {
     let cutoff = Utc::now() - Duration::days(days);  // Výpočet časového limitu
     
-    let result = sqlx::query!(
-        "DELETE FROM system_metrics WHERE timestamp < $1",
-        cutoff
-    )
+    let result = sqlx::query(&format!("DELETE FROM {} WHERE timestamp < $1", metrics_table()))
+    .bind(cutoff)
     .execute(pool)
     .await?;
 
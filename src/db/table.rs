@@ -0,0 +1,105 @@
+// table.rs
+
+use std::sync::OnceLock;
+
+/// Názov tabuľky metrík bez prefixu
+const METRICS_TABLE: &str = "system_metrics";
+
+/// Názov tabuľky vzoriek procesov bez prefixu (`--save-processes`)
+const PROCESS_TABLE: &str = "process_metrics";
+
+/// Najdlhší názov, ktorý dostane prefix (index časovej pečiatky)
+const LONGEST_NAME: &str = "idx_metrics_timestamp";
+
+/// Najväčšia dĺžka identifikátora v PostgreSQL (NAMEDATALEN - 1)
+/// Dlhšie názvy PostgreSQL potichu oreže, čím by sa mohli zraziť
+const MAX_IDENTIFIER_LEN: usize = 63;
+
+/// Prefix tabuľky a indexov pre celý proces (nastaví sa raz pri štarte)
+static TABLE_PREFIX: OnceLock<String> = OnceLock::new();
+
+/// Kontrola prefixu podľa pravidiel SQL identifikátora
+///
+/// Prefix sa vkladá priamo do SQL (názov tabuľky sa nedá bindovať ako parameter),
+/// preto sú povolené len malé písmená ASCII, číslice a `_` a nesmie začínať
+/// číslicou. Prázdny prefix znamená pôvodné názvy.
+pub fn validate_table_prefix(prefix: &str) -> Result<String, String> {
+    let mut chars = prefix.chars();
+    if let Some(first) = chars.next() {
+        if !(first.is_ascii_lowercase() || first == '_') {
+            return Err(format!("table prefix '{}' must start with a lowercase letter or '_'", prefix));
+        }
+    }
+    if !chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_') {
+        return Err(format!("table prefix '{}' may only contain lowercase letters, digits and '_'", prefix));
+    }
+    if prefix.len() + LONGEST_NAME.len() > MAX_IDENTIFIER_LEN {
+        return Err(format!(
+            "table prefix '{}' is too long (at most {} characters)",
+            prefix, MAX_IDENTIFIER_LEN - LONGEST_NAME.len()
+        ));
+    }
+    Ok(prefix.to_string())
+}
+
+/// Nastavenie prefixu z `--table-prefix`, inak z premennej `TABLE_PREFIX`
+/// Volá sa raz pri štarte pred prvým pripojením k databáze
+pub fn init_table_prefix(from_cli: Option<&str>) -> Result<(), String> {
+    let prefix = match from_cli {
+        Some(prefix) => prefix.to_string(),
+        None => std::env::var("TABLE_PREFIX").unwrap_or_default(),
+    };
+    let prefix = validate_table_prefix(prefix.trim())?;
+    let _ = TABLE_PREFIX.set(prefix);  // Druhé nastavenie sa ignoruje
+    Ok(())
+}
+
+/// Názov tabuľky metrík s prefixom (napr. `tenant1_system_metrics`)
+pub fn metrics_table() -> String {
+    prefixed(METRICS_TABLE)
+}
+
+/// Názov tabuľky vzoriek procesov s prefixom (napr. `tenant1_process_metrics`)
+pub fn process_metrics_table() -> String {
+    prefixed(PROCESS_TABLE)
+}
+
+/// Názov indexu s prefixom - indexy sú v PostgreSQL v rovnakom mennom
+/// priestore ako tabuľky, preto tiež potrebujú prefix
+pub fn index_name(name: &str) -> String {
+    prefixed(name)
+}
+
+/// Pridanie prefixu (bez nastavenia prázdny)
+fn prefixed(name: &str) -> String {
+    format!("{}{}", TABLE_PREFIX.get().map(String::as_str).unwrap_or(""), name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_prefixes_are_accepted() {
+        for prefix in ["", "tenant1_", "_x", "a"] {
+            assert_eq!(validate_table_prefix(prefix).as_deref(), Ok(prefix));
+        }
+    }
+
+    #[test]
+    fn invalid_characters_are_rejected() {
+        for prefix in ["1tenant", "Tenant_", "te-nant", "a b", "x;drop", "é_"] {
+            assert!(validate_table_prefix(prefix).is_err(), "{prefix} should be rejected");
+        }
+    }
+
+    #[test]
+    fn prefix_must_leave_room_for_the_longest_name() {
+        let longest_allowed = "a".repeat(MAX_IDENTIFIER_LEN - LONGEST_NAME.len());
+        assert!(validate_table_prefix(&longest_allowed).is_ok());
+
+        let too_long = format!("{}a", longest_allowed);
+        let error = validate_table_prefix(&too_long).unwrap_err();
+        assert!(error.contains("too long"), "{error}");
+    }
+}
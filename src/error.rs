@@ -0,0 +1,84 @@
+// error.rs
+
+/// Popis exit kódov pre `--help` (musí zodpovedať `AppError::exit_code`)
+pub const EXIT_CODES_HELP: &str = "\
+Exit codes:
+  0  success
+  1  I/O error
+  2  invalid configuration or arguments
+  3  database error
+  4  cannot bind the address or socket
+  5  terminal unavailable (TUI, interactive menu)";
+
+/// Chyby režimov aplikácie (TUI, API, menu, collect)
+/// Podľa variantu sa dá rozhodnúť, čo sa pokazilo (napr. pre exit kód)
+#[derive(Debug, thiserror::Error)]
+pub enum AppError {
+    /// Databáza je nedostupná alebo dotaz zlyhal
+    #[error("database error: {0}")]
+    Db(#[from] sqlx::Error),
+
+    /// Chyba vstupu/výstupu (súbory, sockety po spustení servera)
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Neplatné nastavenia (argumenty, premenné prostredia)
+    #[error("configuration error: {0}")]
+    Config(String),
+
+    /// Adresu alebo socket servera sa nepodarilo obsadiť
+    #[error("cannot bind {addr}: {reason}")]
+    Bind { addr: String, reason: String },
+
+    /// Terminál nie je dostupný alebo ho nejde ovládať (TUI, menu)
+    #[error("terminal error: {0}")]
+    Terminal(String),
+}
+
+impl AppError {
+    /// Exit kód podľa kategórie chyby - skripty podľa neho môžu reagovať
+    /// Kód 2 používa aj clap pri neplatných argumentoch
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            AppError::Io(_) => 1,
+            AppError::Config(_) => 2,
+            AppError::Db(_) => 3,
+            AppError::Bind { .. } => 4,
+            AppError::Terminal(_) => 5,
+        }
+    }
+
+    /// Chyba naviazania adresy (port obsadený, socket používa iný server)
+    pub fn bind(addr: impl ToString, reason: impl ToString) -> Self {
+        AppError::Bind { addr: addr.to_string(), reason: reason.to_string() }
+    }
+}
+
+/// Chyby interaktívnych výziev menu sú chybami terminálu
+impl From<dialoguer::Error> for AppError {
+    fn from(error: dialoguer::Error) -> Self {
+        AppError::Terminal(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn each_category_has_its_own_exit_code() {
+        let io = std::io::Error::other("disk full");
+        assert_eq!(AppError::Io(io).exit_code(), 1);
+        assert_eq!(AppError::Config("bad".into()).exit_code(), 2);
+        assert_eq!(AppError::Db(sqlx::Error::PoolTimedOut).exit_code(), 3);
+        assert_eq!(AppError::bind("0.0.0.0:8080", "in use").exit_code(), 4);
+        assert_eq!(AppError::Terminal("not a tty".into()).exit_code(), 5);
+    }
+
+    #[test]
+    fn help_text_lists_every_exit_code() {
+        for code in 0..=5 {
+            assert!(EXIT_CODES_HELP.contains(&format!("  {}  ", code)), "exit code {code} is missing");
+        }
+    }
+}
@@ -0,0 +1,16 @@
+// lib.rs
+
+//! Moduly aplikácie ako knižnica
+//! Používa ich binárka (`main.rs`) aj integračné testy v `tests/`
+
+pub mod api;
+pub mod db;
+pub mod models;
+pub mod services;
+pub mod cli;
+pub mod modes;
+pub mod config;
+pub mod error;
+
+#[doc(hidden)]
+pub mod test_support;  // Spoločné pomôcky pre testy (aj pre `tests/`)
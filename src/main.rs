@@ -1,31 +1,53 @@
-mod api;
-mod db;
-mod models;
-mod services;
-mod cli;
-mod modes;
-mod config;
-
 use clap::Parser;
-use config::{Cli, Commands, init_environment};
-use modes::{run_tui_mode, run_api_mode, show_interactive_menu};
+use system_monitor::db;
+use system_monitor::config::{Cli, Commands, init_environment};
+use system_monitor::error::AppError;
+use system_monitor::modes::{run_tui_mode, run_api_mode, run_sensors_mode, run_collect_mode, run_snapshot_mode, run_diff_mode, run_keybindings_mode, show_interactive_menu};
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
+async fn main() {
     
     init_environment();
     
    
     let cli = Cli::parse();
     
+    // Chyba sa vypíše a proces skončí s kódom podľa kategórie (pozri `--help`)
+    if let Err(e) = run(cli).await {
+        eprintln!("❌ {}", e);
+        std::process::exit(e.exit_code());
+    }
+}
+
+/// Spustenie zvoleného režimu
+async fn run(cli: Cli) -> Result<(), AppError> {
+    // Prefix tabuľky pre všetky databázové dotazy (--table-prefix / TABLE_PREFIX)
+    db::init_table_prefix(cli.table_prefix.as_deref()).map_err(AppError::Config)?;
+    
    
     match cli.command {
-        Some(Commands::Tui) => {
-            run_tui_mode()?; 
+        Some(Commands::Tui(args)) => {
+            run_tui_mode(args).await?; 
             Ok(()) 
         }
-        Some(Commands::Api { host, port, save_metrics }) => {
-            run_api_mode(host, port, save_metrics).await
+        Some(Commands::Api(args)) => {
+            run_api_mode(args).await
+        }
+        Some(Commands::Sensors) => {
+            run_sensors_mode();
+            Ok(())
+        }
+        Some(Commands::Collect(args)) => {
+            run_collect_mode(args).await
+        }
+        Some(Commands::Snapshot(args)) => {
+            run_snapshot_mode(args)
+        }
+        Some(Commands::Diff(args)) => {
+            run_diff_mode(args)
+        }
+        Some(Commands::Keybindings) => {
+            run_keybindings_mode()
         }
         None => {
             show_interactive_menu().await
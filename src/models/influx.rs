@@ -0,0 +1,160 @@
+// influx.rs
+
+use super::SystemMetrics;
+
+/// Názov measurementu v InfluxDB
+pub const INFLUX_MEASUREMENT: &str = "system";
+
+/// Escapovanie hodnoty tagu (čiarka, medzera a `=` majú v line protocol význam)
+fn escape_tag(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        if matches!(c, ',' | ' ' | '=') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+impl SystemMetrics {
+    /// Metriky vo formáte InfluxDB line protocol (jeden riadok bez `\n`)
+    ///
+    /// `system,host=<host> cpu_usage=12.5,memory_used=8123456i,... <timestamp v ns>`
+    ///
+    /// Celé čísla majú príponu `i`, boolean je `true`/`false`, chýbajúce (`None`) a nekonečné hodnoty
+    /// sa vynechajú. Výstup sa dá priamo poslať do `influx write`.
+    pub fn to_influx_line(&self, host: &str) -> String {
+        let mut fields: Vec<String> = Vec::new();
+
+        let mut float = |name: &str, value: Option<f64>| {
+            if let Some(value) = value.filter(|v| v.is_finite()) {
+                fields.push(format!("{}={}", name, value));
+            }
+        };
+        float("cpu_usage", Some(self.cpu_usage));
+        float("gpu_usage", self.gpu_usage);
+        float("gpu_temperature", self.gpu_temperature);
+        float("network_sent_kbps", self.network_sent_kbps);
+        float("network_recv_kbps", self.network_recv_kbps);
+        float("cpu_temperature", self.cpu_temperature);
+        float("motherboard_temperature", self.motherboard_temperature);
+        float("disk_temperature", self.disk_temperature);
+        float("max_temperature", self.max_temperature);
+        float("package_power_watts", self.package_power_watts);
+        float("battery_percent", self.battery_percent.map(f64::from));
+
+        let integers = [
+            ("memory_total", Some(self.memory_total)),
+            ("memory_used", Some(self.memory_used)),
+            ("memory_available", Some(self.memory_available)),
+            ("swap_total", Some(self.swap_total)),
+            ("swap_used", Some(self.swap_used)),
+            ("disk_total", Some(self.disk_total)),
+            ("disk_used", Some(self.disk_used)),
+            ("disk_available", Some(self.disk_available)),
+            ("gpu_memory_total", self.gpu_memory_total),
+            ("gpu_memory_used", self.gpu_memory_used),
+            ("process_count", Some(self.process_count)),
+            ("system_uptime", Some(self.system_uptime)),
+            ("open_file_descriptors", self.open_file_descriptors),
+            ("entropy_available", self.entropy_available),
+        ];
+        for (name, value) in integers {
+            if let Some(value) = value {
+                fields.push(format!("{}={}i", name, value));
+            }
+        }
+
+        // Boolean v line protocol ako `true` / `false`
+        if let Some(charging) = self.battery_charging {
+            fields.push(format!("battery_charging={}", charging));
+        }
+
+        format!(
+            "{},host={} {} {}",
+            INFLUX_MEASUREMENT,
+            escape_tag(host),
+            fields.join(","),
+            self.timestamp.timestamp_nanos_opt().unwrap_or_default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_metrics;
+    use chrono::DateTime;
+
+    /// Vzorka s pevným časom 2023-11-14 22:13:20 UTC
+    fn fixed_sample() -> SystemMetrics {
+        sample_metrics(DateTime::from_timestamp(1_700_000_000, 0).unwrap(), 12.5)
+    }
+
+    #[test]
+    fn minimal_sample_produces_exact_line() {
+        let metrics = SystemMetrics {
+            gpu_name: None,
+            gpu_usage: None,
+            gpu_memory_total: None,
+            gpu_memory_used: None,
+            gpu_temperature: None,
+            network_sent_kbps: None,
+            network_recv_kbps: None,
+            cpu_temperature: None,
+            motherboard_temperature: None,
+            disk_temperature: None,
+            max_temperature: None,
+            open_file_descriptors: None,
+            entropy_available: None,
+            package_power_watts: None,
+            battery_percent: None,
+            battery_charging: None,
+            memory_total: 100,
+            memory_used: 40,
+            memory_available: 60,
+            swap_total: 0,
+            swap_used: 0,
+            disk_total: 1000,
+            disk_used: 250,
+            disk_available: 750,
+            process_count: 7,
+            system_uptime: 3600,
+            ..fixed_sample()
+        };
+
+        assert_eq!(
+            metrics.to_influx_line("web01"),
+            "system,host=web01 cpu_usage=12.5,memory_total=100i,memory_used=40i,memory_available=60i,\
+             swap_total=0i,swap_used=0i,disk_total=1000i,disk_used=250i,disk_available=750i,\
+             process_count=7i,system_uptime=3600i 1700000000000000000"
+        );
+    }
+
+    #[test]
+    fn optional_fields_are_typed() {
+        let line = fixed_sample().to_influx_line("web01");
+
+        assert!(line.contains(",gpu_usage=12.5,"), "{line}");
+        assert!(line.contains(",gpu_memory_used=1073741824i,"), "{line}");
+        assert!(line.contains(",battery_percent=80,"), "{line}");
+        assert!(line.contains(",battery_charging=false "), "{line}");
+    }
+
+    #[test]
+    fn non_finite_values_are_skipped() {
+        let metrics = SystemMetrics { gpu_usage: Some(f64::NAN), cpu_temperature: Some(f64::INFINITY), ..fixed_sample() };
+        let line = metrics.to_influx_line("web01");
+
+        assert!(!line.contains("gpu_usage="), "{line}");
+        assert!(!line.contains("cpu_temperature="), "{line}");
+    }
+
+    #[test]
+    fn host_tag_is_escaped() {
+        let line = fixed_sample().to_influx_line("rack 1,node=2");
+
+        assert!(line.starts_with("system,host=rack\\ 1\\,node\\=2 cpu_usage="), "{line}");
+    }
+}
@@ -2,10 +2,45 @@
 
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::fmt;
+
+/// Predvolený strop sieťovej rýchlosti v KB/s (100 MB/s)
+/// Vyššie hodnoty sa považujú za chybné dáta a `sanitize()` ich vynuluje
+pub const DEFAULT_MAX_NETWORK_KBPS: u64 = 100 * 1024;
+
+/// Sieťová hodnota vynulovaná metódou `sanitize()` (pre log)
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkCorrection {
+    pub field: &'static str,  // Názov opraveného poľa
+    pub value: f64,           // Pôvodná hodnota v KB/s
+}
+
+impl fmt::Display for NetworkCorrection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {:.1} KB/s zeroed", self.field, self.value)
+    }
+}
+
+/// Vynulovanie zápornej, neplatnej (NaN) alebo nereálne vysokej hodnoty v KB/s
+fn sanitize_kbps(
+    field: &'static str,
+    value: f64,
+    max_kbps: u64,
+    corrections: &mut Vec<NetworkCorrection>,
+) -> bool {
+    let plausible = (0.0..=max_kbps as f64).contains(&value);  // NaN neprejde
+    if !plausible {
+        corrections.push(NetworkCorrection { field, value });
+    }
+    plausible
+}
 
 /// Hlavná štruktúra pre systémové metriky
 /// Obsahuje všetky kľúčové metriky systému vrátane teplôt
 /// Serializácia a deserializácia pre JSON a SQL podporu
+/// Popis polí pre klientov je v `schema::SYSTEM_METRICS_SCHEMA`
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct SystemMetrics {
     pub id: Option<i64>,                    // Databázové ID (voliteľné pre nové záznamy)
@@ -21,11 +56,13 @@ pub struct SystemMetrics {
     pub disk_available: i64,                // Dostupné miesto na disku
     
     // GPU metriky (voliteľné)
+    // Všetky GPU polia vrátane teploty nastavuje výhradne `apply_gpu_info`
+    // z výsledku `get_gpu_info()` monitora - iné miesta ich nechávajú `None`
     pub gpu_name: Option<String>,           // Názov GPU zariadenia
     pub gpu_usage: Option<f64>,             // Využitie GPU v percentách
     pub gpu_memory_total: Option<i64>,      // Celková GPU pamäť v bajtoch
     pub gpu_memory_used: Option<i64>,       // Použitá GPU pamäť v bajtoch
-    pub gpu_temperature: Option<f64>,       // Teplota GPU v °C (LEN RAZ!)
+    pub gpu_temperature: Option<f64>,       // Teplota GPU v °C (zdroj: GpuInfo)
     
     // Sieťové metriky (voliteľné)
     pub network_sent_kbps: Option<f64>,     // Odoslané dáta v KB/s
@@ -41,7 +78,254 @@ pub struct SystemMetrics {
     pub disk_temperature: Option<f64>,      // Teplota disku v °C
     pub max_temperature: Option<f64>,       // Maximálna nameraná teplota v °C
     
-    // POZOR: gpu_temperature už existuje vyššie - NEOPAKOVAŤ!
+    // Tlak na zdroje jadra (len Linux, inde None)
+    pub open_file_descriptors: Option<i64>, // Otvorené deskriptory súborov v systéme
+    pub entropy_available: Option<i64>,     // Dostupná entropia jadra v bitoch
+    
+    // Príkon procesorového balíka z RAPL (len Linux, inde None)
+    pub package_power_watts: Option<f64>,   // Odhad príkonu balíka vo wattoch
+    
+    // Batéria notebooku (desktopy, servery a iné OS než Linux majú None)
+    pub battery_percent: Option<f32>,       // Nabitie batérie v percentách
+    pub battery_charging: Option<bool>,     // Nabíja sa batéria?
+}
+
+/// Bajty za sekundu na KB/s (None, ak sa nič neprenieslo)
+fn kbps_or_none(bytes: u64) -> Option<f64> {
+    (bytes > 0).then_some(bytes as f64 / 1024.0)
+}
+
+/// Podiel z celku v percentách (0 ak celok nie je známy alebo je nulový)
+fn percent_of(used: i64, total: i64) -> f64 {
+    if total > 0 { used as f64 / total as f64 * 100.0 } else { 0.0 }
+}
+
+impl SystemMetrics {
+    /// Začiatok skladania vzorky s časom `timestamp` (pozri `SystemMetricsBuilder`)
+    pub fn builder(timestamp: DateTime<Utc>) -> SystemMetricsBuilder {
+        SystemMetricsBuilder {
+            metrics: SystemMetrics {
+                id: None,
+                timestamp,
+                cpu_usage: 0.0,
+                memory_total: 0,
+                memory_used: 0,
+                memory_available: 0,
+                swap_total: 0,
+                swap_used: 0,
+                disk_total: 0,
+                disk_used: 0,
+                disk_available: 0,
+                gpu_name: None,
+                gpu_usage: None,
+                gpu_memory_total: None,
+                gpu_memory_used: None,
+                gpu_temperature: None,  // Nastavuje len apply_gpu_info (zdroj: get_gpu_info)
+                network_sent_kbps: None,
+                network_recv_kbps: None,
+                process_count: 0,
+                system_uptime: 0,
+                cpu_temperature: None,
+                motherboard_temperature: None,
+                disk_temperature: None,
+                max_temperature: None,
+                open_file_descriptors: None,
+                entropy_available: None,
+                package_power_watts: None,
+                battery_percent: None,
+                battery_charging: None,
+            },
+        }
+    }
+
+    /// Využitie RAM v percentách (0 pri nulovej celkovej pamäti)
+    pub fn memory_percent(&self) -> f64 {
+        percent_of(self.memory_used, self.memory_total)
+    }
+
+    /// Celková RAM je známa (0 = monitor ju nezistil, pozri `services::meminfo`)
+    pub fn is_memory_known(&self) -> bool {
+        self.memory_total > 0
+    }
+
+    /// Využitie disku v percentách (0 pri neznámom disku)
+    pub fn disk_percent(&self) -> f64 {
+        percent_of(self.disk_used, self.disk_total)
+    }
+
+    /// Využitie swapu v percentách (0 ak swap nie je nastavený)
+    pub fn swap_percent(&self) -> f64 {
+        percent_of(self.swap_used, self.swap_total)
+    }
+
+    /// Dostupná RAM v MB (celé MB nadol)
+    pub fn memory_available_mb(&self) -> u64 {
+        self.memory_available.max(0) as u64 / 1024 / 1024
+    }
+
+    /// Dostupná RAM klesla pod `threshold_mb` (`--memory-warn-available-mb`)
+    /// Pri nedostupnej pamäti sa nehlási - nula by nebola skutočná hodnota
+    pub fn is_memory_low(&self, threshold_mb: u64) -> bool {
+        self.is_memory_known()
+            && (self.memory_available.max(0) as u64) < threshold_mb.saturating_mul(1024 * 1024)
+    }
+
+    /// Doplnenie GPU polí (názov, využitie, pamäť, teplota) z `GpuInfo`
+    /// Jediné miesto, kde sa nastavuje `gpu_temperature` - v debug builde
+    /// sa overí, že teplota predtým nastavená nebola
+    pub fn apply_gpu_info(&mut self, gpu_info: &GpuInfo) {
+        debug_assert!(
+            self.gpu_temperature.is_none(),
+            "gpu_temperature must be set only once, from get_gpu_info()"
+        );
+
+        self.gpu_name = Some(gpu_info.name.clone());
+        self.gpu_usage = Some(gpu_info.usage);
+        self.gpu_memory_total = Some(gpu_info.memory_total as i64);
+        self.gpu_memory_used = Some(gpu_info.memory_used as i64);
+        self.gpu_temperature = gpu_info.temperature;
+    }
+
+    /// Vynulovanie záporných a nereálnych sieťových rýchlostí (nad `max_kbps`)
+    /// Volá sa v monitoroch, takže chybné hodnoty sa nedostanú do DB ani UI.
+    /// Vráti zoznam opráv pre log (prázdny, ak bolo všetko v poriadku).
+    pub fn sanitize(&mut self, max_kbps: u64) -> Vec<NetworkCorrection> {
+        let mut corrections = Vec::new();
+
+        for (field, value) in [
+            ("network_sent_kbps", &mut self.network_sent_kbps),
+            ("network_recv_kbps", &mut self.network_recv_kbps),
+        ] {
+            if let Some(kbps) = *value {
+                if !sanitize_kbps(field, kbps, max_kbps, &mut corrections) {
+                    *value = Some(0.0);
+                }
+            }
+        }
+
+        corrections
+    }
+
+    /// Porovnanie dvoch vzoriek pre deduplikáciu pred uložením do DB
+    /// Vzorky sa považujú za zhodné, ak sa CPU, RAM aj disk líšia
+    /// najviac o `tolerance` percentuálnych bodov
+    pub fn is_near_duplicate(&self, other: &SystemMetrics, tolerance: f64) -> bool {
+        let cpu_diff = (self.cpu_usage - other.cpu_usage).abs();
+        let memory_diff = (self.memory_percent() - other.memory_percent()).abs();
+        let disk_diff = (self.disk_percent() - other.disk_percent()).abs();
+
+        cpu_diff <= tolerance
+            && memory_diff <= tolerance
+            && disk_diff <= tolerance
+            && self.memory_total == other.memory_total  // Iný stroj / zmena RAM = nová vzorka
+            && self.disk_total == other.disk_total
+    }
+}
+
+/// Skladanie `SystemMetrics` v monitoroch (`SystemMetrics::builder`)
+///
+/// Nenastavené polia ostanú nulové alebo `None`; GPU polia sa nevypĺňajú,
+/// nastavuje ich len `apply_gpu_info`. Nové pole tak stačí doplniť do
+/// `builder` a jednej metódy namiesto každého monitora.
+#[derive(Debug, Clone)]
+#[must_use]
+pub struct SystemMetricsBuilder {
+    metrics: SystemMetrics,  // Rozpracovaná vzorka
+}
+
+impl SystemMetricsBuilder {
+    /// Využitie CPU v percentách
+    pub fn cpu_usage(mut self, cpu_usage: f64) -> Self {
+        self.metrics.cpu_usage = cpu_usage;
+        self
+    }
+
+    /// RAM v bajtoch (celková, použitá, dostupná)
+    pub fn memory(mut self, total: u64, used: u64, available: u64) -> Self {
+        self.metrics.memory_total = total as i64;
+        self.metrics.memory_used = used as i64;
+        self.metrics.memory_available = available as i64;
+        self
+    }
+
+    /// Swap v bajtoch (celkový, použitý)
+    pub fn swap(mut self, total: u64, used: u64) -> Self {
+        self.metrics.swap_total = total as i64;
+        self.metrics.swap_used = used as i64;
+        self
+    }
+
+    /// Hlavný disk v bajtoch (celková veľkosť, použité, dostupné)
+    pub fn disk(mut self, total: u64, used: u64, available: u64) -> Self {
+        self.metrics.disk_total = total as i64;
+        self.metrics.disk_used = used as i64;
+        self.metrics.disk_available = available as i64;
+        self
+    }
+
+    /// Sieťová prevádzka v bajtoch za sekundu - uloží sa v KB/s, nula ako `None`
+    pub fn network_bytes(mut self, sent: u64, recv: u64) -> Self {
+        self.metrics.network_sent_kbps = kbps_or_none(sent);
+        self.metrics.network_recv_kbps = kbps_or_none(recv);
+        self
+    }
+
+    /// Počet procesov a doba behu systému v sekundách
+    pub fn system(mut self, process_count: usize, uptime_secs: u64) -> Self {
+        self.metrics.process_count = process_count as i64;
+        self.metrics.system_uptime = uptime_secs as i64;
+        self
+    }
+
+    /// Teploty v °C (CPU, základná doska, disk, maximum)
+    pub fn temperatures(
+        mut self,
+        cpu: Option<f64>,
+        motherboard: Option<f64>,
+        disk: Option<f64>,
+        max: Option<f64>,
+    ) -> Self {
+        self.metrics.cpu_temperature = cpu;
+        self.metrics.motherboard_temperature = motherboard;
+        self.metrics.disk_temperature = disk;
+        self.metrics.max_temperature = max;
+        self
+    }
+
+    /// Tlak na zdroje jadra (otvorené deskriptory, entropia v bitoch)
+    pub fn kernel_counters(mut self, open_file_descriptors: Option<i64>, entropy_available: Option<i64>) -> Self {
+        self.metrics.open_file_descriptors = open_file_descriptors;
+        self.metrics.entropy_available = entropy_available;
+        self
+    }
+
+    /// Príkon procesorového balíka vo wattoch (RAPL)
+    pub fn package_power_watts(mut self, watts: Option<f64>) -> Self {
+        self.metrics.package_power_watts = watts;
+        self
+    }
+
+    /// Stav batérie (nabitie v percentách, nabíjanie)
+    pub fn battery(mut self, percent: Option<f32>, charging: Option<bool>) -> Self {
+        self.metrics.battery_percent = percent;
+        self.metrics.battery_charging = charging;
+        self
+    }
+
+    /// Hotová vzorka
+    pub fn build(self) -> SystemMetrics {
+        self.metrics
+    }
+}
+
+/// Názov procesu, alebo `[pid N]`, ak je prázdny (niektoré platformy
+/// názov neposkytujú a riadok v tabuľke by bol prázdny)
+pub fn name_or_pid(pid: u32, name: &str) -> Cow<'_, str> {
+    if name.trim().is_empty() {
+        Cow::Owned(format!("[pid {}]", pid))
+    } else {
+        Cow::Borrowed(name)
+    }
 }
 
 /// Informácie o procese
@@ -49,11 +333,206 @@ pub struct SystemMetrics {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProcessInfo {
     pub pid: u32,                           // ID procesu
+    pub parent_pid: Option<u32>,            // ID rodičovského procesu (ak existuje)
     pub name: String,                       // Názov procesu
-    pub memory: u64,                        // Použitá pamäť v bajtoch
+    pub memory: u64,                        // Rezidentná pamäť (RSS) v bajtoch
+    #[serde(default)]
+    pub virtual_memory: u64,                // Virtuálna pamäť v bajtoch
     pub cpu_usage: f32,                     // Využitie CPU v percentách
     pub network_sent: Option<u64>,          // Odoslané sieťové dáta v bajtoch
     pub network_recv: Option<u64>,          // Prijaté sieťové dáta v bajtoch
+    #[serde(default)]
+    pub disk_read: Option<u64>,             // Prečítané z disku od poslednej obnovy (bajty)
+    #[serde(default)]
+    pub disk_write: Option<u64>,            // Zapísané na disk od poslednej obnovy (bajty)
+    #[serde(default)]
+    pub swap: Option<u64>,                  // Swap procesu v bajtoch (len Linux, inak None)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cmd: Vec<String>,                   // Príkazový riadok (program a argumenty; prázdny bez práv)
+}
+
+/// Pamäť procesu zobrazená v TUI (`--memory-metric`, kláves `V`)
+/// RSS započítava aj zdieľané stránky, virtuálna pamäť celý adresný priestor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum MemoryMetric {
+    #[default]
+    Rss,      // Rezidentná pamäť (`memory`)
+    Virtual,  // Virtuálna pamäť (`virtual_memory`)
+}
+
+impl MemoryMetric {
+    /// Krátky názov pre hlavičky tabuliek
+    pub fn label(&self) -> &'static str {
+        match self {
+            MemoryMetric::Rss => "RSS",
+            MemoryMetric::Virtual => "Virtual",
+        }
+    }
+
+    /// Zvolená pamäť procesu v bajtoch
+    pub fn bytes(&self, process: &ProcessInfo) -> u64 {
+        match self {
+            MemoryMetric::Rss => process.memory,
+            MemoryMetric::Virtual => process.virtual_memory,
+        }
+    }
+
+    /// Druhá z dvoch metrík (prepínanie klávesom)
+    pub fn toggled(&self) -> Self {
+        match self {
+            MemoryMetric::Rss => MemoryMetric::Virtual,
+            MemoryMetric::Virtual => MemoryMetric::Rss,
+        }
+    }
+}
+
+/// Poradie procesov v `/api/processes/top?sort=disk`
+/// V query parametri sa zapisuje malými písmenami
+#[derive(Debug, Clone, Copy, Default, PartialEq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProcessSort {
+    #[default]
+    Combined,   // CPU + sieťová a disková aktivita v MB
+    Cpu,        // Využitie CPU
+    Memory,     // Použitá pamäť
+    Network,    // Odoslané + prijaté bajty
+    Disk,       // Prečítané + zapísané bajty
+    Swap,       // Swap procesu (len Linux)
+}
+
+impl ProcessSort {
+    /// Porovnanie pre zostupné poradie podľa zvoleného kritéria
+    fn compare(self, a: &ProcessInfo, b: &ProcessInfo) -> Ordering {
+        match self {
+            ProcessSort::Combined => b.combined_score().total_cmp(&a.combined_score()),
+            ProcessSort::Cpu => b.cpu_usage.total_cmp(&a.cpu_usage),
+            ProcessSort::Memory => b.memory.cmp(&a.memory),
+            ProcessSort::Network => b.network_bytes().cmp(&a.network_bytes()),
+            ProcessSort::Disk => b.disk_bytes().cmp(&a.disk_bytes()),
+            ProcessSort::Swap => b.swap.unwrap_or(0).cmp(&a.swap.unwrap_or(0)),
+        }
+    }
+
+    /// Ponechanie `limit` najvyšších procesov, zoradených zostupne
+    ///
+    /// Pri tisícoch procesov sa celý zoznam netriedi: `select_nth_unstable_by`
+    /// oddelí top N v lineárnom čase a triedi sa len tých N (O(n + N log N)).
+    /// Výsledok je rovnaký ako pri úplnom zoradení a orezaní, len pri zhodnom
+    /// skóre môže byť poradie procesov iné.
+    pub fn top_n(self, processes: &mut Vec<ProcessInfo>, limit: usize) {
+        if limit == 0 {
+            processes.clear();
+            return;
+        }
+        if processes.len() > limit {
+            processes.select_nth_unstable_by(limit - 1, |a, b| self.compare(a, b));
+            processes.truncate(limit);
+        }
+        processes.sort_by(|a, b| self.compare(a, b));
+    }
+}
+
+/// Minimálne hodnoty pre `/api/processes/top?min_cpu=5&min_memory_mb=100`
+/// Procesy pod niektorou hranicou sa vyradia ešte pred výberom top N
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ProcessMinimums {
+    pub cpu: Option<f32>,     // Najnižšie využitie CPU v percentách
+    pub memory: Option<u64>,  // Najnižšia pamäť (RSS) v bajtoch
+}
+
+impl ProcessMinimums {
+    /// Spĺňa proces všetky zadané hranice?
+    pub fn matches(&self, process: &ProcessInfo) -> bool {
+        self.cpu.is_none_or(|min| process.cpu_usage >= min)
+            && self.memory.is_none_or(|min| process.memory >= min)
+    }
+}
+
+/// Prahy využitia CPU procesu pre farbu v zoznamoch (v percentách)
+/// Predvolené hodnoty: žltá od 30 %, červená od 70 %
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CpuThresholds {
+    pub warn: f64,      // Od tejto hodnoty žltá
+    pub critical: f64,  // Od tejto hodnoty červená
+}
+
+impl Default for CpuThresholds {
+    fn default() -> Self {
+        CpuThresholds { warn: 30.0, critical: 70.0 }
+    }
+}
+
+impl CpuThresholds {
+    /// Vytvorenie prahov s kontrolou poradia (varovanie <= kritická)
+    pub fn new(warn: f64, critical: f64) -> Result<Self, String> {
+        if warn <= critical {
+            Ok(CpuThresholds { warn, critical })
+        } else {
+            Err(format!(
+                "Invalid CPU thresholds: expected warn <= critical, got {} / {}",
+                warn, critical
+            ))
+        }
+    }
+}
+
+impl ProcessInfo {
+    /// Názov na zobrazenie - pri prázdnom názve názov spustiteľného súboru
+    /// z príkazového riadku, inak `[pid N]`
+    /// Všetky zoznamy procesov v TUI zobrazujú názov cez túto funkciu
+    pub fn display_name(&self) -> Cow<'_, str> {
+        if self.name.trim().is_empty() {
+            let executable = self.cmd.first()
+                .and_then(|path| std::path::Path::new(path).file_name())
+                .and_then(|name| name.to_str())
+                .filter(|name| !name.trim().is_empty());
+            if let Some(executable) = executable {
+                return Cow::Borrowed(executable);
+            }
+        }
+        name_or_pid(self.pid, &self.name)
+    }
+
+    /// Príkazový riadok spojený medzerami (None, ak ho nie je možné zistiť)
+    pub fn command_line(&self) -> Option<String> {
+        let joined = self.cmd.join(" ");
+        (!joined.trim().is_empty()).then_some(joined)
+    }
+
+    /// Odoslané + prijaté sieťové bajty
+    pub fn network_bytes(&self) -> u64 {
+        self.network_sent.unwrap_or(0) + self.network_recv.unwrap_or(0)
+    }
+
+    /// Prečítané + zapísané diskové bajty
+    pub fn disk_bytes(&self) -> u64 {
+        self.disk_read.unwrap_or(0) + self.disk_write.unwrap_or(0)
+    }
+
+    /// Kombinované skóre pre top procesy: CPU % + sieťová a disková aktivita v MB
+    /// Proces s veľkým diskovým I/O sa tak dostane medzi top aj pri nízkom CPU
+    pub fn combined_score(&self) -> f32 {
+        self.cpu_usage + (self.network_bytes() + self.disk_bytes()) as f32 / 1024.0 / 1024.0
+    }
+
+    /// Vynulovanie nereálnej sieťovej aktivity procesu (nad `max_kbps`)
+    /// Vráti zoznam opráv pre log (prázdny, ak bolo všetko v poriadku)
+    pub fn sanitize(&mut self, max_kbps: u64) -> Vec<NetworkCorrection> {
+        let mut corrections = Vec::new();
+
+        for (field, value) in [
+            ("network_sent", &mut self.network_sent),
+            ("network_recv", &mut self.network_recv),
+        ] {
+            if let Some(bytes) = *value {
+                if !sanitize_kbps(field, bytes as f64 / 1024.0, max_kbps, &mut corrections) {
+                    *value = Some(0);
+                }
+            }
+        }
+
+        corrections
+    }
 }
 
 /// Informácie o CPU
@@ -63,6 +542,7 @@ pub struct CpuInfo {
     pub name: String,                       // Názov procesora
     pub usage: f32,                         // Celkové využitie v percentách
     pub frequency: u64,                     // Frekvencia v Hz
+    pub physical_core: Option<usize>,       // Fyzické jadro (od 1), None ak topológia nie je známa
 }
 
 /// Informácie o pamäti
@@ -93,4 +573,447 @@ pub struct GpuInfo {
     pub memory_total: u64,                  // Celková GPU pamäť v bajtoch
     pub memory_used: u64,                   // Použitá GPU pamäť v bajtoch
     pub temperature: Option<f64>,           // Teplota GPU v °C
-}
\ No newline at end of file
+    #[serde(default)]
+    pub source: GpuSource,                  // Pôvod hodnôt (reálna detekcia / simulácia)
+}
+
+/// Pôvod údajov o GPU
+/// Bez úspešnej detekcie reálnej karty sú hodnoty odvodené od záťaže CPU
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum GpuSource {
+    #[default]
+    Simulated,  // Odhad z využitia CPU
+    Real,       // Údaje z ovládača grafickej karty
+}
+
+impl GpuSource {
+    /// Označenie pre zobrazenie v UI
+    pub fn label(&self) -> &'static str {
+        match self {
+            GpuSource::Simulated => "simulated",
+            GpuSource::Real => "real",
+        }
+    }
+}
+
+/// Hodnoty jednej vzorky potrebné pre výpočet zmeny v čase
+/// Načítava sa len pre najstaršiu a najnovšiu vzorku v okne
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct MetricsPoint {
+    pub timestamp: DateTime<Utc>,           // Časová značka vzorky
+    pub disk_used: i64,                     // Použitý priestor na disku
+    pub memory_used: i64,                   // Použitá RAM v bajtoch
+    pub process_count: i64,                 // Počet procesov
+}
+
+/// Jedna uložená vzorka procesu (`--save-processes`)
+/// Procesy s rovnakým názvom sa rozlišujú podľa PID
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct ProcessPoint {
+    pub timestamp: DateTime<Utc>,           // Čas uloženia (rovnaký ako riadok metrík)
+    pub pid: i64,                           // PID procesu
+    pub cpu_usage: f32,                     // Využitie CPU v percentách
+    pub memory: i64,                        // Pamäť (RSS) v bajtoch
+}
+
+/// Zmena metrík medzi najstaršou a najnovšou vzorkou v časovom okne
+/// Pri menej ako dvoch vzorkách sú všetky hodnoty `None` (v JSON `null`)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MetricsDelta {
+    pub from: Option<DateTime<Utc>>,        // Čas najstaršej vzorky
+    pub to: Option<DateTime<Utc>>,          // Čas najnovšej vzorky
+    pub elapsed_hours: Option<f64>,         // Dĺžka okna medzi vzorkami v hodinách
+    pub disk_used_delta: Option<i64>,       // Zmena použitého disku v bajtoch
+    pub disk_used_per_hour: Option<f64>,    // Zmena disku za hodinu
+    pub memory_used_delta: Option<i64>,     // Zmena použitej RAM v bajtoch
+    pub memory_used_per_hour: Option<f64>,  // Zmena RAM za hodinu
+    pub process_count_delta: Option<i64>,   // Zmena počtu procesov
+    pub process_count_per_hour: Option<f64>, // Zmena počtu procesov za hodinu
+}
+
+impl MetricsDelta {
+    /// Výpočet zmeny medzi dvoma vzorkami
+    /// Ak vzorky chýbajú alebo majú rovnaký čas (jediná vzorka), vráti prázdny výsledok
+    pub fn between(oldest: Option<&MetricsPoint>, newest: Option<&MetricsPoint>) -> Self {
+        let (oldest, newest) = match (oldest, newest) {
+            (Some(o), Some(n)) if n.timestamp > o.timestamp => (o, n),
+            _ => return Self::default(),  // Menej ako dve vzorky
+        };
+
+        let elapsed_hours = (newest.timestamp - oldest.timestamp).num_milliseconds() as f64 / 3_600_000.0;
+        let per_hour = |delta: i64| delta as f64 / elapsed_hours;
+
+        let disk_delta = newest.disk_used - oldest.disk_used;
+        let memory_delta = newest.memory_used - oldest.memory_used;
+        let process_delta = newest.process_count - oldest.process_count;
+
+        Self {
+            from: Some(oldest.timestamp),
+            to: Some(newest.timestamp),
+            elapsed_hours: Some(elapsed_hours),
+            disk_used_delta: Some(disk_delta),
+            disk_used_per_hour: Some(per_hour(disk_delta)),
+            memory_used_delta: Some(memory_delta),
+            memory_used_per_hour: Some(per_hour(memory_delta)),
+            process_count_delta: Some(process_delta),
+            process_count_per_hour: Some(per_hour(process_delta)),
+        }
+    }
+}
+
+/// Zjednodušené metriky pre klientov s obmedzeným prenosom (IoT displeje)
+/// Vracia `/api/metrics/current?format=compact`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompactMetrics {
+    pub cpu: f64,                // Využitie CPU v %
+    pub mem_pct: f64,            // Využitie RAM v %
+    pub disk_pct: f64,           // Využitie disku v %
+    pub gpu: Option<f64>,        // Využitie GPU v %
+    pub temp: Option<f64>,       // Maximálna teplota v °C
+}
+
+impl From<&SystemMetrics> for CompactMetrics {
+    /// Odvodenie percent z úplných metrík (zaokrúhlené na jedno desatinné miesto)
+    fn from(metrics: &SystemMetrics) -> Self {
+        let round = |value: f64| (value * 10.0).round() / 10.0;
+
+        Self {
+            cpu: round(metrics.cpu_usage),
+            mem_pct: round(metrics.memory_percent()),
+            disk_pct: round(metrics.disk_percent()),
+            gpu: metrics.gpu_usage.map(round),
+            temp: metrics.max_temperature.map(round),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timestamp() -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000, 0).unwrap()
+    }
+
+    #[test]
+    fn builder_without_setters_gives_an_empty_sample() {
+        let metrics = SystemMetrics::builder(timestamp()).build();
+
+        assert_eq!(metrics.timestamp, timestamp());
+        assert_eq!(metrics.id, None);
+        assert_eq!(metrics.cpu_usage, 0.0);
+        assert_eq!(metrics.memory_total, 0);
+        assert_eq!(metrics.process_count, 0);
+        assert_eq!(metrics.gpu_name, None);
+        assert_eq!(metrics.network_sent_kbps, None);
+        assert_eq!(metrics.max_temperature, None);
+        assert_eq!(metrics.battery_charging, None);
+    }
+
+    #[test]
+    fn builder_sets_every_group_of_fields() {
+        let metrics = SystemMetrics::builder(timestamp())
+            .cpu_usage(37.5)
+            .memory(16_000, 6_000, 10_000)
+            .swap(2_000, 500)
+            .disk(512_000, 200_000, 312_000)
+            .network_bytes(2048, 512)
+            .system(321, 86_400)
+            .temperatures(Some(48.0), Some(36.0), None, Some(48.0))
+            .kernel_counters(Some(4096), Some(256))
+            .package_power_watts(Some(17.25))
+            .battery(Some(80.0), Some(true))
+            .build();
+
+        assert_eq!(metrics.cpu_usage, 37.5);
+        assert_eq!((metrics.memory_total, metrics.memory_used, metrics.memory_available), (16_000, 6_000, 10_000));
+        assert_eq!((metrics.swap_total, metrics.swap_used), (2_000, 500));
+        assert_eq!((metrics.disk_total, metrics.disk_used, metrics.disk_available), (512_000, 200_000, 312_000));
+        assert_eq!((metrics.network_sent_kbps, metrics.network_recv_kbps), (Some(2.0), Some(0.5)));
+        assert_eq!((metrics.process_count, metrics.system_uptime), (321, 86_400));
+        assert_eq!(metrics.cpu_temperature, Some(48.0));
+        assert_eq!(metrics.motherboard_temperature, Some(36.0));
+        assert_eq!(metrics.disk_temperature, None);
+        assert_eq!(metrics.max_temperature, Some(48.0));
+        assert_eq!((metrics.open_file_descriptors, metrics.entropy_available), (Some(4096), Some(256)));
+        assert_eq!(metrics.package_power_watts, Some(17.25));
+        assert_eq!((metrics.battery_percent, metrics.battery_charging), (Some(80.0), Some(true)));
+        // GPU polia nastavuje len `apply_gpu_info`
+        assert_eq!(metrics.gpu_usage, None);
+    }
+
+    #[test]
+    fn zero_network_traffic_is_stored_as_none() {
+        let metrics = SystemMetrics::builder(timestamp()).network_bytes(0, 1024).build();
+
+        assert_eq!(metrics.network_sent_kbps, None);
+        assert_eq!(metrics.network_recv_kbps, Some(1.0));
+    }
+
+    fn gpu(temperature: Option<f64>) -> GpuInfo {
+        GpuInfo {
+            name: "Test GPU".to_string(),
+            usage: 40.0,
+            memory_total: 8 * 1024,
+            memory_used: 2 * 1024,
+            temperature,
+            source: GpuSource::Simulated,
+        }
+    }
+
+    #[test]
+    fn gpu_fields_come_from_one_gpu_info() {
+        let mut metrics = SystemMetrics::builder(timestamp()).build();
+
+        metrics.apply_gpu_info(&gpu(Some(61.5)));
+
+        assert_eq!(metrics.gpu_name.as_deref(), Some("Test GPU"));
+        assert_eq!(metrics.gpu_usage, Some(40.0));
+        assert_eq!((metrics.gpu_memory_total, metrics.gpu_memory_used), (Some(8 * 1024), Some(2 * 1024)));
+        assert_eq!(metrics.gpu_temperature, Some(61.5));
+    }
+
+    #[test]
+    fn missing_gpu_temperature_stays_missing() {
+        let mut metrics = SystemMetrics::builder(timestamp()).build();
+
+        metrics.apply_gpu_info(&gpu(None));
+
+        assert_eq!(metrics.gpu_temperature, None);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "gpu_temperature must be set only once")]
+    fn second_gpu_temperature_source_is_caught() {
+        let mut metrics = SystemMetrics::builder(timestamp()).build();
+
+        metrics.apply_gpu_info(&gpu(Some(61.5)));
+        metrics.apply_gpu_info(&gpu(Some(70.0)));
+    }
+
+    fn point(hours: i64, disk_used: i64, memory_used: i64, process_count: i64) -> MetricsPoint {
+        MetricsPoint { timestamp: timestamp() + chrono::Duration::hours(hours), disk_used, memory_used, process_count }
+    }
+
+    #[test]
+    fn delta_between_two_samples_has_changes_and_hourly_rates() {
+        let oldest = point(0, 1_000, 4_000, 200);
+        let newest = point(2, 3_000, 3_000, 210);
+
+        let delta = MetricsDelta::between(Some(&oldest), Some(&newest));
+
+        assert_eq!((delta.from, delta.to), (Some(oldest.timestamp), Some(newest.timestamp)));
+        assert_eq!(delta.elapsed_hours, Some(2.0));
+        assert_eq!((delta.disk_used_delta, delta.disk_used_per_hour), (Some(2_000), Some(1_000.0)));
+        assert_eq!((delta.memory_used_delta, delta.memory_used_per_hour), (Some(-1_000), Some(-500.0)));
+        assert_eq!((delta.process_count_delta, delta.process_count_per_hour), (Some(10), Some(5.0)));
+    }
+
+    #[test]
+    fn delta_needs_two_distinct_samples() {
+        let only = point(0, 1_000, 4_000, 200);
+
+        for delta in [
+            MetricsDelta::between(None, None),
+            MetricsDelta::between(Some(&only), None),
+            MetricsDelta::between(Some(&only), Some(&only)),
+        ] {
+            assert_eq!(delta.elapsed_hours, None);
+            assert_eq!(delta.disk_used_per_hour, None);
+        }
+    }
+
+    #[test]
+    fn compact_metrics_have_short_keys_and_derived_percentages() {
+        let metrics = crate::test_support::sample_metrics(timestamp(), 37.54);
+        let compact = serde_json::to_value(CompactMetrics::from(&metrics)).unwrap();
+
+        let mut keys: Vec<&str> = compact.as_object().unwrap().keys().map(String::as_str).collect();
+        keys.sort_unstable();
+        assert_eq!(keys, ["cpu", "disk_pct", "gpu", "mem_pct", "temp"]);
+
+        assert_eq!(compact["cpu"], 37.5);
+        assert_eq!(compact["mem_pct"], 37.5);   // 6 z 16 GB
+        assert_eq!(compact["disk_pct"], 39.1);  // 200 z 512 GB
+        assert_eq!(compact["gpu"], 12.5);
+        assert_eq!(compact["temp"], 55.0);
+    }
+
+    #[test]
+    fn gpu_source_marks_simulated_and_real_data() {
+        assert_eq!(gpu(None).source.label(), "simulated");
+        assert_eq!(GpuInfo { source: GpuSource::Real, ..gpu(None) }.source.label(), "real");
+    }
+
+    #[test]
+    fn percentages_of_known_totals() {
+        let metrics = SystemMetrics::builder(timestamp())
+            .memory(16_000, 4_000, 12_000)
+            .swap(2_000, 500)
+            .disk(1_000, 750, 250)
+            .build();
+
+        assert_eq!(metrics.memory_percent(), 25.0);
+        assert_eq!(metrics.swap_percent(), 25.0);
+        assert_eq!(metrics.disk_percent(), 75.0);
+    }
+
+    #[test]
+    fn zero_totals_give_zero_percent() {
+        let metrics = SystemMetrics::builder(timestamp())
+            .memory(0, 4_000, 0)
+            .swap(0, 500)
+            .disk(0, 750, 0)
+            .build();
+
+        assert_eq!(metrics.memory_percent(), 0.0);
+        assert_eq!(metrics.swap_percent(), 0.0);
+        assert_eq!(metrics.disk_percent(), 0.0);
+    }
+
+    #[test]
+    fn memory_is_low_only_below_the_threshold() {
+        const MB: u64 = 1024 * 1024;
+        let metrics = SystemMetrics::builder(timestamp())
+            .memory(16_000 * MB, 6_000 * MB, 10_000 * MB)
+            .build();
+
+        assert!(metrics.is_memory_low(12_000));
+        assert!(!metrics.is_memory_low(8_000));
+        assert!(!metrics.is_memory_low(10_000));  // Presne na prahu ešte nie je nízka
+
+        // Nedostupná pamäť (0 B celkom) varovanie nespustí
+        let unknown = SystemMetrics::builder(timestamp()).build();
+        assert!(!unknown.is_memory_low(12_000));
+    }
+
+    #[test]
+    fn sanitize_zeroes_negative_and_over_cap_rates() {
+        let mut metrics = SystemMetrics::builder(timestamp()).build();
+        metrics.network_sent_kbps = Some(-12.0);
+        metrics.network_recv_kbps = Some(5_000.0);
+
+        let corrections = metrics.sanitize(1_000);
+
+        assert_eq!((metrics.network_sent_kbps, metrics.network_recv_kbps), (Some(0.0), Some(0.0)));
+        let fields: Vec<&str> = corrections.iter().map(|c| c.field).collect();
+        assert_eq!(fields, ["network_sent_kbps", "network_recv_kbps"]);
+        assert_eq!(corrections[1].to_string(), "network_recv_kbps = 5000.0 KB/s zeroed");
+    }
+
+    #[test]
+    fn sanitize_keeps_normal_and_missing_rates() {
+        let mut metrics = SystemMetrics::builder(timestamp()).build();
+        metrics.network_sent_kbps = Some(250.0);
+
+        assert!(metrics.sanitize(1_000).is_empty());
+        assert_eq!((metrics.network_sent_kbps, metrics.network_recv_kbps), (Some(250.0), None));
+    }
+
+    #[test]
+    fn process_sanitize_checks_bytes_per_second_against_the_cap() {
+        let mut process = ProcessInfo {
+            network_sent: Some(2_048 * 1024),  // 2048 KB/s
+            network_recv: Some(512 * 1024),
+            ..crate::test_support::sample_process(1, "curl")
+        };
+
+        let corrections = process.sanitize(1_000);
+
+        assert_eq!(corrections.len(), 1);
+        assert_eq!((process.network_sent, process.network_recv), (Some(0), Some(512 * 1024)));
+    }
+
+    fn io_process(pid: u32, cpu: f32, disk_read: Option<u64>, disk_write: Option<u64>) -> ProcessInfo {
+        ProcessInfo { cpu_usage: cpu, disk_read, disk_write, ..crate::test_support::sample_process(pid, "io") }
+    }
+
+    fn pids(processes: &[ProcessInfo]) -> Vec<u32> {
+        processes.iter().map(|process| process.pid).collect()
+    }
+
+    #[test]
+    fn disk_sort_orders_by_read_plus_write() {
+        let mut processes = vec![
+            io_process(1, 90.0, None, None),
+            io_process(2, 1.0, Some(300), Some(300)),
+            io_process(3, 5.0, Some(1_000), None),
+            io_process(4, 0.0, None, Some(100)),
+        ];
+
+        ProcessSort::Disk.top_n(&mut processes, 10);
+
+        assert_eq!(pids(&processes), [3, 2, 4, 1]);
+    }
+
+    #[test]
+    fn heavy_disk_io_raises_the_combined_score() {
+        let mut processes = vec![
+            io_process(1, 10.0, None, None),
+            io_process(2, 1.0, Some(50 * 1024 * 1024), None),  // 50 MB
+        ];
+
+        ProcessSort::Combined.top_n(&mut processes, 1);
+
+        assert_eq!(pids(&processes), [2]);
+    }
+
+    #[test]
+    fn memory_metric_toggle_selects_the_displayed_field() {
+        let process = ProcessInfo { memory: 100, virtual_memory: 400, ..crate::test_support::sample_process(1, "app") };
+
+        let metric = MemoryMetric::default();
+        assert_eq!((metric.label(), metric.bytes(&process)), ("RSS", 100));
+
+        let metric = metric.toggled();
+        assert_eq!((metric.label(), metric.bytes(&process)), ("Virtual", 400));
+        assert_eq!(metric.toggled(), MemoryMetric::Rss);
+    }
+
+    #[test]
+    fn top_n_matches_a_full_sort() {
+        // Rôzne (pseudonáhodné) hodnoty - poradie pri zhode nie je určené
+        let all: Vec<ProcessInfo> = (0..5_000u32)
+            .map(|pid| ProcessInfo {
+                cpu_usage: (pid * 7919 % 5_000) as f32 / 50.0,
+                memory: u64::from(pid * 104_729 % 5_000),
+                ..crate::test_support::sample_process(pid, "bulk")
+            })
+            .collect();
+
+        for sort in [ProcessSort::Cpu, ProcessSort::Memory, ProcessSort::Combined] {
+            let mut expected = all.clone();
+            expected.sort_by(|a, b| sort.compare(a, b));
+            expected.truncate(20);
+
+            let mut top = all.clone();
+            sort.top_n(&mut top, 20);
+
+            assert_eq!(pids(&top), pids(&expected), "{sort:?}");
+        }
+    }
+
+    #[test]
+    fn top_n_handles_short_lists_and_zero_limit() {
+        let mut processes = vec![io_process(1, 5.0, None, None), io_process(2, 9.0, None, None)];
+        ProcessSort::Cpu.top_n(&mut processes, 20);
+        assert_eq!(pids(&processes), [2, 1]);
+
+        ProcessSort::Cpu.top_n(&mut processes, 0);
+        assert!(processes.is_empty());
+    }
+
+    #[test]
+    fn empty_names_fall_back_to_the_executable_or_pid() {
+        let named = crate::test_support::sample_process(42, "firefox");
+        let blank = ProcessInfo { name: "  ".to_string(), ..named.clone() };
+        let with_cmd = ProcessInfo { cmd: vec!["/usr/lib/firefox/firefox-bin".to_string(), "-new-tab".to_string()], ..blank.clone() };
+
+        assert_eq!(named.display_name(), "firefox");
+        assert_eq!(blank.display_name(), "[pid 42]");
+        assert_eq!(with_cmd.display_name(), "firefox-bin");
+        assert_eq!(name_or_pid(7, ""), "[pid 7]");
+    }
+}
@@ -4,7 +4,12 @@
 /// Organizuje modely do logických skupín
 pub mod metrics;       // Modul pre systémové metriky
 pub mod temperatures;  // Modul pre teplotné dáta
+pub mod schema;        // Popis polí metrík (jednotky, typy)
+pub mod influx;        // Export metrík v InfluxDB line protocol
+pub mod snapshot;      // Snímka systému a porovnanie dvoch snímok
 
 /// Re-export dôležitých štruktúr pre jednoduchší import
-pub use metrics::{SystemMetrics, CpuInfo, MemoryInfo, DiskInfo, ProcessInfo, GpuInfo};
-pub use temperatures::{TemperatureInfo, TemperatureWarning};
\ No newline at end of file
+pub use metrics::{SystemMetrics, CpuInfo, MemoryInfo, DiskInfo, ProcessInfo, name_or_pid, ProcessSort, ProcessMinimums, CpuThresholds, MemoryMetric, GpuInfo, GpuSource, DEFAULT_MAX_NETWORK_KBPS, MetricsPoint, ProcessPoint, MetricsDelta, CompactMetrics};
+pub use schema::SYSTEM_METRICS_SCHEMA;
+pub use snapshot::{Snapshot, SnapshotDiff};
+pub use temperatures::{TemperatureInfo, TemperatureWarning, TemperatureThresholds, TemperatureMode, SensorReading};
\ No newline at end of file
@@ -0,0 +1,88 @@
+// schema.rs
+
+use serde::Serialize;
+
+/// Popis jedného poľa `SystemMetrics` pre `/api/schema`
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct MetricField {
+    pub name: &'static str,      // Názov poľa v JSON
+    #[serde(rename = "type")]
+    pub kind: &'static str,      // integer / number / string / datetime / boolean
+    pub unit: Option<&'static str>,  // percent, bytes, celsius, kbps, seconds, count, bits, watts (None = bez jednotky)
+    pub optional: bool,          // Môže byť `null`
+}
+
+/// Skrátený zápis pre tabuľku nižšie
+const fn field(name: &'static str, kind: &'static str, unit: Option<&'static str>, optional: bool) -> MetricField {
+    MetricField { name, kind, unit, optional }
+}
+
+const PERCENT: Option<&str> = Some("percent");
+const BYTES: Option<&str> = Some("bytes");
+const CELSIUS: Option<&str> = Some("celsius");
+const KBPS: Option<&str> = Some("kbps");
+
+/// Polia `SystemMetrics` v poradí, v akom sú v štruktúre
+/// Pri zmene `SystemMetrics` sa musí upraviť aj táto tabuľka
+pub const SYSTEM_METRICS_SCHEMA: &[MetricField] = &[
+    field("id", "integer", None, true),
+    field("timestamp", "datetime", None, false),
+    field("cpu_usage", "number", PERCENT, false),
+    field("memory_total", "integer", BYTES, false),
+    field("memory_used", "integer", BYTES, false),
+    field("memory_available", "integer", BYTES, false),
+    field("swap_total", "integer", BYTES, false),
+    field("swap_used", "integer", BYTES, false),
+    field("disk_total", "integer", BYTES, false),
+    field("disk_used", "integer", BYTES, false),
+    field("disk_available", "integer", BYTES, false),
+    field("gpu_name", "string", None, true),
+    field("gpu_usage", "number", PERCENT, true),
+    field("gpu_memory_total", "integer", BYTES, true),
+    field("gpu_memory_used", "integer", BYTES, true),
+    field("gpu_temperature", "number", CELSIUS, true),
+    field("network_sent_kbps", "number", KBPS, true),
+    field("network_recv_kbps", "number", KBPS, true),
+    field("process_count", "integer", Some("count"), false),
+    field("system_uptime", "integer", Some("seconds"), false),
+    field("cpu_temperature", "number", CELSIUS, true),
+    field("motherboard_temperature", "number", CELSIUS, true),
+    field("disk_temperature", "number", CELSIUS, true),
+    field("max_temperature", "number", CELSIUS, true),
+    field("open_file_descriptors", "integer", Some("count"), true),
+    field("entropy_available", "integer", Some("bits"), true),
+    field("package_power_watts", "number", Some("watts"), true),
+    field("battery_percent", "number", PERCENT, true),
+    field("battery_charging", "boolean", None, true),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SystemMetrics;
+    use crate::test_support::sample_metrics;
+    use chrono::Utc;
+
+    #[test]
+    fn every_metrics_field_appears_exactly_once() {
+        let json = serde_json::to_value(sample_metrics(Utc::now(), 10.0)).unwrap();
+        let fields = json.as_object().unwrap();
+
+        for name in fields.keys() {
+            let count = SYSTEM_METRICS_SCHEMA.iter().filter(|field| field.name == name).count();
+            assert_eq!(count, 1, "field `{name}` is described {count} times");
+        }
+        assert_eq!(SYSTEM_METRICS_SCHEMA.len(), fields.len());
+    }
+
+    #[test]
+    fn missing_values_are_only_in_optional_fields() {
+        let json = serde_json::to_value(SystemMetrics::builder(Utc::now()).build()).unwrap();
+
+        for field in SYSTEM_METRICS_SCHEMA {
+            if json[field.name].is_null() {
+                assert!(field.optional, "`{}` can be null", field.name);
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+// snapshot.rs
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use super::metrics::{ProcessInfo, SystemMetrics};
+
+/// Snímka systému uložená do JSON súboru
+///
+/// Polia `SystemMetrics` sú na najvyššej úrovni (rovnaký tvar ako záznam
+/// z `/api/metrics/history`), procesy sú voliteľné - súbor bez nich sa
+/// porovná len podľa metrík.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    #[serde(flatten)]
+    pub metrics: SystemMetrics,          // Systémové metriky v čase snímky
+    #[serde(default)]
+    pub processes: Vec<ProcessInfo>,     // Procesy v čase snímky (voliteľné)
+}
+
+/// Zmena jednej metriky medzi dvoma snímkami
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldDelta {
+    pub field: &'static str,  // Názov poľa (ako v `SystemMetrics`)
+    pub before: f64,          // Hodnota v prvej snímke
+    pub after: f64,           // Hodnota v druhej snímke
+}
+
+impl FieldDelta {
+    /// Rozdiel `after - before`
+    pub fn delta(&self) -> f64 {
+        self.after - self.before
+    }
+}
+
+/// Proces, ktorý je v oboch snímkach, ale zmenilo sa mu CPU alebo pamäť
+#[derive(Debug, Clone)]
+pub struct ProcessChange {
+    pub before: ProcessInfo,  // Proces v prvej snímke
+    pub after: ProcessInfo,   // Proces v druhej snímke
+}
+
+/// Rozdiel dvoch snímok (`system-monitor diff`)
+#[derive(Debug, Clone, Default)]
+pub struct SnapshotDiff {
+    pub fields: Vec<FieldDelta>,           // Metriky (aj nezmenené; chýbajúce hodnoty sa vynechajú)
+    pub added: Vec<ProcessInfo>,           // Procesy len v druhej snímke
+    pub removed: Vec<ProcessInfo>,         // Procesy len v prvej snímke
+    pub changed: Vec<ProcessChange>,       // Procesy so zmeneným CPU alebo pamäťou
+}
+
+impl SnapshotDiff {
+    /// Porovnanie snímok `before` a `after`
+    ///
+    /// Procesy sa párujú podľa PID; proces s rovnakým PID, ale iným názvom
+    /// (PID znovu pridelené) sa berie ako zaniknutý a nový. Zoznamy sú
+    /// zoradené podľa PID.
+    pub fn between(before: &Snapshot, after: &Snapshot) -> Self {
+        let fields = metric_fields(&before.metrics)
+            .into_iter()
+            .zip(metric_fields(&after.metrics))
+            .filter_map(|((field, before), (_, after))| Some(FieldDelta { field, before: before?, after: after? }))
+            .collect();
+
+        let old: HashMap<u32, &ProcessInfo> = before.processes.iter().map(|p| (p.pid, p)).collect();
+        let new: HashMap<u32, &ProcessInfo> = after.processes.iter().map(|p| (p.pid, p)).collect();
+        let same = |a: &ProcessInfo, b: &ProcessInfo| a.name == b.name;
+
+        let mut added: Vec<ProcessInfo> = after.processes.iter()
+            .filter(|p| !old.get(&p.pid).is_some_and(|o| same(o, p)))
+            .cloned()
+            .collect();
+        let mut removed: Vec<ProcessInfo> = before.processes.iter()
+            .filter(|p| !new.get(&p.pid).is_some_and(|n| same(p, n)))
+            .cloned()
+            .collect();
+        let mut changed: Vec<ProcessChange> = before.processes.iter()
+            .filter_map(|p| new.get(&p.pid).filter(|n| same(p, n)).map(|n| (p, *n)))
+            .filter(|(p, n)| p.cpu_usage != n.cpu_usage || p.memory != n.memory)
+            .map(|(p, n)| ProcessChange { before: p.clone(), after: n.clone() })
+            .collect();
+
+        added.sort_by_key(|p| p.pid);
+        removed.sort_by_key(|p| p.pid);
+        changed.sort_by_key(|c| c.before.pid);
+
+        Self { fields, added, removed, changed }
+    }
+}
+
+/// Porovnávané metriky snímky (None = hodnota nebola zmeraná)
+fn metric_fields(m: &SystemMetrics) -> Vec<(&'static str, Option<f64>)> {
+    vec![
+        ("cpu_usage", Some(m.cpu_usage)),
+        ("memory_used", Some(m.memory_used as f64)),
+        ("memory_available", Some(m.memory_available as f64)),
+        ("swap_used", Some(m.swap_used as f64)),
+        ("disk_used", Some(m.disk_used as f64)),
+        ("disk_available", Some(m.disk_available as f64)),
+        ("gpu_usage", m.gpu_usage),
+        ("network_sent_kbps", m.network_sent_kbps),
+        ("network_recv_kbps", m.network_recv_kbps),
+        ("process_count", Some(m.process_count as f64)),
+        ("max_temperature", m.max_temperature),
+        ("package_power_watts", m.package_power_watts),
+        ("battery_percent", m.battery_percent.map(f64::from)),
+    ]
+}
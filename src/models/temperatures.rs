@@ -1,6 +1,7 @@
 // temperatures.rs
 
 use serde::{Deserialize, Serialize};
+use super::metrics::SystemMetrics;
 
 /// Štruktúra pre zber teplôt komponentov
 /// Centralizované ukladanie teplôt rôznych systémových komponentov
@@ -32,6 +33,17 @@ impl TemperatureInfo {
         Self::default()  // Použitie default hodnot
     }
 
+    /// Teploty komponentov z uloženej / zobrazenej vzorky metrík
+    pub fn from_metrics(metrics: &SystemMetrics) -> Self {
+        let celsius = |value: Option<f64>| value.map(|t| t as f32);
+        TemperatureInfo {
+            cpu_temp: celsius(metrics.cpu_temperature),
+            gpu_temp: celsius(metrics.gpu_temperature),
+            motherboard_temp: celsius(metrics.motherboard_temperature),
+            disk_temp: celsius(metrics.disk_temperature),
+        }
+    }
+
     /// Výpočet maximálnej teploty zo všetkých komponentov
     /// Vráti None ak nie sú dostupné žiadne teploty
     pub fn get_max_temp(&self) -> Option<f32> {
@@ -49,22 +61,87 @@ impl TemperatureInfo {
             .max_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal))  // Nájdenie maxima
     }
 
-    /// Určenie úrovne varovania podľa maximálnej teploty
+    /// Určenie úrovne varovania podľa maximálnej teploty a prahov (`--temp-medium` ...)
     /// Používa sa pre vizuálnu indikáciu teplotného stavu systému
-    pub fn get_warning_level(&self) -> TemperatureWarning {
-        if let Some(max_temp) = self.get_max_temp() {
-            // Rozdelenie podľa teplotných prahov
-            if max_temp > 85.0 {
-                TemperatureWarning::Critical  // Kritická teplota (>85°C)
-            } else if max_temp > 75.0 {
-                TemperatureWarning::High      // Vysoká teplota (75-85°C)
-            } else if max_temp > 65.0 {
-                TemperatureWarning::Medium    // Stredná teplota (65-75°C)
-            } else {
-                TemperatureWarning::Normal    // Normálna teplota (<65°C)
-            }
+    pub fn get_warning_level_with(&self, thresholds: &TemperatureThresholds) -> TemperatureWarning {
+        match self.get_max_temp() {
+            Some(max_temp) => thresholds.classify(max_temp as f64),
+            None => TemperatureWarning::Unknown,  // Neznáma teplota (žiadne dáta)
+        }
+    }
+}
+
+/// Jeden nájdený zdroj teploty (výstup príkazu `sensors`)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SensorReading {
+    pub source: String,          // Typ zdroja (WMI, hwmon, lm-sensors)
+    pub name: String,            // Názov senzora / WMI triedy
+    pub location: String,        // Cesta, WMI dotaz alebo čip
+    pub celsius: Option<f32>,    // Aktuálna hodnota (None = zdroj existuje, ale teplotu neuvádza)
+}
+
+/// Režim zberu teplôt
+/// Na VM a niektorých notebookoch nie je čitateľný žiadny senzor a odhady sú zavádzajúce
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, clap::ValueEnum)]
+pub enum TemperatureMode {
+    Off,       // Teploty sa nezbierajú ani nezobrazujú (v DB sú NULL)
+    #[default]
+    Estimate,  // Reálne senzory, pri ich absencii odhad podľa záťaže CPU
+    Real,      // Len reálne senzory, inak None
+}
+
+/// Teplotné prahy pre úrovne varovania (v °C)
+/// Predvolené hodnoty: stredná >65, vysoká >75, kritická >85
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TemperatureThresholds {
+    pub medium: f64,     // Hranica strednej teploty
+    pub high: f64,       // Hranica vysokej teploty
+    pub critical: f64,   // Hranica kritickej teploty
+}
+
+impl Default for TemperatureThresholds {
+    fn default() -> Self {
+        TemperatureThresholds {
+            medium: 65.0,
+            high: 75.0,
+            critical: 85.0,
+        }
+    }
+}
+
+impl TemperatureThresholds {
+    /// Predvolené farebné pásma teplôt v TUI (zelená <50, žltá <70, červená <85, inak fialová)
+    /// Nižšie než prahy varovania - farba upozorní skôr, než sa ohlási varovanie
+    pub const fn color_bands() -> Self {
+        TemperatureThresholds {
+            medium: 50.0,
+            high: 70.0,
+            critical: 85.0,
+        }
+    }
+
+    /// Vytvorenie prahov s kontrolou poradia (stredná <= vysoká <= kritická)
+    pub fn new(medium: f64, high: f64, critical: f64) -> Result<Self, String> {
+        if medium <= high && high <= critical {
+            Ok(TemperatureThresholds { medium, high, critical })
+        } else {
+            Err(format!(
+                "Invalid temperature thresholds: expected medium <= high <= critical, got {} / {} / {}",
+                medium, high, critical
+            ))
+        }
+    }
+
+    /// Zaradenie konkrétnej teploty do úrovne varovania
+    pub fn classify(&self, temp: f64) -> TemperatureWarning {
+        if temp > self.critical {
+            TemperatureWarning::Critical  // Kritická teplota
+        } else if temp > self.high {
+            TemperatureWarning::High      // Vysoká teplota
+        } else if temp > self.medium {
+            TemperatureWarning::Medium    // Stredná teplota
         } else {
-            TemperatureWarning::Unknown       // Neznáma teplota (žiadne dáta)
+            TemperatureWarning::Normal    // Normálna teplota
         }
     }
 }
@@ -78,4 +155,42 @@ pub enum TemperatureWarning {
     High,       // Vysoká teplota - oranžová
     Critical,   // Kritická teplota - červená
     Unknown,    // Neznámy stav - šedá
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info_with_cpu(celsius: f32) -> TemperatureInfo {
+        TemperatureInfo { cpu_temp: Some(celsius), ..TemperatureInfo::default() }
+    }
+
+    #[test]
+    fn custom_thresholds_reclassify_a_sample_temperature() {
+        let info = info_with_cpu(78.0);
+        assert_eq!(info.get_warning_level_with(&TemperatureThresholds::default()), TemperatureWarning::High);
+
+        let hot_hardware = TemperatureThresholds::new(80.0, 90.0, 95.0).unwrap();
+        assert_eq!(info.get_warning_level_with(&hot_hardware), TemperatureWarning::Normal);
+
+        let strict = TemperatureThresholds::new(40.0, 50.0, 60.0).unwrap();
+        assert_eq!(info.get_warning_level_with(&strict), TemperatureWarning::Critical);
+    }
+
+    #[test]
+    fn missing_temperatures_are_unknown() {
+        let thresholds = TemperatureThresholds::default();
+        assert_eq!(TemperatureInfo::default().get_warning_level_with(&thresholds), TemperatureWarning::Unknown);
+    }
+
+    #[test]
+    fn color_bands_keep_their_own_defaults() {
+        assert_eq!(TemperatureThresholds::color_bands().classify(60.0), TemperatureWarning::Medium);
+        assert_eq!(TemperatureThresholds::default().classify(60.0), TemperatureWarning::Normal);
+    }
+
+    #[test]
+    fn thresholds_out_of_order_are_rejected() {
+        assert!(TemperatureThresholds::new(80.0, 70.0, 90.0).is_err());
+    }
+}
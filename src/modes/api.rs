@@ -1,87 +1,459 @@
 // api.rs
 
-use crate::api::{create_router, AppState};
+use crate::api::{create_router, nest_under_base_path, normalize_base_path, AppState};
+use crate::config::ApiArgs;
 use crate::db::connection::create_pool;
+use crate::error::AppError;
 use crate::services::api_monitor::ApiSystemMonitor;  // Import API monitora
+use crate::services::syslog::SyslogSink;  // Varovania do syslogu (`--syslog`)
+use super::saver::{spawn_metrics_saver, SaveInterval, SaverOptions};  // Ukladanie metrík na pozadí
+use super::log_file::init_log_file;  // Logy do súboru (`--log-file`)
+use axum::Router;
 use std::net::SocketAddr;
-use std::sync::Arc;
-use tokio::sync::Mutex;
+use std::path::Path;
 
 /// Hlavná funkcia pre spustenie REST API módu
 /// Inicializuje API server, databázu a spúšťa background ukladanie metrík
-pub async fn run_api_mode(host: String, port: u16, save_metrics: bool) -> Result<(), Box<dyn std::error::Error>> {
+pub async fn run_api_mode(args: ApiArgs) -> Result<(), AppError> {
     println!("🚀 System Monitor & API - Starting REST API Mode...");
     println!("────────────────────────────────────────────────────");
     
+    // Len overenie konfigurácie - server sa nespúšťa
+    if args.dry_run {
+        return run_dry_run(&args).await;
+    }
+    
+    // Logy do súboru (`--log-file`) - guard musí žiť do konca behu servera
+    let _log_guard = match &args.log_file {
+        Some(path) => {
+            let guard = init_log_file(path)?;
+            println!("📝 Writing logs to {} (rotated daily)", path.display());
+            Some(guard)
+        }
+        None => None,
+    };
+    
+    // Prefix ciest (`--base-path`) - chybný prefix ukončí štart pred pripojením k DB
+    let base_path = args.base_path.as_deref()
+        .map(normalize_base_path)
+        .transpose()
+        .map_err(AppError::Config)?
+        .flatten();
+    
     // Vytvorenie connection pool pre databázu
     let pool = create_pool().await?;
     println!("✅ Connected to PostgreSQL database");
     
+    // Interval ukladania zdieľaný s API (PUT /api/config/save-interval)
+    // Bez `--save-metrics` ukladanie nebeží a endpoint zmenu odmietne
+    let save_interval = args.save_metrics.then(SaveInterval::default);
+    
     // Vytvorenie API monitora a stavu aplikácie
-    let api_monitor = ApiSystemMonitor::new();  // Nový API monitor
-    let app_state = AppState::new(pool.clone(), api_monitor);
+    let mut api_monitor = ApiSystemMonitor::new(args.temperatures);  // Nový API monitor
+    api_monitor.set_max_network_kbps(args.max_network_kbps);
+    api_monitor.set_include_removable_disks(args.include_removable_disks);
+    api_monitor.set_network_sample(args.network_sample.map(|n| n as usize));
+    api_monitor.set_snapshot_ttl(std::time::Duration::from_millis(args.snapshot_ttl_ms));
+    let mut app_state = AppState::new(
+        pool.clone(),
+        api_monitor,
+        args.max_process_limit as usize,
+        std::time::Duration::from_secs(args.stream_interval),  // Interval vzoriek streamu
+        save_interval.clone(),
+    );
     
-    // Vytvorenie routera (smerovača) pre API
-    let app = create_router(app_state);
+    // Kópia varovaní do syslogu (`--syslog`) - pred zdieľaním logu s úlohami
+    if args.syslog {
+        let sink = SyslogSink::connect()
+            .map_err(|e| AppError::Config(format!("cannot connect to syslog: {}", e)))?;
+        app_state.events = app_state.events.clone().with_syslog(sink);
+        println!("📨 Sending warnings and errors to syslog");
+    }
+    
+    // Log udalostí zdieľaný s ukladaním na pozadí (číta ho `/api/logs`)
+    let events = app_state.events.clone();
+    
+    // Jeden monitor pre handlery aj ukladanie na pozadí (jedno meranie, spoločná cache)
+    let monitor = app_state.system_monitor.clone();
+    // Aj pool je spoločný - nové pripojenie z ukladania použijú aj handlery
+    let db_pool = app_state.db_pool.clone();
+    
+    // Vytvorenie routera (smerovača) pre API, pod prefixom pri `--base-path`
+    let app = nest_under_base_path(create_router(app_state), base_path.as_deref());
     
     // Spustenie background ukladania metrík (ak je povolené)
-    if save_metrics {
-        start_background_saving(pool.clone()).await?;
+    if let Some(save_interval) = save_interval {
+        spawn_metrics_saver(db_pool, monitor, SaverOptions {
+            dedupe: args.dedupe,
+            dedupe_tolerance: args.dedupe_tolerance,
+            quiet: false,
+            save_processes: args.save_processes,
+            memory_warn_available_mb: args.memory_warn_available_mb,
+        }, save_interval, Some(events));
     } else {
         // Informácia o vypnutom ukladaní
         println!("⚠️  Background metric saving is disabled");
         println!("   Use --save-metrics flag to enable automatic saving to database");
     }
     
+    // Unix socket namiesto TCP (`--uds`)
+    if let Some(path) = &args.uds {
+        let ctrl_c = async { let _ = tokio::signal::ctrl_c().await; };
+        return serve_unix_socket(path, app, base_path.as_deref().unwrap_or(""), args.stream_interval, ctrl_c).await;
+    }
+    
     // Konfigurácia adresy a spustenie servera
-    let addr = SocketAddr::from((host.parse::<std::net::Ipv4Addr>()?, port));
-    let listener = tokio::net::TcpListener::bind(addr).await?;
+    let listener = bind_tcp(&args.host, args.port).await?;
+    let addr = listener.local_addr()?;
     
     // Informácie o spustenom serveri
-    println!("🌐 REST API is ready at http://{}", addr);
+    // Riadok `Listening on` je stabilný pre nástroje, ktoré hľadajú pridelený port
+    println!("Listening on {}", addr);
+    tracing::info!("REST API listening on {}", addr);
+    println!("🌐 REST API is ready at http://{}{}", addr, base_path.as_deref().unwrap_or(""));
+    print_endpoints(base_path.as_deref().unwrap_or(""), args.stream_interval);
+    
+    // Spustenie servera
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+/// Naviazanie TCP listenera na `host:port`
+/// S `--port 0` vyberie voľný port systém - skutočný port sa zistí z `local_addr()`
+async fn bind_tcp(host: &str, port: u16) -> Result<tokio::net::TcpListener, AppError> {
+    let ip = host.parse::<std::net::Ipv4Addr>()
+        .map_err(|e| AppError::Config(format!("host '{}' is not a valid IPv4 address ({})", host, e)))?;
+    let addr = SocketAddr::from((ip, port));
+    tokio::net::TcpListener::bind(addr).await
+        .map_err(|e| AppError::bind(addr, e))
+}
+
+/// Výpis dostupných endpointov po spustení servera (s prefixom z `--base-path`)
+fn print_endpoints(base_path: &str, stream_interval: u64) {
     println!("📊 Available endpoints:");
-    println!("   • GET  /api/metrics     - System metrics");
-    println!("   • GET  /api/processes   - Top processes");
-    println!("   • GET  /api/health      - Health check");
-    println!("   • GET  /api/gpu         - GPU information");
+    println!("   • GET  {}/api/metrics     - System metrics", base_path);
+    println!("   • GET  {}/api/processes   - Top processes", base_path);
+    println!("   • GET  {}/health          - Health check (version, uptime)", base_path);
+    println!("   • GET  {}/api/gpu         - GPU information", base_path);
+    println!("   • GET  {}/api/events/metrics - Live metrics (SSE, every {}s)", base_path, stream_interval);
+    println!("   • GET  {}/api/logs        - Recent background events (?level=warn)", base_path);
+    println!("   • PUT  {}/api/config/save-interval - Change the metric save interval", base_path);
     println!("✅ Server is ready!");
     println!("🛑 Press Ctrl+C to stop the server");
+}
+
+/// Obsluha rovnakého routera cez Unix socket (`--uds`)
+///
+/// `axum::serve` v0.7 prijíma len TCP listener, preto sa spojenia obsluhujú
+/// priamo cez hyper. Socket po predchádzajúcom páde sa zmaže, socket
+/// bežiaceho servera nie. Po dokončení `shutdown` (Ctrl+C) sa súbor socketu odstráni.
+#[cfg(unix)]
+async fn serve_unix_socket(
+    path: &Path,
+    app: Router,
+    base_path: &str,
+    stream_interval: u64,
+    shutdown: impl std::future::Future<Output = ()>,
+) -> Result<(), AppError> {
+    use hyper_util::rt::{TokioExecutor, TokioIo};
+    use hyper_util::server::conn::auto::Builder;
+    use hyper_util::service::TowerToHyperService;
+
+    remove_stale_socket(path).await?;
+    let listener = tokio::net::UnixListener::bind(path)
+        .map_err(|e| AppError::bind(path.display(), e))?;
     
-    // Spustenie servera
-    axum::serve(listener, app).await?;
+    println!("Listening on unix:{}", path.display());
+    println!("🌐 REST API is ready at unix:{}{}", path.display(), base_path);
+    print_endpoints(base_path, stream_interval);
+    
+    tokio::pin!(shutdown);
+    
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => {
+                let stream = match accepted {
+                    Ok((stream, _)) => stream,
+                    Err(e) => {
+                        // Napr. vyčerpané deskriptory - server beží ďalej
+                        eprintln!("⚠️  [API] Failed to accept connection: {}", e);
+                        tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+                        continue;
+                    }
+                };
+                let service = TowerToHyperService::new(app.clone());
+                tokio::spawn(async move {
+                    if let Err(e) = Builder::new(TokioExecutor::new())
+                        .serve_connection(TokioIo::new(stream), service)
+                        .await
+                    {
+                        eprintln!("⚠️  [API] Connection error: {}", e);
+                    }
+                });
+            }
+            _ = &mut shutdown => break,
+        }
+    }
+    
+    // Upratanie súboru socketu
+    std::fs::remove_file(path)?;
+    println!("🧹 Removed socket {}", path.display());
+    Ok(())
+}
+
+/// Mimo Unixu Unix sockety nie sú podporované
+#[cfg(not(unix))]
+async fn serve_unix_socket(
+    _path: &Path,
+    _app: Router,
+    _base_path: &str,
+    _stream_interval: u64,
+    _shutdown: impl std::future::Future<Output = ()>,
+) -> Result<(), AppError> {
+    Err(AppError::Config("--uds is only supported on Unix".to_string()))
+}
+
+/// Odstránenie socketu, ktorý zostal po predchádzajúcom behu
+///
+/// Ak sa na socket dá pripojiť, používa ho iný server a vráti sa chyba.
+/// Cesta, ktorá nie je socket, sa nikdy nemaže.
+#[cfg(unix)]
+async fn remove_stale_socket(path: &Path) -> Result<(), AppError> {
+    use std::os::unix::fs::FileTypeExt;
+
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        return Ok(());  // Súbor neexistuje
+    };
+    if !metadata.file_type().is_socket() {
+        return Err(AppError::bind(path.display(), "path exists and is not a socket"));
+    }
+    if tokio::net::UnixStream::connect(path).await.is_ok() {
+        return Err(AppError::bind(path.display(), "socket is in use by another server"));
+    }
+    std::fs::remove_file(path)?;
     Ok(())
 }
 
-/// Spustenie background úlohy pre automatické ukladanie metrík
-/// Metriky sa ukladajú každých 60 sekúnd do databázy
-async fn start_background_saving(pool: sqlx::PgPool) -> Result<(), Box<dyn std::error::Error>> {
-    // Vytvorenie monitora v Arc a Mutex pre bezpečný viacvláknový prístup
-    let monitor_arc = Arc::new(Mutex::new(ApiSystemMonitor::new())); 
-    
-    // Spustenie asynchrónnej úlohy
-    tokio::spawn(async move {
-        println!("⚙️  Background metric saving started (60s interval)...");
-        
-        // Nekonečný cyklus pre pravidelné ukladanie
-        loop {
-            // Získanie metrík synchronizovaným prístupom
-            let (metrics, gpu_info) = {
-                let mut monitor = monitor_arc.lock().await;  // Zámok pre bezpečný prístup
-                let metrics = monitor.get_metrics_for_db();   // Získanie metrík
-                let gpu_info = monitor.get_gpu_info();        // Získanie GPU informácií
-                (metrics, gpu_info)
-            };
-            
-            // Uloženie metrík do databázy
-            match crate::db::save_metrics(&pool, &metrics, gpu_info.as_ref()).await {
-                Ok(id) => println!("💾 [Auto-Save] Metrics saved to DB (ID: {})", id),  // Úspech
-                Err(e) => eprintln!("❌ [Auto-Save] Error saving to DB: {}", e),       // Chyba
+/// Overenie pripravenosti servera bez jeho spustenia (`--dry-run`)
+/// Skontroluje host, nastavenia, pripojenie k databáze a dostupnosť portu.
+/// Vráti chybu so zoznamom zlyhaných kontrol (nenulový exit kód).
+async fn run_dry_run(args: &ApiArgs) -> Result<(), AppError> {
+    println!("🧪 Dry run - validating configuration without serving...");
+    
+    let mut failures: Vec<String> = Vec::new();  // Zlyhané kontroly
+    
+    // 1. Host musí byť platná IPv4 adresa (rovnako ako pri spustení servera)
+    let host = match args.host.parse::<std::net::Ipv4Addr>() {
+        Ok(ip) => {
+            println!("   ✅ Host:     {}", ip);
+            Some(ip)
+        }
+        Err(e) => {
+            println!("   ❌ Host:     '{}' is not a valid IPv4 address ({})", args.host, e);
+            failures.push(format!("host '{}': {}", args.host, e));
+            None
+        }
+    };
+    
+    // 1b. Prefix ciest (`--base-path`)
+    if let Some(raw) = &args.base_path {
+        match normalize_base_path(raw) {
+            Ok(prefix) => println!("   ✅ Base path: {}", prefix.as_deref().unwrap_or("/ (none)")),
+            Err(e) => {
+                println!("   ❌ Base path: {}", e);
+                failures.push(e);
+            }
+        }
+    }
+    
+    // 2. Nastavenia ukladania (`--dedupe-tolerance` overuje už clap)
+    println!(
+        "   ✅ Config:   save_metrics={}, dedupe={}, max_process_limit={}",
+        args.save_metrics, args.dedupe, args.max_process_limit
+    );
+    
+    // 3. Pripojenie k databáze (vrátane vytvorenia schémy)
+    match create_pool().await {
+        Ok(pool) => {
+            println!("   ✅ Database: connected");
+            pool.close().await;  // Uvoľnenie spojení
+        }
+        Err(e) => {
+            println!("   ❌ Database: {}", e);
+            failures.push(format!("database: {}", e));
+        }
+    }
+    
+    // 4. Port (alebo Unix socket) musí byť voľný - listener sa hneď uvoľní
+    if let Some(path) = &args.uds {
+        match check_unix_socket(path).await {
+            Ok(()) => println!("   ✅ Socket:   {} is available", path.display()),
+            Err(e) => {
+                println!("   ❌ Socket:   cannot bind {} ({})", path.display(), e);
+                failures.push(format!("socket {}: {}", path.display(), e));
             }
-            
-            // Čakanie 60 sekúnd pred ďalším uložením
-            tokio::time::sleep(tokio::time::Duration::from_secs(60)).await;
         }
-    });
+    } else if let Some(ip) = host {
+        let addr = SocketAddr::from((ip, args.port));
+        match tokio::net::TcpListener::bind(addr).await {
+            Ok(listener) => {
+                drop(listener);
+                println!("   ✅ Port:     {} is available", addr);
+            }
+            Err(e) => {
+                println!("   ❌ Port:     cannot bind {} ({})", addr, e);
+                failures.push(format!("port {}: {}", addr, e));
+            }
+        }
+    }
     
+    // Súhrn
+    if failures.is_empty() {
+        println!("✅ Dry run passed - server is ready to start");
+        Ok(())
+    } else {
+        println!("❌ Dry run failed ({} check(s))", failures.len());
+        Err(AppError::Config(format!("dry run failed: {}", failures.join("; "))))
+    }
+}
+
+/// Skúšobné naviazanie Unix socketu pre `--dry-run` (súbor sa hneď zmaže)
+#[cfg(unix)]
+async fn check_unix_socket(path: &Path) -> Result<(), AppError> {
+    remove_stale_socket(path).await?;
+    drop(tokio::net::UnixListener::bind(path).map_err(|e| AppError::bind(path.display(), e))?);
+    std::fs::remove_file(path)?;
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Mimo Unixu Unix sockety nie sú podporované
+#[cfg(not(unix))]
+async fn check_unix_socket(_path: &Path) -> Result<(), AppError> {
+    Err(AppError::Config("--uds is only supported on Unix".to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::override_database_url;
+
+    #[tokio::test]
+    async fn dry_run_with_bad_database_url_fails_with_the_db_error() {
+        let _url = override_database_url("not-a-database-url").await;
+        let args = ApiArgs { dry_run: true, port: 0, ..ApiArgs::default() };
+
+        let error = run_api_mode(args).await.unwrap_err();
+
+        assert_ne!(error.exit_code(), 0);
+        assert!(error.to_string().contains("database:"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn unreachable_database_surfaces_as_a_db_error() {
+        let _url = override_database_url("not-a-database-url").await;
+        let args = ApiArgs { port: 0, ..ApiArgs::default() };
+
+        let error = run_api_mode(args).await.unwrap_err();
+
+        assert!(matches!(error, AppError::Db(_)), "{error:?}");
+        assert_eq!(error.exit_code(), 3);
+    }
+
+    #[tokio::test]
+    async fn port_zero_binds_an_assigned_port() {
+        let listener = bind_tcp("127.0.0.1", 0).await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        assert_ne!(addr.port(), 0);
+        assert!(tokio::net::TcpStream::connect(addr).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn invalid_host_is_a_config_error() {
+        let error = bind_tcp("localhost", 0).await.unwrap_err();
+
+        assert!(matches!(error, AppError::Config(_)), "{error}");
+    }
+
+    #[cfg(unix)]
+    fn socket_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("system-monitor-test-{}-{}.sock", std::process::id(), name))
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn stale_socket_is_removed() {
+        let path = socket_path("stale");
+        drop(tokio::net::UnixListener::bind(&path).unwrap());  // Súbor ostane ako po páde
+
+        remove_stale_socket(&path).await.unwrap();
+
+        assert!(!path.exists());
+        assert!(remove_stale_socket(&path).await.is_ok());  // Chýbajúci súbor nevadí
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn socket_in_use_is_kept() {
+        let path = socket_path("live");
+        let _listener = tokio::net::UnixListener::bind(&path).unwrap();
+
+        let error = remove_stale_socket(&path).await.unwrap_err();
+
+        assert!(path.exists());
+        let _ = std::fs::remove_file(&path);
+        assert!(error.to_string().contains("in use"), "{error}");
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn unix_socket_serves_health_and_is_removed_on_shutdown() {
+        use crate::models::TemperatureMode;
+        use axum::body::Body;
+        use hyper_util::rt::TokioIo;
+
+        let path = socket_path("serve");
+        let pool = sqlx::PgPool::connect_lazy("postgres://localhost/unused").unwrap();
+        let state = AppState::new(pool, ApiSystemMonitor::new(TemperatureMode::Off), 100, std::time::Duration::from_secs(60), None);
+        let (stop, stopped) = tokio::sync::oneshot::channel::<()>();
+        let server = tokio::spawn({
+            let path = path.clone();
+            async move {
+                serve_unix_socket(&path, create_router(state), "", 60, async { let _ = stopped.await; }).await
+            }
+        });
+        // Čakanie, kým server naviaže socket
+        let stream = loop {
+            if let Ok(stream) = tokio::net::UnixStream::connect(&path).await {
+                break stream;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+        };
+
+        let (mut sender, connection) = hyper::client::conn::http1::handshake(TokioIo::new(stream)).await.unwrap();
+        tokio::spawn(connection);
+        let request = axum::http::Request::get("/health").header("host", "localhost").body(Body::empty()).unwrap();
+        let response = sender.send_request(request).await.unwrap();
+
+        assert_eq!(response.status(), axum::http::StatusCode::OK);
+        let body = axum::body::to_bytes(Body::new(response.into_body()), usize::MAX).await.unwrap();
+        assert!(String::from_utf8_lossy(&body).contains("\"status\""));
+
+        stop.send(()).unwrap();
+        server.await.unwrap().unwrap();
+        assert!(!path.exists());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn regular_file_is_never_removed() {
+        let path = socket_path("file");
+        std::fs::write(&path, "data").unwrap();
+
+        let result = remove_stale_socket(&path).await;
+
+        assert!(path.exists());
+        let _ = std::fs::remove_file(&path);
+        assert!(result.is_err());
+    }
+}
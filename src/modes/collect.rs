@@ -0,0 +1,144 @@
+// collect.rs
+
+use crate::cli::app::get_hostname;
+use crate::config::{CollectArgs, CollectFormat};
+use crate::db::connection::create_pool;
+use crate::error::AppError;
+use crate::services::api_monitor::ApiSystemMonitor;
+use sqlx::PgPool;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::time::{Duration, Interval, MissedTickBehavior};
+
+/// Hlavná funkcia pre headless zber metrík (`system-monitor collect`)
+/// Bez HTTP servera a TUI - len v intervale zbiera metriky a ukladá ich do DB,
+/// alebo ich s `--format influx` vypisuje na stdout v InfluxDB line protocol.
+/// Ctrl+C ukončí zber po dokončení rozpracovaného uloženia.
+///
+/// Stavové hlásenia idú na stderr, aby stdout obsahoval len dáta.
+pub async fn run_collect_mode(args: CollectArgs) -> Result<(), AppError> {
+    eprintln!("📥 System Monitor - Headless Collector");
+    eprintln!("────────────────────────────────────────────────────");
+
+    // Databáza je potrebná len pri ukladaní do PostgreSQL
+    let pool = match args.format {
+        CollectFormat::Db => {
+            let pool = create_pool().await?;
+            eprintln!("✅ Connected to PostgreSQL database");
+            Some(pool)
+        }
+        CollectFormat::Influx => None,
+    };
+    let host = get_hostname();
+
+    // Monitor v Arc a Mutex - zber beží v blokujúcom vlákne
+    let mut monitor = ApiSystemMonitor::new(args.temperatures);
+    monitor.set_max_network_kbps(args.max_network_kbps);
+    monitor.set_network_sample(args.network_sample.map(|n| n as usize));
+    monitor.set_include_removable_disks(args.include_removable_disks);
+    let monitor = Arc::new(Mutex::new(monitor));
+
+    let mut ticker = collect_ticker(Duration::from_secs(args.interval));
+
+    eprintln!("⚙️  Collecting metrics every {}s", args.interval);
+    eprintln!("🛑 Press Ctrl+C to stop");
+
+    let mut saved: u64 = 0;  // Počet uložených vzoriek
+
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {}
+            _ = tokio::signal::ctrl_c() => break,
+        }
+
+        if collect_cycle(&monitor, pool.as_ref(), &host).await {
+            saved += 1;
+        }
+    }
+
+    // Korektné uzavretie spojení s databázou
+    if let Some(pool) = pool {
+        pool.close().await;
+    }
+    eprintln!("👋 Collector stopped ({} samples written)", saved);
+    Ok(())
+}
+
+/// Ticker zberu - prvé meranie hneď, ďalšie v intervale; zmeškané tiky sa nedobiehajú
+fn collect_ticker(interval: Duration) -> Interval {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    ticker
+}
+
+/// Jeden cyklus zberu - meranie a uloženie do DB (bez `pool` výpis line protocol)
+/// Vráti true, ak sa vzorka zapísala; chyba sa len vypíše, zber pokračuje
+async fn collect_cycle(monitor: &Arc<Mutex<ApiSystemMonitor>>, pool: Option<&PgPool>, host: &str) -> bool {
+    // refresh_all() je synchrónne - zber v blokujúcom vlákne
+    let task_monitor = Arc::clone(monitor);
+    let metrics = match tokio::task::spawn_blocking(move || {
+        task_monitor.blocking_lock().get_metrics_for_db()
+    }).await {
+        Ok(metrics) => metrics,
+        Err(e) => {
+            eprintln!("❌ [Collect] Metrics collection failed: {}", e);
+            return false;
+        }
+    };
+
+    let Some(pool) = pool else {
+        // Line protocol - jeden riadok na vzorku
+        println!("{}", metrics.to_influx_line(host));
+        return true;
+    };
+
+    // Uloženie metrík do databázy
+    match crate::db::save_metrics(pool, &metrics).await {
+        Ok(id) => {
+            eprintln!("💾 [Collect] Metrics saved to DB (ID: {})", id);
+            true
+        }
+        Err(e) => {
+            eprintln!("❌ [Collect] Error saving to DB: {}", e);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::TemperatureMode;
+    use crate::test_support::test_db;
+    use tokio::time::Instant;
+
+    fn monitor() -> Arc<Mutex<ApiSystemMonitor>> {
+        Arc::new(Mutex::new(ApiSystemMonitor::new(TemperatureMode::Off)))
+    }
+
+    #[tokio::test]
+    async fn ticker_fires_at_once_then_after_the_interval() {
+        let interval = Duration::from_millis(200);
+        let mut ticker = collect_ticker(interval);
+        let start = Instant::now();
+
+        ticker.tick().await;
+        assert!(start.elapsed() < interval);
+
+        ticker.tick().await;
+        assert!(start.elapsed() >= interval);
+    }
+
+    #[tokio::test]
+    async fn cycle_without_database_writes_a_line() {
+        assert!(collect_cycle(&monitor(), None, "test-host").await);
+    }
+
+    #[tokio::test]
+    async fn cycle_inserts_one_row() {
+        let Some(db) = test_db().await else { return };
+
+        assert!(collect_cycle(&monitor(), Some(&db.pool), "test-host").await);
+        assert_eq!(crate::db::count_metrics(&db.pool).await.unwrap(), 1);
+    }
+}
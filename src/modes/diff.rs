@@ -0,0 +1,171 @@
+// diff.rs
+
+use crate::config::DiffArgs;
+use crate::error::AppError;
+use crate::models::{Snapshot, SnapshotDiff, SYSTEM_METRICS_SCHEMA};
+use std::path::Path;
+
+/// Porovnanie dvoch snímok (`system-monitor diff <file1> <file2>`)
+/// Vypíše zmeny metrík a procesy nové, zaniknuté a so zmeneným CPU/pamäťou
+pub fn run_diff_mode(args: DiffArgs) -> Result<(), AppError> {
+    let before = load_snapshot(&args.before)?;
+    let after = load_snapshot(&args.after)?;
+    let diff = SnapshotDiff::between(&before, &after);
+
+    println!("🔍 System Monitor - Snapshot Diff");
+    println!("───────────────────────────────────────");
+    println!("   {} ({})", args.before.display(), before.metrics.timestamp);
+    println!("-> {} ({})", args.after.display(), after.metrics.timestamp);
+
+    println!();
+    println!("📊 Metrics:");
+    for field in &diff.fields {
+        let unit = unit_of(field.field);
+        println!(
+            "   {:<20} {:>14} -> {:>14}  ({})",
+            field.field,
+            format_value(field.before, unit, false),
+            format_value(field.after, unit, false),
+            format_value(field.delta(), unit, true),
+        );
+    }
+
+    if before.processes.is_empty() && after.processes.is_empty() {
+        println!();
+        println!("ℹ️  Snapshots contain no processes");
+        return Ok(());
+    }
+
+    println!();
+    println!("➕ New processes ({}):", diff.added.len());
+    for process in &diff.added {
+        println!("   {:>7}  {:<30} {:>6.1}%  {}", process.pid, process.display_name(), process.cpu_usage, format_value(process.memory as f64, Some("bytes"), false));
+    }
+
+    println!("➖ Gone processes ({}):", diff.removed.len());
+    for process in &diff.removed {
+        println!("   {:>7}  {:<30} {:>6.1}%  {}", process.pid, process.display_name(), process.cpu_usage, format_value(process.memory as f64, Some("bytes"), false));
+    }
+
+    println!("✏️  Changed processes ({}):", diff.changed.len());
+    for change in &diff.changed {
+        let cpu_delta = (change.after.cpu_usage - change.before.cpu_usage) as f64;
+        let memory_delta = change.after.memory as f64 - change.before.memory as f64;
+        println!(
+            "   {:>7}  {:<30} CPU {}  memory {}",
+            change.after.pid,
+            change.after.display_name(),
+            format_value(cpu_delta, Some("percent"), true),
+            format_value(memory_delta, Some("bytes"), true),
+        );
+    }
+
+    Ok(())
+}
+
+/// Načítanie snímky zo súboru (`.yaml`/`.yml` ako YAML, inak JSON)
+/// Chýbajúci súbor je chyba I/O, neplatný obsah chyba konfigurácie
+fn load_snapshot(path: &Path) -> Result<Snapshot, AppError> {
+    let content = std::fs::read_to_string(path)?;
+    let is_yaml = path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml"));
+
+    let parsed = if is_yaml {
+        serde_yaml::from_str(&content).map_err(|e| e.to_string())
+    } else {
+        serde_json::from_str(&content).map_err(|e| e.to_string())
+    };
+    parsed.map_err(|e| AppError::Config(format!("{}: invalid snapshot: {}", path.display(), e)))
+}
+
+/// Jednotka poľa podľa `/api/schema`
+fn unit_of(field: &str) -> Option<&'static str> {
+    SYSTEM_METRICS_SCHEMA.iter().find(|f| f.name == field).and_then(|f| f.unit)
+}
+
+/// Hodnota s jednotkou (bajty v MB); `signed` pridá `+` pri kladnom rozdiele
+fn format_value(value: f64, unit: Option<&str>, signed: bool) -> String {
+    let sign = if signed && value > 0.0 { "+" } else { "" };
+    match unit {
+        Some("percent") => format!("{}{:.1}%", sign, value),
+        Some("bytes") => format!("{}{:.1} MB", sign, value / 1_048_576.0),
+        Some("kbps") => format!("{}{:.1} KB/s", sign, value),
+        Some("celsius") => format!("{}{:.1}°C", sign, value),
+        Some("watts") => format!("{}{:.1} W", sign, value),
+        _ => format!("{}{}", sign, value),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::ProcessInfo;
+    use crate::test_support::{sample_metrics, sample_process};
+    use chrono::{Duration, Utc};
+
+    /// Zápis snímky ako JSON do dočasného súboru
+    fn write_snapshot(name: &str, snapshot: &Snapshot) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("system-monitor-test-{}-{}", std::process::id(), name));
+        std::fs::write(&path, serde_json::to_string(snapshot).unwrap()).unwrap();
+        path
+    }
+
+    #[test]
+    fn known_snapshots_report_process_and_metric_changes() {
+        let now = Utc::now();
+        let before = Snapshot {
+            metrics: sample_metrics(now - Duration::minutes(5), 20.0),
+            processes: vec![
+                ProcessInfo { cpu_usage: 0.1, ..sample_process(1, "init") },
+                ProcessInfo { cpu_usage: 10.0, ..sample_process(200, "firefox") },
+                ProcessInfo { cpu_usage: 5.0, ..sample_process(300, "old-job") },
+            ],
+        };
+        let mut after = Snapshot {
+            metrics: sample_metrics(now, 35.0),
+            processes: vec![
+                ProcessInfo { cpu_usage: 0.1, ..sample_process(1, "init") },
+                ProcessInfo { cpu_usage: 25.0, ..sample_process(200, "firefox") },
+                ProcessInfo { cpu_usage: 1.0, ..sample_process(300, "new-job") },
+            ],
+        };
+        after.metrics.gpu_usage = None;  // Nezmeraná hodnota sa neporovnáva
+        let before_path = write_snapshot("before.json", &before);
+        let after_path = write_snapshot("after.json", &after);
+
+        let loaded = (load_snapshot(&before_path), load_snapshot(&after_path));
+        let result = run_diff_mode(DiffArgs { before: before_path.clone(), after: after_path.clone() });
+        let _ = std::fs::remove_file(&before_path);
+        let _ = std::fs::remove_file(&after_path);
+
+        result.unwrap();
+        let diff = SnapshotDiff::between(&loaded.0.unwrap(), &loaded.1.unwrap());
+        let names = |processes: &[ProcessInfo]| processes.iter().map(|p| p.name.clone()).collect::<Vec<_>>();
+        assert_eq!(names(&diff.added), ["new-job"]);    // Znovu pridelené PID
+        assert_eq!(names(&diff.removed), ["old-job"]);
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].after.cpu_usage, 25.0);
+        let cpu = diff.fields.iter().find(|field| field.field == "cpu_usage").unwrap();
+        assert_eq!(cpu.delta(), 15.0);
+        assert!(diff.fields.iter().all(|field| field.field != "gpu_usage"));
+    }
+
+    #[test]
+    fn missing_and_invalid_files_are_distinct_errors() {
+        let missing = std::env::temp_dir().join("system-monitor-test-missing-snapshot.json");
+        assert!(matches!(load_snapshot(&missing), Err(AppError::Io(_))));
+
+        let invalid = std::env::temp_dir().join(format!("system-monitor-test-{}-invalid.json", std::process::id()));
+        std::fs::write(&invalid, "{ not json").unwrap();
+        let result = load_snapshot(&invalid);
+        let _ = std::fs::remove_file(&invalid);
+        assert!(matches!(result, Err(AppError::Config(_))));
+    }
+
+    #[test]
+    fn values_are_formatted_with_their_unit_and_sign() {
+        assert_eq!(format_value(15.0, unit_of("cpu_usage"), true), "+15.0%");
+        assert_eq!(format_value(-1_048_576.0, unit_of("memory_used"), true), "-1.0 MB");
+        assert_eq!(format_value(42.0, unit_of("process_count"), false), "42");
+    }
+}
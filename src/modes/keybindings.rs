@@ -0,0 +1,32 @@
+// keybindings.rs
+
+use crate::cli::app::{keybindings_for, mode_id, KEYBINDING_MODES};
+use crate::error::AppError;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::io;
+
+/// Jedna skratka vo výstupe `keybindings`
+#[derive(Serialize)]
+struct KeyEntry {
+    key: &'static str,     // Označenie klávesu ako v päte (napr. "Q/Esc")
+    action: &'static str,  // Popis akcie
+}
+
+/// Výpis klávesových skratok TUI (`system-monitor keybindings`)
+/// JSON objekt `{ "overview": [{ "key": "H", "action": "Help" }, ...], ... }`
+/// z rovnakých tabuliek ako päty a obrazovka pomoci; režimy sú zoradené podľa názvu
+pub fn run_keybindings_mode() -> Result<(), AppError> {
+    let table: BTreeMap<&str, Vec<KeyEntry>> = KEYBINDING_MODES.iter()
+        .map(|&mode| {
+            let entries = keybindings_for(mode).iter()
+                .map(|binding| KeyEntry { key: binding.key, action: binding.action })
+                .collect();
+            (mode_id(mode), entries)
+        })
+        .collect();
+
+    let output = serde_json::to_string_pretty(&table).map_err(io::Error::other)?;
+    println!("{}", output);
+    Ok(())
+}
@@ -0,0 +1,84 @@
+// log_file.rs
+
+use crate::error::AppError;
+use std::path::Path;
+use tracing_appender::non_blocking::{NonBlocking, WorkerGuard};
+use tracing_appender::rolling::{RollingFileAppender, Rotation};
+
+/// Zápis tracing udalostí do súboru (`api --log-file`)
+///
+/// Súbor sa rotuje denne - k názvu sa pridá dátum (napr. `api.log.2026-10-16`).
+/// Výpisy na stdout ostávajú, súbor obsahuje udalosti úloh na pozadí
+/// (rovnaké ako `/api/logs`) a štart servera. Zápis beží vo vlastnom vlákne;
+/// vrátený guard treba držať do konca behu, inak sa posledné riadky stratia.
+pub fn init_log_file(path: &Path) -> Result<WorkerGuard, AppError> {
+    let (writer, guard) = log_file_writer(path)?;
+    tracing_subscriber::fmt()
+        .with_writer(writer)
+        .with_ansi(false)  // Bez farebných escape sekvencií v súbore
+        .try_init()
+        .map_err(|e| unwritable(path, e.to_string()))?;
+
+    Ok(guard)
+}
+
+/// Zapisovač do denne rotovaného súboru a jeho guard
+fn log_file_writer(path: &Path) -> Result<(NonBlocking, WorkerGuard), AppError> {
+    let file_name = path.file_name()
+        .ok_or_else(|| unwritable(path, "path has no file name".to_string()))?;
+    let directory = path.parent()
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+
+    // Súbor sa otvára hneď - chýbajúci adresár alebo práva sa prejavia pri štarte
+    let appender = RollingFileAppender::builder()
+        .rotation(Rotation::DAILY)
+        .filename_prefix(file_name.to_string_lossy())
+        .build(directory)
+        .map_err(|e| unwritable(path, e.to_string()))?;
+
+    Ok(tracing_appender::non_blocking(appender))
+}
+
+/// Chyba konfigurácie pre súbor, do ktorého nejde zapisovať
+fn unwritable(path: &Path, reason: String) -> AppError {
+    AppError::Config(format!("cannot write log file {}: {}", path.display(), reason))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn records_are_written_to_the_dated_file() {
+        let dir = std::env::temp_dir().join(format!("system-monitor-test-{}-logs", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let (writer, guard) = log_file_writer(&dir.join("api.log")).unwrap();
+        let subscriber = tracing_subscriber::fmt().with_writer(writer).with_ansi(false).finish();
+        tracing::subscriber::with_default(subscriber, || tracing::warn!("Available memory is low"));
+        drop(guard);  // Dopísanie riadkov z vlákna zápisu
+
+        let files: Vec<_> = std::fs::read_dir(&dir).unwrap().map(|entry| entry.unwrap().path()).collect();
+        let content = files.iter().map(|file| std::fs::read_to_string(file).unwrap()).collect::<String>();
+        let _ = std::fs::remove_dir_all(&dir);
+
+        assert_eq!(files.len(), 1);
+        assert!(files[0].file_name().unwrap().to_string_lossy().starts_with("api.log."));  // Dátum rotácie
+        assert!(content.contains("WARN") && content.contains("Available memory is low"), "{content}");
+    }
+
+    #[test]
+    fn unwritable_paths_are_config_errors() {
+        let not_a_dir = std::env::temp_dir().join(format!("system-monitor-test-{}-not-a-dir", std::process::id()));
+        std::fs::write(&not_a_dir, "").unwrap();
+
+        let result = log_file_writer(&not_a_dir.join("api.log"));  // Adresár je v skutočnosti súbor
+        let _ = std::fs::remove_file(&not_a_dir);
+
+        let error = result.unwrap_err();
+        assert!(matches!(error, AppError::Config(_)));
+        assert!(error.to_string().contains("cannot write log file"), "{error}");
+        assert!(log_file_writer(Path::new("/")).is_err());  // Bez názvu súboru
+    }
+}
@@ -2,10 +2,21 @@
 
 use dialoguer::{theme::ColorfulTheme, Select, Input, Confirm};
 use crate::modes::{run_tui_mode, run_api_mode};
+use crate::config::{ApiArgs, Cli, TuiArgs};
+use crate::error::AppError;
+use clap::CommandFactory;  // Výpis použitia bez interaktívneho menu
+use std::io::IsTerminal;    // Detekcia terminálu na vstupe
 
 /// Zobrazenie interaktívneho menu pre výber režimu aplikácie
 /// Užívateľ vyberá medzi TUI, API alebo nápovedou
-pub async fn show_interactive_menu() -> Result<(), Box<dyn std::error::Error>> {
+///
+/// Bez terminálu na vstupe (pipe, CI) by výber zlyhal alebo čakal navždy,
+/// preto sa namiesto menu vypíše použitie a vráti chyba (nenulový exit kód).
+pub async fn show_interactive_menu() -> Result<(), AppError> {
+    if let Some(result) = fallback_without_terminal(&std::io::stdin()) {
+        return result;
+    }
+    
     // Grafická hlavička menu
     println!("╔═══════════════════════════════════════════╗");
     println!("║     🖥️  SYSTEM MONITOR v1.0               ║");
@@ -34,7 +45,7 @@ pub async fn show_interactive_menu() -> Result<(), Box<dyn std::error::Error>> {
         0 => {
             // Spustenie TUI módu
             println!();
-            run_tui_mode()  // Táto funkcia vracia Result
+            run_tui_mode(TuiArgs::default()).await  // Táto funkcia vracia Result
         }
         1 => {
             // Spustenie API módu s podmenu
@@ -62,9 +73,26 @@ pub async fn show_interactive_menu() -> Result<(), Box<dyn std::error::Error>> {
     }
 }
 
+/// Náhrada menu, ak vstup nie je terminál (súbor, pipe); None = menu sa zobrazí
+fn fallback_without_terminal(input: &impl IsTerminal) -> Option<Result<(), AppError>> {
+    (!input.is_terminal()).then(non_interactive_fallback)
+}
+
+/// Náhrada menu bez terminálu - výpis použitia a odkaz na podpríkazy
+fn non_interactive_fallback() -> Result<(), AppError> {
+    eprintln!("⚠️  The interactive menu needs a terminal, but stdin is not a TTY.");
+    eprintln!("   Run a subcommand instead, e.g. `system-monitor api` or `system-monitor collect`.");
+    eprintln!();
+    Cli::command().print_help()?;
+    
+    Err(AppError::Terminal(
+        "interactive menu requires a terminal; use a subcommand (tui, api, sensors, collect)".to_string(),
+    ))
+}
+
 /// Pomocná funkcia bez rekurzie pre jedno zobrazenie menu
 /// Používa sa pre vyhnutie sa stack overflow pri rekurzívnych volaniach
-async fn show_interactive_menu_once() -> Result<(), Box<dyn std::error::Error>> {
+async fn show_interactive_menu_once() -> Result<(), AppError> {
     // Opätovné zobrazenie menu (rovnaké ako hlavná funkcia)
     println!("╔═══════════════════════════════════════════╗");
     println!("║     🖥️  SYSTEM MONITOR v1.0               ║");
@@ -91,7 +119,7 @@ async fn show_interactive_menu_once() -> Result<(), Box<dyn std::error::Error>>
         0 => {
             // TUI režim
             println!();
-            run_tui_mode()
+            run_tui_mode(TuiArgs::default()).await
         }
         1 => {
             // API režim
@@ -114,7 +142,7 @@ async fn show_interactive_menu_once() -> Result<(), Box<dyn std::error::Error>>
 
 /// Podmenu pre konfiguráciu API
 /// Umožňuje rýchle spustenie alebo vlastné nastavenia
-async fn show_api_submenu() -> Result<(), Box<dyn std::error::Error>> {
+async fn show_api_submenu() -> Result<(), AppError> {
     let api_choices = vec![
         "🚀 Start API with default settings (127.0.0.1:3000)",  // Rýchle spustenie
         "⚙️  Start API with custom settings",                   // Vlastné nastavenia
@@ -131,7 +159,7 @@ async fn show_api_submenu() -> Result<(), Box<dyn std::error::Error>> {
     match api_selection {
         0 => {
             // Spustenie s predvolenými nastaveniami
-            run_api_mode("127.0.0.1".to_string(), 3000, true).await
+            run_api_mode(ApiArgs { save_metrics: true, ..ApiArgs::default() }).await
         }
         1 => {
             // Vlastné nastavenia - interaktívne zadávanie
@@ -159,7 +187,7 @@ async fn show_api_submenu() -> Result<(), Box<dyn std::error::Error>> {
                 .interact()?;
             
             // Spustenie s vlastnými nastaveniami
-            run_api_mode(host, port, save_metrics).await
+            run_api_mode(ApiArgs { host, port, save_metrics, ..ApiArgs::default() }).await
         }
         2 => {
             // Návrat do hlavného menu
@@ -170,7 +198,7 @@ async fn show_api_submenu() -> Result<(), Box<dyn std::error::Error>> {
 }
 
 /// Zobrazenie nápovedy s inštrukciami na používanie aplikácie
-fn show_help() -> Result<(), Box<dyn std::error::Error>> {
+fn show_help() -> Result<(), AppError> {
     println!();
     println!("╔═══════════════════════════════════════════╗");
     println!("║              SYSTEM MONITOR HELP          ║");
@@ -197,4 +225,23 @@ fn show_help() -> Result<(), Box<dyn std::error::Error>> {
     std::io::stdin().read_line(&mut input).unwrap();
     
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn non_tty_input_takes_the_fallback_path() {
+        let path = std::env::temp_dir().join(format!("system-monitor-test-{}-stdin", std::process::id()));
+        let file = std::fs::File::create(&path).unwrap();  // Ako presmerovaný vstup
+
+        let result = fallback_without_terminal(&file);
+        let _ = std::fs::remove_file(&path);
+
+        let error = result.expect("fallback should be taken").unwrap_err();
+        assert!(matches!(error, AppError::Terminal(_)));
+        assert_ne!(error.exit_code(), 0);
+        assert!(error.to_string().contains("use a subcommand"), "{error}");
+    }
+}
@@ -5,8 +5,20 @@
 pub mod tui;   // Terminal User Interface mód
 pub mod api;   // REST API mód
 pub mod menu;  // Interaktívne menu
+pub mod sensors;  // Diagnostika teplotných senzorov
+pub mod saver;    // Ukladanie metrík do DB na pozadí (API aj TUI)
+pub mod collect;  // Headless zber metrík do DB (bez servera a TUI)
+pub mod snapshot; // Snímka metrík a procesov do JSON/YAML
+pub mod diff;     // Porovnanie dvoch snímok
+pub mod log_file; // Zápis logov API do súboru (`--log-file`)
+pub mod keybindings; // Export klávesových skratok TUI ako JSON
 
 /// Re-export hlavných funkcií pre jednoduchší import
 pub use tui::run_tui_mode;            // Export TUI spúšťacej funkcie
 pub use api::run_api_mode;            // Export API spúšťacej funkcie
-pub use menu::show_interactive_menu;  // Export funkcie na zobrazenie menu
\ No newline at end of file
+pub use menu::show_interactive_menu;  // Export funkcie na zobrazenie menu
+pub use sensors::run_sensors_mode;    // Export diagnostiky senzorov
+pub use collect::run_collect_mode;    // Export headless zberu metrík
+pub use snapshot::run_snapshot_mode;  // Export snímky systému
+pub use diff::run_diff_mode;          // Export porovnania snímok
+pub use keybindings::run_keybindings_mode;  // Export výpisu klávesových skratok
\ No newline at end of file
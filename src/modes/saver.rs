@@ -0,0 +1,347 @@
+// saver.rs
+
+use crate::db::connection::reconnect_pool;
+use crate::db::SharedPool;
+use crate::models::{ProcessMinimums, ProcessSort, SystemMetrics};
+use crate::services::api_monitor::SharedMonitor;
+use crate::services::event_log::{EventLevel, EventLog};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::time::{Duration, Instant};
+
+/// Predvolený interval medzi uloženiami metrík do databázy (v sekundách)
+pub const DEFAULT_SAVE_INTERVAL_SECS: u64 = 60;
+
+/// Najkratší interval nastaviteľný za behu - chráni databázu pred zahltením
+pub const MIN_SAVE_INTERVAL_SECS: u64 = 5;
+
+/// Najdlhší interval nastaviteľný za behu (1 deň)
+pub const MAX_SAVE_INTERVAL_SECS: u64 = 86_400;
+
+/// Najviac procesov uložených za jeden cyklus (`--save-processes`, podľa CPU)
+pub const PROCESS_HISTORY_LIMIT: usize = 10;
+
+/// Počiatočné čakanie pred novým pripojením po výpadku databázy
+const RECONNECT_BACKOFF: Duration = Duration::from_secs(DEFAULT_SAVE_INTERVAL_SECS);
+
+/// Počet po sebe idúcich chýb spojenia, po ktorých sa pool vytvorí nanovo
+const RECONNECT_AFTER_FAILURES: u32 = 3;
+
+/// Najdlhšie čakanie medzi pokusmi o nové pripojenie (backoff sa zdvojnásobuje)
+const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(600);
+
+/// Sledovanie výpadku databázy pre ukladanie na pozadí
+///
+/// Po `RECONNECT_AFTER_FAILURES` chybách spojenia za sebou sa skúsi nový pool;
+/// ak pripojenie zlyhá, ďalší pokus príde po zdvojnásobenom čakaní
+/// (od `RECONNECT_BACKOFF` do `MAX_RECONNECT_BACKOFF`).
+#[derive(Debug)]
+struct PoolHealth {
+    failures: u32,          // Po sebe idúce chyby spojenia
+    backoff: Duration,      // Čakanie po ďalšom neúspešnom pripojení
+    next_attempt: Instant,  // Najskorší čas ďalšieho pokusu
+}
+
+impl PoolHealth {
+    fn new() -> Self {
+        Self { failures: 0, backoff: RECONNECT_BACKOFF, next_attempt: Instant::now() }
+    }
+
+    /// Úspešný zápis - výpadok skončil
+    fn record_success(&mut self) {
+        *self = Self::new();
+    }
+
+    /// Chyba zápisu - počítajú sa len chyby spojenia, nie napr. chyby v dátach
+    fn record_failure(&mut self, error: &sqlx::Error) {
+        if is_connection_error(error) {
+            self.failures += 1;
+        }
+    }
+
+    /// Je čas skúsiť nový pool?
+    fn should_reconnect(&self) -> bool {
+        self.failures >= RECONNECT_AFTER_FAILURES && Instant::now() >= self.next_attempt
+    }
+
+    /// Nové pripojenie zlyhalo - ďalší pokus až po backoffe
+    fn reconnect_failed(&mut self) -> Duration {
+        let wait = self.backoff;
+        self.next_attempt = Instant::now() + wait;
+        self.backoff = (self.backoff * 2).min(MAX_RECONNECT_BACKOFF);
+        wait
+    }
+}
+
+/// Chyby, pri ktorých je databáza nedostupná (reštart, výpadok siete)
+fn is_connection_error(error: &sqlx::Error) -> bool {
+    matches!(
+        error,
+        sqlx::Error::Io(_) | sqlx::Error::Tls(_) | sqlx::Error::PoolTimedOut | sqlx::Error::PoolClosed
+    )
+}
+
+/// Interval ukladania zdieľaný medzi úlohou na pozadí a API
+///
+/// Úloha ho číta pred každým čakaním, takže zmena cez
+/// `PUT /api/config/save-interval` platí od nasledujúceho uloženia.
+#[derive(Debug, Clone)]
+pub struct SaveInterval(Arc<AtomicU64>);
+
+impl SaveInterval {
+    /// Vytvorenie intervalu v sekundách
+    pub fn new(seconds: u64) -> Self {
+        Self(Arc::new(AtomicU64::new(seconds)))
+    }
+
+    /// Aktuálny interval v sekundách
+    pub fn seconds(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    /// Zmena intervalu (rozsah kontroluje volajúci)
+    pub fn set_seconds(&self, seconds: u64) {
+        self.0.store(seconds, Ordering::Relaxed);
+    }
+
+    /// Aktuálne čakanie medzi uloženiami
+    fn duration(&self) -> Duration {
+        Duration::from_secs(self.seconds())
+    }
+}
+
+/// Čakanie ukladania na ďalší cyklus
+/// Interval sa číta až tu, takže zmena za behu platí od najbližšieho čakania
+pub(crate) async fn wait_for_next_save(interval: &SaveInterval) {
+    tokio::time::sleep(interval.duration()).await;
+}
+
+impl Default for SaveInterval {
+    fn default() -> Self {
+        Self::new(DEFAULT_SAVE_INTERVAL_SECS)
+    }
+}
+
+/// Nastavenia ukladania metrík na pozadí
+/// Nastavenia zberu (teploty, strop siete, vzorka, disky) patria monitoru
+#[derive(Debug, Clone, Copy)]
+pub struct SaverOptions {
+    pub dedupe: bool,                  // Preskočenie takmer identických vzoriek
+    pub dedupe_tolerance: f64,         // Tolerancia deduplikácie v percentuálnych bodoch
+    pub quiet: bool,                   // Bez výpisov na stdout (TUI by ich prekreslilo)
+    pub save_processes: bool,          // Ukladanie top procesov do `process_metrics`
+    pub memory_warn_available_mb: Option<u64>, // Varovanie pri dostupnej RAM pod N MB
+}
+
+/// Výpisy ukladania na pozadí - na konzolu (mimo TUI) a do logu udalostí (v API)
+struct SaverLog {
+    quiet: bool,               // Bez výpisov na konzolu
+    events: Option<EventLog>,  // Log udalostí pre `/api/logs`
+}
+
+impl SaverLog {
+    /// Zdroj udalostí v logu
+    const SOURCE: &'static str = "auto-save";
+
+    fn write(&self, level: EventLevel, icon: &str, message: String) {
+        if !self.quiet {
+            match level {
+                EventLevel::Info => println!("{} [Auto-Save] {}", icon, message),
+                EventLevel::Warn | EventLevel::Error => eprintln!("{} [Auto-Save] {}", icon, message),
+            }
+        }
+        if let Some(events) = &self.events {
+            events.record(level, Self::SOURCE, message);
+        }
+    }
+
+    fn info(&self, icon: &str, message: String) {
+        self.write(EventLevel::Info, icon, message);
+    }
+
+    fn warn(&self, icon: &str, message: String) {
+        self.write(EventLevel::Warn, icon, message);
+    }
+
+    fn error(&self, icon: &str, message: String) {
+        self.write(EventLevel::Error, icon, message);
+    }
+}
+
+/// Výsledok uloženia jednej vzorky (`save_sample`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveOutcome {
+    Inserted(i64),  // Nový riadok s týmto ID
+    Touched(i64),   // Takmer zhodná vzorka - v poslednom riadku sa zvýšil `sample_count`
+}
+
+/// Uloženie vzorky s deduplikáciou voči poslednému uloženému riadku
+///
+/// S `dedupe_tolerance` sa vzorka, ktorá sa od `last_saved` líši najviac
+/// o toleranciu (`SystemMetrics::is_near_duplicate`), neuloží ako nový riadok,
+/// len sa v poslednom riadku aktualizuje `last_seen`. Nový riadok sa stane
+/// novým `last_saved`.
+pub async fn save_sample(
+    pool: &sqlx::PgPool,
+    last_saved: &mut Option<(i64, SystemMetrics)>,
+    metrics: SystemMetrics,
+    dedupe_tolerance: Option<f64>,
+) -> Result<SaveOutcome, sqlx::Error> {
+    let duplicate_of = last_saved.as_ref()
+        .filter(|(_, last)| dedupe_tolerance.is_some_and(|tolerance| metrics.is_near_duplicate(last, tolerance)))
+        .map(|(id, _)| *id);
+
+    match duplicate_of {
+        Some(id) => {
+            crate::db::touch_metrics(pool, id, metrics.timestamp).await?;
+            Ok(SaveOutcome::Touched(id))
+        }
+        None => {
+            let id = crate::db::save_metrics(pool, &metrics).await?;
+            *last_saved = Some((id, metrics));
+            Ok(SaveOutcome::Inserted(id))
+        }
+    }
+}
+
+/// Spustenie background úlohy pre automatické ukladanie metrík
+/// Metriky sa ukladajú do databázy každých `interval` sekúnd (štandardne 60,
+/// v API sa dá zmeniť za behu)
+/// S `dedupe` sa takmer identické vzorky neukladajú ako nové riadky
+/// Po reštarte databázy sa pool sám obnoví (pozri `PoolHealth`)
+///
+/// Používa ho API režim aj TUI s `--save-metrics`. Musí sa volať v tokio runtime.
+/// S `events` sa priebeh zapisuje aj do logu udalostí (API ho vystavuje na `/api/logs`).
+///
+/// `monitor` je v API ten istý ako v `AppState` - systém sa tak nemeria
+/// dvakrát a sieťová cache je rovnaká pre handlery aj ukladanie. Rovnako
+/// `pool` - pool obnovený po výpadku databázy tak používajú aj handlery.
+pub fn spawn_metrics_saver(
+    shared_pool: SharedPool,
+    monitor: SharedMonitor,
+    options: SaverOptions,
+    interval: SaveInterval,
+    events: Option<EventLog>,
+) {
+    let SaverOptions { dedupe, dedupe_tolerance: tolerance, quiet, save_processes, memory_warn_available_mb, .. } = options;
+
+    // Výpis len mimo TUI - v alternatívnej obrazovke by rozbil vykreslenie
+    let log = SaverLog { quiet, events };
+
+    // Spustenie asynchrónnej úlohy
+    tokio::spawn(async move {
+        log.info("⚙️ ", format!("Background metric saving started ({}s interval)", interval.seconds()));
+        if dedupe {
+            log.info("⚙️ ", format!("Deduplication enabled (tolerance: {:.1}%)", tolerance));
+        }
+        if save_processes {
+            log.info("⚙️ ", format!("Saving top {} processes by CPU per cycle", PROCESS_HISTORY_LIMIT));
+        }
+
+        // Posledný uložený záznam (ID + metriky) pre porovnanie
+        let mut last_saved: Option<(i64, SystemMetrics)> = None;
+        let mut health = PoolHealth::new();
+        let mut memory_low = false;  // Dostupná RAM bola pri poslednej vzorke pod prahom
+
+        // Nekonečný cyklus pre pravidelné ukladanie
+        loop {
+            // Získanie metrík v blokujúcom vlákne (refresh_all() je synchrónne)
+            // GPU údaje sú už v metrikách (jedno meranie cez get_gpu_info)
+            let task_monitor = monitor.clone();
+            // Panic pri zbere sa zachytí a monitor sa vytvorí nanovo
+            // Procesy z rovnakej obnovy ako metriky (druhá obnova by skreslila CPU procesov)
+            let (metrics, processes) = match tokio::task::spawn_blocking(move || {
+                task_monitor.collect_blocking(|monitor| {
+                    let metrics = monitor.get_metrics_for_db();  // Získanie metrík vrátane GPU
+                    let processes = if save_processes {
+                        monitor.current_top_processes(PROCESS_HISTORY_LIMIT, ProcessSort::Cpu, ProcessMinimums::default())
+                    } else {
+                        Vec::new()
+                    };
+                    (metrics, processes)
+                })
+            }).await {
+                Ok(Ok(collected)) => collected,
+                Ok(Err(panic)) => {
+                    log.error("❌", format!("Metrics collection panicked, monitor restarted: {}", panic));
+                    wait_for_next_save(&interval).await;
+                    continue;
+                }
+                Err(e) => {
+                    log.error("❌", format!("Metrics collection failed: {}", e));
+                    wait_for_next_save(&interval).await;
+                    continue;
+                }
+            };
+
+            let timestamp = metrics.timestamp;  // Čas vzorky pre procesy (metriky sa presunú do `last_saved`)
+            let pool = shared_pool.get();       // Aktuálny pool (po výpadku databázy nový)
+
+            // Varovanie len pri prechode cez prah, nie pri každej vzorke
+            if let Some(threshold_mb) = memory_warn_available_mb {
+                let low = metrics.is_memory_low(threshold_mb);
+                if low && !memory_low {
+                    log.warn("⚠️ ", format!(
+                        "Available memory {} MB is below {} MB", metrics.memory_available_mb(), threshold_mb
+                    ));
+                } else if !low && memory_low {
+                    log.info("✅", format!("Available memory recovered above {} MB", threshold_mb));
+                }
+                memory_low = low;
+            }
+
+            // Uloženie metrík do databázy (zhodná vzorka len aktualizuje posledný záznam)
+            let result = match save_sample(&pool, &mut last_saved, metrics, dedupe.then_some(tolerance)).await {
+                Ok(SaveOutcome::Inserted(id)) => {
+                    log.info("💾", format!("Metrics saved to DB (ID: {})", id));  // Úspech
+                    Ok(())
+                }
+                Ok(SaveOutcome::Touched(id)) => {
+                    log.info("💤", format!("Unchanged sample, updated record (ID: {})", id));
+                    Ok(())
+                }
+                Err(e) => {
+                    log.error("❌", format!("Error saving to DB: {}", e));  // Chyba
+                    Err(e)
+                }
+            };
+
+            // Vzorky procesov s rovnakým časom ako metriky (aj pri deduplikovanej vzorke)
+            let result = match result {
+                Ok(()) if !processes.is_empty() => {
+                    match crate::db::save_process_metrics(&pool, timestamp, &processes).await {
+                        Ok(_) => Ok(()),
+                        Err(e) => {
+                            log.error("❌", format!("Error saving process samples to DB: {}", e));
+                            Err(e)
+                        }
+                    }
+                }
+                other => other,
+            };
+
+            // Obnova spojenia po opakovaných chybách (napr. reštart databázy)
+            match result {
+                Ok(()) => health.record_success(),
+                Err(e) => health.record_failure(&e),
+            }
+            if health.should_reconnect() {
+                log.warn("🔌", format!("{} connection errors in a row, reconnecting to database", health.failures));
+                match reconnect_pool().await {
+                    Ok(new_pool) => {
+                        log.info("🔌", "Reconnected to database".to_string());
+                        shared_pool.replace(new_pool);  // Od ďalšej požiadavky ho používajú aj handlery
+                        health.record_success();
+                    }
+                    Err(e) => {
+                        let wait = health.reconnect_failed();
+                        log.error("❌", format!("Reconnect failed: {} (next attempt in {}s)", e, wait.as_secs()));
+                    }
+                }
+            }
+
+            // Čakanie pred ďalším uložením
+            wait_for_next_save(&interval).await;
+        }
+    });
+}
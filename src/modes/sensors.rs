@@ -0,0 +1,33 @@
+// sensors.rs
+
+use crate::models::TemperatureMode;
+use crate::services::TemperatureMonitor;
+
+/// Diagnostika teplotných senzorov
+/// Vypíše všetky nájdené zdroje teplôt a ich aktuálne hodnoty,
+/// aby sa dalo zistiť, prečo sa zobrazujú len odhady
+pub fn run_sensors_mode() {
+    println!("🌡️  System Monitor - Temperature Sensors");
+    println!("───────────────────────────────────────");
+    
+    // Režim `Real` - bez odhadov, len skutočné zdroje
+    let monitor = TemperatureMonitor::new(TemperatureMode::Real);
+    let sensors = monitor.list_sensors();
+    
+    if sensors.is_empty() {
+        println!("❌ No temperature sensors found");
+        println!("   Checked: WMI (Windows), /sys/class/hwmon (Linux), `sensors -j` (lm-sensors)");
+        println!("   The TUI will show estimated values; use --temperatures off to hide them");
+        return;
+    }
+    
+    println!("✅ Found {} sensor(s):", sensors.len());
+    for sensor in &sensors {
+        let reading = match sensor.celsius {
+            Some(celsius) => format!("{:.1}°C", celsius),
+            None => "no reading".to_string(),  // Zdroj existuje, ale teplotu neuvádza
+        };
+        println!("   • [{}] {} = {}", sensor.source, sensor.name, reading);
+        println!("     {}", sensor.location);
+    }
+}
@@ -0,0 +1,83 @@
+// snapshot.rs
+
+use crate::config::{SnapshotArgs, SnapshotFormat};
+use crate::error::AppError;
+use crate::models::Snapshot;
+use crate::services::api_monitor::ApiSystemMonitor;
+use std::io;
+
+/// Snímka systému (`system-monitor snapshot`)
+/// Zmeria metriky a procesy a vypíše ich na stdout alebo do `--output`
+pub fn run_snapshot_mode(args: SnapshotArgs) -> Result<(), AppError> {
+    let mut monitor = ApiSystemMonitor::new(args.temperatures);
+
+    // Využitie CPU potrebuje dve merania s odstupom
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    let metrics = monitor.get_metrics_for_db();
+    let processes = monitor.get_processes();
+
+    let output = render_snapshot(&Snapshot { metrics, processes }, args.format)?;
+
+    match &args.output {
+        Some(path) => {
+            std::fs::write(path, output)?;
+            eprintln!("📸 Snapshot written to {}", path.display());  // Stdout ostáva prázdny
+        }
+        None => println!("{}", output),
+    }
+    Ok(())
+}
+
+/// Serializácia snímky podľa `--format`
+/// YAML aj JSON majú rovnaké polia, `diff` načíta oba
+fn render_snapshot(snapshot: &Snapshot, format: SnapshotFormat) -> Result<String, AppError> {
+    let rendered = match format {
+        SnapshotFormat::Json => serde_json::to_string(snapshot).map_err(io::Error::other)?,
+        SnapshotFormat::Pretty => serde_json::to_string_pretty(snapshot).map_err(io::Error::other)?,
+        SnapshotFormat::Yaml => serde_yaml::to_string(snapshot).map_err(io::Error::other)?,
+    };
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::SystemMetrics;
+    use crate::test_support::{sample_metrics, sample_process};
+
+    fn snapshot() -> Snapshot {
+        Snapshot {
+            metrics: sample_metrics(chrono::Utc::now(), 42.5),
+            processes: vec![sample_process(1234, "firefox")],
+        }
+    }
+
+    #[test]
+    fn yaml_round_trips_into_system_metrics() {
+        let snapshot = snapshot();
+
+        let yaml = render_snapshot(&snapshot, SnapshotFormat::Yaml).unwrap();
+        let metrics: SystemMetrics = serde_yaml::from_str(&yaml).unwrap();
+        let parsed: Snapshot = serde_yaml::from_str(&yaml).unwrap();
+
+        // Porovnanie cez JSON - `SystemMetrics` nemá `PartialEq`
+        assert_eq!(serde_json::to_value(&metrics).unwrap(), serde_json::to_value(&snapshot.metrics).unwrap());
+        assert_eq!(parsed.processes.len(), 1);
+        assert_eq!(parsed.processes[0].name, "firefox");
+    }
+
+    #[test]
+    fn json_formats_carry_the_same_fields() {
+        let snapshot = snapshot();
+
+        let compact = render_snapshot(&snapshot, SnapshotFormat::Json).unwrap();
+        let pretty = render_snapshot(&snapshot, SnapshotFormat::Pretty).unwrap();
+
+        assert!(!compact.contains('\n'));
+        assert!(pretty.contains('\n'));
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&compact).unwrap(),
+            serde_json::from_str::<serde_json::Value>(&pretty).unwrap(),
+        );
+    }
+}
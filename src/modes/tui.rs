@@ -1,17 +1,133 @@
 // tui.rs
 
+use crate::services::api_monitor::{ApiSystemMonitor, SharedMonitor};
 use crate::services::monitor::SystemMonitor;
+use crate::services::process_filter::ProcessFilter;
+use crate::services::interface_filter::InterfaceFilter;
+use crate::services::system_processes::SystemProcessGroup;
 use crate::cli::runner::run_tui;
+use crate::cli::app::{DbState, DbStatus};
+use crate::config::TuiArgs;
+use crate::db::connection::reconnect_pool;  // Bez výpisov - beží popri TUI
+use crate::db::SharedPool;
+use crate::error::AppError;
+use std::time::Duration;
+use super::saver::{spawn_metrics_saver, SaveInterval, SaverOptions};  // Ukladanie metrík na pozadí
+
+/// Čakanie medzi pokusmi o pripojenie, kým databáza nie je dostupná
+const DB_RETRY_INTERVAL: Duration = Duration::from_secs(30);
 
 /// Hlavná funkcia pre spustenie TUI (Terminal User Interface) módu
 /// Inicializuje systémový monitor a spustí TUI rozhranie
-pub fn run_tui_mode() -> Result<(), Box<dyn std::error::Error>> {
+/// S `--save-metrics` sa popri TUI ukladajú metriky do databázy
+pub async fn run_tui_mode(args: TuiArgs) -> Result<(), AppError> {
     println!("🚀 System Monitor - Starting TUI Mode...");
     println!("───────────────────────────────────────");
     
+    // Kontrola nastavení pred prepnutím terminálu do raw módu
+    args.temperature_thresholds().map_err(AppError::Config)?;
+    args.temperature_colors().map_err(AppError::Config)?;
+    args.cpu_thresholds().map_err(AppError::Config)?;
+    
+    // Ukladanie do DB popri TUI - pripája sa na pozadí, TUI na databázu nečaká
+    let db = DbState::new(args.save_metrics);
+    if args.save_metrics {
+        println!("🔌 Connecting to PostgreSQL in the background - metrics are saved once connected");
+        spawn_db_connect(&args, db.clone());
+    }
+    
     // Vytvorenie nového inštancie systémového monitora
-    let monitor = SystemMonitor::new();
+    let mut monitor = SystemMonitor::new(args.temperatures);
+    monitor.set_process_filter(ProcessFilter::new(&args.ignore_processes, args.ignore_self));
+    monitor.set_interface_filter(InterfaceFilter::new(&args.include_interfaces, &args.exclude_interfaces));
+    monitor.set_system_group(SystemProcessGroup::new(args.system_pid_below, args.collapse_system));
+    monitor.set_max_network_kbps(args.max_network_kbps);
+    monitor.set_include_removable_disks(args.include_removable_disks);
+    monitor.set_network_sample(args.network_sample.map(|n| n as usize));
     
     // Spustenie TUI rozhrania s monitorom
-    run_tui(monitor)
-}
\ No newline at end of file
+    run_tui(monitor, args, db).map_err(|e| AppError::Terminal(e.to_string()))
+}
+
+/// Pripájanie k databáze na pozadí pre `--save-metrics`
+///
+/// Kým pripojenie zlyháva, stav je `Unavailable` a pokus sa opakuje
+/// po `DB_RETRY_INTERVAL`. Po pripojení sa spustí ukladanie metrík
+/// (výpadky potom rieši samotné ukladanie).
+fn spawn_db_connect(args: &TuiArgs, db: DbState) {
+    let options = SaverOptions {
+        dedupe: false,
+        dedupe_tolerance: 0.0,
+        quiet: true,  // Výpisy by rozbili TUI
+        save_processes: false,
+        memory_warn_available_mb: None,  // TUI varuje v titulku
+    };
+
+    // Ukladanie má vlastný monitor - TUI meria cez `SystemMonitor` na svojom vlákne
+    let mut monitor = ApiSystemMonitor::new(args.temperatures);
+    monitor.set_max_network_kbps(args.max_network_kbps);
+    monitor.set_include_removable_disks(args.include_removable_disks);
+    monitor.set_network_sample(args.network_sample.map(|n| n as usize));
+    let monitor = SharedMonitor::new(monitor);
+
+    tokio::spawn(async move {
+        loop {
+            match reconnect_pool().await {
+                Ok(pool) => {
+                    db.set(DbStatus::Connected);
+                    spawn_metrics_saver(SharedPool::new(pool), monitor, options, SaveInterval::default(), None);  // TUI nemá `/api/logs`
+                    return;
+                }
+                Err(e) => {
+                    db.set(DbStatus::Unavailable(e.to_string()));
+                    tokio::time::sleep(DB_RETRY_INTERVAL).await;
+                }
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{override_database_url, test_db};
+
+    /// Čakanie, kým `done` nevráti true (najviac 10 s)
+    async fn wait_until(mut done: impl FnMut() -> bool) {
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while !done() {
+                tokio::time::sleep(Duration::from_millis(20)).await;
+            }
+        })
+        .await
+        .expect("condition was not met in time");
+    }
+
+    #[tokio::test]
+    async fn unreachable_database_leaves_the_tui_running_without_saving() {
+        let _url = override_database_url("not-a-database-url").await;
+        let db = DbState::new(true);
+
+        spawn_db_connect(&TuiArgs { save_metrics: true, ..TuiArgs::default() }, db.clone());
+
+        wait_until(|| matches!(db.status(), DbStatus::Unavailable(_))).await;
+    }
+
+    #[tokio::test]
+    async fn saver_is_spawned_once_the_database_connects() {
+        let Some(test) = test_db().await else { return };
+        let db = DbState::new(true);
+
+        spawn_db_connect(&TuiArgs { save_metrics: true, ..TuiArgs::default() }, db.clone());
+        wait_until(|| db.status() == DbStatus::Connected).await;
+
+        // Prvú vzorku uloží až spustené ukladanie
+        tokio::time::timeout(Duration::from_secs(10), async {
+            while crate::db::count_metrics(&test.pool).await.unwrap() == 0 {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+            }
+        })
+        .await
+        .expect("the saver stored a sample");
+    }
+}
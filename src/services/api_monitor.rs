@@ -1,23 +1,54 @@
 // api_monitor.rs
 
-use crate::models::{CpuInfo, DiskInfo, MemoryInfo, ProcessInfo, SystemMetrics, GpuInfo};
+use crate::models::{CpuInfo, DiskInfo, MemoryInfo, ProcessInfo, ProcessSort, ProcessMinimums, SystemMetrics, GpuInfo, GpuSource, TemperatureMode, DEFAULT_MAX_NETWORK_KBPS};
+use crate::services::sampling::network_candidates;
+use crate::services::process_names::process_name;
+use crate::services::cpu_topology;
+use crate::services::kernel_counters;
+use crate::services::power::PowerMeter;
+use crate::services::battery::battery_status;
+use crate::services::disks::{disk_spaces, primary_disk};
+use crate::services::meminfo::{system_memory, zero_total_warning};
+use crate::services::network_cache::NetworkStatsCache;
+use crate::services::process_swap::{fill_swap, process_swap};
 use chrono::Utc;
 use sysinfo::{System, Disks};
 use std::collections::HashMap;
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, PoisonError};
+use std::time::{Duration, Instant};
+use tokio::sync::Mutex;
 use rand::Rng;
 
+/// Ako dlho sa znovu použijú dáta poslednej obnovy
+/// Rýchlo po sebe idúce požiadavky tak nerobia každá vlastný `refresh_all()`
+pub const DEFAULT_SNAPSHOT_TTL: Duration = Duration::from_millis(500);
+
 /// API systémový monitor - špecializovaná verzia pre REST API server
 /// Zodpovedá za zbieranie a správu systémových metrík pre API endpointy
 pub struct ApiSystemMonitor {
     system: System,                    // Hlavný systémový objekt sysinfo
     disks: Disks,                      // Zoznam diskov
-    network_stats_cache: HashMap<u32, (u64, u64)>, // Cache sieťových štatistík procesov
-    last_network_update: std::time::Instant,  // Čas poslednej aktualizácie cache
+    network_cache: NetworkStatsCache,  // Cache sieťových štatistík procesov (útlm starých hodnôt)
+    temperature_mode: TemperatureMode,  // Režim teplôt (API monitor nemá senzory, len odhady)
+    max_network_kbps: u64,             // Strop sieťovej rýchlosti pre `sanitize()`
+    network_sample: Option<usize>,     // Sieť len pre N procesov s najvyšším CPU (`--network-sample`)
+    power_meter: PowerMeter,           // Príkon procesora z RAPL (rozdiel medzi meraniami)
+    include_removable_disks: bool,     // Vymeniteľné médiá v diskových metrikách (`--include-removable-disks`)
+    snapshot_ttl: Duration,            // Platnosť dát poslednej obnovy
+    last_refresh: Instant,             // Čas poslednej obnovy
+    refresh_count: u64,                // Počet skutočných obnov od vytvorenia
+    external_refresh: bool,            // Obnovu robí `SharedMonitor` mimo zámku - `refresh()` nič nerobí
 }
 
 impl ApiSystemMonitor {
     /// Konštruktor pre vytvorenie novej inštancie API monitora
-    pub fn new() -> Self {
+    ///
+    /// # Argumenty
+    /// * `temperature_mode` - Režim teplôt; mimo `Estimate` sa ukladajú NULL hodnoty
+    pub fn new(temperature_mode: TemperatureMode) -> Self {
         let mut system = System::new_all();      // Vytvorenie systému so všetkými komponentmi
         let disks = Disks::new_with_refreshed_list();  // Vytvorenie zoznamu diskov s obnovením
         system.refresh_all();                    // Inicializačné obnovenie všetkých dát
@@ -25,16 +56,97 @@ impl ApiSystemMonitor {
         Self {
             system,
             disks,
-            network_stats_cache: HashMap::new(),  // Prázdna cache
-            last_network_update: std::time::Instant::now(),  // Aktuálny čas
+            network_cache: NetworkStatsCache::default(),  // Prázdna cache
+            temperature_mode,
+            max_network_kbps: DEFAULT_MAX_NETWORK_KBPS,
+            power_meter: PowerMeter::default(),
+            network_sample: None,
+            include_removable_disks: false,
+            snapshot_ttl: DEFAULT_SNAPSHOT_TTL,
+            last_refresh: Instant::now(),
+            refresh_count: 0,
+            external_refresh: false,
+        }
+    }
+
+    /// Nastavenie stropu sieťovej rýchlosti v KB/s (`--max-network-kbps`)
+    pub fn set_max_network_kbps(&mut self, max_kbps: u64) {
+        self.max_network_kbps = max_kbps;
+    }
+
+    /// Započítanie vymeniteľných médií (USB, DVD) do diskových metrík
+    /// (`--include-removable-disks`)
+    pub fn set_include_removable_disks(&mut self, include: bool) {
+        self.include_removable_disks = include;
+    }
+
+    /// Výpočet sieťových štatistík len pre `limit` procesov s najvyšším CPU
+    /// (`--network-sample`); None = všetky procesy
+    pub fn set_network_sample(&mut self, limit: Option<usize>) {
+        self.network_sample = limit;
+    }
+
+    /// Platnosť dát poslednej obnovy (`--snapshot-ttl-ms`, `Duration::ZERO` = obnova pri každom zbere)
+    pub fn set_snapshot_ttl(&mut self, ttl: Duration) {
+        self.snapshot_ttl = ttl;
+    }
+
+    /// Zber dát s izoláciou panic
+    ///
+    /// Panic v `f` (napr. neočakávané dáta zo sysinfo) sa zachytí a monitor
+    /// sa vytvorí nanovo s rovnakými nastaveniami - jeho stav mohol ostať
+    /// rozbitý uprostred obnovy. Vráti text panic; ďalšie zbery pokračujú
+    /// s novým monitorom.
+    pub fn collect_isolated<T>(&mut self, f: impl FnOnce(&mut Self) -> T) -> Result<T, String> {
+        match panic::catch_unwind(AssertUnwindSafe(|| f(self))) {
+            Ok(value) => Ok(value),
+            Err(payload) => {
+                self.restart();
+                Err(panic_message(payload.as_ref()))
+            }
         }
     }
 
+    /// Nový monitor s nastaveniami aktuálneho (režim teplôt, strop siete, vzorka)
+    fn restart(&mut self) {
+        let mut fresh = Self::new(self.temperature_mode);
+        fresh.max_network_kbps = self.max_network_kbps;
+        fresh.network_sample = self.network_sample;
+        fresh.include_removable_disks = self.include_removable_disks;
+        fresh.snapshot_ttl = self.snapshot_ttl;
+        fresh.external_refresh = self.external_refresh;
+        *self = fresh;
+    }
+
     /// Obnovenie všetkých systémových dát
     /// Volané pred každým zberom metrík pre aktuálne dáta
+    ///
+    /// Ak od poslednej obnovy neuplynul `snapshot_ttl`, použijú sa existujúce
+    /// dáta. Monitor v `SharedMonitor` sa tu neobnovuje - nové dáta dostane
+    /// cez `swap_snapshot` ešte pred zberom.
     pub fn refresh(&mut self) {
+        if self.external_refresh || !self.needs_refresh() {
+            return;
+        }
         self.system.refresh_all();  // Obnovenie všetkých systémových informácií
         self.disks.refresh();       // Obnovenie informácií o diskoch
+        self.last_refresh = Instant::now();
+        self.refresh_count += 1;
+    }
+
+    /// Dáta poslednej obnovy už neplatia (`snapshot_ttl`)
+    /// Prvá obnova po vytvorení prebehne vždy (využitie CPU potrebuje dve merania)
+    fn needs_refresh(&self) -> bool {
+        self.refresh_count == 0 || self.last_refresh.elapsed() >= self.snapshot_ttl
+    }
+
+    /// Výmena dát sysinfo za novšie obnovené mimo zámku
+    /// V `snapshot` ostanú predchádzajúce dáta - obnovia sa pri ďalšej výmene
+    fn swap_snapshot(&mut self, snapshot: &mut SystemSnapshot) {
+        std::mem::swap(&mut self.system, &mut snapshot.system);
+        std::mem::swap(&mut self.disks, &mut snapshot.disks);
+        self.last_refresh = Instant::now();
+        self.refresh_count += 1;
     }
 
     /// Získanie informácií o GPU (simulované)
@@ -49,8 +161,9 @@ impl ApiSystemMonitor {
         let memory_total = 8 * 1024 * 1024 * 1024;  // 8 GB v bajtoch
         let memory_used = (memory_total as f64 * 0.3) as u64;  // 30% využitia
         
-        // Simulácia teploty GPU na základe využitia
-        let gpu_temp = Some(40.0 + gpu_usage * 0.3);
+        // Simulácia teploty GPU na základe využitia (len v režime odhadov)
+        let estimate = self.temperature_mode == TemperatureMode::Estimate;
+        let gpu_temp = estimate.then_some(40.0 + gpu_usage * 0.3);
         
         Some(GpuInfo {
             name: "GPU (Simulated)".to_string(),  // Názov indikujúci simuláciu
@@ -58,6 +171,7 @@ impl ApiSystemMonitor {
             memory_total,
             memory_used,
             temperature: gpu_temp,
+            source: GpuSource::Simulated,  // Reálna detekcia GPU zatiaľ nie je
         })
     }
 
@@ -67,23 +181,22 @@ impl ApiSystemMonitor {
         let mut network_stats = HashMap::new();
         let mut rng = rand::thread_rng();  // Generátor náhodných čísel
         
-        for (pid, process) in self.system.processes() {
+        for (pid, process) in network_candidates(&self.system, self.network_sample) {
             let pid_num = pid.as_u32();
             
             // Výpočet sieťovej aktivity pre proces
-            let (sent, recv) = if let Some(&stats) = self.network_stats_cache.get(&pid_num) {
-                // Ak máme cache, použijeme ju ako základ
-                let cpu_factor = process.cpu_usage() as f64 / 100.0;
-                let random_factor = 0.5 + rng.gen::<f64>() * 1.5;  // Náhodný faktor 0.5-2.0
-                
-                // Výpočet nových hodnôt s decay (90% starých hodnôt + nový príspevok)
-                let new_sent = (stats.0 as f64 * 0.9 + cpu_factor * 1024.0 * 1024.0 * random_factor) as u64;
-                let new_recv = (stats.1 as f64 * 0.9 + cpu_factor * 1024.0 * 1024.0 * random_factor * 2.0) as u64;
-                
-                (new_sent, new_recv)
+            let cpu_factor = process.cpu_usage() as f64 / 100.0;
+            let random_factor = 0.5 + rng.gen::<f64>() * 1.5;  // Náhodný faktor 0.5-2.0
+            let contribution = (
+                cpu_factor * 1024.0 * 1024.0 * random_factor,
+                cpu_factor * 1024.0 * 1024.0 * random_factor * 2.0,
+            );
+
+            // Ak máme cache, použijeme ju ako základ (útlm starých hodnôt + nový príspevok)
+            let (sent, recv) = if let Some(stats) = self.network_cache.decayed(pid_num, contribution) {
+                stats
             } else {
                 // Prvý výpočet pre proces
-                let cpu_factor = process.cpu_usage() as f64 / 100.0;
                 let process_name = process.name().to_lowercase();
                 
                 // Rôzne základné hodnoty podľa typu procesu
@@ -108,19 +221,21 @@ impl ApiSystemMonitor {
             network_stats.insert(pid_num, (sent, recv));
         }
         
-        // Aktualizácia cache každých 5 sekúnd
-        if self.last_network_update.elapsed() > std::time::Duration::from_secs(5) {
-            self.network_stats_cache = network_stats.clone();
-            self.last_network_update = std::time::Instant::now();
-        }
+        // Aktualizácia cache (najviac raz za interval cache)
+        self.network_cache.store(&network_stats);
         
         network_stats
     }
 
     /// Získanie top procesov podľa kombinovaného skóre (CPU + sieťová aktivita)
-    pub fn get_top_processes(&mut self, limit: usize) -> Vec<ProcessInfo> {
+    pub fn get_top_processes(&mut self, limit: usize, sort: ProcessSort, minimums: ProcessMinimums) -> Vec<ProcessInfo> {
         self.refresh();  // Obnovenie dát
-        
+        self.current_top_processes(limit, sort, minimums)
+    }
+
+    /// Top procesy z dát poslednej obnovy (bez `refresh()`)
+    /// Hneď po `get_metrics_for_db` - druhá obnova by CPU procesov merala za príliš krátky čas
+    pub fn current_top_processes(&mut self, limit: usize, sort: ProcessSort, minimums: ProcessMinimums) -> Vec<ProcessInfo> {
         let network_stats = self.get_network_stats_for_processes();
         
         // Transformácia sysinfo procesov na naše ProcessInfo
@@ -130,29 +245,46 @@ impl ApiSystemMonitor {
             .iter()
             .map(|(pid, process)| {
                 let pid_num = pid.as_u32();
-                let (network_sent, network_recv) = network_stats.get(&pid_num)
-                    .copied()
-                    .unwrap_or((0, 0));  // Default 0 ak neexistujú štatistiky
+                // Proces mimo vzorky `--network-sample` nemá sieťové hodnoty (None)
+                let network = network_stats.get(&pid_num).copied();
                 
                 ProcessInfo {
                     pid: pid_num,
-                    name: process.name().to_string(),
+                    parent_pid: process.parent().map(|p| p.as_u32()),
+                    name: process_name(process),
                     cpu_usage: process.cpu_usage(),
                     memory: process.memory(),
-                    network_sent: Some(network_sent),
-                    network_recv: Some(network_recv),
+                    virtual_memory: process.virtual_memory(),
+                    network_sent: network.map(|(sent, _)| sent),
+                    network_recv: network.map(|(_, recv)| recv),
+                    disk_read: Some(process.disk_usage().read_bytes),
+                    disk_write: Some(process.disk_usage().written_bytes),
+                    swap: None,
+                    cmd: process.cmd().to_vec(),
                 }
             })
             .collect();
 
-        // Zoradenie podľa kombinovaného skóre (CPU + sieťová aktivita v MB)
-        processes.sort_by(|a, b| {
-            let a_score = a.cpu_usage + (a.network_sent.unwrap_or(0) + a.network_recv.unwrap_or(0)) as f32 / 1024.0 / 1024.0;
-            let b_score = b.cpu_usage + (b.network_sent.unwrap_or(0) + b.network_recv.unwrap_or(0)) as f32 / 1024.0 / 1024.0;
-            b_score.partial_cmp(&a_score).unwrap()  // Zostupné poradie
-        });
-        
-        processes.truncate(limit);  // Obmedzenie na zadaný počet
+        // Vynulovanie nereálnej sieťovej aktivity pred zoradením
+        for process in &mut processes {
+            for correction in process.sanitize(self.max_network_kbps) {
+                eprintln!("⚠️  [Sanitize] {} ({}): {}", process.name, process.pid, correction);
+            }
+        }
+
+        // Filter `min_cpu` / `min_memory_mb` sa uplatní pred limitom
+        processes.retain(|process| minimums.matches(process));
+
+        // Swap sa číta po jednom súbore na proces - pre všetky len pri triedení podľa neho
+        if sort == ProcessSort::Swap {
+            fill_swap(&mut processes);
+        }
+
+        // Výber `limit` najvyšších podľa zvoleného kritéria (predvolene kombinované skóre)
+        sort.top_n(&mut processes, limit);
+        if sort != ProcessSort::Swap {
+            fill_swap(&mut processes);
+        }
         processes
     }
 
@@ -163,71 +295,57 @@ impl ApiSystemMonitor {
         // CPU metriky
         let cpu_usage = self.system.global_cpu_info().cpu_usage() as f64;
         
-        // RAM metriky
-        let memory = self.system.total_memory();
-        let memory_used = self.system.used_memory();
-        let memory_available = self.system.available_memory();
+        // RAM metriky (pri nulovej celkovej pamäti z `/proc/meminfo` alebo nedostupné)
+        let memory = system_memory(&self.system);
+        if let Some(warning) = zero_total_warning(&memory) {
+            eprintln!("⚠️  [Memory] {}", warning);
+        }
         
         // Swap metriky
         let swap_total = self.system.total_swap();
         let swap_used = self.system.used_swap();
 
         // Disk metriky (prvý disk)
-        let disk = self.disks.list().first();
+        let disk = primary_disk(&disk_spaces(&self.disks), self.include_removable_disks);
         let (disk_total, disk_used, disk_available) = if let Some(d) = disk {
-            (d.total_space(), d.total_space() - d.available_space(), d.available_space())
+            (d.total, d.used(), d.available)
         } else {
             (0, 0, 0)
         };
 
-        // Počet procesov
-        let process_count = self.system.processes().len() as i64;
-        
-        // Sieťové štatistiky (celkové)
+        // Sieťové štatistiky (celkové, v KB/s ich prepočíta builder)
         let network_stats = self.get_network_stats_for_processes();
         let total_sent: u64 = network_stats.values().map(|&(sent, _)| sent).sum();
         let total_recv: u64 = network_stats.values().map(|&(_, recv)| recv).sum();
+
+        // Hardcode teploty pre API sú len odhady - v režimoch `Off` a `Real` sa neuvádzajú
+        let estimate = self.temperature_mode == TemperatureMode::Estimate;
+        let battery = battery_status();  // None bez batérie
         
-        // Konverzia na KB/s
-        let network_sent_kbps = if total_sent > 0 { 
-            Some(total_sent as f64 / 1024.0) 
-        } else { 
-            None 
-        };
-        
-        let network_recv_kbps = if total_recv > 0 { 
-            Some(total_recv as f64 / 1024.0) 
-        } else { 
-            None 
-        };
+        // Vytvorenie SystemMetrics objektu
+        let mut metrics = SystemMetrics::builder(Utc::now())
+            .cpu_usage(cpu_usage)
+            .memory(memory.total, memory.used, memory.available)
+            .swap(swap_total, swap_used)
+            .disk(disk_total, disk_used, disk_available)
+            .network_bytes(total_sent, total_recv)
+            .system(self.system.processes().len(), sysinfo::System::uptime())
+            .temperatures(
+                estimate.then_some(40.0),  // Hardcode teploty pre API
+                estimate.then_some(35.0),
+                estimate.then_some(38.0),
+                estimate.then_some(45.0),
+            )
+            .kernel_counters(kernel_counters::open_file_descriptors(), kernel_counters::entropy_available())
+            .package_power_watts(self.power_meter.sample())
+            .battery(battery.map(|b| b.percent), battery.and_then(|b| b.charging))
+            .build();
 
-        // Vytvorenie SystemMetrics objektu s hardcode teplotami pre API
-        SystemMetrics {
-            id: None,
-            timestamp: Utc::now(),
-            cpu_usage,
-            memory_total: memory as i64,
-            memory_used: memory_used as i64,
-            memory_available: memory_available as i64,
-            swap_total: swap_total as i64,
-            swap_used: swap_used as i64,
-            disk_total: disk_total as i64,
-            disk_used: disk_used as i64,
-            disk_available: disk_available as i64,
-            gpu_name: None,
-            gpu_usage: None,
-            gpu_memory_total: None,
-            gpu_memory_used: None,
-            gpu_temperature: None,
-            network_sent_kbps,
-            network_recv_kbps,
-            process_count,
-            system_uptime: sysinfo::System::uptime() as i64,
-            cpu_temperature: Some(40.0),  // Hardcode teploty pre API
-            motherboard_temperature: Some(35.0), 
-            disk_temperature: Some(38.0),
-            max_temperature: Some(45.0), 
+        // Vynulovanie nereálnych sieťových rýchlostí pred uložením do DB
+        for correction in metrics.sanitize(self.max_network_kbps) {
+            eprintln!("⚠️  [Sanitize] System metrics: {}", correction);
         }
+        metrics
     }
 
     /// Získanie metrík optimalizovaných pre ukladanie do databázy
@@ -237,35 +355,42 @@ impl ApiSystemMonitor {
         
         // Pridanie GPU informácií ak sú dostupné
         if let Some(gpu_info) = self.get_gpu_info() {
-            metrics.gpu_name = Some(gpu_info.name);
-            metrics.gpu_usage = Some(gpu_info.usage);
-            metrics.gpu_memory_total = Some(gpu_info.memory_total as i64);
-            metrics.gpu_memory_used = Some(gpu_info.memory_used as i64);
-            metrics.gpu_temperature = gpu_info.temperature;
+            metrics.apply_gpu_info(&gpu_info);
         }
         
         metrics
     }
     
     /// Získanie informácií o všetkých CPU jadrách
+    /// Logické procesory s rovnakým `physical_core` zdieľajú jedno fyzické jadro
     pub fn get_cpu_info(&self) -> Vec<CpuInfo> {
-        self.system.cpus()
-            .iter()
+        let cpus = self.system.cpus();
+        let physical_cores = cpu_topology::physical_core_numbers(cpus.len());
+
+        cpus.iter()
+            .zip(physical_cores)
             .enumerate()
-            .map(|(i, cpu)| CpuInfo {
+            .map(|(i, (cpu, physical_core))| CpuInfo {
                 name: format!("CPU {}", i + 1),
                 usage: cpu.cpu_usage(),
                 frequency: cpu.frequency(),
+                physical_core,
             })
             .collect()
     }
+
+    /// Počet fyzických jadier (None, ak ho platforma neposkytuje)
+    pub fn physical_core_count(&self) -> Option<usize> {
+        self.system.physical_core_count()
+    }
     
     /// Získanie informácií o pamäti
     pub fn get_memory_info(&self) -> MemoryInfo {
+        let memory = system_memory(&self.system);
         MemoryInfo {
-            total: self.system.total_memory(),
-            used: self.system.used_memory(),
-            available: self.system.available_memory(),
+            total: memory.total,
+            used: memory.used,
+            available: memory.available,
         }
     }
     
@@ -288,12 +413,186 @@ impl ApiSystemMonitor {
             .iter()
             .map(|(pid, process)| ProcessInfo {
                 pid: pid.as_u32(),
-                name: process.name().to_string(),
+                parent_pid: process.parent().map(|p| p.as_u32()),
+                name: process_name(process),
                 cpu_usage: process.cpu_usage(),
                 memory: process.memory(),
+                virtual_memory: process.virtual_memory(),
                 network_sent: None,
                 network_recv: None,
+                disk_read: Some(process.disk_usage().read_bytes),
+                disk_write: Some(process.disk_usage().written_bytes),
+                swap: None,
+                cmd: process.cmd().to_vec(),
             })
             .collect()
     }
-}
\ No newline at end of file
+
+    /// Jeden proces podľa PID po obnovení dát (bez sieťových štatistík)
+    /// None, ak proces neexistuje
+    pub fn get_process(&mut self, pid: u32) -> Option<ProcessInfo> {
+        self.refresh();
+        let process = self.system.process(sysinfo::Pid::from_u32(pid))?;
+        Some(ProcessInfo {
+            pid,
+            parent_pid: process.parent().map(|p| p.as_u32()),
+            name: process_name(process),
+            cpu_usage: process.cpu_usage(),
+            memory: process.memory(),
+            virtual_memory: process.virtual_memory(),
+            network_sent: None,
+            network_recv: None,
+            disk_read: Some(process.disk_usage().read_bytes),
+            disk_write: Some(process.disk_usage().written_bytes),
+            swap: process_swap(pid),
+            cmd: process.cmd().to_vec(),
+        })
+    }
+}
+
+/// Dáta sysinfo jednej obnovy (systém a disky)
+struct SystemSnapshot {
+    system: System,
+    disks: Disks,
+    #[cfg(test)]
+    delay: Duration,  // Umelo pomalá obnova v testoch
+}
+
+impl SystemSnapshot {
+    /// Nové obnovené dáta (ako v `ApiSystemMonitor::new`)
+    fn new() -> Self {
+        let mut system = System::new_all();
+        system.refresh_all();
+        Self {
+            system,
+            disks: Disks::new_with_refreshed_list(),
+            #[cfg(test)]
+            delay: Duration::ZERO,
+        }
+    }
+
+    /// `refresh_all()` - trvá desiatky milisekúnd
+    fn refresh(&mut self) {
+        #[cfg(test)]
+        std::thread::sleep(self.delay);
+        self.system.refresh_all();
+        self.disks.refresh();
+    }
+}
+
+/// API monitor zdieľaný medzi handlermi, streamom metrík a ukladaním na pozadí
+///
+/// `refresh_all()` beží nad náhradnou inštanciou sysinfo bez zámku monitora;
+/// pod zámkom sa nové dáta len vymenia s aktuálnymi. Ostatné zbery tak počas
+/// obnovy nečakajú a zbery, ktoré prišli počas nej, použijú jej výsledok
+/// namiesto vlastnej obnovy.
+#[derive(Clone)]
+pub struct SharedMonitor {
+    monitor: Arc<Mutex<ApiSystemMonitor>>,     // Monitor - zámok sa drží len počas výmeny a zberu
+    spare: Arc<std::sync::Mutex<SystemSnapshot>>,  // Náhradné dáta; zámok drží práve obnovujúci zber
+    refreshes: Arc<AtomicU64>,                 // Počet dokončených obnov
+}
+
+impl SharedMonitor {
+    /// Zabalenie monitora - ďalej ho obnovuje len `SharedMonitor`
+    pub fn new(mut monitor: ApiSystemMonitor) -> Self {
+        monitor.external_refresh = true;
+        Self {
+            monitor: Arc::new(Mutex::new(monitor)),
+            spare: Arc::new(std::sync::Mutex::new(SystemSnapshot::new())),
+            refreshes: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Zber dát s izoláciou panic (pozri `ApiSystemMonitor::collect_isolated`)
+    /// Blokuje - volá sa v `spawn_blocking`
+    pub fn collect_blocking<T>(&self, f: impl FnOnce(&mut ApiSystemMonitor) -> T) -> Result<T, String> {
+        self.refresh_outside_lock()?;
+        self.monitor.blocking_lock().collect_isolated(f)
+    }
+
+    /// Obnova náhradných dát a ich výmena s dátami monitora
+    /// Zber, ktorý čakal na prebiehajúcu obnovu, už neobnovuje
+    fn refresh_outside_lock(&self) -> Result<(), String> {
+        let seen = self.refreshes.load(Ordering::Acquire);
+        let mut spare = self.spare.lock().unwrap_or_else(PoisonError::into_inner);
+        if self.refreshes.load(Ordering::Acquire) != seen || !self.monitor.blocking_lock().needs_refresh() {
+            return Ok(());
+        }
+
+        if let Err(payload) = panic::catch_unwind(AssertUnwindSafe(|| spare.refresh())) {
+            *spare = SystemSnapshot::new();  // Dáta rozbité uprostred obnovy sa zahodia
+            return Err(panic_message(payload.as_ref()));
+        }
+        self.monitor.blocking_lock().swap_snapshot(&mut spare);
+        self.refreshes.fetch_add(1, Ordering::Release);
+        Ok(())
+    }
+
+    /// Počet dokončených obnov
+    #[cfg(test)]
+    pub fn refresh_count(&self) -> u64 {
+        self.refreshes.load(Ordering::Acquire)
+    }
+
+    /// Umelo pomalá obnova (simulácia pomalého `refresh_all()`)
+    #[cfg(test)]
+    pub fn set_refresh_delay(&self, delay: Duration) {
+        self.spare.lock().unwrap().delay = delay;
+    }
+
+    /// Priamy zámok monitora (napr. na zdržanie ostatných zberov)
+    #[cfg(test)]
+    pub async fn lock(&self) -> tokio::sync::MutexGuard<'_, ApiSystemMonitor> {
+        self.monitor.lock().await
+    }
+}
+
+/// Text panic z `catch_unwind` (`panic!` so správou dá `&str` alebo `String`)
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload.downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn off_mode_reports_no_temperatures() {
+        let mut monitor = ApiSystemMonitor::new(TemperatureMode::Off);
+        let metrics = monitor.get_metrics_for_db();
+
+        assert_eq!(metrics.cpu_temperature, None);
+        assert_eq!(metrics.motherboard_temperature, None);
+        assert_eq!(metrics.disk_temperature, None);
+        assert_eq!(metrics.max_temperature, None);
+        assert_eq!(metrics.gpu_temperature, None);
+    }
+
+    #[test]
+    fn estimated_gpu_is_marked_simulated() {
+        let gpu = ApiSystemMonitor::new(TemperatureMode::Off).get_gpu_info().unwrap();
+        assert_eq!(gpu.source, GpuSource::Simulated);
+    }
+
+    #[test]
+    fn processes_report_resident_and_virtual_memory() {
+        let mut monitor = ApiSystemMonitor::new(TemperatureMode::Off);
+        let own = monitor.get_process(std::process::id()).expect("the test process is listed");
+
+        assert!(own.memory > 0);
+        assert!(own.virtual_memory >= own.memory);
+    }
+
+    #[test]
+    fn single_process_carries_its_command_line() {
+        let mut monitor = ApiSystemMonitor::new(TemperatureMode::Off);
+        let own = monitor.get_process(std::process::id()).expect("the test process is listed");
+
+        let program = std::env::args().next().unwrap();
+        assert_eq!(own.cmd.first(), Some(&program));
+    }
+}
@@ -0,0 +1,111 @@
+// battery.rs
+
+/// Zdroje napájania (batérie, adaptéry, UPS) - len Linux
+#[cfg(target_os = "linux")]
+const POWER_SUPPLY: &str = "/sys/class/power_supply";
+
+/// Stav batérie notebooku
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BatteryStatus {
+    pub percent: f32,            // Nabitie v percentách (0 - 100)
+    pub charging: Option<bool>,  // Nabíja sa? (None = stav neznámy)
+}
+
+/// Stav prvej batérie v `/sys/class/power_supply`
+/// None na desktopoch a serveroch bez batérie a mimo Linuxu
+pub fn battery_status() -> Option<BatteryStatus> {
+    read_batteries()
+        .into_iter()
+        .find_map(|(capacity, status)| parse_battery(&capacity, &status))
+}
+
+/// Stav batérie z obsahu súborov `capacity` a `status`
+pub fn parse_battery(capacity: &str, status: &str) -> Option<BatteryStatus> {
+    Some(BatteryStatus {
+        percent: parse_capacity(capacity)?,
+        charging: parse_charging(status),
+    })
+}
+
+/// Nabitie z `capacity` (celé číslo, orezané na 0 - 100)
+pub fn parse_capacity(content: &str) -> Option<f32> {
+    content.trim().parse::<f32>().ok().map(|percent| percent.clamp(0.0, 100.0))
+}
+
+/// Nabíjanie zo `status` (`Charging`, `Discharging`, `Not charging`, `Full`, `Unknown`)
+/// Plná batéria sa nenabíja; `Unknown` a neznáme hodnoty dajú None
+pub fn parse_charging(content: &str) -> Option<bool> {
+    match content.trim() {
+        "Charging" => Some(true),
+        "Discharging" | "Not charging" | "Full" => Some(false),
+        _ => None,
+    }
+}
+
+/// Obsah `capacity` a `status` všetkých zdrojov typu `Battery`
+/// (adaptéry `Mains` a batérie periférií bez `capacity` sa preskočia)
+#[cfg(target_os = "linux")]
+fn read_batteries() -> Vec<(String, String)> {
+    let Ok(entries) = std::fs::read_dir(POWER_SUPPLY) else {
+        return Vec::new();
+    };
+
+    let mut supplies: Vec<_> = entries.filter_map(|entry| entry.ok()).map(|entry| entry.path()).collect();
+    supplies.sort();  // BAT0 pred BAT1
+
+    supplies
+        .into_iter()
+        .filter(|path| std::fs::read_to_string(path.join("type")).is_ok_and(|kind| kind.trim() == "Battery"))
+        .filter_map(|path| {
+            let capacity = std::fs::read_to_string(path.join("capacity")).ok()?;
+            let status = std::fs::read_to_string(path.join("status")).unwrap_or_default();
+            Some((capacity, status))
+        })
+        .collect()
+}
+
+/// Mimo Linuxu sa batéria nezisťuje
+#[cfg(not(target_os = "linux"))]
+fn read_batteries() -> Vec<(String, String)> {
+    Vec::new()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sysfs_contents_are_parsed() {
+        assert_eq!(
+            parse_battery("87\n", "Charging\n"),
+            Some(BatteryStatus { percent: 87.0, charging: Some(true) })
+        );
+        assert_eq!(
+            parse_battery("100\n", "Full\n"),
+            Some(BatteryStatus { percent: 100.0, charging: Some(false) })
+        );
+    }
+
+    #[test]
+    fn unknown_status_keeps_the_capacity() {
+        assert_eq!(
+            parse_battery("42", "Unknown"),
+            Some(BatteryStatus { percent: 42.0, charging: None })
+        );
+        assert_eq!(parse_battery("42", "").map(|battery| battery.charging), Some(None));
+    }
+
+    #[test]
+    fn capacity_is_required_and_clamped() {
+        assert_eq!(parse_battery("", "Charging"), None);
+        assert_eq!(parse_battery("n/a", "Discharging"), None);
+        assert_eq!(parse_capacity("105"), Some(100.0));
+        assert_eq!(parse_capacity("-3"), Some(0.0));
+    }
+
+    #[test]
+    fn not_charging_means_not_charging() {
+        assert_eq!(parse_charging("Not charging\n"), Some(false));
+        assert_eq!(parse_charging("Discharging"), Some(false));
+    }
+}
@@ -0,0 +1,505 @@
+// connections.rs
+
+use crate::models::ProcessInfo;
+use std::collections::HashMap;
+use std::process::Command;
+
+/// Poznámka, keď sa nepodarilo zistiť žiadne spojenia
+pub const ELEVATED_PRIVILEGES_NOTE: &str = "Real connections require elevated privileges";
+
+/// Poznámka, keď niektoré spojenia nemali známy PID
+pub const HIDDEN_PIDS_NOTE: &str = "Some PIDs are hidden - run elevated to see all connections";
+
+/// Reprezentácia sieťového spojenia procesu
+/// Obsahuje informácie o lokálnom a vzdialenom konci spojenia
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkConnection {
+    pub process_name: String,    // Názov procesu vytvárajúceho spojenie
+    pub local_address: String,   // Lokálna IP adresa a port
+    pub remote_address: String,  // Vzdialená IP adresa a port
+    pub protocol: String,        // Sieťový protokol (TCP/UDP)
+    pub state: String,           // Stav spojenia (ESTABLISHED, LISTENING, atď.)
+    pub pid: u32,               // PID procesu (0 = nepodarilo sa zistiť)
+    pub bytes_sent: Option<u64>,     // Odoslané bajty za život spojenia (ak ich OS poskytuje)
+    pub bytes_received: Option<u64>, // Prijaté bajty za život spojenia (ak ich OS poskytuje)
+}
+
+impl NetworkConnection {
+    /// Odoslané + prijaté bajty, ak sú známe
+    pub fn total_bytes(&self) -> Option<u64> {
+        Some(self.bytes_sent? + self.bytes_received?)
+    }
+}
+
+/// Podiel jednotlivých spojení na prevádzke procesu (súčet 1.0)
+///
+/// Ak všetky spojenia majú bajty od OS (`ss -i` na Linuxe), podiel zodpovedá
+/// ich bajtom. Inak (Windows, macOS, chýbajúce údaje alebo nulové bajty)
+/// sa celková prevádzka procesu rozdelí rovnomerne.
+///
+/// # Návratová hodnota
+/// (podiely v poradí spojení, boli podiely namerané?)
+pub fn traffic_shares(connections: &[&NetworkConnection]) -> (Vec<f64>, bool) {
+    if connections.is_empty() {
+        return (Vec::new(), false);
+    }
+
+    let bytes: Option<Vec<u64>> = connections.iter().map(|conn| conn.total_bytes()).collect();
+    if let Some(bytes) = bytes {
+        let total: u64 = bytes.iter().sum();
+        if total > 0 {
+            return (bytes.iter().map(|&b| b as f64 / total as f64).collect(), true);
+        }
+    }
+
+    let even = 1.0 / connections.len() as f64;
+    (vec![even; connections.len()], false)
+}
+
+/// Výsledok jedného zisťovania spojení
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionSnapshot {
+    pub connections: Vec<NetworkConnection>,  // Aktívne (nie pasívne) spojenia
+    pub pids_resolved: bool,                  // Majú všetky spojenia známy PID?
+}
+
+impl ConnectionSnapshot {
+    /// Zostavenie výsledku z naparsovaných spojení
+    /// Spojenie bez PID (napr. cudzí proces bez root práv) znamená neúplné dáta
+    fn from_connections(connections: Vec<NetworkConnection>) -> Self {
+        let pids_resolved = connections.iter().all(|conn| conn.pid != 0);
+        Self { connections, pids_resolved }
+    }
+}
+
+/// Spojenia pripravené na zobrazenie
+/// Poznámka (napr. chýbajúce práva) sa nesie zvlášť, nie ako falošné spojenie
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionsResult {
+    pub connections: Vec<NetworkConnection>,  // Spojenia zobrazených procesov
+    pub note: Option<String>,                 // Vysvetlenie neúplných dát
+}
+
+impl ConnectionsResult {
+    /// Počet spojení pre každý PID (procesy bez spojení v mape nie sú)
+    pub fn count_by_pid(&self) -> HashMap<u32, usize> {
+        let mut counts = HashMap::new();
+        for conn in &self.connections {
+            *counts.entry(conn.pid).or_insert(0) += 1;
+        }
+        counts
+    }
+}
+
+/// Spárovanie zistených spojení so zoznamom procesov
+///
+/// # Argumenty
+/// * `snapshot` - Výsledok `ConnectionProvider::snapshot`
+/// * `processes` - Procesy, ktorých spojenia sa majú zobraziť
+///
+/// # Návratová hodnota
+/// Spojenia procesov zo zoznamu (s ich názvom). Ak žiadne nie sú,
+/// použijú sa procesy so sieťovou aktivitou; ak nie sú ani tie,
+/// výsledok je prázdny s poznámkou o potrebných právach.
+pub fn resolve_connections(snapshot: ConnectionSnapshot, processes: &[ProcessInfo]) -> ConnectionsResult {
+    let connections: Vec<NetworkConnection> = snapshot.connections
+        .into_iter()
+        .filter_map(|mut conn| {
+            let process = processes.iter().find(|p| p.pid == conn.pid)?;
+            conn.process_name = process.name.clone();
+            Some(conn)
+        })
+        .collect();
+
+    if !connections.is_empty() {
+        let note = (!snapshot.pids_resolved).then(|| HIDDEN_PIDS_NOTE.to_string());
+        return ConnectionsResult { connections, note };
+    }
+
+    // Fallback - procesy so sieťovou aktivitou (bez konkrétnych adries)
+    let connections: Vec<NetworkConnection> = processes
+        .iter()
+        .take(10)
+        .filter(|proc| proc.network_sent.unwrap_or(0) > 100 || proc.network_recv.unwrap_or(0) > 100)
+        .map(|proc| NetworkConnection {
+            process_name: proc.name.clone(),
+            local_address: format!("PID:{}", proc.pid),
+            remote_address: "Network activity detected".to_string(),
+            protocol: "DATA".to_string(),
+            state: "ACTIVE".to_string(),
+            pid: proc.pid,
+            bytes_sent: None,
+            bytes_received: None,
+        })
+        .collect();
+
+    let note = connections.is_empty().then(|| ELEVATED_PRIVILEGES_NOTE.to_string());
+    ConnectionsResult { connections, note }
+}
+
+/// Zdroj sieťových spojení pre konkrétnu platformu
+/// Zdieľa sa s vláknom zberu TUI, preto `Send + Sync`
+pub trait ConnectionProvider: Send + Sync {
+    /// Zistenie aktuálnych aktívnych spojení
+    /// Pri chybe (chýbajúci nástroj, práva) vráti prázdny výsledok
+    fn snapshot(&self) -> ConnectionSnapshot;
+}
+
+/// Windows - `netstat -ano -p TCP` (PID je vždy dostupný)
+pub struct NetstatProvider;
+
+/// Linux - `ss -tunapi` (PID cudzích procesov len s root právami, bajty len pre TCP)
+pub struct SsProvider;
+
+/// macOS - `lsof -i -n -P` (vidí len procesy, ku ktorým má prístup)
+pub struct LsofProvider;
+
+impl ConnectionProvider for NetstatProvider {
+    fn snapshot(&self) -> ConnectionSnapshot {
+        run_command("netstat", &["-ano", "-p", "TCP"])
+            .map(|output| ConnectionSnapshot::from_connections(parse_netstat(&output)))
+            .unwrap_or_default()
+    }
+}
+
+impl ConnectionProvider for SsProvider {
+    fn snapshot(&self) -> ConnectionSnapshot {
+        run_command("ss", &["-tunapi"])
+            .map(|output| ConnectionSnapshot::from_connections(parse_ss(&output)))
+            .unwrap_or_default()
+    }
+}
+
+impl ConnectionProvider for LsofProvider {
+    fn snapshot(&self) -> ConnectionSnapshot {
+        run_command("lsof", &["-i", "-n", "-P"])
+            .map(|output| ConnectionSnapshot::from_connections(parse_lsof(&output)))
+            .unwrap_or_default()
+    }
+}
+
+/// Zdroj spojení pre aktuálny operačný systém
+/// Vráti None na platformách bez podpory
+pub fn platform_provider() -> Option<Box<dyn ConnectionProvider>> {
+    match std::env::consts::OS {
+        "windows" => Some(Box::new(NetstatProvider)),
+        "linux" => Some(Box::new(SsProvider)),
+        "macos" => Some(Box::new(LsofProvider)),
+        _ => None,
+    }
+}
+
+/// Spustenie externého príkazu a vrátenie jeho štandardného výstupu
+fn run_command(program: &str, args: &[&str]) -> Option<String> {
+    Command::new(program)
+        .args(args)
+        .output()
+        .ok()
+        .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Parsovanie výstupu `netstat -ano -p TCP` (Windows)
+///
+/// Formát riadku: `TCP  192.168.1.5:50000  52.1.1.1:443  ESTABLISHED  1234`
+/// Pasívne spojenia (LISTENING) sa preskakujú.
+pub fn parse_netstat(output: &str) -> Vec<NetworkConnection> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() < 5 || parts[0] != "TCP" {
+                return None;  // Hlavička alebo iný protokol
+            }
+
+            let pid = parts[4].parse::<u32>().ok()?;
+            let (local, remote, state) = (parts[1], parts[2], parts[3]);
+
+            // Filtrovanie pasívnych spojení
+            if state == "LISTENING" || remote == "[::]:0" || remote == "0.0.0.0:0" {
+                return None;
+            }
+
+            Some(NetworkConnection {
+                process_name: String::new(),  // Doplní sa podľa PID
+                local_address: local.to_string(),
+                remote_address: remote.to_string(),
+                protocol: "TCP".to_string(),
+                state: state.to_string(),
+                pid,
+                bytes_sent: None,  // netstat bajty spojení neposkytuje
+                bytes_received: None,
+            })
+        })
+        .collect()
+}
+
+/// Parsovanie výstupu `ss -tunapi` (Linux)
+///
+/// Formát riadku: `tcp  ESTAB  0  0  10.0.0.5:22  10.0.0.9:51234  users:(("sshd",pid=1234,fd=4))`
+/// Stĺpec s procesom chýba, ak k socketu nie sú práva - vtedy je PID 0.
+/// Pri TCP nasleduje odsadený riadok s informáciami (`-i`), z ktorého sa berú
+/// `bytes_sent` a `bytes_received`; staršie verzie `ss` ich nemusia vypisovať.
+pub fn parse_ss(output: &str) -> Vec<NetworkConnection> {
+    let mut connections = Vec::new();
+    let mut last_kept = false;  // Patrí ďalší odsadený riadok k prijatému spojeniu?
+
+    for line in output.lines().skip(1) {  // Hlavička
+        // Odsadený riadok `-i` patrí k predchádzajúcemu socketu
+        if line.starts_with(char::is_whitespace) {
+            if let Some(conn) = connections.last_mut().filter(|_| last_kept) {
+                apply_ss_tcp_info(conn, line);
+            }
+            continue;
+        }
+
+        last_kept = match parse_ss_socket(line) {
+            Some(conn) => {
+                connections.push(conn);
+                true
+            }
+            None => false,  // Pasívny socket - jeho `-i` riadok sa preskočí
+        };
+    }
+
+    connections
+}
+
+/// Jeden riadok socketu z `ss -tunapi` (bez `-i` informácií)
+fn parse_ss_socket(line: &str) -> Option<NetworkConnection> {
+    let parts: Vec<&str> = line.split_whitespace().collect();
+    if parts.len() < 6 {
+        return None;
+    }
+
+    let (netid, state, local, remote) = (parts[0], parts[1], parts[4], parts[5]);
+
+    // Filtrovanie pasívnych spojení
+    if state == "LISTEN" || state == "UNCONN" || remote.ends_with(":*") {
+        return None;
+    }
+
+    // users:(("názov",pid=123,fd=4)) - berie sa prvý proces
+    let users = parts.get(6).copied().unwrap_or("");
+    let process_name = users
+        .split("((\"")
+        .nth(1)
+        .and_then(|rest| rest.split('"').next())
+        .unwrap_or("")
+        .to_string();
+    let pid = users
+        .split("pid=")
+        .nth(1)
+        .and_then(|rest| rest.split(|c: char| !c.is_ascii_digit()).next())
+        .and_then(|pid| pid.parse::<u32>().ok())
+        .unwrap_or(0);
+
+    Some(NetworkConnection {
+        process_name,
+        local_address: local.to_string(),
+        remote_address: remote.to_string(),
+        protocol: netid.to_uppercase(),
+        state: state.to_string(),
+        pid,
+        bytes_sent: None,  // Doplní sa z riadku `-i`
+        bytes_received: None,
+    })
+}
+
+/// Doplnenie bajtov z riadku `-i`, napr. `cubic ... bytes_sent:1234 bytes_received:5678 ...`
+fn apply_ss_tcp_info(conn: &mut NetworkConnection, line: &str) {
+    for token in line.split_whitespace() {
+        if let Some(value) = token.strip_prefix("bytes_sent:") {
+            conn.bytes_sent = value.parse().ok();
+        } else if let Some(value) = token.strip_prefix("bytes_received:") {
+            conn.bytes_received = value.parse().ok();
+        }
+    }
+}
+
+/// Parsovanie výstupu `lsof -i -n -P` (macOS)
+///
+/// Formát riadku:
+/// `Safari  412 user  25u IPv4 0x1234  0t0  TCP 10.0.0.5:50000->17.0.0.1:443 (ESTABLISHED)`
+/// Sockety bez vzdialeného konca (`->`) sú pasívne a preskakujú sa.
+pub fn parse_lsof(output: &str) -> Vec<NetworkConnection> {
+    output
+        .lines()
+        .skip(1)  // Hlavička
+        .filter_map(|line| {
+            let parts: Vec<&str> = line.split_whitespace().collect();
+            if parts.len() < 9 {
+                return None;
+            }
+
+            let pid = parts[1].parse::<u32>().ok()?;
+            let (local, remote) = parts[8].split_once("->")?;
+            let state = parts
+                .get(9)
+                .map(|s| s.trim_matches(|c| c == '(' || c == ')').to_string())
+                .unwrap_or_default();
+
+            Some(NetworkConnection {
+                process_name: parts[0].replace("\\x20", " "),  // lsof escapuje medzery
+                local_address: local.to_string(),
+                remote_address: remote.to_string(),
+                protocol: parts[7].to_string(),
+                state,
+                pid,
+                bytes_sent: None,  // lsof bajty spojení neposkytuje
+                bytes_received: None,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NETSTAT_OUTPUT: &str = "
+Active Connections
+
+  Proto  Local Address          Foreign Address        State           PID
+  TCP    0.0.0.0:135            0.0.0.0:0              LISTENING       1000
+  TCP    192.168.1.5:50000      52.1.1.1:443           ESTABLISHED     1234
+  TCP    [::1]:50001            [::1]:5432             TIME_WAIT       0
+";
+
+    const SS_OUTPUT: &str = "\
+Netid State  Recv-Q Send-Q Local Address:Port Peer Address:Port Process
+udp   UNCONN 0      0      0.0.0.0:68         0.0.0.0:*
+tcp   LISTEN 0      128    0.0.0.0:22         0.0.0.0:*         users:((\"sshd\",pid=800,fd=3))
+tcp   ESTAB  0      0      10.0.0.5:22        10.0.0.9:51234    users:((\"sshd\",pid=1234,fd=4))
+tcp   ESTAB  0      0      10.0.0.5:40000     1.1.1.1:443
+";
+
+    const LSOF_OUTPUT: &str = "\
+COMMAND     PID USER   FD   TYPE DEVICE SIZE/OFF NODE NAME
+Safari      412 user   25u  IPv4 0x1234 0t0      TCP  10.0.0.5:50000->17.0.0.1:443 (ESTABLISHED)
+rapportd    300 user   4u   IPv4 0x5678 0t0      TCP  *:49152 (LISTEN)
+Google\\x20 500 user   30u  IPv6 0x9abc 0t0      UDP  [::1]:5353->[::1]:5354
+";
+
+    #[test]
+    fn netstat_keeps_active_tcp_connections() {
+        let connections = parse_netstat(NETSTAT_OUTPUT);
+
+        assert_eq!(connections.len(), 2);
+        assert_eq!(connections[0].pid, 1234);
+        assert_eq!(connections[0].remote_address, "52.1.1.1:443");
+        assert_eq!(connections[0].state, "ESTABLISHED");
+        assert_eq!(connections[1].state, "TIME_WAIT");
+    }
+
+    #[test]
+    fn ss_skips_passive_sockets_and_reads_the_process() {
+        let connections = parse_ss(SS_OUTPUT);
+
+        assert_eq!(connections.len(), 2);
+        assert_eq!(connections[0].process_name, "sshd");
+        assert_eq!(connections[0].pid, 1234);
+        assert_eq!(connections[0].protocol, "TCP");
+        assert_eq!(connections[1].pid, 0);  // Socket cudzieho procesu bez root práv
+    }
+
+    #[test]
+    fn lsof_keeps_sockets_with_a_remote_end() {
+        let connections = parse_lsof(LSOF_OUTPUT);
+
+        assert_eq!(connections.len(), 2);
+        assert_eq!(connections[0].process_name, "Safari");
+        assert_eq!(connections[0].local_address, "10.0.0.5:50000");
+        assert_eq!(connections[0].state, "ESTABLISHED");
+        assert_eq!(connections[1].process_name, "Google ");
+        assert_eq!(connections[1].protocol, "UDP");
+        assert_eq!(connections[1].state, "");
+    }
+
+    #[test]
+    fn snapshot_reports_unresolved_pids() {
+        assert!(!ConnectionSnapshot::from_connections(parse_netstat(NETSTAT_OUTPUT)).pids_resolved);  // TIME_WAIT s PID 0
+        assert!(ConnectionSnapshot::from_connections(parse_lsof(LSOF_OUTPUT)).pids_resolved);
+        assert!(!ConnectionSnapshot::from_connections(parse_ss(SS_OUTPUT)).pids_resolved);
+    }
+
+    #[test]
+    fn no_connections_give_an_empty_list_with_a_note() {
+        let processes = [crate::test_support::sample_process(42, "idle")];
+        let result = resolve_connections(ConnectionSnapshot::default(), &processes);
+
+        assert!(result.connections.is_empty());
+        assert_eq!(result.note.as_deref(), Some(ELEVATED_PRIVILEGES_NOTE));
+    }
+
+    #[test]
+    fn unresolved_pids_keep_connections_with_a_note() {
+        let processes = [crate::test_support::sample_process(1234, "firefox")];
+        let result = resolve_connections(ConnectionSnapshot::from_connections(parse_netstat(NETSTAT_OUTPUT)), &processes);
+
+        assert_eq!(result.connections.len(), 1);
+        assert_eq!(result.connections[0].process_name, "firefox");
+        assert_eq!(result.note.as_deref(), Some(HIDDEN_PIDS_NOTE));  // TIME_WAIT bez PID
+    }
+
+    const SS_INFO_OUTPUT: &str = "\
+Netid State  Recv-Q Send-Q Local Address:Port Peer Address:Port Process
+tcp   LISTEN 0      128    0.0.0.0:22         0.0.0.0:*         users:((\"sshd\",pid=800,fd=3))
+\t cubic rto:1000 bytes_sent:999 bytes_received:999
+tcp   ESTAB  0      0      10.0.0.5:40000     1.1.1.1:443       users:((\"curl\",pid=900,fd=5))
+\t cubic wscale:7,7 rto:204 bytes_sent:1000 bytes_acked:1000 bytes_received:2000
+tcp   ESTAB  0      0      10.0.0.5:40001     1.1.1.1:443       users:((\"curl\",pid=900,fd=6))
+\t cubic wscale:7,7 rto:204 bytes_sent:500 bytes_received:500
+";
+
+    #[test]
+    fn ss_info_lines_fill_in_bytes() {
+        let connections = parse_ss(SS_INFO_OUTPUT);
+
+        assert_eq!(connections.len(), 2);  // Riadok `-i` pasívneho socketu sa nepriradí
+        assert_eq!(connections[0].bytes_sent, Some(1000));
+        assert_eq!(connections[0].bytes_received, Some(2000));
+        assert_eq!(connections[1].total_bytes(), Some(1000));
+        assert_eq!(parse_ss(SS_OUTPUT)[0].total_bytes(), None);
+    }
+
+    #[test]
+    fn traffic_shares_follow_measured_bytes() {
+        let connections = parse_ss(SS_INFO_OUTPUT);
+        let refs: Vec<&NetworkConnection> = connections.iter().collect();
+
+        let (shares, measured) = traffic_shares(&refs);
+
+        assert!(measured);
+        assert!((shares[0] - 0.75).abs() < 1e-9);
+        assert!((shares[1] - 0.25).abs() < 1e-9);
+    }
+
+    #[test]
+    fn traffic_shares_fall_back_to_an_even_split() {
+        let connections = parse_ss(SS_OUTPUT);  // Bez `-i` riadkov
+        let refs: Vec<&NetworkConnection> = connections.iter().collect();
+        assert_eq!(traffic_shares(&refs), (vec![0.5, 0.5], false));
+
+        let mut idle = connections[0].clone();
+        idle.bytes_sent = Some(0);
+        idle.bytes_received = Some(0);
+        assert_eq!(traffic_shares(&[&idle, &idle]), (vec![0.5, 0.5], false));
+
+        assert_eq!(traffic_shares(&[]), (Vec::new(), false));
+    }
+
+    #[test]
+    fn connections_are_counted_per_pid() {
+        let mut connections = parse_ss(SS_INFO_OUTPUT);  // Dve spojenia PID 900
+        connections.extend(parse_ss(SS_OUTPUT));         // PID 1234 a spojenie bez PID
+        let result = ConnectionsResult { connections, note: None };
+
+        let counts = result.count_by_pid();
+
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts[&900], 2);
+        assert_eq!(counts[&1234], 1);
+        assert_eq!(counts[&0], 1);
+        assert!(ConnectionsResult::default().count_by_pid().is_empty());
+    }
+}
@@ -0,0 +1,88 @@
+// cpu_topology.rs
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Adresár s logickými procesormi (len Linux)
+const SYS_DEVICES_CPU: &str = "/sys/devices/system/cpu";
+
+/// Číslo fyzického jadra (od 1) pre každý z `logical_count` logických procesorov
+///
+/// Logické procesory s rovnakým zoznamom súrodencov v sysfs
+/// (`topology/core_cpus_list`, na starších jadrách `thread_siblings_list`)
+/// zdieľajú jedno fyzické jadro (hyperthreading/SMT). Jadrá sa číslujú
+/// v poradí, v akom sa prvýkrát objavia. Ak topológia nie je dostupná
+/// (iný OS, kontajner bez sysfs), vráti pre daný procesor None.
+pub fn physical_core_numbers(logical_count: usize) -> Vec<Option<usize>> {
+    physical_core_numbers_in(Path::new(SYS_DEVICES_CPU), logical_count)
+}
+
+/// `physical_core_numbers` pre zadaný adresár procesorov
+fn physical_core_numbers_in(cpu_dir: &Path, logical_count: usize) -> Vec<Option<usize>> {
+    let mut cores: HashMap<usize, usize> = HashMap::new();
+
+    (0..logical_count)
+        .map(|cpu| {
+            let first_sibling = first_sibling(cpu_dir, cpu)?;
+            let next = cores.len() + 1;
+            Some(*cores.entry(first_sibling).or_insert(next))
+        })
+        .collect()
+}
+
+/// Najnižší logický procesor, s ktorým `cpu` zdieľa fyzické jadro
+fn first_sibling(cpu_dir: &Path, cpu: usize) -> Option<usize> {
+    let topology = cpu_dir.join(format!("cpu{}", cpu)).join("topology");
+
+    ["core_cpus_list", "thread_siblings_list"]
+        .iter()
+        .find_map(|file| fs::read_to_string(topology.join(file)).ok())
+        .and_then(|list| parse_first_cpu(&list))
+}
+
+/// Prvé číslo zo zoznamu procesorov v tvare `"0,4"` alebo `"0-1"`
+fn parse_first_cpu(list: &str) -> Option<usize> {
+    list.trim()
+        .split([',', '-'])
+        .next()?
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn first_cpu_is_read_from_lists_and_ranges() {
+        assert_eq!(parse_first_cpu("0,4\n"), Some(0));
+        assert_eq!(parse_first_cpu("2-3"), Some(2));
+        assert_eq!(parse_first_cpu(""), None);
+    }
+
+    #[test]
+    fn missing_topology_leaves_cores_unknown() {
+        assert_eq!(physical_core_numbers_in(Path::new("/nonexistent/cpu"), 2), [None, None]);
+    }
+
+    #[test]
+    fn smt_siblings_share_a_physical_core() {
+        let dir = std::env::temp_dir().join(format!("system-monitor-test-{}-cpu", std::process::id()));
+        for (cpu, file, siblings) in [
+            (0, "core_cpus_list", "0,2"),
+            (1, "core_cpus_list", "1,3"),
+            (2, "core_cpus_list", "0,2"),
+            (3, "thread_siblings_list", "1,3"),  // Staršie jadro
+        ] {
+            let topology = dir.join(format!("cpu{}", cpu)).join("topology");
+            fs::create_dir_all(&topology).unwrap();
+            fs::write(topology.join(file), format!("{}\n", siblings)).unwrap();
+        }
+
+        let cores = physical_core_numbers_in(&dir, 5);
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(cores, [Some(1), Some(2), Some(1), Some(2), None]);  // cpu4 bez topológie
+    }
+}
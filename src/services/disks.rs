@@ -0,0 +1,78 @@
+// disks.rs
+
+use sysinfo::{Disk, Disks};
+
+/// Kapacita jedného disku pre súhrnné diskové metriky
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiskSpace {
+    pub total: u64,       // Celková veľkosť v bajtoch
+    pub available: u64,   // Voľné miesto v bajtoch
+    pub removable: bool,  // Vymeniteľné médium (USB kľúč, DVD)
+}
+
+impl DiskSpace {
+    /// Použité miesto v bajtoch
+    pub fn used(&self) -> u64 {
+        self.total.saturating_sub(self.available)
+    }
+}
+
+impl From<&Disk> for DiskSpace {
+    fn from(disk: &Disk) -> Self {
+        Self {
+            total: disk.total_space(),
+            available: disk.available_space(),
+            removable: disk.is_removable(),
+        }
+    }
+}
+
+/// Kapacity všetkých diskov zo zoznamu sysinfo
+pub fn disk_spaces(disks: &Disks) -> Vec<DiskSpace> {
+    disks.list().iter().map(DiskSpace::from).collect()
+}
+
+/// Disk pre súhrnné metriky - prvý disk v zozname
+/// Vymeniteľné médiá sa preskočia, pokiaľ nie je zapnuté `--include-removable-disks`
+pub fn primary_disk(disks: &[DiskSpace], include_removable: bool) -> Option<DiskSpace> {
+    disks.iter().copied().find(|disk| include_removable || !disk.removable)
+}
+
+/// Súčet kapacít diskov v bajtoch (vymeniteľné len s `include_removable`)
+pub fn total_space(disks: &[DiskSpace], include_removable: bool) -> u64 {
+    disks.iter()
+        .filter(|disk| include_removable || !disk.removable)
+        .map(|disk| disk.total)
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GB: u64 = 1024 * 1024 * 1024;
+
+    fn disk(total_gb: u64, available_gb: u64, removable: bool) -> DiskSpace {
+        DiskSpace { total: total_gb * GB, available: available_gb * GB, removable }
+    }
+
+    #[test]
+    fn removable_disks_are_excluded_by_default() {
+        let disks = [disk(32, 30, true), disk(512, 200, false), disk(1024, 1000, false)];
+
+        assert_eq!(total_space(&disks, false), 1536 * GB);
+        assert_eq!(total_space(&disks, true), 1568 * GB);
+        assert_eq!(primary_disk(&disks, false), Some(disks[1]));  // USB kľúč na začiatku zoznamu
+        assert_eq!(primary_disk(&disks, true), Some(disks[0]));
+    }
+
+    #[test]
+    fn only_removable_disks_give_no_primary_disk() {
+        let disks = [disk(16, 1, true)];
+
+        assert_eq!(primary_disk(&disks, false), None);
+        assert_eq!(total_space(&disks, false), 0);
+        assert_eq!(disks[0].used(), 15 * GB);
+        assert_eq!(disk(1, 2, false).used(), 0);  // Nekonzistentné hodnoty z OS
+    }
+}
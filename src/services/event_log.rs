@@ -0,0 +1,133 @@
+// event_log.rs
+
+use super::syslog::SyslogSink;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// Najväčší počet udalostí v pamäti - staršie sa zahodia
+pub const EVENT_LOG_CAPACITY: usize = 500;
+
+/// Závažnosť udalosti (zoradené od najmenej závažnej)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EventLevel {
+    #[default]
+    Info,   // Bežný priebeh (uloženie, pripojenie)
+    Warn,   // Problém, ktorý sa dá prekonať
+    Error,  // Zlyhaná operácia
+}
+
+/// Jedna udalosť úlohy na pozadí
+#[derive(Debug, Clone, Serialize)]
+pub struct LogEvent {
+    pub timestamp: DateTime<Utc>,  // Čas udalosti
+    pub level: EventLevel,         // Závažnosť
+    pub source: &'static str,      // Zdroj udalosti (napr. "auto-save")
+    pub message: String,           // Text bez emodži a prefixov
+}
+
+/// Obmedzený log posledných udalostí zdieľaný medzi úlohami a API
+///
+/// Po dosiahnutí `capacity` sa pri každom zápise zahodí najstaršia udalosť.
+#[derive(Debug, Clone)]
+pub struct EventLog {
+    events: Arc<Mutex<VecDeque<LogEvent>>>,
+    capacity: usize,
+    syslog: Option<Arc<SyslogSink>>,  // Kópia varovaní a chýb do syslogu (`--syslog`)
+}
+
+impl EventLog {
+    /// Vytvorenie logu so zadanou kapacitou (najmenej 1)
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            events: Arc::new(Mutex::new(VecDeque::with_capacity(capacity))),
+            capacity,
+            syslog: None,
+        }
+    }
+
+    /// Odosielanie varovaní a chýb aj do syslogu
+    /// Treba nastaviť pred rozdelením kópií logu medzi úlohy
+    pub fn with_syslog(mut self, sink: SyslogSink) -> Self {
+        self.syslog = Some(Arc::new(sink));
+        self
+    }
+
+    /// Zápis udalosti s aktuálnym časom
+    pub fn record(&self, level: EventLevel, source: &'static str, message: impl Into<String>) {
+        let event = LogEvent { timestamp: Utc::now(), level, source, message: message.into() };
+
+        // Kópia pre `--log-file` (bez nastaveného súboru sa zahodí)
+        match level {
+            EventLevel::Info => tracing::info!(source, "{}", event.message),
+            EventLevel::Warn => tracing::warn!(source, "{}", event.message),
+            EventLevel::Error => tracing::error!(source, "{}", event.message),
+        }
+
+        // Informačné udalosti (bežné uloženia) by syslog len zahltili
+        if let Some(syslog) = self.syslog.as_ref().filter(|_| level >= EventLevel::Warn) {
+            syslog.send(level, source, &event.message);
+        }
+
+        if let Ok(mut events) = self.events.lock() {
+            if events.len() == self.capacity {
+                events.pop_front();
+            }
+            events.push_back(event);
+        }
+    }
+
+    /// Najviac `limit` najnovších udalostí so závažnosťou aspoň `min_level`
+    /// (od najnovšej)
+    pub fn recent(&self, min_level: EventLevel, limit: usize) -> Vec<LogEvent> {
+        self.events
+            .lock()
+            .map(|events| {
+                events
+                    .iter()
+                    .rev()
+                    .filter(|event| event.level >= min_level)
+                    .take(limit)
+                    .cloned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+impl Default for EventLog {
+    fn default() -> Self {
+        Self::new(EVENT_LOG_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn oldest_events_are_dropped_at_capacity() {
+        let log = EventLog::new(2);
+        for message in ["first", "second", "third"] {
+            log.record(EventLevel::Info, "test", message);
+        }
+
+        let messages: Vec<String> = log.recent(EventLevel::Info, 10).into_iter().map(|event| event.message).collect();
+        assert_eq!(messages, ["third", "second"]);
+    }
+
+    #[test]
+    fn recent_respects_level_and_limit() {
+        let log = EventLog::default();
+        log.record(EventLevel::Warn, "test", "a");
+        log.record(EventLevel::Info, "test", "b");
+        log.record(EventLevel::Error, "test", "c");
+
+        assert_eq!(log.recent(EventLevel::Warn, 10).len(), 2);
+        assert_eq!(log.recent(EventLevel::Info, 1)[0].message, "c");
+        assert!(log.recent(EventLevel::Error, 0).is_empty());
+    }
+}
@@ -0,0 +1,123 @@
+// interface_filter.rs
+
+/// Výber sieťových rozhraní pre súčty prenesených dát
+/// (`--include-interface`, `--exclude-interface`)
+///
+/// Vzory sa porovnávajú bez ohľadu na veľkosť písmen; `*` na konci znamená
+/// ľubovoľné pokračovanie (napr. `veth*`), inak musí názov sedieť presne.
+/// Loopback sa vynecháva, kým ho nevyberie niektorý vzor v `include`.
+#[derive(Debug, Clone, Default)]
+pub struct InterfaceFilter {
+    include: Vec<String>,  // Len tieto rozhrania (prázdne = všetky)
+    exclude: Vec<String>,  // Tieto rozhrania nikdy
+}
+
+impl InterfaceFilter {
+    /// Vytvorenie filtra zo vzorov (prázdne vzory sa ignorujú)
+    pub fn new(include: &[String], exclude: &[String]) -> Self {
+        let normalize = |patterns: &[String]| {
+            patterns
+                .iter()
+                .map(|pattern| pattern.trim().to_lowercase())
+                .filter(|pattern| !pattern.is_empty())
+                .collect()
+        };
+        Self { include: normalize(include), exclude: normalize(exclude) }
+    }
+
+    /// Započítava sa rozhranie do súčtov?
+    pub fn is_included(&self, name: &str) -> bool {
+        let name = name.to_lowercase();
+        let matches = |patterns: &[String]| patterns.iter().any(|pattern| matches_pattern(pattern, &name));
+
+        if matches(&self.exclude) {
+            return false;
+        }
+        if self.include.is_empty() {
+            return !is_loopback(&name);
+        }
+        matches(&self.include)
+    }
+
+    /// Súčet (odoslané, prijaté) vybraných rozhraní
+    pub fn totals<'a>(&self, interfaces: impl IntoIterator<Item = (&'a str, u64, u64)>) -> (u64, u64) {
+        interfaces
+            .into_iter()
+            .filter(|(name, _, _)| self.is_included(name))
+            .fold((0, 0), |(sent, recv), (_, tx, rx)| (sent + tx, recv + rx))
+    }
+}
+
+/// Zhoda názvu (malými písmenami) so vzorom s voliteľnou `*` na konci
+fn matches_pattern(pattern: &str, name: &str) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => name.starts_with(prefix),
+        None => name == pattern,
+    }
+}
+
+/// Loopback rozhranie (`lo` na Linuxe, `lo0` na macOS, "Loopback ..." na Windows)
+fn is_loopback(name: &str) -> bool {
+    name == "lo" || name.starts_with("lo0") || name.contains("loopback")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(include: &[&str], exclude: &[&str]) -> InterfaceFilter {
+        let owned = |patterns: &[&str]| patterns.iter().map(|p| p.to_string()).collect::<Vec<_>>();
+        InterfaceFilter::new(&owned(include), &owned(exclude))
+    }
+
+    #[test]
+    fn default_filter_skips_only_loopback() {
+        let filter = filter(&[], &[]);
+
+        assert!(filter.is_included("eth0"));
+        assert!(filter.is_included("wlan0"));
+        assert!(!filter.is_included("lo"));
+        assert!(!filter.is_included("lo0"));
+        assert!(!filter.is_included("Loopback Pseudo-Interface 1"));
+    }
+
+    #[test]
+    fn include_patterns_support_trailing_wildcard() {
+        let filter = filter(&["eth*", "WLAN0"], &[]);
+
+        assert!(filter.is_included("eth0"));
+        assert!(filter.is_included("eth1"));
+        assert!(filter.is_included("wlan0"));
+        assert!(!filter.is_included("wlan1"));
+        assert!(!filter.is_included("docker0"));
+    }
+
+    #[test]
+    fn exclude_wins_over_include() {
+        let filter = filter(&["eth*"], &["eth1"]);
+
+        assert!(filter.is_included("eth0"));
+        assert!(!filter.is_included("eth1"));
+    }
+
+    #[test]
+    fn explicit_include_can_select_loopback() {
+        assert!(filter(&["lo"], &[]).is_included("lo"));
+    }
+
+    #[test]
+    fn blank_patterns_are_ignored() {
+        let filter = filter(&["  ", ""], &[" "]);
+
+        assert!(filter.is_included("eth0"));
+        assert!(!filter.is_included("lo"));
+    }
+
+    #[test]
+    fn totals_sum_selected_interfaces() {
+        let filter = filter(&[], &["docker*"]);
+        let interfaces = [("eth0", 100, 200), ("lo", 1000, 1000), ("docker0", 50, 50), ("wlan0", 1, 2)];
+
+        assert_eq!(filter.totals(interfaces), (101, 202));
+    }
+}
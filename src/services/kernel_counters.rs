@@ -0,0 +1,70 @@
+// kernel_counters.rs
+
+/// Alokované, voľné a maximálne deskriptory súborov (len Linux)
+const FILE_NR: &str = "/proc/sys/fs/file-nr";
+
+/// Odhad dostupnej entropie jadra v bitoch (len Linux)
+const ENTROPY_AVAIL: &str = "/proc/sys/kernel/random/entropy_avail";
+
+/// Počet otvorených deskriptorov súborov v celom systéme
+/// None mimo Linuxu alebo ak súbor nie je čitateľný
+pub fn open_file_descriptors() -> Option<i64> {
+    read_proc(FILE_NR).and_then(|content| parse_file_nr(&content))
+}
+
+/// Dostupná entropia jadra v bitoch
+/// None mimo Linuxu alebo ak súbor nie je čitateľný
+pub fn entropy_available() -> Option<i64> {
+    read_proc(ENTROPY_AVAIL).and_then(|content| parse_entropy_avail(&content))
+}
+
+/// Otvorené deskriptory z obsahu `file-nr` (`alokované voľné maximum`)
+/// Novšie jadrá majú voľné vždy 0, staršie ich odčítajú od alokovaných
+pub fn parse_file_nr(content: &str) -> Option<i64> {
+    let mut fields = content.split_whitespace().map(|field| field.parse::<i64>().ok());
+    let allocated = fields.next()??;
+    let free = fields.next()??;
+    Some((allocated - free).max(0))
+}
+
+/// Entropia z obsahu `entropy_avail` (jedno číslo)
+pub fn parse_entropy_avail(content: &str) -> Option<i64> {
+    content.trim().parse().ok()
+}
+
+#[cfg(target_os = "linux")]
+fn read_proc(path: &str) -> Option<String> {
+    std::fs::read_to_string(path).ok()
+}
+
+/// Mimo Linuxu `/proc` neexistuje
+#[cfg(not(target_os = "linux"))]
+fn read_proc(_path: &str) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn file_nr_gives_allocated_minus_free() {
+        assert_eq!(parse_file_nr("9824\t0\t9223372036854775807\n"), Some(9824));
+        assert_eq!(parse_file_nr("4096\t1024\t65536\n"), Some(3072));  // Staršie jadro
+        assert_eq!(parse_file_nr("9824\n"), None);
+        assert_eq!(parse_file_nr("abc 0 1"), None);
+    }
+
+    #[test]
+    fn entropy_avail_is_a_single_number() {
+        assert_eq!(parse_entropy_avail("256\n"), Some(256));
+        assert_eq!(parse_entropy_avail(""), None);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn linux_exposes_both_counters() {
+        assert!(open_file_descriptors().is_some_and(|count| count > 0));
+        assert!(entropy_available().is_some());
+    }
+}
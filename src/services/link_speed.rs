@@ -0,0 +1,68 @@
+// link_speed.rs
+
+use std::fs;
+use std::path::Path;
+
+/// Adresár so sieťovými rozhraniami (len Linux)
+const SYS_CLASS_NET: &str = "/sys/class/net";
+
+/// Zistenie rýchlosti sieťového pripojenia v Mbps
+///
+/// Číta `/sys/class/net/<iface>/speed` pre aktívne rozhrania (okrem loopbacku)
+/// a vráti najvyššiu hodnotu. Na iných OS alebo pri virtuálnych rozhraniach,
+/// ktoré rýchlosť neuvádzajú (-1), vráti None.
+pub fn detect_link_speed_mbps() -> Option<u64> {
+    fs::read_dir(SYS_CLASS_NET)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter(|entry| entry.file_name() != "lo")
+        .filter(|entry| read_trimmed(&entry.path().join("operstate")).as_deref() == Some("up"))
+        .filter_map(|entry| read_trimmed(&entry.path().join("speed"))?.parse::<i64>().ok())
+        .filter(|&speed| speed > 0)
+        .max()
+        .map(|speed| speed as u64)
+}
+
+/// Obsah súboru bez bielych znakov (None pri chybe čítania)
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|content| content.trim().to_string())
+}
+
+/// Využitie linky v percentách (0 - 100)
+///
+/// # Argumenty
+/// * `kb_per_sec` - Prenos v KB/s (1 KB = 1024 B)
+/// * `link_mbps` - Rýchlosť linky v Mbps (1 Mbps = 1 000 000 b/s)
+///
+/// Napr. 120 Mbps na 1000 Mbps linke = 12 %. Neznáma linka (0) alebo
+/// neplatný prenos (NaN) dá 0 %.
+pub fn link_usage_percent(kb_per_sec: f64, link_mbps: u64) -> f64 {
+    if link_mbps == 0 || !kb_per_sec.is_finite() {
+        return 0.0;
+    }
+    let bits_per_sec = kb_per_sec * 1024.0 * 8.0;
+    (bits_per_sec / (link_mbps as f64 * 1_000_000.0) * 100.0).clamp(0.0, 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Mbps prepočítané na KB/s (1 KB = 1024 B)
+    fn kbps_of_mbps(mbps: f64) -> f64 {
+        mbps * 1_000_000.0 / 8.0 / 1024.0
+    }
+
+    #[test]
+    fn throughput_is_a_share_of_the_link_speed() {
+        assert!((link_usage_percent(kbps_of_mbps(120.0), 1000) - 12.0).abs() < 1e-9);
+        assert!((link_usage_percent(kbps_of_mbps(50.0), 100) - 50.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn usage_is_clamped_and_unknown_links_give_zero() {
+        assert_eq!(link_usage_percent(kbps_of_mbps(2000.0), 1000), 100.0);
+        assert_eq!(link_usage_percent(1000.0, 0), 0.0);
+        assert_eq!(link_usage_percent(f64::NAN, 1000), 0.0);
+    }
+}
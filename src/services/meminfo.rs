@@ -0,0 +1,129 @@
+// meminfo.rs
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use sysinfo::System;
+
+/// Súhrn pamäte jadra (len Linux)
+const PROC_MEMINFO: &str = "/proc/meminfo";
+
+/// Varovanie o nulovej celkovej pamäti sa vypíše len raz za beh
+static ZERO_TOTAL_WARNED: AtomicBool = AtomicBool::new(false);
+
+/// Odkiaľ pochádzajú hodnoty pamäte
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MemorySource {
+    Sysinfo,       // Bežný prípad - sysinfo vrátilo nenulovú celkovú pamäť
+    ProcMeminfo,   // Sysinfo vrátilo 0, hodnoty sú z `/proc/meminfo`
+    Unavailable,   // Pamäť sa nedá zistiť - všetky hodnoty sú 0
+}
+
+/// RAM systému v bajtoch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryReading {
+    pub total: u64,          // Celková RAM (0 = nedostupná)
+    pub used: u64,           // Použitá RAM
+    pub available: u64,      // Dostupná RAM
+    pub source: MemorySource,
+}
+
+impl MemoryReading {
+    /// Pamäť sa nepodarilo zistiť
+    pub const UNAVAILABLE: Self = Self { total: 0, used: 0, available: 0, source: MemorySource::Unavailable };
+}
+
+/// RAM zo sysinfo; pri nulovej celkovej pamäti (niektoré kontajnery a sandboxy)
+/// sa skúsi `/proc/meminfo`, inak sa pamäť označí ako nedostupná
+pub fn system_memory(system: &System) -> MemoryReading {
+    let total = system.total_memory();
+    if total > 0 {
+        return MemoryReading {
+            total,
+            used: system.used_memory(),
+            available: system.available_memory(),
+            source: MemorySource::Sysinfo,
+        };
+    }
+
+    read_proc(PROC_MEMINFO)
+        .and_then(|content| parse_meminfo(&content))
+        .unwrap_or(MemoryReading::UNAVAILABLE)
+}
+
+/// Text varovania pri prvom meraní, ktoré nepochádza zo sysinfo
+/// Ďalšie volania vrátia None - volajúci ho vypíše podľa režimu (stdout / log)
+pub fn zero_total_warning(reading: &MemoryReading) -> Option<&'static str> {
+    let message = match reading.source {
+        MemorySource::Sysinfo => return None,
+        MemorySource::ProcMeminfo => "sysinfo reported 0 total memory, using /proc/meminfo instead",
+        MemorySource::Unavailable => "sysinfo reported 0 total memory, memory metrics are unavailable",
+    };
+    (!ZERO_TOTAL_WARNED.swap(true, Ordering::Relaxed)).then_some(message)
+}
+
+/// RAM z obsahu `/proc/meminfo` (hodnoty v kB)
+/// Staršie jadrá nemajú `MemAvailable` - použije sa `MemFree`
+pub fn parse_meminfo(content: &str) -> Option<MemoryReading> {
+    let field = |name: &str| {
+        content.lines()
+            .find_map(|line| line.strip_prefix(name)?.strip_prefix(':'))
+            .and_then(|rest| rest.split_whitespace().next()?.parse::<u64>().ok())
+            .map(|kb| kb * 1024)
+    };
+
+    let total = field("MemTotal").filter(|&total| total > 0)?;
+    let available = field("MemAvailable").or_else(|| field("MemFree"))?.min(total);
+    Some(MemoryReading {
+        total,
+        used: total - available,
+        available,
+        source: MemorySource::ProcMeminfo,
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn read_proc(path: &str) -> Option<String> {
+    std::fs::read_to_string(path).ok()
+}
+
+/// Mimo Linuxu `/proc` neexistuje
+#[cfg(not(target_os = "linux"))]
+fn read_proc(_path: &str) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meminfo_values_are_converted_to_bytes() {
+        let content = "MemTotal:       16384000 kB\nMemFree:         2048000 kB\nMemAvailable:    8192000 kB\n";
+        let reading = parse_meminfo(content).unwrap();
+
+        assert_eq!(reading.total, 16_384_000 * 1024);
+        assert_eq!(reading.available, 8_192_000 * 1024);
+        assert_eq!(reading.used, 8_192_000 * 1024);
+        assert_eq!(reading.source, MemorySource::ProcMeminfo);
+    }
+
+    #[test]
+    fn older_kernels_fall_back_to_mem_free() {
+        let reading = parse_meminfo("MemTotal: 4000 kB\nMemFree: 1000 kB\n").unwrap();
+        assert_eq!(reading.available, 1000 * 1024);
+    }
+
+    #[test]
+    fn zero_or_missing_total_is_not_a_reading() {
+        assert_eq!(parse_meminfo("MemTotal: 0 kB\nMemFree: 0 kB\n"), None);
+        assert_eq!(parse_meminfo(""), None);
+    }
+
+    #[test]
+    fn zero_total_warning_is_printed_once() {
+        let sysinfo = MemoryReading { source: MemorySource::Sysinfo, ..MemoryReading::UNAVAILABLE };
+        assert_eq!(zero_total_warning(&sysinfo), None);
+
+        assert!(zero_total_warning(&MemoryReading::UNAVAILABLE).unwrap().contains("unavailable"));
+        assert_eq!(zero_total_warning(&MemoryReading::UNAVAILABLE), None);
+    }
+}
@@ -0,0 +1,162 @@
+// memory_growth.rs
+
+use crate::models::ProcessInfo;
+use std::collections::{HashMap, VecDeque};
+use std::time::Instant;
+
+/// Počet posledných vzoriek pamäte uchovávaných pre každý proces
+/// Proces sa hodnotí až po naplnení celého okna
+pub const MEMORY_GROWTH_WINDOW: usize = 20;
+
+/// Proces, ktorého pamäť v celom okne len rastie
+#[derive(Debug, Clone)]
+pub struct MemoryGrowth {
+    pub pid: u32,                  // ID procesu
+    pub name: String,              // Názov procesu
+    pub memory: u64,               // Aktuálna pamäť v bajtoch
+    pub growth_mb_per_min: f64,    // Rýchlosť rastu v MB/min
+}
+
+/// História pamäte jedného procesu
+#[derive(Debug, Clone)]
+struct ProcessMemory {
+    name: String,
+    samples: VecDeque<(Instant, u64)>,  // (čas vzorky, pamäť v bajtoch)
+}
+
+/// Obmedzená história pamäte procesov podľa PID
+/// Slúži na odhalenie procesov, ktorých pamäť neustále stúpa (úniky pamäte)
+#[derive(Debug, Clone)]
+pub struct MemoryHistory {
+    window: usize,                           // Počet vzoriek na proces
+    processes: HashMap<u32, ProcessMemory>,  // PID -> história
+}
+
+impl MemoryHistory {
+    /// Vytvorenie histórie so zadaným počtom vzoriek na proces (najmenej 2)
+    pub fn new(window: usize) -> Self {
+        Self {
+            window: window.max(2),
+            processes: HashMap::new(),
+        }
+    }
+
+    /// Započítanie novej vzorky všetkých procesov
+    /// Procesy, ktoré vo vzorke chýbajú (skončili), sa z histórie odstránia
+    pub fn record(&mut self, processes: &[ProcessInfo]) {
+        let at = Instant::now();
+        let window = self.window;
+        let alive: HashMap<u32, &ProcessInfo> = processes.iter().map(|p| (p.pid, p)).collect();
+        self.processes.retain(|pid, _| alive.contains_key(pid));
+
+        for (pid, process) in alive {
+            let entry = self.processes.entry(pid).or_insert_with(|| ProcessMemory {
+                name: process.name.clone(),
+                samples: VecDeque::with_capacity(window),
+            });
+
+            // Recyklovaný PID s iným názvom je nový proces
+            if entry.name != process.name {
+                entry.name = process.name.clone();
+                entry.samples.clear();
+            }
+
+            entry.samples.push_back((at, process.memory));
+            if entry.samples.len() > window {
+                entry.samples.pop_front();
+            }
+        }
+    }
+
+    /// Procesy s plným oknom vzoriek, ktorých pamäť nikdy neklesla
+    /// a celkovo stúpla; zoradené od najrýchlejšie rastúceho
+    pub fn growing(&self) -> Vec<MemoryGrowth> {
+        let mut growing: Vec<MemoryGrowth> = self.processes
+            .iter()
+            .filter(|(_, history)| history.samples.len() == self.window)
+            .filter_map(|(&pid, history)| {
+                let growth_mb_per_min = monotonic_growth_rate(&history.samples)?;
+                let memory = history.samples.back().map(|&(_, bytes)| bytes)?;
+                Some(MemoryGrowth { pid, name: history.name.clone(), memory, growth_mb_per_min })
+            })
+            .collect();
+
+        growing.sort_by(|a, b| b.growth_mb_per_min.total_cmp(&a.growth_mb_per_min));
+        growing
+    }
+}
+
+impl Default for MemoryHistory {
+    fn default() -> Self {
+        Self::new(MEMORY_GROWTH_WINDOW)
+    }
+}
+
+/// Rýchlosť rastu v MB/min, ak pamäť v sérii nikdy neklesla a celkovo stúpla
+/// Vráti None pre stabilnú, klesajúcu alebo kolísajúcu sériu
+fn monotonic_growth_rate(samples: &VecDeque<(Instant, u64)>) -> Option<f64> {
+    let never_drops = samples
+        .iter()
+        .zip(samples.iter().skip(1))
+        .all(|((_, prev), (_, next))| next >= prev);
+
+    let &(first_at, first) = samples.front()?;
+    let &(last_at, last) = samples.back()?;
+    let minutes = last_at.duration_since(first_at).as_secs_f64() / 60.0;
+
+    if !never_drops || last <= first || minutes <= 0.0 {
+        return None;
+    }
+
+    Some((last - first) as f64 / 1024.0 / 1024.0 / minutes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_process;
+    use std::time::Duration;
+
+    const MB: u64 = 1024 * 1024;
+
+    /// Séria vzoriek po jednej minúte
+    fn series(memory_mb: &[u64]) -> VecDeque<(Instant, u64)> {
+        let start = Instant::now();
+        memory_mb
+            .iter()
+            .enumerate()
+            .map(|(minute, &mb)| (start + Duration::from_secs(60 * minute as u64), mb * MB))
+            .collect()
+    }
+
+    #[test]
+    fn increasing_series_is_flagged_with_its_rate() {
+        assert_eq!(monotonic_growth_rate(&series(&[100, 110, 110, 130])), Some(10.0));
+    }
+
+    #[test]
+    fn stable_or_fluctuating_series_is_not_flagged() {
+        assert_eq!(monotonic_growth_rate(&series(&[100, 100, 100, 100])), None);
+        assert_eq!(monotonic_growth_rate(&series(&[100, 120, 110, 130])), None);
+    }
+
+    #[test]
+    fn growing_needs_a_full_window_and_evicts_exited_processes() {
+        let mut history = MemoryHistory::new(3);
+        for mb in [100, 150, 200] {
+            history.record(&[
+                ProcessInfo { memory: mb * MB, ..sample_process(1, "leaky") },
+                ProcessInfo { memory: 50 * MB, ..sample_process(2, "stable") },
+            ]);
+            std::thread::sleep(Duration::from_millis(2));
+        }
+
+        let growing = history.growing();
+        assert_eq!(growing.len(), 1);
+        assert_eq!((growing[0].pid, growing[0].memory), (1, 200 * MB));
+
+        history.record(&[ProcessInfo { memory: 50 * MB, ..sample_process(2, "stable") }]);  // PID 1 skončil
+        assert!(history.growing().is_empty());
+        assert!(!history.processes.contains_key(&1));
+    }
+}
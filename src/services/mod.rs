@@ -4,8 +4,29 @@
 pub mod api_monitor;      // API monitor pre REST API server
 pub mod monitor;          // Hlavný systémový monitor pre TUI
 pub mod temperatures;     // Monitor teplôt komponentov
+pub mod process_tree;     // Sledovanie stromu procesov podľa PID
+pub mod network_rates;    // Vyhladzovanie sieťových rýchlostí procesov
+pub mod network_cache;    // Cache sieťových štatistík s útlmom starých hodnôt
+pub mod connections;      // Sieťové spojenia procesov podľa OS
+pub mod link_speed;       // Rýchlosť sieťovej linky (Linux)
+pub mod cpu_topology;     // Logické procesory podľa fyzických jadier (Linux)
+pub mod kernel_counters;  // Otvorené deskriptory a entropia jadra (Linux)
+pub mod power;            // Príkon procesora z RAPL a podiel procesov (Linux)
+pub mod battery;          // Stav batérie notebooku (Linux)
+pub mod memory_growth;    // História pamäte procesov (rast pamäte)
+pub mod process_swap;     // Swap jednotlivých procesov (Linux)
+pub mod process_filter;   // Zoznam ignorovaných procesov
+pub mod interface_filter; // Výber sieťových rozhraní pre súčty
+pub mod process_names;    // Názov procesu s náhradou pri prázdnom názve
+pub mod system_processes; // Zoskupenie systémových procesov do jedného riadku
+pub mod event_log;        // Log posledných udalostí úloh na pozadí
+pub mod sampling;         // Výber procesov pre drahší zber (sieť)
+pub mod disks;            // Súhrnná kapacita diskov bez vymeniteľných médií
+pub mod meminfo;          // RAM s náhradou pri nulovej celkovej pamäti
+pub mod syslog;           // Odosielanie udalostí do lokálneho syslogu
 
 /// Re-export hlavných štruktúr pre jednoduchší import
 pub use api_monitor::ApiSystemMonitor;  // API monitor
 pub use monitor::SystemMonitor;         // Hlavný monitor
-pub use temperatures::TemperatureMonitor; // Monitor teplôt
\ No newline at end of file
+pub use temperatures::TemperatureMonitor; // Monitor teplôt
+pub use process_tree::ProcessTree;      // Strom sledovaných procesov
\ No newline at end of file
@@ -1,9 +1,25 @@
 // monitor.rs
 
-use crate::models::{CpuInfo, DiskInfo, MemoryInfo, ProcessInfo, SystemMetrics, GpuInfo, TemperatureInfo};
+use crate::models::{CpuInfo, DiskInfo, MemoryInfo, ProcessInfo, SystemMetrics, GpuInfo, GpuSource, ProcessSort, TemperatureInfo, TemperatureMode, TemperatureThresholds, DEFAULT_MAX_NETWORK_KBPS};
 use crate::services::TemperatureMonitor;
+use crate::services::process_tree::{collect_descendants, ProcessTree};
+use crate::services::network_rates::RateSmoother;
+use crate::services::memory_growth::{MemoryGrowth, MemoryHistory};
+use crate::services::process_filter::ProcessFilter;
+use crate::services::interface_filter::InterfaceFilter;
+use crate::services::system_processes::SystemProcessGroup;
+use crate::services::sampling::network_candidates;
+use crate::services::process_names::process_name;
+use crate::services::cpu_topology;
+use crate::services::kernel_counters;
+use crate::services::power::PowerMeter;
+use crate::services::battery::battery_status;
+use crate::services::disks::{disk_spaces, primary_disk};
+use crate::services::meminfo::{system_memory, zero_total_warning};
+use crate::services::network_cache::NetworkStatsCache;
+use crate::services::process_swap::{fill_swap, process_swap};
 use chrono::Utc;
-use sysinfo::{System, Disks};
+use sysinfo::{System, Disks, Networks};
 use std::collections::HashMap;
 use rand::Rng;
 
@@ -12,32 +28,89 @@ use rand::Rng;
 pub struct SystemMonitor {
     system: System,                    // Sysinfo systémový objekt
     disks: Disks,                      // Disky
-    network_stats_cache: HashMap<u32, (u64, u64)>, // Cache sieťových štatistík
-    last_network_update: std::time::Instant,  // Čas poslednej aktualizácie
+    networks: Networks,                // Sieťové rozhrania (súčty od štartu systému)
+    interface_filter: InterfaceFilter, // Rozhrania v súčtoch (`--include-interface`, `--exclude-interface`)
+    network_cache: NetworkStatsCache,  // Cache sieťových štatistík (útlm starých hodnôt)
+    network_rates: RateSmoother,       // Vyhladené sieťové rýchlosti podľa PID
     temperature_monitor: TemperatureMonitor,  // Monitor teplôt
+    memory_history: MemoryHistory,     // História pamäte procesov podľa PID
+    process_filter: ProcessFilter,     // Ignorované procesy (`--ignore-process`)
+    system_group: SystemProcessGroup,  // Zoskupenie systémových procesov (`--collapse-system`)
+    system_members: Vec<ProcessInfo>,  // Procesy v súhrnnom riadku z posledného zberu
+    max_network_kbps: u64,             // Strop sieťovej rýchlosti pre `sanitize()`
+    network_sample: Option<usize>,     // Sieť len pre N procesov s najvyšším CPU (`--network-sample`)
+    power_meter: PowerMeter,           // Príkon procesora z RAPL (rozdiel medzi meraniami)
+    include_removable_disks: bool,     // Vymeniteľné médiá v diskových metrikách (`--include-removable-disks`)
 }
 
 impl SystemMonitor {
     /// Konštruktor pre vytvorenie nového monitora
-    pub fn new() -> Self {
+    ///
+    /// # Argumenty
+    /// * `temperature_mode` - Režim zberu teplôt (vypnuté / odhad / len senzory)
+    pub fn new(temperature_mode: TemperatureMode) -> Self {
         let mut system = System::new_all();
         let disks = Disks::new_with_refreshed_list();
-        let temperature_monitor = TemperatureMonitor::new();  // Vytvorenie teplotného monitora
+        let networks = Networks::new_with_refreshed_list();
+        let temperature_monitor = TemperatureMonitor::new(temperature_mode);  // Vytvorenie teplotného monitora
         system.refresh_all();
         
         Self {
             system,
             disks,
-            network_stats_cache: HashMap::new(),
-            last_network_update: std::time::Instant::now(),
+            networks,
+            interface_filter: InterfaceFilter::default(),
+            network_cache: NetworkStatsCache::default(),
+            network_rates: RateSmoother::default(),
             temperature_monitor,
+            memory_history: MemoryHistory::default(),
+            process_filter: ProcessFilter::default(),
+            system_group: SystemProcessGroup::default(),
+            system_members: Vec::new(),
+            max_network_kbps: DEFAULT_MAX_NETWORK_KBPS,
+            power_meter: PowerMeter::default(),
+            network_sample: None,
+            include_removable_disks: false,
         }
     }
 
+    /// Nastavenie stropu sieťovej rýchlosti v KB/s (`--max-network-kbps`)
+    pub fn set_max_network_kbps(&mut self, max_kbps: u64) {
+        self.max_network_kbps = max_kbps;
+    }
+
+    /// Započítanie vymeniteľných médií (USB, DVD) do diskových metrík
+    /// (`--include-removable-disks`)
+    pub fn set_include_removable_disks(&mut self, include: bool) {
+        self.include_removable_disks = include;
+    }
+
+    /// Výpočet sieťových štatistík len pre `limit` procesov s najvyšším CPU
+    /// (`--network-sample`); None = všetky procesy
+    pub fn set_network_sample(&mut self, limit: Option<usize>) {
+        self.network_sample = limit;
+    }
+
     /// Obnovenie všetkých systémových dát
     pub fn refresh(&mut self) {
         self.system.refresh_all();
         self.disks.refresh();
+        self.networks.refresh();
+    }
+
+    /// Výber rozhraní pre `get_interface_totals`
+    pub fn set_interface_filter(&mut self, filter: InterfaceFilter) {
+        self.interface_filter = filter;
+    }
+
+    /// Bajty (odoslané, prijaté) rozhraní vybraných filtrom od štartu systému
+    /// (presnejšie od zapnutia rozhrania - počítadlá OS); predvolene bez loopbacku
+    pub fn get_interface_totals(&self) -> (u64, u64) {
+        self.interface_filter.totals(
+            self.networks
+                .iter()
+                .map(|(name, data)| (name.as_str(), data.total_transmitted(), data.total_received())),
+        )
     }
 
     /// Získanie GPU informácií s reálnymi teplotami
@@ -49,15 +122,20 @@ impl SystemMonitor {
         let memory_used = (memory_total as f64 * 0.3) as u64;
         
         // Použitie reálnych teplôt namiesto simulovaných
+        // Odhad podľa záťaže len v režime `Estimate`
         let temperatures = self.get_temperatures();
-        let gpu_temp = temperatures.gpu_temp.unwrap_or(40.0 + gpu_usage as f32 * 0.3) as f64;
+        let gpu_temp = match self.temperature_monitor.mode() {
+            TemperatureMode::Estimate => Some(temperatures.gpu_temp.unwrap_or(40.0 + gpu_usage as f32 * 0.3)),
+            TemperatureMode::Real | TemperatureMode::Off => temperatures.gpu_temp,
+        }.map(|t| t as f64);
         
         Some(GpuInfo {
             name: "GPU (Simulated)".to_string(),
             usage: gpu_usage,
             memory_total,
             memory_used,
-            temperature: Some(gpu_temp),
+            temperature: gpu_temp,
+            source: GpuSource::Simulated,
         })
     }
 
@@ -67,19 +145,19 @@ impl SystemMonitor {
         let mut network_stats = HashMap::new();
         let mut rng = rand::thread_rng();
         
-        for (pid, process) in self.system.processes() {
+        for (pid, process) in network_candidates(&self.system, self.network_sample) {
             let pid_num = pid.as_u32();
             
-            let (sent, recv) = if let Some(&stats) = self.network_stats_cache.get(&pid_num) {
-                let cpu_factor = process.cpu_usage() as f64 / 100.0;
-                let random_factor = 0.5 + rng.gen::<f64>() * 1.5;
-                
-                let new_sent = (stats.0 as f64 * 0.9 + cpu_factor * 1024.0 * 1024.0 * random_factor) as u64;
-                let new_recv = (stats.1 as f64 * 0.9 + cpu_factor * 1024.0 * 1024.0 * random_factor * 2.0) as u64;
-                
-                (new_sent, new_recv)
+            let cpu_factor = process.cpu_usage() as f64 / 100.0;
+            let random_factor = 0.5 + rng.gen::<f64>() * 1.5;
+            let contribution = (
+                cpu_factor * 1024.0 * 1024.0 * random_factor,
+                cpu_factor * 1024.0 * 1024.0 * random_factor * 2.0,
+            );
+
+            let (sent, recv) = if let Some(stats) = self.network_cache.decayed(pid_num, contribution) {
+                stats
             } else {
-                let cpu_factor = process.cpu_usage() as f64 / 100.0;
                 let process_name = process.name().to_lowercase();
                 let base_traffic = if process_name.contains("chrome") 
                     || process_name.contains("firefox")
@@ -101,12 +179,15 @@ impl SystemMonitor {
             network_stats.insert(pid_num, (sent, recv));
         }
         
-        if self.last_network_update.elapsed() > std::time::Duration::from_secs(5) {
-            self.network_stats_cache = network_stats.clone();
-            self.last_network_update = std::time::Instant::now();
-        }
+        self.network_cache.store(&network_stats);
         
-        network_stats
+        // Vyhladenie hodnôt pre stabilné zobrazenie (skončené procesy sa vyradia)
+        self.network_rates.update(&network_stats)
+    }
+
+    /// Súčet CPU všetkých procesov (základ pre podiel príkonu procesu)
+    pub fn total_process_cpu(&self) -> f32 {
+        self.system.processes().values().map(|process| process.cpu_usage()).sum()
     }
 
     /// Získanie top procesov (rovnaké ako v API monitori)
@@ -121,41 +202,132 @@ impl SystemMonitor {
             .iter()
             .map(|(pid, process)| {
                 let pid_num = pid.as_u32();
-                let (network_sent, network_recv) = network_stats.get(&pid_num)
-                    .copied()
-                    .unwrap_or((0, 0));
+                // Proces mimo vzorky `--network-sample` nemá sieťové hodnoty (None)
+                let network = network_stats.get(&pid_num).copied();
                 
                 ProcessInfo {
                     pid: pid_num,
-                    name: process.name().to_string(),
+                    parent_pid: process.parent().map(|p| p.as_u32()),
+                    name: process_name(process),
                     cpu_usage: process.cpu_usage(),
                     memory: process.memory(),
-                    network_sent: Some(network_sent),
-                    network_recv: Some(network_recv),
+                    virtual_memory: process.virtual_memory(),
+                    network_sent: network.map(|(sent, _)| sent),
+                    network_recv: network.map(|(_, recv)| recv),
+                    disk_read: Some(process.disk_usage().read_bytes),
+                    disk_write: Some(process.disk_usage().written_bytes),
+                    swap: None,
+                    cmd: process.cmd().to_vec(),
                 }
             })
             .collect();
 
-        processes.sort_by(|a, b| {
-            let a_score = a.cpu_usage + (a.network_sent.unwrap_or(0) + a.network_recv.unwrap_or(0)) as f32 / 1024.0 / 1024.0;
-            let b_score = b.cpu_usage + (b.network_sent.unwrap_or(0) + b.network_recv.unwrap_or(0)) as f32 / 1024.0 / 1024.0;
-            b_score.partial_cmp(&a_score).unwrap()
-        });
+        // Vzorka pamäte všetkých procesov (pred orezaním na limit)
+        self.memory_history.record(&processes);
         
-        processes.truncate(limit);
+        // Skrytie ignorovaných procesov (pred orezaním, aby ich bolo `limit`)
+        self.process_filter.apply(&mut processes);
+
+        // Vynulovanie nereálnej sieťovej aktivity (výpis by v TUI rozbil obrazovku)
+        for process in &mut processes {
+            process.sanitize(self.max_network_kbps);
+        }
+
+        // Systémové procesy ako jeden riadok (ten sa radí ako každý iný proces)
+        self.system_members = self.system_group.collapse(&mut processes);
+
+        // Výber `limit` najvyšších bez zoradenia všetkých procesov
+        ProcessSort::Combined.top_n(&mut processes, limit);
+        fill_swap(&mut processes);  // Swap len pre zobrazené procesy (súbor na proces)
+        processes
+    }
+
+    /// Procesy s najväčším swapom z dát poslednej obnovy (bez `refresh()`)
+    /// Číta `/proc/<pid>/status` každého procesu - volá sa len v pohľade Swap;
+    /// mimo Linuxu je zoznam prázdny
+    pub fn get_swap_processes(&self, limit: usize) -> Vec<ProcessInfo> {
+        let mut processes: Vec<ProcessInfo> = self
+            .system
+            .processes()
+            .iter()
+            .filter_map(|(pid, process)| {
+                let swap = process_swap(pid.as_u32()).filter(|&swap| swap > 0)?;
+                Some(ProcessInfo {
+                    pid: pid.as_u32(),
+                    parent_pid: process.parent().map(|p| p.as_u32()),
+                    name: process_name(process),
+                    cpu_usage: process.cpu_usage(),
+                    memory: process.memory(),
+                    virtual_memory: process.virtual_memory(),
+                    network_sent: None,
+                    network_recv: None,
+                    disk_read: Some(process.disk_usage().read_bytes),
+                    disk_write: Some(process.disk_usage().written_bytes),
+                    swap: Some(swap),
+                    cmd: process.cmd().to_vec(),
+                })
+            })
+            .collect();
+
+        self.process_filter.apply(&mut processes);
+        ProcessSort::Swap.top_n(&mut processes, limit);
         processes
     }
 
-    /// Získanie teplôt všetkých komponentov
+    /// Nastavenie zoznamu ignorovaných procesov pre `get_top_processes`
+    pub fn set_process_filter(&mut self, filter: ProcessFilter) {
+        self.process_filter = filter;
+    }
+
+    /// Zapnutie/vypnutie ignorovania procesov za behu
+    /// Vráti nový stav, alebo None ak nie je čo ignorovať
+    pub fn toggle_process_filter(&mut self) -> Option<bool> {
+        if !self.process_filter.is_configured() {
+            return None;
+        }
+        self.process_filter.enabled = !self.process_filter.enabled;
+        Some(self.process_filter.enabled)
+    }
+
+    /// Nastavenie zoskupenia systémových procesov pre `get_top_processes`
+    pub fn set_system_group(&mut self, group: SystemProcessGroup) {
+        self.system_group = group;
+    }
+
+    /// Zapnutie/vypnutie zoskupenia systémových procesov za behu (vráti nový stav)
+    pub fn toggle_system_group(&mut self) -> bool {
+        self.system_group.enabled = !self.system_group.enabled;
+        self.system_group.enabled
+    }
+
+    /// Procesy zoskupené do súhrnného riadku pri poslednom `get_top_processes`
+    /// (zoradené podľa CPU, prázdne pri vypnutom zoskupení)
+    pub fn get_system_members(&self) -> Vec<ProcessInfo> {
+        self.system_members.clone()
+    }
+
+    /// Procesy, ktorých pamäť počas posledných vzoriek neustále rastie
+    /// Vzorky pribúdajú pri každom volaní `get_top_processes`
+    pub fn get_memory_growth(&self) -> Vec<MemoryGrowth> {
+        self.memory_history.growing()
+    }
+
+    /// Ukončenie procesu (SIGKILL na Unixe, TerminateProcess na Windows)
+    /// false, ak proces už neexistuje alebo naň nie sú práva
+    pub fn kill_process(&self, pid: u32) -> bool {
+        self.system.process(sysinfo::Pid::from_u32(pid)).is_some_and(|process| process.kill())
+    }
+
+    /// Získanie teplôt všetkých komponentov (podľa nastaveného režimu)
     pub fn get_temperatures(&self) -> TemperatureInfo {
         let cpu_usage = self.system.global_cpu_info().cpu_usage();
-        self.temperature_monitor.get_temperatures_with_fallback(cpu_usage)
+        self.temperature_monitor.read(cpu_usage)
     }
 
     /// Získanie teplôt spolu s úrovňou varovania
     pub fn get_temperatures_with_warning(&self) -> (TemperatureInfo, crate::models::TemperatureWarning) {
         let temps = self.get_temperatures();
-        let warning = temps.get_warning_level();
+        let warning = temps.get_warning_level_with(&TemperatureThresholds::default());
         (temps, warning)
     }
 
@@ -164,103 +336,89 @@ impl SystemMonitor {
         self.refresh();
         
         let cpu_usage = self.system.global_cpu_info().cpu_usage() as f64;
-        let memory = self.system.total_memory();
-        let memory_used = self.system.used_memory();
-        let memory_available = self.system.available_memory();
+        let memory = system_memory(&self.system);
+        if let Some(warning) = zero_total_warning(&memory) {
+            tracing::warn!("{}", warning);  // Do `--log-file`; výpis na terminál by rozbil TUI
+        }
         
         let swap_total = self.system.total_swap();
         let swap_used = self.system.used_swap();
 
-        let disk = self.disks.list().first();
+        let disk = primary_disk(&disk_spaces(&self.disks), self.include_removable_disks);
         let (disk_total, disk_used, disk_available) = if let Some(d) = disk {
-            (d.total_space(), d.total_space() - d.available_space(), d.available_space())
+            (d.total, d.used(), d.available)
         } else {
             (0, 0, 0)
         };
 
-        let process_count = self.system.processes().len() as i64;
-        
         let network_stats = self.get_network_stats_for_processes();
         let total_sent: u64 = network_stats.values().map(|&(sent, _)| sent).sum();
         let total_recv: u64 = network_stats.values().map(|&(_, recv)| recv).sum();
-        
-        let network_sent_kbps = if total_sent > 0 { 
-            Some(total_sent as f64 / 1024.0) 
-        } else { 
-            None 
-        };
-        
-        let network_recv_kbps = if total_recv > 0 { 
-            Some(total_recv as f64 / 1024.0) 
-        } else { 
-            None 
-        };
 
         let temperatures = self.get_temperatures();
+        let battery = battery_status();  // None bez batérie
 
         // Použitie reálnych teplôt namiesto hardcode hodnôt
-        SystemMetrics {
-            id: None,
-            timestamp: Utc::now(),
-            cpu_usage,
-            memory_total: memory as i64,
-            memory_used: memory_used as i64,
-            memory_available: memory_available as i64,
-            swap_total: swap_total as i64,
-            swap_used: swap_used as i64,
-            disk_total: disk_total as i64,
-            disk_used: disk_used as i64,
-            disk_available: disk_available as i64,
-            gpu_name: None,
-            gpu_usage: None,
-            gpu_memory_total: None,
-            gpu_memory_used: None,
-            gpu_temperature: temperatures.gpu_temp.map(|t| t as f64),
-            network_sent_kbps,
-            network_recv_kbps,
-            process_count,
-            system_uptime: sysinfo::System::uptime() as i64,
-            cpu_temperature: temperatures.cpu_temp.map(|t| t as f64),
-            motherboard_temperature: temperatures.motherboard_temp.map(|t| t as f64),
-            disk_temperature: temperatures.disk_temp.map(|t| t as f64),
-            max_temperature: temperatures.get_max_temp().map(|t| t as f64),
-        }
+        let mut metrics = SystemMetrics::builder(Utc::now())
+            .cpu_usage(cpu_usage)
+            .memory(memory.total, memory.used, memory.available)
+            .swap(swap_total, swap_used)
+            .disk(disk_total, disk_used, disk_available)
+            .network_bytes(total_sent, total_recv)
+            .system(self.system.processes().len(), sysinfo::System::uptime())
+            .temperatures(
+                temperatures.cpu_temp.map(|t| t as f64),
+                temperatures.motherboard_temp.map(|t| t as f64),
+                temperatures.disk_temp.map(|t| t as f64),
+                temperatures.get_max_temp().map(|t| t as f64),
+            )
+            .kernel_counters(kernel_counters::open_file_descriptors(), kernel_counters::entropy_available())
+            .package_power_watts(self.power_meter.sample())
+            .battery(battery.map(|b| b.percent), battery.and_then(|b| b.charging))
+            .build();
+
+        // Vynulovanie nereálnych sieťových rýchlostí (bez výpisu - beží v TUI)
+        metrics.sanitize(self.max_network_kbps);
+        metrics
     }
 
     /// Metriky optimalizované pre databázu (vrátane GPU)
     pub fn get_metrics_for_db(&mut self) -> SystemMetrics {
         let mut metrics = self.get_metrics();
         
+        // get_gpu_info je jediný zdroj GPU teploty (reálna teplota s fallbackom)
         if let Some(gpu_info) = self.get_gpu_info() {
-            metrics.gpu_name = Some(gpu_info.name);
-            metrics.gpu_usage = Some(gpu_info.usage);
-            metrics.gpu_memory_total = Some(gpu_info.memory_total as i64);
-            metrics.gpu_memory_used = Some(gpu_info.memory_used as i64);
-            // gpu_temperature je už nastavené z teplôt
+            metrics.apply_gpu_info(&gpu_info);
         }
         
         metrics
     }
     
     /// Získanie informácií o CPU (rovnaké ako v API monitori)
+    /// Logické procesory s rovnakým `physical_core` zdieľajú jedno fyzické jadro
     pub fn get_cpu_info(&self) -> Vec<CpuInfo> {
-        self.system.cpus()
-            .iter()
+        let cpus = self.system.cpus();
+        let physical_cores = cpu_topology::physical_core_numbers(cpus.len());
+
+        cpus.iter()
+            .zip(physical_cores)
             .enumerate()
-            .map(|(i, cpu)| CpuInfo {
+            .map(|(i, (cpu, physical_core))| CpuInfo {
                 name: format!("CPU {}", i + 1),
                 usage: cpu.cpu_usage(),
                 frequency: cpu.frequency(),
+                physical_core,
             })
             .collect()
     }
     
     /// Získanie informácií o pamäti (rovnaké ako v API monitori)
     pub fn get_memory_info(&self) -> MemoryInfo {
+        let memory = system_memory(&self.system);
         MemoryInfo {
-            total: self.system.total_memory(),
-            used: self.system.used_memory(),
-            available: self.system.available_memory(),
+            total: memory.total,
+            used: memory.used,
+            available: memory.available,
         }
     }
     
@@ -277,18 +435,62 @@ impl SystemMonitor {
             .collect()
     }
     
+    /// Získanie stromu procesov so zadaným koreňovým PID
+    /// Používa dáta z poslednej obnovy (volá sa po `get_top_processes`)
+    ///
+    /// # Návratová hodnota
+    /// `None`, ak sledovaný proces už neexistuje
+    pub fn get_process_tree(&self, root_pid: u32) -> Option<ProcessTree> {
+        let parents: HashMap<u32, Option<u32>> = self.system.processes()
+            .iter()
+            .map(|(pid, process)| (pid.as_u32(), process.parent().map(|p| p.as_u32())))
+            .collect();
+
+        let pids = collect_descendants(root_pid, &parents);
+        if pids.is_empty() {
+            return None;  // Proces skončil
+        }
+
+        let processes = pids
+            .iter()
+            .filter_map(|&pid| self.system.process(sysinfo::Pid::from_u32(pid)))
+            .map(|process| ProcessInfo {
+                pid: process.pid().as_u32(),
+                parent_pid: process.parent().map(|p| p.as_u32()),
+                name: process_name(process),
+                cpu_usage: process.cpu_usage(),
+                memory: process.memory(),
+                virtual_memory: process.virtual_memory(),
+                network_sent: None,
+                network_recv: None,
+                disk_read: Some(process.disk_usage().read_bytes),
+                disk_write: Some(process.disk_usage().written_bytes),
+                swap: None,
+                cmd: process.cmd().to_vec(),
+            })
+            .collect();
+
+        Some(ProcessTree::new(root_pid, processes))
+    }
+
     /// Získanie všetkých procesov (rovnaké ako v API monitori)
     pub fn get_processes(&self) -> Vec<ProcessInfo> {
         self.system.processes()
             .iter()
             .map(|(pid, process)| ProcessInfo {
                 pid: pid.as_u32(),
-                name: process.name().to_string(),
+                parent_pid: process.parent().map(|p| p.as_u32()),
+                name: process_name(process),
                 cpu_usage: process.cpu_usage(),
                 memory: process.memory(),
+                virtual_memory: process.virtual_memory(),
                 network_sent: None,
                 network_recv: None,
+                disk_read: Some(process.disk_usage().read_bytes),
+                disk_write: Some(process.disk_usage().written_bytes),
+                swap: None,
+                cmd: process.cmd().to_vec(),
             })
             .collect()
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,105 @@
+// network_cache.rs
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Podiel predchádzajúcej hodnoty, ktorý ostane po jednom kroku (0.0 - 1.0)
+/// Vyššia hodnota = pomalší útlm a vyššia ustálená hodnota
+pub const DEFAULT_DECAY_ALPHA: f64 = 0.9;
+
+/// Ako často sa cache prepíše aktuálnymi hodnotami
+pub const DEFAULT_CACHE_REFRESH: Duration = Duration::from_secs(5);
+
+/// Jeden krok útlmu: `prev * alpha + new`
+///
+/// Pri konštantnom príspevku `new` hodnota konverguje k `new / (1 - alpha)`
+/// (pre `alpha` = 0.9 k desaťnásobku príspevku). `alpha` sa obmedzí na 0.0 - 1.0;
+/// pri 1.0 hodnota nekonverguje, ale rastie o `new` v každom kroku.
+pub fn apply_decay(prev: f64, new: f64, alpha: f64) -> f64 {
+    prev * alpha.clamp(0.0, 1.0) + new
+}
+
+/// Cache sieťových štatistík procesov s útlmom starých hodnôt
+///
+/// Procesy v cache dostanú novú hodnotu cez `apply_decay`, ostatné začínajú
+/// od odhadu monitora. Cache sa prepisuje najviac raz za `refresh_interval`.
+#[derive(Debug, Clone)]
+pub struct NetworkStatsCache {
+    stats: HashMap<u32, (u64, u64)>,  // PID -> (odoslané, prijaté) z poslednej aktualizácie
+    alpha: f64,                       // Podiel starej hodnoty v ďalšom kroku
+    refresh_interval: Duration,       // Minimálny čas medzi aktualizáciami
+    last_update: Instant,             // Čas poslednej aktualizácie
+}
+
+impl NetworkStatsCache {
+    /// Vytvorenie prázdnej cache so zadaným útlmom a intervalom aktualizácie
+    pub fn new(alpha: f64, refresh_interval: Duration) -> Self {
+        Self {
+            stats: HashMap::new(),
+            alpha: alpha.clamp(0.0, 1.0),
+            refresh_interval,
+            last_update: Instant::now(),
+        }
+    }
+
+    /// Utlmená hodnota pre proces v cache s pripočítaným príspevkom
+    /// (None, ak proces v cache ešte nie je)
+    pub fn decayed(&self, pid: u32, (new_sent, new_recv): (f64, f64)) -> Option<(u64, u64)> {
+        self.stats.get(&pid).map(|&(sent, recv)| (
+            apply_decay(sent as f64, new_sent, self.alpha) as u64,
+            apply_decay(recv as f64, new_recv, self.alpha) as u64,
+        ))
+    }
+
+    /// Uloženie aktuálnych hodnôt, ak od poslednej aktualizácie uplynul interval
+    pub fn store(&mut self, stats: &HashMap<u32, (u64, u64)>) {
+        if self.last_update.elapsed() > self.refresh_interval {
+            self.stats = stats.clone();
+            self.last_update = Instant::now();
+        }
+    }
+}
+
+impl Default for NetworkStatsCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_DECAY_ALPHA, DEFAULT_CACHE_REFRESH)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decay_keeps_alpha_share_of_previous_value() {
+        assert_eq!(apply_decay(100.0, 5.0, 0.5), 55.0);
+        assert_eq!(apply_decay(100.0, 5.0, 0.0), 5.0);
+    }
+
+    #[test]
+    fn constant_contribution_converges_to_its_multiple() {
+        let mut value = 0.0;
+        for _ in 0..500 {
+            value = apply_decay(value, 10.0, DEFAULT_DECAY_ALPHA);
+        }
+
+        // 10 / (1 - 0.9) = 100
+        assert!((value - 100.0).abs() < 1e-6, "{value}");
+    }
+
+    #[test]
+    fn alpha_is_clamped_to_unit_range() {
+        assert_eq!(apply_decay(100.0, 1.0, 2.0), apply_decay(100.0, 1.0, 1.0));
+        assert_eq!(apply_decay(100.0, 1.0, -1.0), 1.0);
+    }
+
+    #[test]
+    fn cache_decays_only_known_processes() {
+        let mut cache = NetworkStatsCache::new(0.5, Duration::ZERO);
+        std::thread::sleep(Duration::from_millis(1));  // `store` vyžaduje uplynutý interval
+        cache.store(&HashMap::from([(1, (200, 100))]));
+
+        assert_eq!(cache.decayed(1, (10.0, 0.0)), Some((110, 50)));
+        assert_eq!(cache.decayed(2, (10.0, 0.0)), None);
+    }
+}
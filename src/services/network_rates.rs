@@ -0,0 +1,114 @@
+// network_rates.rs
+
+use std::collections::HashMap;
+
+/// Váha novej vzorky pri vyhladzovaní (0.0 - 1.0)
+/// Nižšia hodnota = stabilnejšie, ale pomalšie reagujúce čísla
+pub const DEFAULT_SMOOTHING_ALPHA: f64 = 0.3;
+
+/// Exponenciálne vyhladzovanie (EMA) sieťových rýchlostí jednotlivých procesov
+/// Zabraňuje skákaniu hodnôt v sieťovej tabuľke medzi vzorkami
+#[derive(Debug, Clone)]
+pub struct RateSmoother {
+    alpha: f64,                          // Váha novej vzorky
+    rates: HashMap<u32, (f64, f64)>,     // PID -> vyhladené (odoslané, prijaté)
+}
+
+impl RateSmoother {
+    /// Vytvorenie vyhladzovača so zadanou váhou novej vzorky
+    pub fn new(alpha: f64) -> Self {
+        Self {
+            alpha: alpha.clamp(0.0, 1.0),
+            rates: HashMap::new(),
+        }
+    }
+
+    /// Započítanie novej vzorky a vrátenie vyhladených hodnôt
+    ///
+    /// # Argumenty
+    /// * `samples` - Mapa PID -> (odoslané, prijaté) z aktuálneho merania
+    ///
+    /// # Návratová hodnota
+    /// Vyhladené hodnoty pre rovnaké PID. Procesy, ktoré vo vzorke
+    /// chýbajú (skončili), sa z cache odstránia.
+    pub fn update(&mut self, samples: &HashMap<u32, (u64, u64)>) -> HashMap<u32, (u64, u64)> {
+        // Odstránenie PID, ktoré už neexistujú
+        self.rates.retain(|pid, _| samples.contains_key(pid));
+
+        let alpha = self.alpha;
+        samples
+            .iter()
+            .map(|(&pid, &(sent, recv))| {
+                let (sent, recv) = (sent as f64, recv as f64);
+
+                // Nový proces začína priamo na nameranej hodnote
+                let entry = self.rates.entry(pid).or_insert((sent, recv));
+                entry.0 += alpha * (sent - entry.0);
+                entry.1 += alpha * (recv - entry.1);
+
+                (pid, (entry.0.round() as u64, entry.1.round() as u64))
+            })
+            .collect()
+    }
+}
+
+impl Default for RateSmoother {
+    fn default() -> Self {
+        Self::new(DEFAULT_SMOOTHING_ALPHA)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(entries: &[(u32, u64, u64)]) -> HashMap<u32, (u64, u64)> {
+        entries.iter().map(|&(pid, sent, recv)| (pid, (sent, recv))).collect()
+    }
+
+    #[test]
+    fn new_process_starts_at_its_measured_rate() {
+        let mut smoother = RateSmoother::new(0.5);
+
+        let rates = smoother.update(&sample(&[(1, 100, 40)]));
+
+        assert_eq!(rates[&1], (100, 40));
+    }
+
+    #[test]
+    fn rates_converge_toward_a_steady_value() {
+        let mut smoother = RateSmoother::new(0.5);
+        smoother.update(&sample(&[(1, 0, 0)]));
+
+        // Polovičná váha: 0 -> 500 -> 750 -> 875 ...
+        assert_eq!(smoother.update(&sample(&[(1, 1000, 200)]))[&1], (500, 100));
+        assert_eq!(smoother.update(&sample(&[(1, 1000, 200)]))[&1], (750, 150));
+
+        let mut last = (0, 0);
+        for _ in 0..20 {
+            last = smoother.update(&sample(&[(1, 1000, 200)]))[&1];
+        }
+        assert_eq!(last, (1000, 200));
+    }
+
+    #[test]
+    fn alpha_one_disables_smoothing() {
+        let mut smoother = RateSmoother::new(1.0);
+        smoother.update(&sample(&[(1, 0, 0)]));
+
+        assert_eq!(smoother.update(&sample(&[(1, 300, 30)]))[&1], (300, 30));
+    }
+
+    #[test]
+    fn finished_processes_are_evicted() {
+        let mut smoother = RateSmoother::new(0.5);
+        smoother.update(&sample(&[(1, 1000, 1000), (2, 10, 10)]));
+
+        let rates = smoother.update(&sample(&[(2, 10, 10)]));
+        assert!(!rates.contains_key(&1));
+
+        // Vrátený PID (napr. znovu použitý) začína odznova, nie od starej hodnoty
+        let rates = smoother.update(&sample(&[(1, 0, 0), (2, 10, 10)]));
+        assert_eq!(rates[&1], (0, 0));
+    }
+}
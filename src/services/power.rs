@@ -0,0 +1,99 @@
+// power.rs
+
+use std::fs;
+use std::time::Instant;
+
+/// Zóna RAPL pre prvý procesorový balík (Intel aj novšie AMD, len Linux)
+const RAPL_PACKAGE: &str = "/sys/class/powercap/intel-rapl:0";
+
+/// Hrubý odhad príkonu procesorového balíka z počítadla energie RAPL
+///
+/// RAPL udáva spotrebovanú energiu v mikrojouloch od štartu; príkon je
+/// rozdiel medzi dvoma meraniami delený uplynulým časom. Prvé meranie
+/// preto vráti None. Bez RAPL (iný OS, AMD bez ovládača, virtuálny stroj)
+/// alebo bez práv na čítanie (`energy_uj` býva len pre root) je vždy None.
+#[derive(Debug, Default)]
+pub struct PowerMeter {
+    last: Option<(u64, Instant)>,  // Posledné počítadlo energie (µJ) a čas merania
+}
+
+impl PowerMeter {
+    /// Príkon balíka vo wattoch od posledného merania
+    pub fn sample(&mut self) -> Option<f64> {
+        let energy = read_counter("energy_uj")?;
+        let now = Instant::now();
+        let (previous, at) = self.last.replace((energy, now))?;
+
+        package_watts(previous, energy, read_counter("max_energy_range_uj"), now.duration_since(at).as_secs_f64())
+    }
+}
+
+/// Príkon vo wattoch z dvoch stavov počítadla energie (µJ)
+///
+/// Počítadlo po `max_range_uj` pretečie na nulu; bez známeho rozsahu sa
+/// pretečené meranie zahodí. Nulový čas medzi meraniami dá None.
+pub fn package_watts(previous_uj: u64, current_uj: u64, max_range_uj: Option<u64>, elapsed_secs: f64) -> Option<f64> {
+    if elapsed_secs <= 0.0 {
+        return None;
+    }
+
+    let delta_uj = if current_uj >= previous_uj {
+        current_uj - previous_uj
+    } else {
+        max_range_uj?.checked_sub(previous_uj)? + current_uj  // Pretečenie počítadla
+    };
+    Some(delta_uj as f64 / 1_000_000.0 / elapsed_secs)
+}
+
+/// Podiel príkonu pripísaný procesu podľa jeho podielu na CPU
+///
+/// `total_cpu` je súčet CPU všetkých procesov (v rovnakých jednotkách ako
+/// `process_cpu`). Ide len o hrubý odhad - GPU, disk ani nečinnosť balíka
+/// sa nerozlišujú. Pri nulovom súčte vráti 0.
+pub fn attribute_power(total_watts: f64, process_cpu: f32, total_cpu: f32) -> f64 {
+    if total_cpu <= 0.0 || !total_watts.is_finite() {
+        return 0.0;
+    }
+    total_watts * (process_cpu / total_cpu).clamp(0.0, 1.0) as f64
+}
+
+/// Hodnota súboru zóny RAPL (napr. `energy_uj`)
+fn read_counter(file: &str) -> Option<u64> {
+    fs::read_to_string(format!("{}/{}", RAPL_PACKAGE, file))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn power_is_split_by_cpu_share() {
+        assert_eq!(attribute_power(20.0, 50.0, 200.0), 5.0);
+        assert_eq!(attribute_power(20.0, 200.0, 200.0), 20.0);
+        assert_eq!(attribute_power(20.0, 300.0, 200.0), 20.0);  // Nikdy viac než celý balík
+        assert_eq!(attribute_power(20.0, 10.0, 0.0), 0.0);
+        assert_eq!(attribute_power(f64::NAN, 10.0, 100.0), 0.0);
+    }
+
+    #[test]
+    fn shares_of_all_processes_add_up_to_the_total() {
+        let cpu = [120.0_f32, 60.0, 15.0, 5.0];
+        let total_cpu: f32 = cpu.iter().sum();
+
+        let attributed: f64 = cpu.iter().map(|&process| attribute_power(12.0, process, total_cpu)).sum();
+
+        assert!((attributed - 12.0).abs() < 1e-5);  // Podiel sa počíta v f32
+    }
+
+    #[test]
+    fn watts_come_from_the_energy_delta() {
+        assert_eq!(package_watts(1_000_000, 11_000_000, None, 2.0), Some(5.0));
+        assert_eq!(package_watts(9_000_000, 1_000_000, Some(10_000_000), 1.0), Some(2.0));  // Pretečenie
+        assert_eq!(package_watts(9_000_000, 1_000_000, None, 1.0), None);
+        assert_eq!(package_watts(0, 1_000_000, None, 0.0), None);
+    }
+}
@@ -0,0 +1,98 @@
+// process_filter.rs
+
+use crate::models::ProcessInfo;
+
+/// Zoznam ignorovaných procesov (napr. kernel vlákna, samotný monitor)
+/// Procesy sa skrývajú podľa časti názvu bez ohľadu na veľkosť písmen
+#[derive(Debug, Clone, Default)]
+pub struct ProcessFilter {
+    patterns: Vec<String>,   // Časti názvov (malými písmenami)
+    own_pid: Option<u32>,    // PID tohto monitora, ak sa má skryť
+    pub enabled: bool,       // Filter sa dá za behu vypnúť
+}
+
+impl ProcessFilter {
+    /// Vytvorenie filtra zo zadaných častí názvov
+    ///
+    /// # Argumenty
+    /// * `patterns` - Časti názvov procesov (prázdne sa ignorujú)
+    /// * `hide_self` - Skryť aj proces samotného monitora
+    pub fn new(patterns: &[String], hide_self: bool) -> Self {
+        Self {
+            patterns: patterns
+                .iter()
+                .map(|pattern| pattern.trim().to_lowercase())
+                .filter(|pattern| !pattern.is_empty())
+                .collect(),
+            own_pid: hide_self.then(std::process::id),
+            enabled: true,
+        }
+    }
+
+    /// Je niečo na skrytie? (bez vzorov sa filter neprejaví)
+    pub fn is_configured(&self) -> bool {
+        !self.patterns.is_empty() || self.own_pid.is_some()
+    }
+
+    /// Má sa proces skryť?
+    pub fn is_ignored(&self, process: &ProcessInfo) -> bool {
+        if !self.enabled {
+            return false;
+        }
+        if self.own_pid == Some(process.pid) {
+            return true;
+        }
+
+        let name = process.name.to_lowercase();
+        self.patterns.iter().any(|pattern| name.contains(pattern.as_str()))
+    }
+
+    /// Odstránenie ignorovaných procesov zo zoznamu
+    pub fn apply(&self, processes: &mut Vec<ProcessInfo>) {
+        processes.retain(|process| !self.is_ignored(process));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_process;
+
+    fn names(processes: &[ProcessInfo]) -> Vec<&str> {
+        processes.iter().map(|process| process.name.as_str()).collect()
+    }
+
+    #[test]
+    fn ignored_names_are_removed_case_insensitively() {
+        let filter = ProcessFilter::new(&["KWorker".to_string(), " ".to_string()], false);
+        let mut processes = vec![
+            sample_process(10, "kworker/0:1"),
+            sample_process(20, "firefox"),
+            sample_process(30, "kworker/u8:2"),
+        ];
+
+        filter.apply(&mut processes);
+
+        assert_eq!(names(&processes), ["firefox"]);
+    }
+
+    #[test]
+    fn own_process_can_be_hidden() {
+        let filter = ProcessFilter::new(&[], true);
+
+        assert!(filter.is_configured());
+        assert!(filter.is_ignored(&sample_process(std::process::id(), "system-monitor")));
+        assert!(!filter.is_ignored(&sample_process(1, "init")));
+    }
+
+    #[test]
+    fn disabled_filter_keeps_everything() {
+        let mut filter = ProcessFilter::new(&["firefox".to_string()], true);
+        filter.enabled = false;
+        let mut processes = vec![sample_process(20, "firefox")];
+
+        filter.apply(&mut processes);
+
+        assert_eq!(names(&processes), ["firefox"]);
+    }
+}
@@ -0,0 +1,22 @@
+// process_names.rs
+
+use sysinfo::Process;
+
+/// Názov procesu zo sysinfo s náhradou pri prázdnom názve
+///
+/// Niektoré platformy (napr. procesy bez prístupových práv alebo zombie)
+/// vrátia prázdny názov - vtedy sa použije názov spustiteľného súboru.
+/// Ak chýba aj ten, ostane prázdny a zobrazí sa ako `[pid N]`
+/// (`ProcessInfo::display_name`).
+pub fn process_name(process: &Process) -> String {
+    let name = process.name();
+    if !name.trim().is_empty() {
+        return name.to_string();
+    }
+
+    process
+        .exe()
+        .and_then(|exe| exe.file_name())
+        .map(|file| file.to_string_lossy().into_owned())
+        .unwrap_or_default()
+}
@@ -0,0 +1,61 @@
+// process_swap.rs
+
+use crate::models::ProcessInfo;
+
+/// Swap jedného procesu v bajtoch z `/proc/<pid>/status` (`VmSwap`)
+/// None mimo Linuxu, pri vláknach jadra (pole chýba) alebo ukončenom procese
+pub fn process_swap(pid: u32) -> Option<u64> {
+    read_status(pid).and_then(|content| parse_vm_swap(&content))
+}
+
+/// Doplnenie swapu procesom v zozname (`ProcessInfo::swap`)
+/// Číta jeden súbor na proces - volá sa len pre orezaný zoznam alebo pri triedení podľa swapu
+pub fn fill_swap(processes: &mut [ProcessInfo]) {
+    for process in processes {
+        process.swap = process_swap(process.pid);
+    }
+}
+
+/// Swap z obsahu `/proc/<pid>/status` (riadok `VmSwap:    1234 kB`)
+pub fn parse_vm_swap(content: &str) -> Option<u64> {
+    content.lines()
+        .find_map(|line| line.strip_prefix("VmSwap:"))
+        .and_then(|rest| rest.split_whitespace().next()?.parse::<u64>().ok())
+        .map(|kb| kb * 1024)
+}
+
+#[cfg(target_os = "linux")]
+fn read_status(pid: u32) -> Option<String> {
+    std::fs::read_to_string(format!("/proc/{}/status", pid)).ok()
+}
+
+/// Mimo Linuxu `/proc` neexistuje
+#[cfg(not(target_os = "linux"))]
+fn read_status(_pid: u32) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vm_swap_is_converted_to_bytes() {
+        let status = "Name:\tpostgres\nVmRSS:\t   20480 kB\nVmSwap:\t    1234 kB\nThreads:\t1\n";
+
+        assert_eq!(parse_vm_swap(status), Some(1234 * 1024));
+    }
+
+    #[test]
+    fn zero_swap_is_reported_as_zero() {
+        assert_eq!(parse_vm_swap("VmSwap:\t       0 kB\n"), Some(0));
+    }
+
+    #[test]
+    fn kernel_threads_and_malformed_lines_have_no_swap() {
+        // Vlákna jadra riadok `VmSwap` nemajú
+        assert_eq!(parse_vm_swap("Name:\tkthreadd\nState:\tS (sleeping)\n"), None);
+        assert_eq!(parse_vm_swap("VmSwap:\tunknown kB\n"), None);
+        assert_eq!(parse_vm_swap("VmSwap:\n"), None);
+    }
+}
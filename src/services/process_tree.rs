@@ -0,0 +1,218 @@
+// process_tree.rs
+
+use crate::models::ProcessInfo;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// Sledovaný strom procesov (koreňový proces a všetci jeho potomkovia)
+/// Používa sa v TUI pri spustení s `--watch-pid`
+#[derive(Debug, Clone)]
+pub struct ProcessTree {
+    pub root_pid: u32,                 // PID sledovaného procesu
+    pub processes: Vec<ProcessInfo>,   // Procesy stromu zoradené podľa CPU
+    pub total_cpu: f32,                // Súčet využitia CPU celého stromu (%)
+    pub total_memory: u64,             // Súčet pamäte celého stromu v bajtoch
+}
+
+impl ProcessTree {
+    /// Vytvorenie stromu zo zoznamu procesov s výpočtom súhrnných hodnôt
+    pub fn new(root_pid: u32, mut processes: Vec<ProcessInfo>) -> Self {
+        processes.sort_by(|a, b| b.cpu_usage.partial_cmp(&a.cpu_usage).unwrap_or(std::cmp::Ordering::Equal));
+
+        let total_cpu = processes.iter().map(|p| p.cpu_usage).sum();
+        let total_memory = processes.iter().map(|p| p.memory).sum();
+
+        Self {
+            root_pid,
+            processes,
+            total_cpu,
+            total_memory,
+        }
+    }
+}
+
+/// Zber PID koreňového procesu a všetkých jeho potomkov
+///
+/// # Argumenty
+/// * `root` - PID sledovaného procesu
+/// * `parents` - Mapa PID -> PID rodiča (z `sysinfo::Process::parent`)
+///
+/// # Návratová hodnota
+/// PID stromu v poradí prehľadávania do šírky (koreň je prvý).
+/// Prázdny vektor, ak koreňový proces v mape nie je (proces skončil).
+pub fn collect_descendants(root: u32, parents: &HashMap<u32, Option<u32>>) -> Vec<u32> {
+    if !parents.contains_key(&root) {
+        return Vec::new();
+    }
+
+    // Otočenie mapy: rodič -> zoznam detí
+    let mut children: HashMap<u32, Vec<u32>> = HashMap::new();
+    for (&pid, &parent) in parents {
+        if let Some(parent) = parent {
+            children.entry(parent).or_default().push(pid);
+        }
+    }
+
+    let mut result = Vec::new();
+    let mut visited = HashSet::new();   // Ochrana pred cyklami pri recyklácii PID
+    let mut queue = VecDeque::from([root]);
+
+    while let Some(pid) = queue.pop_front() {
+        if !visited.insert(pid) {
+            continue;
+        }
+        result.push(pid);
+
+        if let Some(kids) = children.get(&pid) {
+            queue.extend(kids.iter().copied());
+        }
+    }
+
+    result
+}
+
+/// Jeden riadok stromového zobrazenia procesov
+#[derive(Debug, Clone)]
+pub struct TreeRow {
+    pub depth: usize,           // Úroveň zanorenia (0 = koreň)
+    pub process: ProcessInfo,   // Údaje procesu
+    pub child_count: usize,     // Počet priamych potomkov
+    pub collapsed: bool,        // Sú potomkovia skrytí?
+}
+
+/// Zostavenie stromu procesov z plochého zoznamu s odkazmi na rodičov
+///
+/// # Argumenty
+/// * `processes` - Všetky procesy (s vyplneným `parent_pid`)
+/// * `collapsed` - PID uzlov, ktorých potomkovia sa nezobrazujú
+///
+/// # Návratová hodnota
+/// Riadky v poradí prehľadávania do hĺbky, súrodenci zoradení podľa PID.
+/// Proces, ktorého rodič v zozname nie je, sa zobrazí ako koreň.
+pub fn build_tree_rows(processes: &[ProcessInfo], collapsed: &HashSet<u32>) -> Vec<TreeRow> {
+    let known: HashSet<u32> = processes.iter().map(|p| p.pid).collect();
+
+    // Rozdelenie procesov na korene a deti podľa rodiča
+    let mut roots: Vec<&ProcessInfo> = Vec::new();
+    let mut children: HashMap<u32, Vec<&ProcessInfo>> = HashMap::new();
+    for process in processes {
+        match process.parent_pid {
+            Some(parent) if parent != process.pid && known.contains(&parent) => {
+                children.entry(parent).or_default().push(process);
+            }
+            _ => roots.push(process),
+        }
+    }
+
+    roots.sort_by_key(|p| p.pid);
+    for kids in children.values_mut() {
+        kids.sort_by_key(|p| p.pid);
+    }
+
+    let mut rows = Vec::with_capacity(processes.len());
+    let mut visited = HashSet::new();   // Ochrana pred cyklami v odkazoch na rodičov
+    let mut stack: Vec<(&ProcessInfo, usize)> = roots.into_iter().rev().map(|p| (p, 0)).collect();
+
+    while let Some((process, depth)) = stack.pop() {
+        if !visited.insert(process.pid) {
+            continue;
+        }
+
+        let kids = children.get(&process.pid).map(|k| k.as_slice()).unwrap_or(&[]);
+        let is_collapsed = collapsed.contains(&process.pid);
+
+        rows.push(TreeRow {
+            depth,
+            process: process.clone(),
+            child_count: kids.len(),
+            collapsed: is_collapsed,
+        });
+
+        // Deti sa vkladajú v opačnom poradí, aby sa spracovali vzostupne podľa PID
+        if !is_collapsed {
+            stack.extend(kids.iter().rev().map(|&kid| (kid, depth + 1)));
+        }
+    }
+
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_process;
+
+    /// Mapa PID -> rodič:  1 -> {2, 3},  2 -> {4},  5 je samostatný proces
+    fn parents() -> HashMap<u32, Option<u32>> {
+        HashMap::from([(1, None), (2, Some(1)), (3, Some(1)), (4, Some(2)), (5, None)])
+    }
+
+    #[test]
+    fn descendants_are_collected_breadth_first() {
+        let mut tree = collect_descendants(1, &parents());
+
+        assert_eq!(tree[0], 1);
+        assert_eq!(tree[3], 4);  // Vnuk až po oboch deťoch
+        tree[1..3].sort();
+        assert_eq!(tree, vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn subtree_and_leaf_roots_collect_only_their_part() {
+        assert_eq!(collect_descendants(2, &parents()), vec![2, 4]);
+        assert_eq!(collect_descendants(5, &parents()), vec![5]);
+    }
+
+    #[test]
+    fn missing_root_gives_an_empty_tree() {
+        assert!(collect_descendants(99, &parents()).is_empty());
+    }
+
+    #[test]
+    fn parent_cycle_does_not_loop_forever() {
+        // Recyklované PID môžu vytvoriť cyklus 1 -> 2 -> 1
+        let parents = HashMap::from([(1, Some(2)), (2, Some(1))]);
+
+        assert_eq!(collect_descendants(1, &parents), vec![1, 2]);
+    }
+
+    /// Proces s odkazom na rodiča
+    fn child(pid: u32, parent: Option<u32>) -> ProcessInfo {
+        ProcessInfo { parent_pid: parent, ..sample_process(pid, &format!("p{pid}")) }
+    }
+
+    /// (PID, hĺbka) riadkov stromu
+    fn shape(rows: &[TreeRow]) -> Vec<(u32, usize)> {
+        rows.iter().map(|row| (row.process.pid, row.depth)).collect()
+    }
+
+    #[test]
+    fn flat_list_is_built_into_a_depth_first_tree() {
+        // Poradie vstupu nezáleží, súrodenci sa radia podľa PID
+        let processes = [child(4, Some(2)), child(3, Some(1)), child(2, Some(1)), child(1, None), child(5, None)];
+
+        let rows = build_tree_rows(&processes, &HashSet::new());
+
+        assert_eq!(shape(&rows), vec![(1, 0), (2, 1), (4, 2), (3, 1), (5, 0)]);
+        assert_eq!(rows.iter().map(|row| row.child_count).collect::<Vec<_>>(), vec![2, 1, 0, 0, 0]);
+    }
+
+    #[test]
+    fn orphans_and_self_parents_become_roots() {
+        let processes = [child(7, Some(99)), child(8, Some(8))];
+
+        let rows = build_tree_rows(&processes, &HashSet::new());
+
+        assert_eq!(shape(&rows), vec![(7, 0), (8, 0)]);
+    }
+
+    #[test]
+    fn collapsed_node_hides_its_descendants() {
+        let processes = [child(1, None), child(2, Some(1)), child(3, Some(2))];
+
+        let rows = build_tree_rows(&processes, &HashSet::from([2]));
+
+        assert_eq!(shape(&rows), vec![(1, 0), (2, 1)]);
+        assert!(rows[1].collapsed);
+        assert_eq!(rows[1].child_count, 1);
+    }
+}
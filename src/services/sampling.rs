@@ -0,0 +1,53 @@
+// sampling.rs
+
+use sysinfo::{Pid, Process, System};
+
+/// Procesy, pre ktoré sa počítajú sieťové štatistiky (`--network-sample`)
+///
+/// Na systémoch s tisícmi procesov je výpočet pre každý PID drahý. S limitom
+/// sa najprv lacno vyberie `limit` procesov s najvyšším CPU (`select_nth_unstable_by`,
+/// bez triedenia celého zoznamu) a sieť sa počíta len pre nich - ostatné
+/// procesy sieťové hodnoty nemajú (`None`). Bez limitu sa vrátia všetky procesy.
+pub fn network_candidates(system: &System, limit: Option<usize>) -> Vec<(&Pid, &Process)> {
+    let mut candidates: Vec<(&Pid, &Process)> = system.processes().iter().collect();
+
+    if let Some(limit) = limit {
+        if candidates.len() > limit {
+            let by_cpu_desc = |a: &(&Pid, &Process), b: &(&Pid, &Process)| b.1.cpu_usage().total_cmp(&a.1.cpu_usage());
+            candidates.select_nth_unstable_by(limit.saturating_sub(1), by_cpu_desc);
+            candidates.truncate(limit);
+        }
+    }
+
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn refreshed_system() -> System {
+        let mut system = System::new();
+        system.refresh_processes();
+        system
+    }
+
+    #[test]
+    fn without_limit_every_process_is_sampled() {
+        let system = refreshed_system();
+
+        assert_eq!(network_candidates(&system, None).len(), system.processes().len());
+        assert_eq!(network_candidates(&system, Some(usize::MAX)).len(), system.processes().len());
+    }
+
+    #[test]
+    fn limit_keeps_the_busiest_processes() {
+        let system = refreshed_system();
+        let candidates = network_candidates(&system, Some(1));
+
+        assert_eq!(candidates.len(), 1);
+        let busiest = candidates[0].1.cpu_usage();
+        assert!(system.processes().values().all(|process| process.cpu_usage() <= busiest));
+        assert!(network_candidates(&system, Some(0)).is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+// syslog.rs
+
+use super::event_log::EventLevel;
+use std::fmt;
+use std::io;
+use std::sync::Mutex;
+use syslog::{Facility, Formatter3164, Logger, LoggerBackend, Severity};
+
+/// Názov aplikácie v syslog správach
+const TAG: &str = "system-monitor";
+
+/// Formát RFC 3164 s facility `daemon`, bez hostiteľa (doplní ho démon)
+/// napr. `<28>Oct 17 10:00:00 system-monitor[1234]: auto-save: Available memory is low`
+pub fn formatter() -> Formatter3164 {
+    Formatter3164 {
+        facility: Facility::LOG_DAEMON,
+        hostname: None,
+        process: TAG.to_string(),
+        pid: std::process::id(),
+    }
+}
+
+/// Závažnosť syslog správy podľa úrovne udalosti
+pub fn severity(level: EventLevel) -> Severity {
+    match level {
+        EventLevel::Info => Severity::LOG_INFO,
+        EventLevel::Warn => Severity::LOG_WARNING,
+        EventLevel::Error => Severity::LOG_ERR,
+    }
+}
+
+/// Text správy `zdroj: správa`
+/// Riadiace znaky (napr. nový riadok z chyby databázy) sa nahradia medzerou,
+/// aby jedna udalosť ostala v syslogu jedným riadkom
+pub fn message_text(source: &str, message: &str) -> String {
+    format!("{}: {}", source, message)
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect()
+}
+
+/// Odosielanie udalostí do lokálneho syslogu (`api --syslog`)
+///
+/// Chyba pri odoslaní (napr. reštart démona) sa ignoruje - udalosť ostáva
+/// v `/api/logs` aj v `--log-file`.
+pub struct SyslogSink {
+    logger: Mutex<Logger<LoggerBackend, Formatter3164>>,  // Zápis vyžaduje `&mut`
+}
+
+impl fmt::Debug for SyslogSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SyslogSink").finish_non_exhaustive()
+    }
+}
+
+impl SyslogSink {
+    /// Pripojenie k socketu syslog démona (`/dev/log`, `/var/run/syslog`, ...)
+    /// Mimo Unixu vráti chybu
+    pub fn connect() -> io::Result<Self> {
+        let logger = syslog::unix(formatter()).map_err(|e| io::Error::other(e.to_string()))?;
+        Ok(Self { logger: Mutex::new(logger) })
+    }
+
+    /// Odoslanie jednej udalosti
+    pub fn send(&self, level: EventLevel, source: &str, message: &str) {
+        let text = message_text(source, message);
+        if let Ok(mut logger) = self.logger.lock() {
+            let _ = match severity(level) {
+                Severity::LOG_ERR => logger.err(text),
+                Severity::LOG_WARNING => logger.warning(text),
+                _ => logger.info(text),
+            };
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use syslog::LogFormat;
+
+    fn formatted(level: EventLevel, source: &str, message: &str) -> String {
+        let mut buffer = Vec::new();
+        formatter().format(&mut buffer, severity(level), message_text(source, message)).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[test]
+    fn priority_combines_daemon_facility_and_severity() {
+        // daemon (3) * 8 + závažnosť
+        assert!(formatted(EventLevel::Warn, "auto-save", "low").starts_with("<28>"));
+        assert!(formatted(EventLevel::Error, "auto-save", "failed").starts_with("<27>"));
+        assert!(formatted(EventLevel::Info, "auto-save", "saved").starts_with("<30>"));
+    }
+
+    #[test]
+    fn message_carries_tag_pid_and_source() {
+        let line = formatted(EventLevel::Warn, "auto-save", "Available memory is low");
+
+        let expected = format!("system-monitor[{}]: auto-save: Available memory is low", std::process::id());
+        assert!(line.ends_with(&expected), "{line}");
+    }
+
+    #[test]
+    fn control_characters_are_replaced() {
+        assert_eq!(message_text("db", "connection lost\nretrying\tsoon"), "db: connection lost retrying soon");
+        assert!(!formatted(EventLevel::Error, "db", "a\r\nb").contains(['\r', '\n']));
+    }
+}
@@ -0,0 +1,137 @@
+// system_processes.rs
+
+use crate::models::ProcessInfo;
+
+/// PID súhrnného riadku - skutočný proces ho nemá (Linux max. 2^22, Windows násobky 4)
+pub const SYSTEM_GROUP_PID: u32 = u32::MAX;
+
+/// Názov súhrnného riadku v zozname procesov
+pub const SYSTEM_GROUP_NAME: &str = "[system processes]";
+
+/// Predvolená hranica PID - procesy pod ňou sa považujú za systémové
+pub const DEFAULT_SYSTEM_PID_BELOW: u32 = 100;
+
+/// Časti názvov systémových procesov (malými písmenami)
+/// Vlákna jadra na Linuxe môžu mať aj vysoké PID, preto nestačí hranica PID
+const SYSTEM_PATTERNS: &[&str] = &[
+    // Linux - vlákna jadra a základné démony
+    "kthreadd", "kworker", "ksoftirqd", "kswapd", "migration/", "rcu_", "watchdog/", "cpuhp/", "irq/", "systemd",
+    // macOS
+    "kernel_task", "launchd",
+    // Windows
+    "svchost", "csrss", "smss", "wininit", "lsass", "services.exe", "registry", "system idle",
+];
+
+/// Zoskupenie systémových procesov do jedného riadku (`--collapse-system`, kláves S)
+#[derive(Debug, Clone)]
+pub struct SystemProcessGroup {
+    pid_below: u32,      // Procesy s nižším PID sú systémové
+    pub enabled: bool,   // Zoskupovanie sa dá za behu vypnúť
+}
+
+impl SystemProcessGroup {
+    /// Vytvorenie zoskupenia s hranicou PID
+    pub fn new(pid_below: u32, enabled: bool) -> Self {
+        Self { pid_below, enabled }
+    }
+
+    /// Je proces systémový? (nízke PID alebo známy názov)
+    pub fn is_system(&self, process: &ProcessInfo) -> bool {
+        if process.pid < self.pid_below {
+            return true;
+        }
+
+        let name = process.name.to_lowercase();
+        SYSTEM_PATTERNS.iter().any(|pattern| name.contains(pattern))
+    }
+
+    /// Nahradenie systémových procesov v zozname jedným súhrnným riadkom
+    ///
+    /// Súhrnný riadok (`SYSTEM_GROUP_PID`) má súčet CPU, pamäte a diskových
+    /// a sieťových hodnôt (None, ak ich nemá žiadny proces). Vráti odobraté
+    /// procesy zoradené podľa CPU (pre rozbalenie); pri vypnutom zoskupení
+    /// sa zoznam nemení.
+    pub fn collapse(&self, processes: &mut Vec<ProcessInfo>) -> Vec<ProcessInfo> {
+        if !self.enabled {
+            return Vec::new();
+        }
+
+        let (mut members, rest): (Vec<ProcessInfo>, Vec<ProcessInfo>) =
+            processes.drain(..).partition(|process| self.is_system(process));
+        *processes = rest;
+
+        if !members.is_empty() {
+            processes.push(aggregate(&members));
+        }
+
+        members.sort_by(|a, b| b.cpu_usage.total_cmp(&a.cpu_usage));
+        members
+    }
+}
+
+impl Default for SystemProcessGroup {
+    fn default() -> Self {
+        Self::new(DEFAULT_SYSTEM_PID_BELOW, false)
+    }
+}
+
+/// Súhrnný riadok so súčtom hodnôt všetkých systémových procesov
+fn aggregate(members: &[ProcessInfo]) -> ProcessInfo {
+    let sum = |value: fn(&ProcessInfo) -> Option<u64>| members.iter().filter_map(value).reduce(|a, b| a + b);
+
+    ProcessInfo {
+        pid: SYSTEM_GROUP_PID,
+        parent_pid: None,
+        name: SYSTEM_GROUP_NAME.to_string(),
+        memory: members.iter().map(|process| process.memory).sum(),
+        virtual_memory: members.iter().map(|process| process.virtual_memory).sum(),
+        cpu_usage: members.iter().map(|process| process.cpu_usage).sum(),
+        network_sent: sum(|process| process.network_sent),
+        network_recv: sum(|process| process.network_recv),
+        disk_read: sum(|process| process.disk_read),
+        disk_write: sum(|process| process.disk_write),
+        swap: sum(|process| process.swap),
+        cmd: Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::sample_process;
+
+    #[test]
+    fn system_processes_collapse_into_one_row() {
+        let group = SystemProcessGroup::new(100, true);
+        let mut processes = vec![
+            ProcessInfo { cpu_usage: 1.0, memory: 100, disk_read: Some(10), ..sample_process(1, "init") },
+            ProcessInfo { cpu_usage: 3.0, ..sample_process(4000, "kworker/0:1") },  // Vlákno jadra s vysokým PID
+            ProcessInfo { cpu_usage: 20.0, memory: 5000, disk_read: Some(7), ..sample_process(5000, "firefox") },
+            ProcessInfo { cpu_usage: 2.0, memory: 300, disk_read: Some(5), ..sample_process(50, "sshd") },
+        ];
+
+        let members = group.collapse(&mut processes);
+
+        assert_eq!(processes.len(), 2);
+        assert_eq!(processes[0].name, "firefox");
+        let row = &processes[1];
+        assert_eq!((row.pid, row.name.as_str()), (SYSTEM_GROUP_PID, SYSTEM_GROUP_NAME));
+        assert_eq!(row.cpu_usage, 6.0);
+        assert_eq!(row.memory, 400);
+        assert_eq!(row.disk_read, Some(15));
+        assert_eq!(row.network_sent, None);  // Žiadny člen hodnotu nemá
+        let member_pids: Vec<u32> = members.iter().map(|member| member.pid).collect();
+        assert_eq!(member_pids, [4000, 50, 1]);  // Zoradené podľa CPU pre rozbalenie
+    }
+
+    #[test]
+    fn disabled_group_or_no_system_processes_leave_the_list_unchanged() {
+        let mut processes = vec![sample_process(1, "init"), sample_process(5000, "firefox")];
+        assert!(SystemProcessGroup::new(100, false).collapse(&mut processes).is_empty());
+        assert_eq!(processes.len(), 2);
+
+        let mut user_only = vec![sample_process(5000, "firefox")];
+        assert!(SystemProcessGroup::new(100, true).collapse(&mut user_only).is_empty());
+        assert_eq!(user_only[0].pid, 5000);  // Bez prázdneho súhrnného riadku
+    }
+}
@@ -1,18 +1,34 @@
 // temperatures.rs
 
-use crate::models::TemperatureInfo;
+use crate::models::{TemperatureInfo, TemperatureMode, SensorReading};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
 use wmi::{COMLibrary, WMIConnection};
 use std::collections::HashMap;
 
+/// Riadok výsledku WMI dotazu (názov stĺpca -> hodnota)
+type WmiRow = HashMap<String, serde_json::Value>;
+
+/// Prečítanie teploty v °C z riadku WMI triedy (None = trieda teplotu neuvádza)
+type WmiTemperatureReader = fn(&WmiRow) -> Option<f32>;
+
 /// Monitor teplôt systémových komponentov
 /// Používa WMI (Windows Management Instrumentation) pre čítanie teplôt
 pub struct TemperatureMonitor {
     wmi_con: Option<WMIConnection>,  // WMI spojenie (len pre Windows)
+    mode: TemperatureMode,           // Režim zberu (vypnuté / odhad / len senzory)
 }
 
 impl TemperatureMonitor {
     /// Konštruktor pre vytvorenie monitora teplôt
-    pub fn new() -> Self {
+    /// V režime `Off` sa WMI spojenie vôbec nevytvára
+    pub fn new(mode: TemperatureMode) -> Self {
+        if mode == TemperatureMode::Off {
+            println!("🌡️  Temperature monitoring is disabled");
+            return TemperatureMonitor { wmi_con: None, mode };
+        }
+        
         let wmi_con = match Self::create_wmi_connection() {
             Ok(con) => {
                 println!("✅ WMI connection established for temperature monitoring");
@@ -26,7 +42,12 @@ impl TemperatureMonitor {
             }
         };
         
-        TemperatureMonitor { wmi_con }
+        TemperatureMonitor { wmi_con, mode }
+    }
+    
+    /// Nastavený režim zberu teplôt
+    pub fn mode(&self) -> TemperatureMode {
+        self.mode
     }
     
     /// Vytvorenie WMI spojenia (len Windows)
@@ -54,46 +75,41 @@ impl TemperatureMonitor {
     fn get_cpu_temperature(&self, wmi_con: &WMIConnection) -> Option<f32> {
         // Prvý pokus: MSAcpi_ThermalZoneTemperature
         let query = "SELECT * FROM MSAcpi_ThermalZoneTemperature";
-        if let Ok(results) = wmi_con.raw_query::<HashMap<String, serde_json::Value>>(query) {
-            for result in results {
-                if let Some(temp_raw) = result.get("CurrentTemperature") {
-                    if let serde_json::Value::Number(num) = temp_raw {
-                        if let Some(temp_kelvin) = num.as_u64() {
-                            // Konverzia z Kelvinov na Celsius
-                            let temp_celsius = (temp_kelvin as f32 / 10.0) - 273.15;
-                            if temp_celsius > 0.0 && temp_celsius < 150.0 {
-                                return Some(temp_celsius);
-                            }
-                        }
-                    }
-                }
+        if let Ok(results) = wmi_con.raw_query::<WmiRow>(query) {
+            if let Some(temp) = results.iter().find_map(Self::thermal_zone_celsius) {
+                return Some(temp);
             }
         }
         
         // Druhý pokus: Win32_TemperatureProbe
         let query = "SELECT * FROM Win32_TemperatureProbe";
-        if let Ok(results) = wmi_con.raw_query::<HashMap<String, serde_json::Value>>(query) {
-            for result in results {
-                if let Some(temp_raw) = result.get("CurrentReading") {
-                    if let serde_json::Value::Number(num) = temp_raw {
-                        if let Some(temp) = num.as_f64() {
-                            let temp_celsius = temp as f32 / 10.0;
-                            if temp_celsius > 0.0 && temp_celsius < 150.0 {
-                                return Some(temp_celsius);
-                            }
-                        }
-                    }
-                }
+        if let Ok(results) = wmi_con.raw_query::<WmiRow>(query) {
+            if let Some(temp) = results.iter().find_map(Self::probe_celsius) {
+                return Some(temp);
             }
         }
         
         None
     }
     
+    /// Teplota z riadku MSAcpi_ThermalZoneTemperature (desatiny Kelvina)
+    fn thermal_zone_celsius(result: &WmiRow) -> Option<f32> {
+        let temp_kelvin = result.get("CurrentTemperature")?.as_u64()?;
+        // Konverzia z Kelvinov na Celsius
+        let temp_celsius = (temp_kelvin as f32 / 10.0) - 273.15;
+        (temp_celsius > 0.0 && temp_celsius < 150.0).then_some(temp_celsius)
+    }
+    
+    /// Teplota z riadku Win32_TemperatureProbe (desatiny °C)
+    fn probe_celsius(result: &WmiRow) -> Option<f32> {
+        let temp_celsius = result.get("CurrentReading")?.as_f64()? as f32 / 10.0;
+        (temp_celsius > 0.0 && temp_celsius < 150.0).then_some(temp_celsius)
+    }
+    
     /// Získanie teploty GPU (zjednodušené)
     fn get_gpu_temperature(&self, wmi_con: &WMIConnection) -> Option<f32> {
         let query = "SELECT * FROM Win32_VideoController";
-        if let Ok(results) = wmi_con.raw_query::<HashMap<String, serde_json::Value>>(query) {
+        if let Ok(results) = wmi_con.raw_query::<WmiRow>(query) {
             for result in results {
                 if let Some(_adapter_ram) = result.get("AdapterRAM") { 
                     // Ak GPU existuje, vráť odhadovanú teplotu
@@ -108,28 +124,16 @@ impl TemperatureMonitor {
     /// Získanie teploty základnej dosky
     fn get_motherboard_temperature(&self, wmi_con: &WMIConnection) -> Option<f32> {
         let query = "SELECT * FROM Win32_TemperatureProbe WHERE Name LIKE '%Motherboard%' OR Name LIKE '%System%'";
-        if let Ok(results) = wmi_con.raw_query::<HashMap<String, serde_json::Value>>(query) {
-            for result in results {
-                if let Some(temp_raw) = result.get("CurrentReading") {
-                    if let serde_json::Value::Number(num) = temp_raw {
-                        if let Some(temp) = num.as_f64() {
-                            let temp_celsius = temp as f32 / 10.0;
-                            if temp_celsius > 0.0 && temp_celsius < 150.0 {
-                                return Some(temp_celsius);
-                            }
-                        }
-                    }
-                }
-            }
-        }
-        
-        None
+        wmi_con.raw_query::<WmiRow>(query)
+            .ok()?
+            .iter()
+            .find_map(Self::probe_celsius)
     }
     
     /// Získanie teploty disku
     fn get_disk_temperature(&self, wmi_con: &WMIConnection) -> Option<f32> {
         let query = "SELECT * FROM MSStorageDriver_ATAPISmartData";
-        if let Ok(results) = wmi_con.raw_query::<HashMap<String, serde_json::Value>>(query) {
+        if let Ok(results) = wmi_con.raw_query::<WmiRow>(query) {
             for result in results {
                 if let Some(vendor_data) = result.get("VendorSpecific") {
                     if let serde_json::Value::Array(bytes) = vendor_data {
@@ -173,4 +177,183 @@ impl TemperatureMonitor {
         // Ak žiadne reálne dáta, vráť odhady
         self.get_estimated_temperatures(cpu_usage)
     }
-}
\ No newline at end of file
+    
+    /// Získanie teplôt podľa nastaveného režimu
+    /// * `Off` - všetky hodnoty None
+    /// * `Real` - len reálne senzory
+    /// * `Estimate` - reálne senzory s fallbackom na odhady
+    pub fn read(&self, cpu_usage: f32) -> TemperatureInfo {
+        match self.mode {
+            TemperatureMode::Off => TemperatureInfo::new(),
+            TemperatureMode::Real => self.get_temperatures(),
+            TemperatureMode::Estimate => self.get_temperatures_with_fallback(cpu_usage),
+        }
+    }
+    
+    /// Zoznam všetkých nájdených zdrojov teplôt (pre príkaz `sensors`)
+    /// Na platforme bez senzorov vráti prázdny zoznam
+    pub fn list_sensors(&self) -> Vec<SensorReading> {
+        let mut sensors = Vec::new();
+        if let Some(wmi_con) = &self.wmi_con {
+            sensors.extend(Self::list_wmi_sensors(wmi_con));
+        }
+        sensors.extend(list_hwmon_sensors(Path::new(HWMON_DIR)));
+        sensors.extend(list_lm_sensors());
+        sensors
+    }
+    
+    /// WMI triedy, z ktorých sa čítajú teploty
+    fn list_wmi_sensors(wmi_con: &WMIConnection) -> Vec<SensorReading> {
+        // (trieda, funkcia pre prečítanie teploty z riadku)
+        let classes: [(&str, WmiTemperatureReader); 4] = [
+            ("MSAcpi_ThermalZoneTemperature", Self::thermal_zone_celsius),
+            ("Win32_TemperatureProbe", Self::probe_celsius),
+            ("Win32_VideoController", |_| None),          // Len detekcia GPU, teplotu neuvádza
+            ("MSStorageDriver_ATAPISmartData", |_| None), // SMART dáta bez dekódovania
+        ];
+        
+        classes
+            .iter()
+            .flat_map(|&(class, read)| {
+                let query = format!("SELECT * FROM {}", class);
+                let results = wmi_con
+                    .raw_query::<WmiRow>(&query)
+                    .unwrap_or_default();
+                results
+                    .iter()
+                    .enumerate()
+                    .map(|(i, result)| SensorReading {
+                        source: "WMI".to_string(),
+                        name: result
+                            .get("InstanceName")
+                            .or_else(|| result.get("Name"))
+                            .and_then(|v| v.as_str())
+                            .map(|s| s.to_string())
+                            .unwrap_or_else(|| format!("{} #{}", class, i + 1)),
+                        location: class.to_string(),
+                        celsius: read(result),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+}
+
+/// Adresár s hwmon senzormi (Linux)
+const HWMON_DIR: &str = "/sys/class/hwmon";
+
+/// Senzory z `/sys/class/hwmon/hwmon*/temp*_input` (hodnoty v tisícinách °C)
+fn list_hwmon_sensors(hwmon_dir: &Path) -> Vec<SensorReading> {
+    let Ok(chips) = fs::read_dir(hwmon_dir) else {
+        return Vec::new();  // Nie je Linux alebo hwmon chýba
+    };
+    
+    let mut sensors = Vec::new();
+    for chip in chips.filter_map(|entry| entry.ok()) {
+        let chip_path = chip.path();
+        let chip_name = read_trimmed(&chip_path.join("name")).unwrap_or_else(|| "unknown".to_string());
+        
+        let Ok(files) = fs::read_dir(&chip_path) else { continue };
+        let mut inputs: Vec<String> = files
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .filter(|file| file.starts_with("temp") && file.ends_with("_input"))
+            .collect();
+        inputs.sort();
+        
+        for input in inputs {
+            let prefix = input.trim_end_matches("_input");
+            let label = read_trimmed(&chip_path.join(format!("{}_label", prefix)))
+                .unwrap_or_else(|| prefix.to_string());
+            let celsius = read_trimmed(&chip_path.join(&input))
+                .and_then(|value| value.parse::<f32>().ok())
+                .map(|millidegrees| millidegrees / 1000.0);
+            
+            sensors.push(SensorReading {
+                source: "hwmon".to_string(),
+                name: format!("{} / {}", chip_name, label),
+                location: chip_path.join(&input).display().to_string(),
+                celsius,
+            });
+        }
+    }
+    sensors
+}
+
+/// Senzory z `sensors -j` (lm-sensors), ak je nástroj nainštalovaný
+fn list_lm_sensors() -> Vec<SensorReading> {
+    let Some(output) = Command::new("sensors").arg("-j").output().ok() else {
+        return Vec::new();
+    };
+    let Ok(json) = serde_json::from_slice::<serde_json::Value>(&output.stdout) else {
+        return Vec::new();
+    };
+    let Some(chips) = json.as_object() else {
+        return Vec::new();
+    };
+    
+    // Štruktúra: { čip: { "Adapter": "...", funkcia: { "temp1_input": 45.0, ... } } }
+    let mut sensors = Vec::new();
+    for (chip, features) in chips {
+        let Some(features) = features.as_object() else { continue };
+        for (feature, values) in features {
+            let Some(values) = values.as_object() else { continue };  // "Adapter" je reťazec
+            for (key, value) in values {
+                if key.starts_with("temp") && key.ends_with("_input") {
+                    sensors.push(SensorReading {
+                        source: "lm-sensors".to_string(),
+                        name: feature.clone(),
+                        location: chip.clone(),
+                        celsius: value.as_f64().map(|v| v as f32),
+                    });
+                }
+            }
+        }
+    }
+    sensors
+}
+
+/// Obsah súboru bez bielych znakov (None pri chybe čítania)
+fn read_trimmed(path: &Path) -> Option<String> {
+    fs::read_to_string(path).ok().map(|content| content.trim().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_hwmon_directory_gives_no_sensors() {
+        assert!(list_hwmon_sensors(Path::new("/nonexistent/hwmon")).is_empty());
+    }
+
+    #[test]
+    fn hwmon_inputs_are_listed_with_labels_and_degrees() {
+        let dir = std::env::temp_dir().join(format!("system-monitor-test-{}-hwmon", std::process::id()));
+        let chip = dir.join("hwmon0");
+        fs::create_dir_all(&chip).unwrap();
+        fs::write(chip.join("name"), "coretemp\n").unwrap();
+        fs::write(chip.join("temp1_input"), "45000\n").unwrap();
+        fs::write(chip.join("temp1_label"), "Package id 0\n").unwrap();
+        fs::write(chip.join("temp2_input"), "garbage\n").unwrap();
+
+        let sensors = list_hwmon_sensors(&dir);
+        let _ = fs::remove_dir_all(&dir);
+
+        assert_eq!(sensors.len(), 2);
+        assert_eq!(sensors[0].source, "hwmon");
+        assert_eq!(sensors[0].name, "coretemp / Package id 0");
+        assert_eq!(sensors[0].celsius, Some(45.0));
+        assert_eq!(sensors[1].name, "coretemp / temp2");
+        assert_eq!(sensors[1].celsius, None);  // Nečitateľná hodnota
+    }
+
+    #[test]
+    fn listing_sensors_never_panics() {
+        // Na strojoch bez senzorov je zoznam prázdny, inak má každý zdroj vyplnený
+        for sensor in TemperatureMonitor::new(TemperatureMode::Real).list_sensors() {
+            assert!(!sensor.source.is_empty());
+            assert!(!sensor.location.is_empty());
+        }
+    }
+}
@@ -0,0 +1,146 @@
+// test_support.rs
+
+//! Spoločné pomôcky pre testy
+//!
+//! Databázové testy potrebujú PostgreSQL z `DATABASE_URL`. Bez neho sa
+//! preskočia (`test_db` vráti `None` a test to zapíše na stderr), takže
+//! `cargo test` beží aj bez databázy.
+
+use crate::cli::app::{DbState, TuiApp};
+use crate::config::TuiArgs;
+use crate::db::{create_pool, init_table_prefix};
+use crate::db::table::{metrics_table, process_metrics_table};
+use crate::models::{ProcessInfo, SystemMetrics, TemperatureMode};
+use crate::services::monitor::SystemMonitor;
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+use std::sync::Arc;
+use tokio::sync::{Mutex, MutexGuard};
+
+/// Prefix testovacích tabuliek - testy nikdy nepíšu do produkčných tabuliek
+pub const TEST_TABLE_PREFIX: &str = "sm_test_";
+
+/// Databázové testy zdieľajú tabuľky, preto bežia po jednom
+static DB_LOCK: Mutex<()> = Mutex::const_new(());
+
+/// Pripojenie k testovacej databáze s prázdnymi tabuľkami
+/// Zámok drží ostatné databázové testy, kým sa hodnota nezahodí
+pub struct TestDb {
+    pub pool: PgPool,
+    _lock: MutexGuard<'static, ()>,
+}
+
+/// Testovacia databáza alebo `None`, ak `DATABASE_URL` nie je nastavené
+///
+/// Tabuľky sa vytvoria cez `create_pool` (rovnaká inicializácia ako v aplikácii)
+/// s prefixom `TEST_TABLE_PREFIX` a pred každým testom sa vyprázdnia.
+/// Test bez databázy skončí hneď: `let Some(db) = test_db().await else { return };`
+pub async fn test_db() -> Option<TestDb> {
+    if std::env::var("DATABASE_URL").is_err() {
+        // Vlákno testu nesie meno testu (výpis je vidieť s `--nocapture`)
+        let test = std::thread::current().name().unwrap_or("database test").to_string();
+        eprintln!("skipped {}: DATABASE_URL is not set", test);
+        return None;
+    }
+
+    let lock = DB_LOCK.lock().await;
+    init_table_prefix(Some(TEST_TABLE_PREFIX)).expect("test table prefix is valid");
+    let pool = create_pool().await.expect("test database is reachable");
+
+    sqlx::query(&format!("TRUNCATE {}, {}", metrics_table(), process_metrics_table()))
+        .execute(&pool)
+        .await
+        .expect("test tables can be truncated");
+
+    Some(TestDb { pool, _lock: lock })
+}
+
+/// Dočasne zmenené `DATABASE_URL` (napr. neplatné pre test chybovej cesty)
+/// Drží zámok databázových testov, pôvodná hodnota sa obnoví pri zahodení
+pub struct DatabaseUrlOverride {
+    previous: Option<String>,
+    _lock: MutexGuard<'static, ()>,
+}
+
+/// Nastavenie `DATABASE_URL` na `url`, kým sa vrátená hodnota nezahodí
+pub async fn override_database_url(url: &str) -> DatabaseUrlOverride {
+    let lock = DB_LOCK.lock().await;
+    let previous = std::env::var("DATABASE_URL").ok();
+    std::env::set_var("DATABASE_URL", url);
+    DatabaseUrlOverride { previous, _lock: lock }
+}
+
+impl Drop for DatabaseUrlOverride {
+    fn drop(&mut self) {
+        match &self.previous {
+            Some(url) => std::env::set_var("DATABASE_URL", url),
+            None => std::env::remove_var("DATABASE_URL"),
+        }
+    }
+}
+
+/// Vzorka metrík so všetkými voliteľnými poľami vyplnenými
+/// Čas je zaokrúhlený na mikrosekundy (presnosť `TIMESTAMPTZ`)
+pub fn sample_metrics(timestamp: DateTime<Utc>, cpu_usage: f64) -> SystemMetrics {
+    let timestamp = DateTime::from_timestamp_micros(timestamp.timestamp_micros())
+        .expect("timestamp is in range");
+
+    SystemMetrics {
+        id: None,
+        timestamp,
+        cpu_usage,
+        memory_total: 16 * 1024 * 1024 * 1024,
+        memory_used: 6 * 1024 * 1024 * 1024,
+        memory_available: 10 * 1024 * 1024 * 1024,
+        swap_total: 2 * 1024 * 1024 * 1024,
+        swap_used: 256 * 1024 * 1024,
+        disk_total: 512 * 1024 * 1024 * 1024,
+        disk_used: 200 * 1024 * 1024 * 1024,
+        disk_available: 312 * 1024 * 1024 * 1024,
+        gpu_name: Some("Test GPU".to_string()),
+        gpu_usage: Some(12.5),
+        gpu_memory_total: Some(8 * 1024 * 1024 * 1024),
+        gpu_memory_used: Some(1024 * 1024 * 1024),
+        gpu_temperature: Some(55.0),
+        network_sent_kbps: Some(120.0),
+        network_recv_kbps: Some(840.5),
+        process_count: 321,
+        system_uptime: 86_400,
+        cpu_temperature: Some(48.0),
+        motherboard_temperature: Some(36.0),
+        disk_temperature: Some(40.0),
+        max_temperature: Some(55.0),
+        open_file_descriptors: Some(4096),
+        entropy_available: Some(256),
+        package_power_watts: Some(17.25),
+        battery_percent: Some(80.0),
+        battery_charging: Some(false),
+    }
+}
+
+/// Proces s daným PID a názvom, ostatné hodnoty nulové alebo prázdne
+/// Testy si potrebné polia nastavia cez `ProcessInfo { cpu_usage: .., ..sample_process(..) }`
+pub fn sample_process(pid: u32, name: &str) -> ProcessInfo {
+    ProcessInfo {
+        pid,
+        parent_pid: None,
+        name: name.to_string(),
+        memory: 0,
+        virtual_memory: 0,
+        cpu_usage: 0.0,
+        network_sent: None,
+        network_recv: None,
+        disk_read: None,
+        disk_write: None,
+        swap: None,
+        cmd: Vec::new(),
+    }
+}
+
+/// TUI aplikácia bez uloženého stavu, databázy a teplotných senzorov
+/// Dáta sa nezbierajú - testy si potrebné polia nastavia samy
+pub fn test_app(config: TuiArgs) -> TuiApp {
+    let config = TuiArgs { no_save_state: true, temperatures: TemperatureMode::Off, ..config };
+    let monitor = SystemMonitor::new(TemperatureMode::Off);
+    TuiApp::new(Arc::new(std::sync::Mutex::new(monitor)), config, DbState::new(false))
+}
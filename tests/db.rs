@@ -0,0 +1,223 @@
+// db.rs
+
+//! Testy databázovej vrstvy proti skutočnému PostgreSQL z `DATABASE_URL`
+//! Bez `DATABASE_URL` sa preskočia (pozri `test_support::test_db`)
+
+use chrono::{Duration, Utc};
+use system_monitor::db::*;
+use system_monitor::db::{connection, table};
+use system_monitor::models::SystemMetrics;
+use system_monitor::test_support::{sample_metrics, test_db};
+
+/// Porovnanie celých záznamov cez JSON (`SystemMetrics` nemá `PartialEq`)
+fn assert_same_metrics(actual: &SystemMetrics, expected: &SystemMetrics) {
+    assert_eq!(
+        serde_json::to_value(actual).unwrap(),
+        serde_json::to_value(expected).unwrap(),
+    );
+}
+
+#[tokio::test]
+async fn create_pool_initializes_empty_tables() {
+    let Some(db) = test_db().await else { return };
+
+    assert_eq!(count_metrics(&db.pool).await.unwrap(), 0);
+    assert!(get_current_metrics(&db.pool).await.unwrap().is_none());
+}
+
+#[tokio::test]
+async fn save_metrics_round_trips_every_column() {
+    let Some(db) = test_db().await else { return };
+    let mut expected = sample_metrics(Utc::now(), 42.5);
+
+    let id = save_metrics(&db.pool, &expected).await.unwrap();
+    expected.id = Some(id);
+
+    let current = get_current_metrics(&db.pool).await.unwrap().expect("saved row is returned");
+    assert_same_metrics(&current, &expected);
+    assert_eq!(count_metrics(&db.pool).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn save_metrics_keeps_missing_optional_values() {
+    let Some(db) = test_db().await else { return };
+    let mut expected = SystemMetrics {
+        gpu_name: None,
+        gpu_usage: None,
+        gpu_memory_total: None,
+        gpu_memory_used: None,
+        gpu_temperature: None,
+        network_sent_kbps: None,
+        network_recv_kbps: None,
+        cpu_temperature: None,
+        motherboard_temperature: None,
+        disk_temperature: None,
+        max_temperature: None,
+        open_file_descriptors: None,
+        entropy_available: None,
+        package_power_watts: None,
+        battery_percent: None,
+        battery_charging: None,
+        ..sample_metrics(Utc::now(), 5.0)
+    };
+
+    expected.id = Some(save_metrics(&db.pool, &expected).await.unwrap());
+
+    let current = get_current_metrics(&db.pool).await.unwrap().unwrap();
+    assert_same_metrics(&current, &expected);
+}
+
+#[tokio::test]
+async fn get_current_metrics_returns_newest_sample() {
+    let Some(db) = test_db().await else { return };
+    let now = Utc::now();
+
+    save_metrics(&db.pool, &sample_metrics(now, 10.0)).await.unwrap();
+    save_metrics(&db.pool, &sample_metrics(now - Duration::minutes(5), 20.0)).await.unwrap();
+
+    let current = get_current_metrics(&db.pool).await.unwrap().unwrap();
+    assert_eq!(current.cpu_usage, 10.0);
+}
+
+#[tokio::test]
+async fn get_latest_metrics_limits_to_newest_in_requested_order() {
+    let Some(db) = test_db().await else { return };
+    let now = Utc::now();
+    for minutes in 0..4 {
+        save_metrics(&db.pool, &sample_metrics(now - Duration::minutes(minutes), minutes as f64)).await.unwrap();
+    }
+
+    let desc: Vec<f64> = get_latest_metrics(&db.pool, 3, SortOrder::Desc).await.unwrap()
+        .iter().map(|m| m.cpu_usage).collect();
+    assert_eq!(desc, vec![0.0, 1.0, 2.0]);
+
+    // Rovnaké tri najnovšie záznamy, len chronologicky
+    let asc: Vec<f64> = get_latest_metrics(&db.pool, 3, SortOrder::Asc).await.unwrap()
+        .iter().map(|m| m.cpu_usage).collect();
+    assert_eq!(asc, vec![2.0, 1.0, 0.0]);
+}
+
+#[tokio::test]
+async fn get_metrics_since_skips_older_samples() {
+    let Some(db) = test_db().await else { return };
+    let now = Utc::now();
+    save_metrics(&db.pool, &sample_metrics(now - Duration::minutes(30), 30.0)).await.unwrap();
+    save_metrics(&db.pool, &sample_metrics(now - Duration::hours(3), 90.0)).await.unwrap();
+
+    let recent = get_metrics_since(&db.pool, 1).await.unwrap();
+    assert_eq!(recent.len(), 1);
+    assert_eq!(recent[0].cpu_usage, 30.0);
+    assert_eq!(get_metrics_since(&db.pool, 4).await.unwrap().len(), 2);
+}
+
+#[tokio::test]
+async fn get_average_cpu_uses_only_the_window() {
+    let Some(db) = test_db().await else { return };
+    let now = Utc::now();
+
+    // Bez dát je priemer 0
+    assert_eq!(get_average_cpu(&db.pool, 1).await.unwrap(), 0.0);
+
+    save_metrics(&db.pool, &sample_metrics(now - Duration::minutes(10), 20.0)).await.unwrap();
+    save_metrics(&db.pool, &sample_metrics(now - Duration::minutes(20), 40.0)).await.unwrap();
+    save_metrics(&db.pool, &sample_metrics(now - Duration::hours(5), 100.0)).await.unwrap();
+
+    assert_eq!(get_average_cpu(&db.pool, 1).await.unwrap(), 30.0);
+}
+
+#[tokio::test]
+async fn cleanup_old_metrics_deletes_only_expired_rows() {
+    let Some(db) = test_db().await else { return };
+    let now = Utc::now();
+    save_metrics(&db.pool, &sample_metrics(now, 1.0)).await.unwrap();
+    save_metrics(&db.pool, &sample_metrics(now - Duration::days(10), 2.0)).await.unwrap();
+    save_metrics(&db.pool, &sample_metrics(now - Duration::days(40), 3.0)).await.unwrap();
+
+    assert_eq!(cleanup_old_metrics(&db.pool, 30).await.unwrap(), 1);
+    assert_eq!(count_metrics(&db.pool).await.unwrap(), 2);
+    assert_eq!(cleanup_old_metrics(&db.pool, 7).await.unwrap(), 1);
+    assert_eq!(count_metrics(&db.pool).await.unwrap(), 1);
+}
+
+#[tokio::test]
+async fn create_pool_restores_missing_temperature_columns() {
+    let Some(db) = test_db().await else { return };
+    let temperatures = ["cpu_temperature", "motherboard_temperature", "disk_temperature", "max_temperature"];
+
+    // Tabuľka v tvare staršej verzie bez teplôt
+    for column in temperatures {
+        sqlx::query(&format!("ALTER TABLE {} DROP COLUMN {}", table::metrics_table(), column))
+            .execute(&db.pool)
+            .await
+            .unwrap();
+    }
+    assert_eq!(connection::missing_metrics_columns(&db.pool).await.unwrap(), temperatures);
+
+    let pool = create_pool().await.unwrap();
+    assert!(connection::missing_metrics_columns(&pool).await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn dedupe_keeps_one_row_for_near_identical_samples() {
+    use system_monitor::modes::saver::{save_sample, SaveOutcome};
+
+    let Some(db) = test_db().await else { return };
+    let now = Utc::now();
+    let mut last_saved = None;
+
+    let first = save_sample(&db.pool, &mut last_saved, sample_metrics(now, 10.0), Some(1.0)).await.unwrap();
+    let SaveOutcome::Inserted(id) = first else { panic!("first sample must be inserted, got {:?}", first) };
+
+    // CPU sa líši o 0.5 bodu - v tolerancii, nový riadok nevznikne
+    let near = sample_metrics(now + Duration::minutes(1), 10.5);
+    assert_eq!(save_sample(&db.pool, &mut last_saved, near, Some(1.0)).await.unwrap(), SaveOutcome::Touched(id));
+    assert_eq!(count_metrics(&db.pool).await.unwrap(), 1);
+
+    let sample_count: i32 = sqlx::query_scalar(&format!("SELECT sample_count FROM {} WHERE id = $1", table::metrics_table()))
+        .bind(id)
+        .fetch_one(&db.pool)
+        .await
+        .unwrap();
+    assert_eq!(sample_count, 2);
+
+    // CPU sa zmení o 20 bodov - nový riadok
+    let changed = sample_metrics(now + Duration::minutes(2), 30.0);
+    let outcome = save_sample(&db.pool, &mut last_saved, changed, Some(1.0)).await.unwrap();
+    assert!(matches!(outcome, SaveOutcome::Inserted(new_id) if new_id != id));
+    assert_eq!(count_metrics(&db.pool).await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn without_dedupe_every_sample_is_a_new_row() {
+    use system_monitor::modes::saver::save_sample;
+
+    let Some(db) = test_db().await else { return };
+    let now = Utc::now();
+    let mut last_saved = None;
+
+    save_sample(&db.pool, &mut last_saved, sample_metrics(now, 10.0), None).await.unwrap();
+    save_sample(&db.pool, &mut last_saved, sample_metrics(now + Duration::minutes(1), 10.0), None).await.unwrap();
+    assert_eq!(count_metrics(&db.pool).await.unwrap(), 2);
+}
+
+#[tokio::test]
+async fn process_history_returns_the_named_process_in_order() {
+    use system_monitor::models::ProcessInfo;
+    use system_monitor::test_support::sample_process;
+
+    let Some(db) = test_db().await else { return };
+    let now = chrono::DateTime::from_timestamp_micros(Utc::now().timestamp_micros()).unwrap();  // Presnosť `TIMESTAMPTZ`
+    let process = |pid: u32, name: &str, cpu_usage: f32| ProcessInfo { cpu_usage, memory: 1024, ..sample_process(pid, name) };
+
+    save_process_metrics(&db.pool, now - Duration::hours(30), &[process(10, "postgres", 90.0)]).await.unwrap();  // Mimo okna
+    save_process_metrics(&db.pool, now - Duration::minutes(2), &[process(10, "postgres", 5.0), process(20, "nginx", 1.0)]).await.unwrap();
+    save_process_metrics(&db.pool, now, &[process(10, "postgres", 7.5)]).await.unwrap();
+
+    let history = get_process_history(&db.pool, "postgres", 24).await.unwrap();
+
+    let points: Vec<(i64, f32)> = history.iter().map(|point| (point.pid, point.cpu_usage)).collect();
+    assert_eq!(points, [(10, 5.0), (10, 7.5)]);
+    assert_eq!(history[1].timestamp, now);
+    assert_eq!(history[1].memory, 1024);
+    assert!(get_process_history(&db.pool, "missing", 24).await.unwrap().is_empty());
+}